@@ -5,4 +5,7 @@ fn main() {
     let _ = datetime!(2021 - 001 24:00);
     let _ = datetime!(2021 - 001 0:00 0);
     let _ = datetime!(2021 - 001 0:00 UTC x);
+    let _ = datetime!(2024 - 03 - 10 2:30 America/New_York);
+    let _ = datetime!(2024 - 11 - 03 1:30 America/New_York);
+    let _ = datetime!(2024 - 01 - 01 0:00 Mars/Olympus_Mons);
 }
@@ -0,0 +1,376 @@
+use time::cron::CronExpression;
+use time::error::InvalidCronExpression;
+use time::macros::datetime;
+use time::{Date, OffsetDateTime, Result};
+
+/// The first `limit` occurrences of the expression after the provided instant.
+fn occurrences(expression: &str, start: OffsetDateTime, limit: usize) -> Vec<OffsetDateTime> {
+    CronExpression::parse(expression)
+        .unwrap()
+        .occurrences(start)
+        .take(limit)
+        .collect()
+}
+
+fn malformed(expression: &str) -> Option<usize> {
+    match CronExpression::parse(expression) {
+        Err(InvalidCronExpression::Malformed { index, .. }) => Some(index),
+        _ => None,
+    }
+}
+
+fn out_of_range(expression: &str) -> Option<(&'static str, usize)> {
+    match CronExpression::parse(expression) {
+        Err(InvalidCronExpression::OutOfRange { field, index, .. }) => Some((field, index)),
+        _ => None,
+    }
+}
+
+fn field_count(expression: &str) -> Option<usize> {
+    match CronExpression::parse(expression) {
+        Err(InvalidCronExpression::FieldCount { count, .. }) => Some(count),
+        _ => None,
+    }
+}
+
+#[test]
+fn parse() -> Result<()> {
+    assert_eq!(
+        CronExpression::parse("0 0 * * *")?,
+        CronExpression::parse("0 0 0 * * *")?
+    );
+    assert_eq!(
+        CronExpression::parse("0 0 * * 7")?,
+        CronExpression::parse("0 0 * * 0")?
+    );
+    assert_eq!(
+        CronExpression::parse("0 0 * * sun")?,
+        CronExpression::parse("@weekly")?
+    );
+    assert_eq!(
+        CronExpression::parse("0 0 1 1 *")?,
+        CronExpression::parse("@ANNUALLY")?
+    );
+    assert_eq!(
+        CronExpression::parse("@yearly")?,
+        CronExpression::parse("@annually")?
+    );
+    assert_eq!(
+        CronExpression::parse("@daily")?,
+        CronExpression::parse("@midnight")?
+    );
+    assert_eq!(
+        CronExpression::parse("*/20 * * * *")?,
+        CronExpression::parse("0,20,40 * * * *")?
+    );
+    assert_eq!(
+        CronExpression::parse("1-10/3 * * * *")?,
+        CronExpression::parse("1,4,7,10 * * * *")?
+    );
+    assert_eq!(
+        CronExpression::parse("5/20 * * * *")?,
+        CronExpression::parse("5,25,45 * * * *")?
+    );
+    assert_eq!(
+        CronExpression::parse("0 0 * Jan-MAR *")?,
+        CronExpression::parse("0 0 * 1-3 *")?
+    );
+    assert_eq!(
+        CronExpression::parse("0 0 * * 5-7")?,
+        CronExpression::parse("0 0 * * 0,5,6")?
+    );
+    assert_eq!(
+        CronExpression::parse("  0\t0 * * *  ")?,
+        CronExpression::parse("0 0 * * *")?
+    );
+    assert_eq!(
+        "0 0 L * ?".parse::<CronExpression>()?,
+        CronExpression::parse("0 0 l * *")?
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_err() {
+    assert_eq!(field_count(""), Some(0));
+    assert_eq!(field_count("* * * *"), Some(4));
+    assert_eq!(field_count("* * * * * * *"), Some(7));
+    assert_eq!(field_count("@daily *"), Some(2));
+
+    assert_eq!(malformed("@reboot"), Some(1));
+    assert_eq!(malformed("*/ * * * *"), Some(2));
+    assert_eq!(malformed("1- * * * *"), Some(2));
+    assert_eq!(malformed("a * * * *"), Some(0));
+    assert_eq!(malformed("1,,2 * * * *"), Some(2));
+    assert_eq!(malformed("* L * * *"), Some(2));
+    assert_eq!(malformed("* * * * L"), Some(8));
+    assert_eq!(malformed("* * * * ?/2"), Some(9));
+    assert_eq!(malformed("* * * * MONDAY"), Some(11));
+    assert_eq!(malformed("* * 1W-3 * *"), Some(6));
+    assert_eq!(malformed("* * * * 1#"), Some(10));
+
+    assert_eq!(out_of_range("60 * * * * *"), Some(("second", 0)));
+    assert_eq!(out_of_range("60 * * * *"), Some(("minute", 0)));
+    assert_eq!(out_of_range("* 24 * * *"), Some(("hour", 2)));
+    assert_eq!(out_of_range("* * 0 * *"), Some(("day of month", 4)));
+    assert_eq!(out_of_range("* * 32W * *"), Some(("day of month", 4)));
+    assert_eq!(out_of_range("* * * 13 *"), Some(("month", 6)));
+    assert_eq!(out_of_range("* * * * 8"), Some(("day of week", 8)));
+    assert_eq!(out_of_range("* * * * 1#6"), Some(("day of week", 10)));
+    assert_eq!(out_of_range("*/0 * * * *"), Some(("minute", 2)));
+    assert_eq!(out_of_range("5-1 * * * *"), Some(("minute", 2)));
+    assert_eq!(out_of_range("99999999999 * * * *"), Some(("minute", 0)));
+}
+
+#[test]
+fn next_occurrence() -> Result<()> {
+    let cron = CronExpression::parse("*/15 9-17 * * MON-FRI")?;
+    assert_eq!(
+        cron.next_occurrence(datetime!(2024-01-05 17:50 UTC)),
+        Some(datetime!(2024-01-08 9:00 UTC))
+    );
+    assert_eq!(
+        cron.next_occurrence(datetime!(2024-01-08 9:00 UTC)),
+        Some(datetime!(2024-01-08 9:15 UTC))
+    );
+    assert_eq!(
+        cron.next_occurrence(datetime!(2024-01-08 9:14:59.999 UTC)),
+        Some(datetime!(2024-01-08 9:15 UTC))
+    );
+
+    let cron = CronExpression::parse("* * * * *")?;
+    assert_eq!(
+        cron.next_occurrence(datetime!(2024-01-01 12:00:00.5 UTC)),
+        Some(datetime!(2024-01-01 12:01 UTC))
+    );
+
+    let cron = CronExpression::parse("*/10 * * * * *")?;
+    assert_eq!(
+        cron.next_occurrence(datetime!(2024-01-01 12:00:05.5 UTC)),
+        Some(datetime!(2024-01-01 12:00:10 UTC))
+    );
+    assert_eq!(
+        cron.next_occurrence(datetime!(2024-01-01 23:59:50 UTC)),
+        Some(datetime!(2024-01-02 0:00 UTC))
+    );
+
+    let cron = CronExpression::parse("0 0,30 * * * *")?;
+    assert_eq!(
+        cron.next_occurrence(datetime!(2024-01-01 12:30 UTC)),
+        Some(datetime!(2024-01-01 13:00 UTC))
+    );
+
+    let cron = CronExpression::parse("59 23 31 12 *")?;
+    assert_eq!(
+        cron.next_occurrence(datetime!(2024-12-31 23:59 UTC)),
+        Some(datetime!(2025-12-31 23:59 UTC))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn offset() -> Result<()> {
+    let cron = CronExpression::parse("0 9 * * *")?;
+    assert_eq!(
+        cron.next_occurrence(datetime!(2024-01-01 10:00 +2)),
+        Some(datetime!(2024-01-02 9:00 +2))
+    );
+    assert_eq!(
+        cron.next_occurrence(datetime!(2024-01-01 8:00 UTC)),
+        Some(datetime!(2024-01-01 9:00 UTC))
+    );
+    assert_eq!(
+        cron.previous_occurrence(datetime!(2024-01-01 8:00 -5)),
+        Some(datetime!(2023-12-31 9:00 -5))
+    );
+    Ok(())
+}
+
+#[test]
+fn previous_occurrence() -> Result<()> {
+    let cron = CronExpression::parse("30 9 * * *")?;
+    assert_eq!(
+        cron.previous_occurrence(datetime!(2024-01-01 9:30 UTC)),
+        Some(datetime!(2023-12-31 9:30 UTC))
+    );
+    assert_eq!(
+        cron.previous_occurrence(datetime!(2024-01-01 9:30:00.5 UTC)),
+        Some(datetime!(2024-01-01 9:30 UTC))
+    );
+
+    let cron = CronExpression::parse("*/10 * * * * *")?;
+    assert_eq!(
+        cron.previous_occurrence(datetime!(2024-01-01 0:00 UTC)),
+        Some(datetime!(2023-12-31 23:59:50 UTC))
+    );
+
+    let cron = CronExpression::parse("0 0 L 2 *")?;
+    assert_eq!(
+        cron.previous_occurrence(datetime!(2024-02-29 0:00 UTC)),
+        Some(datetime!(2023-02-28 0:00 UTC))
+    );
+
+    let cron = CronExpression::parse("*/15 9-17 * * MON-FRI")?;
+    assert_eq!(
+        cron.previous_occurrence(datetime!(2024-01-08 9:00 UTC)),
+        Some(datetime!(2024-01-05 17:45 UTC))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn day_of_month_or_day_of_week() {
+    // Both fields are restricted, so either may match.
+    assert_eq!(
+        occurrences("0 0 13 * 5", datetime!(2024-01-01 0:00 UTC), 4),
+        [
+            datetime!(2024-01-05 0:00 UTC),
+            datetime!(2024-01-12 0:00 UTC),
+            datetime!(2024-01-13 0:00 UTC),
+            datetime!(2024-01-19 0:00 UTC),
+        ]
+    );
+    // The day of month field begins with a wildcard, so both must match.
+    assert_eq!(
+        occurrences("0 0 */2 * 5", datetime!(2024-01-01 0:00 UTC), 4),
+        [
+            datetime!(2024-01-05 0:00 UTC),
+            datetime!(2024-01-19 0:00 UTC),
+            datetime!(2024-02-09 0:00 UTC),
+            datetime!(2024-02-23 0:00 UTC),
+        ]
+    );
+    assert_eq!(
+        occurrences("0 0 13 * ?", datetime!(2024-01-01 0:00 UTC), 2),
+        [
+            datetime!(2024-01-13 0:00 UTC),
+            datetime!(2024-02-13 0:00 UTC),
+        ]
+    );
+}
+
+#[test]
+fn last_day_of_month() {
+    assert_eq!(
+        occurrences("0 0 L * *", datetime!(2024-01-15 0:00 UTC), 4),
+        [
+            datetime!(2024-01-31 0:00 UTC),
+            datetime!(2024-02-29 0:00 UTC),
+            datetime!(2024-03-31 0:00 UTC),
+            datetime!(2024-04-30 0:00 UTC),
+        ]
+    );
+    assert_eq!(
+        occurrences("0 0 L 2 *", datetime!(2023-03-01 0:00 UTC), 2),
+        [
+            datetime!(2024-02-29 0:00 UTC),
+            datetime!(2025-02-28 0:00 UTC),
+        ]
+    );
+    assert_eq!(
+        occurrences("0 0 LW * *", datetime!(2024-01-01 0:00 UTC), 4),
+        [
+            datetime!(2024-01-31 0:00 UTC),
+            datetime!(2024-02-29 0:00 UTC),
+            datetime!(2024-03-29 0:00 UTC),
+            datetime!(2024-04-30 0:00 UTC),
+        ]
+    );
+}
+
+#[test]
+fn nearest_weekday() {
+    assert_eq!(
+        occurrences("0 0 1W * *", datetime!(2024-05-31 0:00 UTC), 4),
+        [
+            datetime!(2024-06-03 0:00 UTC),
+            datetime!(2024-07-01 0:00 UTC),
+            datetime!(2024-08-01 0:00 UTC),
+            datetime!(2024-09-02 0:00 UTC),
+        ]
+    );
+    assert_eq!(
+        occurrences("0 0 31W * *", datetime!(2024-03-01 0:00 UTC), 4),
+        [
+            datetime!(2024-03-29 0:00 UTC),
+            datetime!(2024-05-31 0:00 UTC),
+            datetime!(2024-07-31 0:00 UTC),
+            datetime!(2024-08-30 0:00 UTC),
+        ]
+    );
+    assert_eq!(
+        occurrences("0 0 15W * *", datetime!(2024-06-01 0:00 UTC), 3),
+        [
+            datetime!(2024-06-14 0:00 UTC),
+            datetime!(2024-07-15 0:00 UTC),
+            datetime!(2024-08-15 0:00 UTC),
+        ]
+    );
+}
+
+#[test]
+fn day_of_week_extensions() {
+    assert_eq!(
+        occurrences("0 0 * * 5L", datetime!(2024-01-01 0:00 UTC), 3),
+        [
+            datetime!(2024-01-26 0:00 UTC),
+            datetime!(2024-02-23 0:00 UTC),
+            datetime!(2024-03-29 0:00 UTC),
+        ]
+    );
+    assert_eq!(
+        occurrences("0 0 * * MON#2", datetime!(2024-01-01 0:00 UTC), 3),
+        [
+            datetime!(2024-01-08 0:00 UTC),
+            datetime!(2024-02-12 0:00 UTC),
+            datetime!(2024-03-11 0:00 UTC),
+        ]
+    );
+    assert_eq!(
+        occurrences("0 0 * * 1#1,5L", datetime!(2024-01-01 0:00 UTC), 4),
+        [
+            datetime!(2024-01-26 0:00 UTC),
+            datetime!(2024-02-05 0:00 UTC),
+            datetime!(2024-02-23 0:00 UTC),
+            datetime!(2024-03-04 0:00 UTC),
+        ]
+    );
+}
+
+#[test]
+fn leap_day() {
+    assert_eq!(
+        occurrences("0 0 29 2 *", datetime!(2024-03-01 0:00 UTC), 2),
+        [
+            datetime!(2028-02-29 0:00 UTC),
+            datetime!(2032-02-29 0:00 UTC),
+        ]
+    );
+}
+
+#[test]
+fn no_occurrences() -> Result<()> {
+    for expression in ["0 0 30 2 *", "0 0 31 4,6,9,11 *"] {
+        let cron = CronExpression::parse(expression)?;
+        assert_eq!(cron.next_occurrence(datetime!(2024-01-01 0:00 UTC)), None);
+        assert_eq!(
+            cron.previous_occurrence(datetime!(2024-01-01 0:00 UTC)),
+            None
+        );
+
+        let mut occurrences = cron.occurrences(datetime!(2024-01-01 0:00 UTC));
+        assert_eq!(occurrences.next(), None);
+        assert_eq!(occurrences.next(), None);
+    }
+
+    let cron = CronExpression::parse("0 0 1 1 *")?;
+    let last = Date::MAX.midnight().assume_utc();
+    assert_eq!(cron.next_occurrence(last), None);
+    assert!(cron.previous_occurrence(last).is_some());
+    let first = Date::MIN.midnight().assume_utc();
+    assert_eq!(cron.previous_occurrence(first), None);
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use std::collections::HashSet;
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, time};
-use time::{util, Date, Duration, Month, Weekday};
+use time::{util, Date, Duration, Era, Month, Weekday};
 
 #[test]
 fn debug() {
@@ -461,6 +461,37 @@ fn day() {
     assert_eq!(date!(2020 - 060).day(), 29);
 }
 
+#[test]
+fn quarter() {
+    assert_eq!(date!(2019 - 01 - 01).quarter(), 1);
+    assert_eq!(date!(2019 - 03 - 31).quarter(), 1);
+    assert_eq!(date!(2019 - 04 - 01).quarter(), 2);
+    assert_eq!(date!(2020 - 09 - 30).quarter(), 3);
+    assert_eq!(date!(2020 - 10 - 01).quarter(), 4);
+    assert_eq!(date!(-0043 - 12 - 31).quarter(), 4);
+}
+
+#[test]
+fn era() {
+    assert_eq!(date!(2019 - 01 - 01).era(), Era::Ce);
+    assert_eq!(date!(0001 - 01 - 01).era(), Era::Ce);
+    assert_eq!(date!(0000 - 12 - 31).era(), Era::Bce);
+    assert_eq!(date!(-0043 - 03 - 15).era(), Era::Bce);
+    assert_eq!(Date::MIN.era(), Era::Bce);
+    assert_eq!(Date::MAX.era(), Era::Ce);
+}
+
+#[test]
+fn year_of_era() {
+    assert_eq!(date!(2019 - 01 - 01).year_of_era(), 2019);
+    assert_eq!(date!(0001 - 01 - 01).year_of_era(), 1);
+    assert_eq!(date!(0000 - 12 - 31).year_of_era(), 1);
+    assert_eq!(date!(-0001 - 12 - 31).year_of_era(), 2);
+    assert_eq!(date!(-0043 - 03 - 15).year_of_era(), 44);
+    assert_eq!(Date::MIN.year_of_era(), (1 - Date::MIN.year()) as u32);
+    assert_eq!(Date::MAX.year_of_era(), Date::MAX.year() as u32);
+}
+
 #[test]
 fn iso_week() {
     assert_eq!(date!(2019 - 01 - 01).iso_week(), 1);
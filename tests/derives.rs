@@ -6,7 +6,7 @@ use time::error::{self, ConversionRange, IndeterminateOffset, TryFromParsed};
 use time::ext::NumericalDuration;
 use time::format_description::{self, modifier, well_known, Component, FormatItem, OwnedFormatItem};
 use time::macros::{date, offset, time};
-use time::parsing::Parsed;
+use time::parsing::{Defaults, Parsed, WithDefaults};
 use time::{Duration, Error, Instant, Month, Time, Weekday};
 use time_macros::datetime;
 
@@ -48,8 +48,17 @@ fn clone() {
     assert_cloned_eq!(error::InvalidVariant);
     assert_cloned_eq!(error::ParseFromDescription::InvalidComponent("foo"));
     assert_cloned_eq!(Component::OffsetSecond(modifier::OffsetSecond::default()));
+    assert_cloned_eq!(well_known::HttpDate);
     assert_cloned_eq!(well_known::Rfc2822);
+    assert_cloned_eq!(well_known::Rfc3164::DEFAULT);
+    assert_cloned_eq!(Defaults::new().with_year(2021));
+    let _ = WithDefaults::new(&well_known::Rfc3164::DEFAULT, Defaults::new()).clone();
     assert_cloned_eq!(well_known::Rfc3339);
+    assert_cloned_eq!(well_known::Rfc5424);
+    assert_cloned_eq!(well_known::Rfc5545);
+    assert_cloned_eq!(well_known::UtcTime);
+    assert_cloned_eq!(well_known::GeneralizedTime);
+    assert_cloned_eq!(well_known::Sql);
     assert_cloned_eq!(well_known::Iso8601::DEFAULT);
     assert_cloned_eq!(well_known::iso8601::FormattedComponents::None);
     assert_cloned_eq!(well_known::iso8601::DateKind::Calendar);
@@ -57,6 +66,17 @@ fn clone() {
         decimal_digits: None
     });
     assert_cloned_eq!(well_known::iso8601::OffsetPrecision::Hour);
+    assert_cloned_eq!(
+        well_known::Rfc3339::<
+            {
+                well_known::rfc3339::Config::DEFAULT
+                    .set_use_z_for_utc(false)
+                    .encode()
+            },
+        >::FORMAT
+    );
+    assert_cloned_eq!(well_known::rfc3339::SubsecondDigits::Fixed(3));
+    assert_cloned_eq!(well_known::rfc3339::Separator::Space);
     assert_cloned_eq!(well_known::iso8601::FormattedComponents::None);
     assert_cloned_eq!(component_range_error());
     assert_cloned_eq!(FormatItem::Literal(b""));
@@ -141,14 +161,29 @@ fn debug() {
         Instant::now();
         error::ParseFromDescription::InvalidComponent("foo");
         error::Format::InvalidComponent("foo");
+        well_known::HttpDate;
         well_known::Rfc2822;
+        well_known::Rfc3164::DEFAULT;
+        Defaults::default();
+        WithDefaults::new(&well_known::Rfc3164::DEFAULT, Defaults::new());
         well_known::Rfc3339;
+        well_known::Rfc5424;
+        well_known::Rfc5545;
+        well_known::UtcTime;
+        well_known::GeneralizedTime;
+        well_known::Sql;
         well_known::Iso8601::DEFAULT;
         well_known::iso8601::FormattedComponents::None;
         well_known::iso8601::DateKind::Calendar;
         well_known::iso8601::TimePrecision::Hour { decimal_digits: None };
         well_known::iso8601::OffsetPrecision::Hour;
         well_known::iso8601::Config::DEFAULT;
+        well_known::Rfc3339::<
+            { well_known::rfc3339::Config::DEFAULT.set_use_z_for_utc(false).encode() },
+        >::FORMAT;
+        well_known::rfc3339::SubsecondDigits::Fixed(3);
+        well_known::rfc3339::Separator::Space;
+        well_known::rfc3339::Config::DEFAULT;
         component_range_error();
         Error::ConversionRange(ConversionRange);
         time::util::local_offset::Soundness::Sound;
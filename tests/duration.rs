@@ -531,6 +531,88 @@ fn display() {
     );
 }
 
+#[test]
+fn from_str() {
+    for duration in [
+        0.seconds(),
+        60.days(),
+        (-48).hours(),
+        26.hours(),
+        93_784_005_006_007.nanoseconds(),
+        (-93_784_005_006_007).nanoseconds(),
+        Duration::MAX,
+        Duration::MIN,
+    ] {
+        assert_eq!(duration.to_string().parse(), Ok(duration));
+    }
+    assert_eq!(
+        format!("{:.3}", 26.hours()).parse(),
+        Ok(93_571_200.milliseconds())
+    );
+    assert_eq!(
+        format!("{:.2}", 1_500.microseconds()).parse(),
+        Ok(1_500.microseconds())
+    );
+
+    assert_eq!("1h30m".parse(), Ok(90.minutes()));
+    assert_eq!("+1h30m".parse(), Ok(90.minutes()));
+    assert_eq!("-1h30m".parse(), Ok((-90).minutes()));
+    assert_eq!("1 hour 30 minutes".parse(), Ok(90.minutes()));
+    assert_eq!("2 Days, 4 HOURS".parse(), Ok(52.hours()));
+    assert_eq!("1w 1d".parse(), Ok(8.days()));
+    assert_eq!("3 weeks".parse(), Ok(21.days()));
+    assert_eq!("5 secs".parse(), Ok(5.seconds()));
+    assert_eq!("5 sec".parse(), Ok(5.seconds()));
+    assert_eq!("2 hrs".parse(), Ok(2.hours()));
+    assert_eq!("1 millisecond".parse(), Ok(1.milliseconds()));
+    assert_eq!("1us 1μs 1µs".parse(), Ok(3.microseconds()));
+    assert_eq!("7 nanoseconds".parse(), Ok(7.nanoseconds()));
+    assert_eq!("30m1h30m".parse(), Ok(2.hours()));
+
+    assert_eq!("1.5h".parse(), Ok(90.minutes()));
+    assert_eq!(".5s".parse(), Ok(500.milliseconds()));
+    assert_eq!("1.s".parse(), Ok(1.seconds()));
+    assert_eq!("-1.5s".parse(), Ok((-1_500).milliseconds()));
+    assert_eq!("0.0000000019s".parse(), Ok(1.nanoseconds()));
+    assert_eq!("1.0000000000000000000001w".parse(), Ok(1.weeks()));
+}
+
+#[test]
+fn from_str_error() {
+    use time::error::InvalidDuration;
+
+    macro_rules! assert_err {
+        ($s:expr, $variant:ident, $index:expr) => {
+            assert!(matches!(
+                $s.parse::<Duration>(),
+                Err(InvalidDuration::$variant { index, .. }) if index == $index
+            ))
+        };
+    }
+
+    assert_err!("", ExpectedNumber, 0);
+    assert_err!("-", ExpectedNumber, 1);
+    assert_err!(" 1h", ExpectedNumber, 0);
+    assert_err!("1h ", ExpectedNumber, 3);
+    assert_err!("1h,", ExpectedNumber, 3);
+    assert_err!("1h ,2m", ExpectedNumber, 3);
+    assert_err!("1h--2m", ExpectedNumber, 2);
+    assert_err!(".h", ExpectedNumber, 0);
+    assert_err!("1", InvalidUnit, 1);
+    assert_err!("1h30", InvalidUnit, 4);
+    assert_err!("1 fortnight", InvalidUnit, 2);
+    assert_err!("1µ", InvalidUnit, 1);
+    assert_err!("1h 1.5.s", InvalidUnit, 6);
+    assert_err!("9223372036854775808s", OutOfRange, 0);
+    assert_err!("1s 9223372036854775807s", OutOfRange, 3);
+    assert_err!("99999999999999999999999999999999999999999w", OutOfRange, 0);
+
+    assert_eq!(
+        "1h 1x".parse::<Duration>().unwrap_err().to_string(),
+        "invalid unit at byte index 4"
+    );
+}
+
 #[test]
 fn try_from_std_duration() {
     assert_eq!(Duration::try_from(0.std_seconds()), Ok(0.seconds()));
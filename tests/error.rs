@@ -3,7 +3,9 @@ use std::io;
 
 use time::error::{
     ComponentRange, ConversionRange, DifferentVariant, Error, Format, IndeterminateOffset,
-    InvalidFormatDescription, InvalidVariant, Parse, ParseFromDescription, TryFromParsed,
+    InvalidCronExpression, InvalidDuration, InvalidFormatDescription, InvalidRecurrenceRule,
+    InvalidRelativeTime, InvalidTimeZone, InvalidVariant, Located, Parse, ParseFromDescription,
+    TryFromParsed, UnresolvedLocalTime,
 };
 use time::macros::format_description;
 use time::parsing::Parsed;
@@ -44,10 +46,34 @@ fn unexpected_trailing_characters() -> Parse {
     Time::parse("a", format_description!("")).unwrap_err()
 }
 
+fn located() -> Located<Parse> {
+    Date::parse_located("2024-Foo-01", format_description!("[year]-[month]-[day]")).unwrap_err()
+}
+
 fn invalid_format_description() -> InvalidFormatDescription {
     format_description::parse("[").unwrap_err()
 }
 
+fn invalid_time_zone() -> InvalidTimeZone {
+    time::zone::PosixTz::parse("").unwrap_err()
+}
+
+fn invalid_duration() -> InvalidDuration {
+    "".parse::<time::Duration>().unwrap_err()
+}
+
+fn invalid_relative_time() -> InvalidRelativeTime {
+    time::relative::parse("", time::OffsetDateTime::UNIX_EPOCH).unwrap_err()
+}
+
+fn invalid_recurrence_rule() -> InvalidRecurrenceRule {
+    time::recurrence::RecurrenceRule::parse("").unwrap_err()
+}
+
+fn invalid_cron_expression() -> InvalidCronExpression {
+    time::cron::CronExpression::parse("").unwrap_err()
+}
+
 fn io_error() -> io::Error {
     io::Error::last_os_error()
 }
@@ -59,6 +85,7 @@ fn invalid_literal() -> ParseFromDescription {
 #[test]
 fn debug() {
     assert_dbg_reflexive!(Parse::from(ParseFromDescription::InvalidComponent("a")));
+    assert_dbg_reflexive!(located());
     assert_dbg_reflexive!(invalid_format_description());
     assert_dbg_reflexive!(DifferentVariant);
     assert_dbg_reflexive!(InvalidVariant);
@@ -103,6 +130,7 @@ fn display() {
         unexpected_trailing_characters(),
         Error::from(unexpected_trailing_characters()),
     );
+    assert_display_eq!(located(), Error::from(located()));
     assert_display_eq!(
         invalid_format_description(),
         Error::from(invalid_format_description())
@@ -110,6 +138,28 @@ fn display() {
     assert_display_eq!(io_error(), Format::from(io_error()));
     assert_display_eq!(DifferentVariant, Error::from(DifferentVariant));
     assert_display_eq!(InvalidVariant, Error::from(InvalidVariant));
+    assert_display_eq!(
+        UnresolvedLocalTime::Ambiguous,
+        Error::from(UnresolvedLocalTime::Ambiguous)
+    );
+    assert_display_eq!(
+        UnresolvedLocalTime::Nonexistent,
+        Error::from(UnresolvedLocalTime::Nonexistent)
+    );
+    assert_display_eq!(invalid_time_zone(), Error::from(invalid_time_zone()));
+    assert_display_eq!(invalid_duration(), Error::from(invalid_duration()));
+    assert_display_eq!(
+        invalid_relative_time(),
+        Error::from(invalid_relative_time())
+    );
+    assert_display_eq!(
+        invalid_recurrence_rule(),
+        Error::from(invalid_recurrence_rule())
+    );
+    assert_display_eq!(
+        invalid_cron_expression(),
+        Error::from(invalid_cron_expression())
+    );
 }
 
 #[test]
@@ -138,6 +188,8 @@ fn source() {
         Error::from(ParseFromDescription::InvalidComponent("a")),
         ParseFromDescription
     );
+    assert_source!(located(), Parse);
+    assert_source!(Error::from(located()), Located<Parse>);
     assert_source!(unexpected_trailing_characters(), None);
     assert_source!(Error::from(unexpected_trailing_characters()), None);
     assert_source!(
@@ -147,6 +199,21 @@ fn source() {
     assert_source!(Format::from(io_error()), io::Error);
     assert_source!(Error::from(DifferentVariant), DifferentVariant);
     assert_source!(Error::from(InvalidVariant), InvalidVariant);
+    assert_source!(
+        Error::from(UnresolvedLocalTime::Ambiguous),
+        UnresolvedLocalTime
+    );
+    assert_source!(Error::from(invalid_time_zone()), InvalidTimeZone);
+    assert_source!(Error::from(invalid_duration()), InvalidDuration);
+    assert_source!(Error::from(invalid_relative_time()), InvalidRelativeTime);
+    assert_source!(
+        Error::from(invalid_recurrence_rule()),
+        InvalidRecurrenceRule
+    );
+    assert_source!(
+        Error::from(invalid_cron_expression()),
+        InvalidCronExpression
+    );
 }
 
 #[test]
@@ -154,6 +221,56 @@ fn component_name() {
     assert_eq!(component_range().name(), "ordinal");
 }
 
+#[test]
+fn location() {
+    let err = located();
+    assert_eq!(err.offset(), Some(5));
+    assert_eq!(err.snippet(), Some("Foo-01"));
+    assert_eq!(
+        err.to_string(),
+        "the 'month' component could not be parsed at byte index 5 (found \"Foo-01\")"
+    );
+    assert_display_eq!(err.into_inner(), Parse::from(err));
+    assert_eq!(
+        Date::parse("2024-Foo-01", format_description!("[year]-[month]-[day]")),
+        Err(err.into_inner())
+    );
+
+    // The location survives conversion to `time::Error`.
+    let parse = || -> time::Result<Date> {
+        Ok(Date::parse_located(
+            "2024-Foo-01",
+            format_description!("[year]-[month]-[day]"),
+        )?)
+    };
+    let error = parse().unwrap_err();
+    assert_display_eq!(err, error);
+    assert_eq!(Located::<Parse>::try_from(error), Ok(err));
+
+    let err =
+        Date::parse_prefix("2024-01", format_description!("[year]-[month]-[day]")).unwrap_err();
+    assert_eq!(err.offset(), Some(7));
+    assert_eq!(err.snippet(), Some(""));
+    assert_eq!(
+        err.to_string(),
+        "a character literal was not valid at byte index 7 (found end of input)"
+    );
+
+    // The snippet does not split a multi-byte character.
+    let err = Time::parse_prefix("12:0€", format_description!("[hour]:[minute]")).unwrap_err();
+    assert_eq!(err.offset(), Some(3));
+    assert_eq!(err.snippet(), Some("0€"));
+    let err =
+        Time::parse_prefix("12:ééééééééé", format_description!("[hour]:[minute]")).unwrap_err();
+    assert_eq!(err.snippet(), Some("éééééééé"));
+
+    let err =
+        Date::parse_prefix("2023-02-29", format_description!("[year]-[month]-[day]")).unwrap_err();
+    assert_eq!(err.offset(), None);
+    assert_eq!(err.snippet(), None);
+    assert_eq!(err.to_string(), err.into_inner().to_string());
+}
+
 #[test]
 fn conversion() {
     assert!(ComponentRange::try_from(Error::from(component_range())).is_ok());
@@ -166,8 +283,17 @@ fn conversion() {
     assert!(Parse::try_from(Error::from(unexpected_trailing_characters())).is_ok());
     assert!(Parse::try_from(Error::from(invalid_literal())).is_ok());
     assert!(Parse::try_from(Error::from(TryFromParsed::InsufficientInformation)).is_ok());
+    assert!(Parse::try_from(Error::from(located())).is_ok());
+    assert!(ParseFromDescription::try_from(Error::from(located())).is_ok());
+    assert!(Located::<Parse>::try_from(Error::from(located())).is_ok());
     assert!(DifferentVariant::try_from(Error::from(DifferentVariant)).is_ok());
     assert!(InvalidVariant::try_from(Error::from(InvalidVariant)).is_ok());
+    assert!(UnresolvedLocalTime::try_from(Error::from(UnresolvedLocalTime::Ambiguous)).is_ok());
+    assert!(InvalidTimeZone::try_from(Error::from(invalid_time_zone())).is_ok());
+    assert!(InvalidDuration::try_from(Error::from(invalid_duration())).is_ok());
+    assert!(InvalidRelativeTime::try_from(Error::from(invalid_relative_time())).is_ok());
+    assert!(InvalidRecurrenceRule::try_from(Error::from(invalid_recurrence_rule())).is_ok());
+    assert!(InvalidCronExpression::try_from(Error::from(invalid_cron_expression())).is_ok());
     assert!(ComponentRange::try_from(TryFromParsed::ComponentRange(component_range())).is_ok());
     assert!(TryFromParsed::try_from(Error::from(TryFromParsed::InsufficientInformation)).is_ok());
     assert!(TryFromParsed::try_from(Parse::from(TryFromParsed::InsufficientInformation)).is_ok());
@@ -181,10 +307,19 @@ fn conversion() {
     assert!(ParseFromDescription::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(ParseFromDescription::try_from(unexpected_trailing_characters()).is_err());
     assert!(Parse::try_from(Error::from(IndeterminateOffset)).is_err());
+    assert!(Located::<Parse>::try_from(Error::from(IndeterminateOffset)).is_err());
+    assert!(Located::<Parse>::try_from(Error::from(invalid_literal())).is_err());
     assert!(DifferentVariant::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(InvalidVariant::try_from(Error::from(IndeterminateOffset)).is_err());
+    assert!(UnresolvedLocalTime::try_from(Error::from(IndeterminateOffset)).is_err());
+    assert!(InvalidTimeZone::try_from(Error::from(IndeterminateOffset)).is_err());
+    assert!(InvalidDuration::try_from(Error::from(IndeterminateOffset)).is_err());
+    assert!(InvalidRelativeTime::try_from(Error::from(IndeterminateOffset)).is_err());
+    assert!(InvalidRecurrenceRule::try_from(Error::from(IndeterminateOffset)).is_err());
+    assert!(InvalidCronExpression::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(ComponentRange::try_from(TryFromParsed::InsufficientInformation).is_err());
     assert!(TryFromParsed::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(TryFromParsed::try_from(unexpected_trailing_characters()).is_err());
+    assert!(TryFromParsed::try_from(Error::from(located())).is_err());
     assert!(std::io::Error::try_from(insufficient_type_information()).is_err());
 }
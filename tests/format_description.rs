@@ -1,4 +1,5 @@
-use time::format_description::{modifier, Component, FormatItem, OwnedFormatItem};
+use time::error::InvalidFormatDescription;
+use time::format_description::{self, modifier, Component, FormatItem, OwnedFormatItem};
 
 #[test]
 fn borrowed_format_item_component_conversions() {
@@ -66,3 +67,108 @@ fn owned_format_item_equality() {
         compound
     );
 }
+
+#[test]
+fn to_description_string() -> time::Result<()> {
+    let roundtrip = |description: &str| -> time::Result<String> {
+        let items = format_description::parse_borrowed::<2>(description)?;
+        Ok(FormatItem::Compound(&items).to_description_string::<2>()?)
+    };
+
+    assert_eq!(roundtrip("[year]-[month]-[day]")?, "[year]-[month]-[day]");
+    assert_eq!(
+        roundtrip("[ year   padding:zero repr:full ] [hour repr:12 PADDING:none]")?,
+        "[year] [hour padding:none repr:12]"
+    );
+    assert_eq!(
+        roundtrip("[ignore count:3][unix_timestamp precision:nanosecond sign:mandatory]")?,
+        "[ignore count:3][unix_timestamp precision:nanosecond sign:mandatory]"
+    );
+    assert_eq!(
+        roundtrip("[day suffix:true] [era repr:ce] [quarter] [zone_abbr]")?,
+        "[day suffix:true] [era repr:ce] [quarter] [zone_abbr]"
+    );
+    assert_eq!(roundtrip(r"\[\]\\ é")?, r"\[\]\\ é");
+
+    let items = format_description::parse_borrowed::<1>("[[[year]] ")?;
+    assert_eq!(
+        FormatItem::Compound(&items).to_description_string::<1>()?,
+        "[[[year]] "
+    );
+    assert_eq!(
+        FormatItem::Compound(&items).to_description_string::<2>()?,
+        r"\[[year]\] "
+    );
+
+    let item = format_description::parse_owned::<2>(
+        "[hour][optional [:[minute]]][first [ [offset_hour]] [\\]Z]]",
+    )?;
+    assert_eq!(
+        item.to_description_string::<2>()?,
+        "[hour][optional [:[minute]]][first [ [offset_hour]] [\\]Z]]"
+    );
+    assert_eq!(
+        OwnedFormatItem::First(Box::new([])).to_description_string::<1>()?,
+        "[first ]"
+    );
+    assert_eq!(
+        format_description::parse_owned::<1>("[first ]")?,
+        OwnedFormatItem::First(Box::new([]))
+    );
+    assert_eq!(
+        FormatItem::Component(Component::Month(modifier::Month::default()))
+            .to_description_string::<2>()?,
+        "[month]"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn to_description_string_error() {
+    assert!(matches!(
+        FormatItem::Compound(&[FormatItem::Literal(b"a"), FormatItem::Literal(b"\xFF")])
+            .to_description_string::<2>(),
+        Err(InvalidFormatDescription::NotSupported {
+            what: "non-UTF-8 literal",
+            index: 1,
+            ..
+        })
+    ));
+    assert!(matches!(
+        OwnedFormatItem::Optional(Box::new(OwnedFormatItem::Literal(Box::new(*b"]"))))
+            .to_description_string::<1>(),
+        Err(InvalidFormatDescription::NotSupported {
+            what: "closing bracket in nested literal",
+            context: "version 1 format descriptions",
+            index: 11,
+            ..
+        })
+    ));
+    assert!(matches!(
+        OwnedFormatItem::Optional(Box::new(OwnedFormatItem::Component(Component::Quarter(
+            modifier::Quarter::default()
+        ))))
+        .to_description_string::<1>(),
+        Err(InvalidFormatDescription::NotSupported {
+            what: "nested description starting with a bracket",
+            index: 11,
+            ..
+        })
+    ));
+    assert_eq!(
+        OwnedFormatItem::Optional(Box::new(OwnedFormatItem::Component(Component::Quarter(
+            modifier::Quarter::default()
+        ))))
+        .to_description_string::<2>(),
+        Ok("[optional [[quarter]]]".to_owned())
+    );
+
+    // Items that cannot be represented are reported as errors rather than panicking.
+    assert!(FormatItem::Literal(b"\xFF")
+        .to_description_string::<2>()
+        .is_err());
+    assert!(OwnedFormatItem::Literal(Box::new([0xFF]))
+        .to_description_string::<2>()
+        .is_err());
+}
@@ -1,10 +1,18 @@
 use std::io;
 
+use time::format_description::locale::{
+    English, French, German, Japanese, Locale, Localized, Spanish,
+};
 use time::format_description::well_known::iso8601::{DateKind, OffsetPrecision, TimePrecision};
-use time::format_description::well_known::{iso8601, Iso8601, Rfc2822, Rfc3339};
+use time::format_description::well_known::rfc3339::{Separator, SubsecondDigits};
+use time::format_description::well_known::{
+    iso8601, rfc3339, GeneralizedTime, HttpDate, Iso8601, Rfc2822, Rfc3164, Rfc3339, Rfc5424,
+    Rfc5545, Sql, UtcTime,
+};
 use time::format_description::{self, FormatItem, OwnedFormatItem};
 use time::macros::{date, datetime, format_description as fd, offset, time};
-use time::{OffsetDateTime, Time};
+use time::zone::{LocalTimeType, TimeZone, Transition, ZoneRules};
+use time::{Duration, OffsetDateTime, Time, UtcOffset};
 
 #[test]
 fn rfc_2822() -> time::Result<()> {
@@ -33,6 +41,37 @@ fn rfc_2822() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn http_date() -> time::Result<()> {
+    assert_eq!(
+        datetime!(1994-11-06 08:49:37 UTC).format(&HttpDate)?,
+        "Sun, 06 Nov 1994 08:49:37 GMT"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05.678 +06:07).format(&HttpDate)?,
+        "Fri, 01 Jan 2021 20:57:05 GMT"
+    );
+    assert_eq!(
+        datetime!(9999-12-31 23:59:59 UTC).format(&HttpDate)?,
+        "Fri, 31 Dec 9999 23:59:59 GMT"
+    );
+    assert_eq!(
+        datetime!(0000-01-01 00:00:00 UTC).format(&HttpDate)?,
+        "Sat, 01 Jan 0000 00:00:00 GMT"
+    );
+
+    assert!(matches!(
+        datetime!(9999-12-31 23:59:59 -01:00).format(&HttpDate),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(-0001-12-31 23:59:59 UTC).format(&HttpDate),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn rfc_3339() -> time::Result<()> {
     assert_eq!(
@@ -96,6 +135,284 @@ fn rfc_3339() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn rfc_3339_config() -> time::Result<()> {
+    const MILLIS: rfc3339::EncodedConfig = rfc3339::Config::DEFAULT
+        .set_subsecond_digits(SubsecondDigits::Fixed(3))
+        .encode();
+    const NO_SUBSECOND: rfc3339::EncodedConfig = rfc3339::Config::DEFAULT
+        .set_subsecond_digits(SubsecondDigits::Fixed(0))
+        .encode();
+    const NINE_DIGITS_OFFSET: rfc3339::EncodedConfig = rfc3339::Config::DEFAULT
+        .set_subsecond_digits(SubsecondDigits::Fixed(9))
+        .set_use_z_for_utc(false)
+        .encode();
+    const SPACE: rfc3339::EncodedConfig = rfc3339::Config::DEFAULT
+        .set_separator(Separator::Space)
+        .encode();
+
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 UTC).format(&Rfc3339::<MILLIS>::FORMAT)?,
+        "2021-01-02T03:04:05.000Z"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05.1 UTC).format(&Rfc3339::<MILLIS>::FORMAT)?,
+        "2021-01-02T03:04:05.100Z"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05.123_999 -01:02).format(&Rfc3339::<MILLIS>::FORMAT)?,
+        "2021-01-02T03:04:05.123-01:02"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05.999 UTC).format(&Rfc3339::<NO_SUBSECOND>::FORMAT)?,
+        "2021-01-02T03:04:05Z"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 UTC).format(&Rfc3339::<NINE_DIGITS_OFFSET>::FORMAT)?,
+        "2021-01-02T03:04:05.000000000+00:00"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05.12 UTC).format(&Rfc3339::<SPACE>::FORMAT)?,
+        "2021-01-02 03:04:05.12Z"
+    );
+
+    assert!(matches!(
+        datetime!(-0001-01-01 0:00 UTC).format(&Rfc3339::<MILLIS>::FORMAT),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn rfc_3164() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2024-03-03 14:05:09).format(&Rfc3164::DEFAULT)?,
+        "Mar  3 14:05:09"
+    );
+    assert_eq!(
+        datetime!(2024-12-25 04:05:06.789 +01:00).format(&Rfc3164::DEFAULT)?,
+        "Dec 25 04:05:06"
+    );
+    assert_eq!(
+        datetime!(2024-03-03 14:05:09)
+            .format(&Rfc3164::with_year_reference(date!(2000 - 01 - 01)))?,
+        "Mar  3 14:05:09"
+    );
+
+    assert!(matches!(
+        time!(14:05:09).format(&Rfc3164::DEFAULT),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn rfc_5424() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2024-03-03 14:05:09 UTC).format(&Rfc5424)?,
+        "2024-03-03T14:05:09Z"
+    );
+    assert_eq!(
+        datetime!(2024-03-03 14:05:09.1 UTC).format(&Rfc5424)?,
+        "2024-03-03T14:05:09.1Z"
+    );
+    assert_eq!(
+        datetime!(2024-03-03 14:05:09.123_456 +01:00).format(&Rfc5424)?,
+        "2024-03-03T14:05:09.123456+01:00"
+    );
+    assert_eq!(
+        datetime!(2024-03-03 14:05:09.123_456_789 -01:30).format(&Rfc5424)?,
+        "2024-03-03T14:05:09.123456-01:30"
+    );
+    assert_eq!(
+        datetime!(2024-03-03 14:05:09.000_000_999 UTC).format(&Rfc5424)?,
+        "2024-03-03T14:05:09Z"
+    );
+
+    assert!(matches!(
+        datetime!(+10_000-01-01 0:00 UTC).format(&Rfc5424),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(2024-01-01 0:00 +00:00:01).format(&Rfc5424),
+        Err(time::error::Format::InvalidComponent("offset_second"))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn rfc_5545() -> time::Result<()> {
+    assert_eq!(date!(2024 - 01 - 01).format(&Rfc5545)?, "20240101");
+    assert_eq!(
+        datetime!(2024-01-01 9:00:05.999).format(&Rfc5545)?,
+        "20240101T090005"
+    );
+    assert_eq!(
+        datetime!(2024-01-01 9:00 UTC).format(&Rfc5545)?,
+        "20240101T090000Z"
+    );
+    assert_eq!(
+        datetime!(2024-01-01 0:30 +1).format(&Rfc5545)?,
+        "20231231T233000Z"
+    );
+    assert_eq!(
+        datetime!(2024-01-01 0:30 +1).format_in_zone(&Rfc5545, &offset!(+1))?,
+        "20231231T233000Z"
+    );
+
+    assert_eq!(Duration::ZERO.format(&Rfc5545)?, "PT0S");
+    assert_eq!(Duration::weeks(7).format(&Rfc5545)?, "P7W");
+    assert_eq!(Duration::days(15).format(&Rfc5545)?, "P15D");
+    assert_eq!(
+        (Duration::days(15) + Duration::hours(5) + Duration::seconds(20)).format(&Rfc5545)?,
+        "P15DT5H0M20S"
+    );
+    assert_eq!(
+        (Duration::days(7) + Duration::minutes(1)).format(&Rfc5545)?,
+        "P7DT1M"
+    );
+    assert_eq!((-Duration::minutes(15)).format(&Rfc5545)?, "-PT15M");
+    assert_eq!(Duration::seconds_f64(1.5).format(&Rfc5545)?, "PT1S");
+
+    assert!(matches!(
+        datetime!(+10_000-01-01 0:00 UTC).format(&Rfc5545),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(-0001-01-01 0:00).format(&Rfc5545),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        Time::MIDNIGHT.format(&Rfc5545),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn utc_time() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2024-01-02 03:04:05 UTC).format(&UtcTime)?,
+        "240102030405Z"
+    );
+    assert_eq!(
+        datetime!(2024-01-02 03:04:05.999 -1).format(&UtcTime)?,
+        "240102040405Z"
+    );
+    assert_eq!(
+        datetime!(1950-01-01 0:00 UTC).format(&UtcTime)?,
+        "500101000000Z"
+    );
+    assert_eq!(
+        datetime!(2049-12-31 23:59:59 UTC).format(&UtcTime)?,
+        "491231235959Z"
+    );
+
+    assert!(matches!(
+        datetime!(1949-12-31 23:59:59 UTC).format(&UtcTime),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(2049-12-31 23:59:59 -1).format(&UtcTime),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(2024-01-02 03:04:05).format(&UtcTime),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn generalized_time() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2024-01-02 03:04:05 UTC).format(&GeneralizedTime)?,
+        "20240102030405Z"
+    );
+    assert_eq!(
+        datetime!(2024-01-02 03:04:05 +5:30).format(&GeneralizedTime)?,
+        "20240101213405Z"
+    );
+    assert_eq!(
+        datetime!(2024-01-02 03:04:05.1 UTC).format(&GeneralizedTime)?,
+        "20240102030405.1Z"
+    );
+    assert_eq!(
+        datetime!(2024-01-02 03:04:05.120 UTC).format(&GeneralizedTime)?,
+        "20240102030405.12Z"
+    );
+    assert_eq!(
+        datetime!(2024-01-02 03:04:05.000_000_001 UTC).format(&GeneralizedTime)?,
+        "20240102030405.000000001Z"
+    );
+    assert_eq!(
+        datetime!(2024-01-02 03:04:05.123_456_789 UTC).format(&GeneralizedTime)?,
+        "20240102030405.123456789Z"
+    );
+    assert_eq!(
+        datetime!(0000-01-01 0:00 UTC).format(&GeneralizedTime)?,
+        "00000101000000Z"
+    );
+
+    assert!(matches!(
+        datetime!(+10_000-01-01 0:00 UTC).format(&GeneralizedTime),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(0000-01-01 0:00 +1).format(&GeneralizedTime),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        date!(2024 - 01 - 02).format(&GeneralizedTime),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn sql() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2024-01-02 03:04:05 UTC).format(&Sql)?,
+        "2024-01-02 03:04:05+00"
+    );
+    assert_eq!(
+        datetime!(2024-01-02 03:04:05.123_456_789 -5:30).format(&Sql)?,
+        "2024-01-02 03:04:05.123456-05:30"
+    );
+    assert_eq!(
+        datetime!(2024-01-02 03:04:05 +1:02:03).format(&Sql)?,
+        "2024-01-02 03:04:05+01:02:03"
+    );
+    assert_eq!(
+        datetime!(2024-01-02 03:04:05.12).format(&Sql)?,
+        "2024-01-02 03:04:05.12"
+    );
+    assert_eq!(
+        datetime!(2024-01-02 03:04:05.000_001).format(&Sql)?,
+        "2024-01-02 03:04:05.000001"
+    );
+    assert_eq!(
+        datetime!(2024-01-02 03:04:05.000_000_999).format(&Sql)?,
+        "2024-01-02 03:04:05"
+    );
+    assert_eq!(date!(2024 - 01 - 02).format(&Sql)?, "2024-01-02");
+    assert_eq!(date!(0001 - 01 - 01).format(&Sql)?, "0001-01-01");
+    assert_eq!(date!(0000 - 12 - 31).format(&Sql)?, "0001-12-31 BC");
+    assert_eq!(
+        datetime!(-0043-03-15 12:00 UTC).format(&Sql)?,
+        "0044-03-15 12:00:00+00 BC"
+    );
+    assert_eq!(time!(23:59:59.5).format(&Sql)?, "23:59:59.5");
+
+    Ok(())
+}
+
 #[test]
 fn iso_8601() -> time::Result<()> {
     macro_rules! assert_format_config {
@@ -550,10 +867,14 @@ fn insufficient_type_information() {
         ));
     };
     assert_insufficient_type_information(Time::MIDNIGHT.format(fd!("[year]")));
+    assert_insufficient_type_information(Time::MIDNIGHT.format(fd!("[quarter]")));
+    assert_insufficient_type_information(Time::MIDNIGHT.format(fd!("[century]")));
+    assert_insufficient_type_information(Time::MIDNIGHT.format(fd!("[era]")));
     assert_insufficient_type_information(Time::MIDNIGHT.format(&Rfc3339));
     assert_insufficient_type_information(date!(2021 - 001).format(&Rfc3339));
     assert_insufficient_type_information(datetime!(2021 - 001 0:00).format(&Rfc3339));
     assert_insufficient_type_information(Time::MIDNIGHT.format(&Rfc2822));
+    assert_insufficient_type_information(datetime!(2021 - 001 0:00).format(&HttpDate));
     assert_insufficient_type_information(date!(2021 - 001).format(&Rfc2822));
     assert_insufficient_type_information(datetime!(2021 - 001 0:00).format(&Rfc2822));
     assert_insufficient_type_information(
@@ -597,6 +918,7 @@ fn failed_write() -> time::Result<()> {
     assert_err!(datetime!(2021-001 0:00:00.1 UTC), Rfc3339);
     assert_err!(datetime!(2021-001 0:00 +0:01), Rfc3339);
     assert_err!(OffsetDateTime::UNIX_EPOCH, Rfc2822);
+    assert_err!(OffsetDateTime::UNIX_EPOCH, HttpDate);
     assert_err!(OffsetDateTime::UNIX_EPOCH, Iso8601::DEFAULT);
     assert_err!(datetime!(2021-001 0:00 +0:01), Iso8601::DEFAULT);
     assert_err!(
@@ -767,3 +1089,425 @@ fn unix_timestamp() -> time::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn zone() -> time::Result<()> {
+    /// A time zone that provides its name.
+    struct Named<'a>(&'static str, ZoneRules<'a>);
+
+    impl TimeZone for Named<'_> {
+        fn offset_at_utc(&self, datetime: OffsetDateTime) -> UtcOffset {
+            self.1.offset_at_utc(datetime)
+        }
+
+        fn name(&self) -> Option<&str> {
+            Some(self.0)
+        }
+
+        fn local_time_type_at_utc(&self, datetime: OffsetDateTime) -> Option<LocalTimeType> {
+            self.1.local_time_type_at_utc(datetime)
+        }
+    }
+
+    let cet = LocalTimeType::new(offset!(+1), false, "CET")?;
+    let cest = LocalTimeType::new(offset!(+2), true, "CEST")?;
+    let transitions = [Transition::new(
+        datetime!(2023-03-26 1:00 UTC).unix_timestamp(),
+        cest,
+    )];
+    let paris = Named("Europe/Paris", ZoneRules::new(cet, &transitions));
+    let format = fd!("[hour]:[minute] [zone_abbr] ([zone_name])");
+
+    assert_eq!(
+        datetime!(2023-03-26 0:30 UTC).format_in_zone(&format, &paris)?,
+        "01:30 CET (Europe/Paris)"
+    );
+    assert_eq!(
+        datetime!(2023-03-26 1:30 UTC).format_in_zone(&format, &paris)?,
+        "03:30 CEST (Europe/Paris)"
+    );
+    assert_eq!(
+        datetime!(2023-03-26 3:30 +2).format_in_zone(fd!("[zone_abbr]"), &paris.1)?,
+        "CEST"
+    );
+
+    assert_eq!(
+        datetime!(2023-03-26 1:30 UTC).format_in_zone(&Rfc5545, &paris)?,
+        "TZID=Europe/Paris:20230326T033000"
+    );
+
+    let mut buf = Vec::new();
+    assert_eq!(
+        datetime!(2023-03-26 1:30 UTC).format_into_in_zone(&mut buf, fd!("[zone_abbr]"), &paris)?,
+        4
+    );
+    assert_eq!(buf, b"CEST");
+
+    Ok(())
+}
+
+#[test]
+fn zone_insufficient_type_information() {
+    let assert_insufficient_type_information = |res| {
+        assert!(matches!(
+            res,
+            Err(time::error::Format::InsufficientTypeInformation { .. })
+        ));
+    };
+    let rules = ZoneRules::fixed(LocalTimeType::new(offset!(+1), false, "CET").unwrap());
+
+    assert_insufficient_type_information(datetime!(2023-01-01 0:00 UTC).format(fd!("[zone_name]")));
+    assert_insufficient_type_information(datetime!(2023-01-01 0:00 UTC).format(fd!("[zone_abbr]")));
+    assert_insufficient_type_information(datetime!(2023-01-01 0:00).format(fd!("[zone_abbr]")));
+    assert_insufficient_type_information(
+        datetime!(2023-01-01 0:00 UTC).format_in_zone(fd!("[zone_name]"), &rules),
+    );
+    assert_insufficient_type_information(
+        datetime!(2023-01-01 0:00 UTC).format_in_zone(fd!("[zone_abbr]"), &offset!(+1)),
+    );
+}
+
+#[test]
+fn localized() -> time::Result<()> {
+    let format = fd!("[weekday] [day] [month repr:long] / [weekday repr:short] [month repr:short]");
+
+    assert_eq!(
+        date!(2023 - 02 - 01).format(&Localized::new(format, &English))?,
+        date!(2023 - 02 - 01).format(format)?
+    );
+    assert_eq!(
+        date!(2023 - 03 - 01).format(&Localized::new(format, &German))?,
+        "Mittwoch 01 März / Mi. März"
+    );
+    assert_eq!(
+        date!(2023 - 02 - 01).format(&Localized::new(format, &French))?,
+        "mercredi 01 février / mer. févr."
+    );
+    assert_eq!(
+        datetime!(2023-08-05 12:00 UTC).format(&Localized::new(format, &Spanish))?,
+        "sábado 05 agosto / sáb ago"
+    );
+    assert_eq!(
+        datetime!(2023-12-31 12:00).format(&Localized::new(format, &Japanese))?,
+        "日曜日 31 12月 / 日 12月"
+    );
+    assert_eq!(
+        date!(2023 - 02 - 01).format(&Localized::new(
+            fd!("[weekday repr:monday] [month]"),
+            &German
+        ))?,
+        "3 02"
+    );
+
+    let owned = format_description::parse_owned::<2>("[month repr:long]")?;
+    assert_eq!(
+        date!(2023 - 10 - 01).format(&Localized::new(&owned, &German))?,
+        "Oktober"
+    );
+
+    let mut buf = Vec::new();
+    assert_eq!(
+        date!(2023 - 10 - 01).format_into(&mut buf, &Localized::new(format, &German))?,
+        29
+    );
+    assert_eq!(buf, b"Sonntag 01 Oktober / So. Okt.");
+
+    Ok(())
+}
+
+#[test]
+fn localized_custom() -> time::Result<()> {
+    /// Names that are all the same, to verify that the table is used.
+    struct Custom;
+
+    impl Locale for Custom {
+        fn month_names(&self) -> &[&str; 12] {
+            &["month"; 12]
+        }
+
+        fn month_abbreviations(&self) -> &[&str; 12] {
+            &["mo"; 12]
+        }
+
+        fn weekday_names(&self) -> &[&str; 7] {
+            &["weekday"; 7]
+        }
+
+        fn weekday_abbreviations(&self) -> &[&str; 7] {
+            &["wd"; 7]
+        }
+    }
+
+    assert_eq!(
+        date!(2023 - 02 - 01).format(&Localized::new(
+            fd!("[weekday] [month repr:long] [weekday repr:short] [month repr:short]"),
+            &Custom
+        ))?,
+        "weekday month wd mo"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn quarter_century_era() -> time::Result<()> {
+    assert_eq!(
+        date!(2024 - 08 - 15).format(fd!("Q[quarter] [year]"))?,
+        "Q3 2024"
+    );
+    assert_eq!(date!(2024 - 12 - 31).format(fd!("[quarter]"))?, "4");
+
+    let format = fd!("[century]|[century padding:space]|[century padding:none]");
+    assert_eq!(date!(2024 - 01 - 01).format(format)?, "20|20|20");
+    assert_eq!(date!(0999 - 01 - 01).format(format)?, "09| 9|9");
+    assert_eq!(date!(0044 - 01 - 01).format(format)?, "00| 0|0");
+    assert_eq!(date!(-0044 - 01 - 01).format(format)?, "-00|- 0|-0");
+    assert_eq!(date!(-1999 - 01 - 01).format(format)?, "-19|-19|-19");
+    assert_eq!(date!(+10_000 - 01 - 01).format(format)?, "100|100|100");
+    assert_eq!(
+        date!(-0044 - 01 - 01).format(fd!("[century][year repr:last_two]"))?,
+        "-0044"
+    );
+
+    let format = fd!("[year repr:era padding:none] [era] / [year repr:era] [era repr:ce]");
+    assert_eq!(date!(2024 - 01 - 01).format(format)?, "2024 AD / 2024 CE");
+    assert_eq!(date!(0001 - 01 - 01).format(format)?, "1 AD / 0001 CE");
+    assert_eq!(date!(0000 - 12 - 31).format(format)?, "1 BC / 0001 BCE");
+    assert_eq!(date!(-0043 - 03 - 15).format(format)?, "44 BC / 0044 BCE");
+    assert_eq!(
+        date!(-0043 - 03 - 15).format(fd!("[year repr:era sign:mandatory]"))?,
+        "0044"
+    );
+    assert_eq!(
+        date!(0001 - 01 - 01).format(fd!("[year repr:era base:iso_week padding:none] [era]"))?,
+        "1 AD"
+    );
+    assert_eq!(
+        datetime!(0001-01-01 0:00 UTC)
+            .to_offset(offset!(-1))
+            .format(fd!("[year repr:era padding:none] [era]"))?,
+        "1 BC"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn day_suffix() -> time::Result<()> {
+    let format = fd!("[month repr:long] [day padding:none suffix:true], [year]");
+    assert_eq!(date!(2024 - 03 - 01).format(format)?, "March 1st, 2024");
+    assert_eq!(date!(2024 - 03 - 02).format(format)?, "March 2nd, 2024");
+    assert_eq!(date!(2024 - 03 - 03).format(format)?, "March 3rd, 2024");
+    assert_eq!(date!(2024 - 03 - 04).format(format)?, "March 4th, 2024");
+    assert_eq!(date!(2024 - 03 - 11).format(format)?, "March 11th, 2024");
+    assert_eq!(date!(2024 - 03 - 12).format(format)?, "March 12th, 2024");
+    assert_eq!(date!(2024 - 03 - 13).format(format)?, "March 13th, 2024");
+    assert_eq!(date!(2024 - 03 - 21).format(format)?, "March 21st, 2024");
+    assert_eq!(date!(2024 - 03 - 22).format(format)?, "March 22nd, 2024");
+    assert_eq!(date!(2024 - 03 - 23).format(format)?, "March 23rd, 2024");
+    assert_eq!(date!(2024 - 03 - 30).format(format)?, "March 30th, 2024");
+    assert_eq!(date!(2024 - 03 - 31).format(format)?, "March 31st, 2024");
+    assert_eq!(
+        date!(2024 - 03 - 03).format(fd!("[day suffix:true]|[day padding:space suffix:true]"))?,
+        "3rd|3rd"
+    );
+    assert_eq!(
+        date!(2024 - 03 - 03).format(&Localized::new(format, &English))?,
+        "March 3rd, 2024"
+    );
+
+    let format = fd!("[day padding:none suffix:true] [month repr:long]");
+    assert_eq!(
+        date!(2024 - 05 - 01).format(&Localized::new(format, &French))?,
+        "1er mai"
+    );
+    assert_eq!(
+        date!(2024 - 05 - 02).format(&Localized::new(format, &French))?,
+        "2 mai"
+    );
+    assert_eq!(
+        date!(2024 - 05 - 02).format(&Localized::new(format, &German))?,
+        "2. Mai"
+    );
+    assert_eq!(
+        date!(2024 - 05 - 02).format(&Localized::new(
+            fd!("[month repr:long][day padding:none suffix:true]"),
+            &Japanese
+        ))?,
+        "5月2日"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn day_suffix_custom() -> time::Result<()> {
+    /// English names, with a suffix that is only present on the first day of the month.
+    struct Custom;
+
+    impl Locale for Custom {
+        fn month_names(&self) -> &[&str; 12] {
+            English.month_names()
+        }
+
+        fn month_abbreviations(&self) -> &[&str; 12] {
+            English.month_abbreviations()
+        }
+
+        fn weekday_names(&self) -> &[&str; 7] {
+            English.weekday_names()
+        }
+
+        fn weekday_abbreviations(&self) -> &[&str; 7] {
+            English.weekday_abbreviations()
+        }
+
+        fn day_suffix(&self, day: u8) -> &str {
+            if day == 1 {
+                "!"
+            } else {
+                ""
+            }
+        }
+    }
+
+    let format = Localized::new(fd!("[day padding:none suffix:true]"), &Custom);
+    assert_eq!(date!(2024 - 05 - 01).format(&format)?, "1!");
+    assert_eq!(date!(2024 - 05 - 02).format(&format)?, "2");
+
+    Ok(())
+}
+
+#[test]
+fn strftime() -> time::Result<()> {
+    let dt = datetime!(2023-07-04 09:05:03 +02:00);
+    let format = |s| -> time::Result<String> {
+        Ok(dt.format(&format_description::parse_strftime_borrowed(s)?)?)
+    };
+
+    assert_eq!(format("%F %T %z")?, "2023-07-04 09:05:03 +0200");
+    assert_eq!(format("%c")?, "Tue Jul  4 09:05:03 2023");
+    assert_eq!(format("%D %r")?, "07/04/23 09:05:03 AM");
+    assert_eq!(format("%-d/%-m/%y %l%P %k:%R")?, "4/7/23  9am  9:09:05");
+    assert_eq!(
+        format("%j %U %W %V %G %g %u %w %C")?,
+        "185 27 27 27 2023 23 2 2 20"
+    );
+    assert_eq!(format("%s")?, "1688454303");
+    assert_eq!(format("%A, %B %e%n100%%")?, "Tuesday, July  4\n100%");
+    assert_eq!(format("%-a %_b %0p")?, "Tue Jul AM");
+    assert_eq!(
+        dt.format(&format_description::parse_strftime_owned("%x %X")?)?,
+        "07/04/23 09:05:03"
+    );
+
+    // The time zone abbreviation is only available when formatting in a time zone.
+    let zone_abbr = format_description::parse_strftime_borrowed("%H:%M %Z")?;
+    assert!(matches!(
+        dt.format(&zone_abbr),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    let cest = ZoneRules::fixed(LocalTimeType::new(offset!(+2), true, "CEST")?);
+    assert_eq!(dt.format_in_zone(&zone_abbr, &cest)?, "09:05 CEST");
+
+    Ok(())
+}
+
+#[test]
+fn format_duration() -> time::Result<()> {
+    let duration = Duration::new(93_784, 5_006_007);
+    assert_eq!(
+        duration.format(fd!("[days]d [hours]h [minutes]m [seconds]s"))?,
+        "01d 02h 03m 04s"
+    );
+    assert_eq!(
+        duration.format(fd!(
+            "[hours total:true]:[minutes]:[seconds].[subsecond digits:3]"
+        ))?,
+        "26:03:04.005"
+    );
+    assert_eq!(
+        duration.format(fd!("[minutes total:true]:[seconds]"))?,
+        "1563:04"
+    );
+    assert_eq!(
+        duration.format(fd!("[seconds total:true].[subsecond]"))?,
+        "93784.005006007"
+    );
+    assert_eq!(
+        duration.format(fd!(
+            "[days padding:space]|[hours padding:none]|[minutes padding:space]"
+        ))?,
+        " 1|2| 3"
+    );
+
+    let format = fd!("[sign][hours total:true]:[minutes]");
+    assert_eq!(Duration::minutes(90).format(format)?, "01:30");
+    assert_eq!(Duration::minutes(-90).format(format)?, "-01:30");
+    assert_eq!(Duration::ZERO.format(format)?, "00:00");
+    assert_eq!(
+        Duration::minutes(90).format(fd!("[sign sign:mandatory][hours total:true]"))?,
+        "+01"
+    );
+    // Without a sign component, a negative duration is preceded by `-`.
+    assert_eq!(
+        Duration::minutes(-90).format(fd!("[minutes total:true]"))?,
+        "-90"
+    );
+    assert_eq!(
+        Duration::seconds(-3_661).format(fd!("[hours]:[minutes]:[seconds]"))?,
+        "-01:01:01"
+    );
+    let format = format_description::parse_owned::<2>("[first [[hours]:[minutes]] [[sign]]]")?;
+    assert_eq!(Duration::seconds(-3_661).format(&format)?, "-01:01");
+    let format = format_description::parse_owned::<2>("[optional [[sign]]][hours]")?;
+    assert_eq!(Duration::seconds(-3_661).format(&format)?, "-01");
+    assert_eq!(
+        Duration::milliseconds(-1_500).format(fd!("[sign][seconds].[subsecond]"))?,
+        "-01.5"
+    );
+
+    assert_eq!(
+        Duration::MAX.format(fd!("[seconds total:true].[subsecond]"))?,
+        "9223372036854775807.999999999"
+    );
+    assert_eq!(
+        Duration::MIN.format(fd!("[sign][days]"))?,
+        "-106751991167300"
+    );
+    assert_eq!(
+        Duration::MIN.format(fd!("[sign][hours total:true]"))?,
+        "-2562047788015215"
+    );
+
+    let format = format_description::parse_owned::<2>(
+        "[sign][first [[days]d ][]][hours]:[minutes][optional [:[seconds]]]",
+    )?;
+    assert_eq!(Duration::hours(25).format(&format)?, "01d 01:00:00");
+
+    let mut buf = Vec::new();
+    assert_eq!(
+        Duration::seconds(61).format_into(&mut buf, fd!("[minutes]:[seconds]"))?,
+        5
+    );
+    assert_eq!(buf, b"01:01");
+
+    assert!(matches!(
+        Duration::ZERO.format(fd!("[hour]")),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    assert!(matches!(
+        Duration::ZERO.format(&Rfc3339),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    assert!(matches!(
+        time!(0:00).format(fd!("[hours]")),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    assert_eq!(
+        Duration::seconds(61).format(&Localized::new(fd!("[minutes]:[seconds]"), &German))?,
+        "01:01"
+    );
+
+    Ok(())
+}
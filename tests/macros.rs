@@ -2,7 +2,7 @@ use core::num::NonZeroU16;
 
 use time::format_description::modifier::*;
 use time::format_description::{Component, FormatItem};
-use time::macros::{date, format_description, time};
+use time::macros::{date, datetime, format_description, offset, time};
 use time::{Date, Time};
 
 #[test]
@@ -210,6 +210,19 @@ fn format_description_coverage() {
             })))
         ]
     );
+    assert_eq!(
+        format_description!("[day padding:none suffix:true][day suffix:true case_sensitive:false]"),
+        &[
+            FormatItem::Component(Component::Day(modifier!(Day {
+                padding: Padding::None,
+                suffix: true,
+            }))),
+            FormatItem::Component(Component::Day(modifier!(Day {
+                suffix: true,
+                case_sensitive: false,
+            })))
+        ]
+    );
     assert_eq!(
         format_description!(
             "[offset_minute padding:space][offset_minute padding:zero][offset_minute padding:none]"
@@ -309,6 +322,50 @@ fn format_description_coverage() {
             }
         )))]
     );
+    assert_eq!(
+        format_description!("[zone_name] [zone_abbr]"),
+        &[
+            FormatItem::Component(Component::ZoneName(ZoneName::default())),
+            FormatItem::Literal(b" "),
+            FormatItem::Component(Component::ZoneAbbr(ZoneAbbr::default())),
+        ]
+    );
+    assert_eq!(
+        format_description!("Q[quarter] [century padding:none] [year repr:era] [era repr:ce]"),
+        &[
+            FormatItem::Literal(b"Q"),
+            FormatItem::Component(Component::Quarter(Quarter::default())),
+            FormatItem::Literal(b" "),
+            FormatItem::Component(Component::Century(modifier!(Century {
+                padding: Padding::None,
+            }))),
+            FormatItem::Literal(b" "),
+            FormatItem::Component(Component::Year(modifier!(Year {
+                repr: YearRepr::Era,
+            }))),
+            FormatItem::Literal(b" "),
+            FormatItem::Component(Component::Era(modifier!(Era { repr: EraRepr::Ce }))),
+        ]
+    );
+    assert_eq!(
+        format_description!(
+            "[sign sign:mandatory][days padding:none]d [hours total:true]:[minutes]:[seconds]"
+        ),
+        &[
+            FormatItem::Component(Component::Sign(modifier!(Sign {
+                sign_is_mandatory: true,
+            }))),
+            FormatItem::Component(Component::Days(modifier!(Days {
+                padding: Padding::None,
+            }))),
+            FormatItem::Literal(b"d "),
+            FormatItem::Component(Component::Hours(modifier!(Hours { total: true }))),
+            FormatItem::Literal(b":"),
+            FormatItem::Component(Component::Minutes(Minutes::default())),
+            FormatItem::Literal(b":"),
+            FormatItem::Component(Component::Seconds(Seconds::default())),
+        ]
+    );
 }
 
 #[test]
@@ -324,3 +381,56 @@ fn time_coverage() {
     assert_eq!(time!(12 AM), Time::MIDNIGHT);
     assert_eq!(Ok(time!(12 PM)), Time::from_hms(12, 0, 0));
 }
+
+#[test]
+fn datetime_zone() {
+    assert_eq!(
+        datetime!(2024-01-15 12:00 America/New_York),
+        datetime!(2024-01-15 12:00 -5)
+    );
+    assert_eq!(
+        datetime!(2024-07-15 12:00 America/New_York),
+        datetime!(2024-07-15 12:00 -4)
+    );
+    assert_eq!(
+        datetime!(2024-07-15 12:00 Europe/Paris),
+        datetime!(2024-07-15 12:00 +2)
+    );
+    assert_eq!(
+        datetime!(2024-07-15 12:00 Asia/Kolkata),
+        datetime!(2024-07-15 12:00 +5:30)
+    );
+    assert_eq!(
+        datetime!(2024-07-15 12:00 Asia/Calcutta),
+        datetime!(2024-07-15 12:00 Asia/Kolkata)
+    );
+    assert_eq!(
+        datetime!(2024-07-15 12:00 Etc/GMT+5),
+        datetime!(2024-07-15 12:00 -5)
+    );
+    assert_eq!(
+        datetime!(2024-07-15 12:00 America/Port-au-Prince),
+        datetime!(2024-07-15 12:00 -4)
+    );
+    assert_eq!(
+        datetime!(1850-01-01 0:00 Europe/Amsterdam).offset(),
+        offset!(+0:19:32)
+    );
+    assert_eq!(datetime!(2024-07-15 12:00 UTC).offset(), offset!(UTC));
+}
+
+#[test]
+fn datetime_zone_ambiguous() {
+    assert_eq!(
+        datetime!(2024-11-03 1:30 America/New_York earliest),
+        datetime!(2024-11-03 1:30 -4)
+    );
+    assert_eq!(
+        datetime!(2024-11-03 1:30 America/New_York latest),
+        datetime!(2024-11-03 1:30 -5)
+    );
+    assert_eq!(
+        datetime!(2024-11-03 0:30 America/New_York latest),
+        datetime!(2024-11-03 0:30 -4)
+    );
+}
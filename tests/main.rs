@@ -131,6 +131,7 @@ require_all_features! {
         }
     }
 
+    mod cron;
     mod date;
     mod derives;
     mod duration;
@@ -146,16 +147,20 @@ require_all_features! {
     mod parse_format_description;
     mod parsed;
     mod parsing;
+    mod postgres;
     mod primitive_date_time;
     #[path = "quickcheck.rs"]
     mod quickcheck_mod;
     mod rand;
+    mod recurrence;
+    mod relative;
     mod serde;
     mod serde_helpers;
     mod time;
     mod utc_offset;
     mod util;
     mod weekday;
+    mod zone;
 
     #[cfg(__ui_tests)]
     #[test]
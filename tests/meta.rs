@@ -8,18 +8,21 @@ use std::hash::Hash;
 use std::iter::Sum;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use std::panic::{RefUnwindSafe, UnwindSafe};
+use std::str::FromStr;
 use std::time::{Duration as StdDuration, Instant as StdInstant, SystemTime};
 
 use quickcheck::Arbitrary;
 use rand::distributions::{Distribution, Standard};
 use serde::{Deserialize, Serialize};
-use time::format_description::well_known::iso8601;
+use time::format_description::locale::{self, Locale, Localized};
+use time::format_description::well_known::{iso8601, rfc3339};
 use time::format_description::{modifier, well_known, Component, FormatItem};
 use time::formatting::Formattable;
-use time::parsing::{Parsable, Parsed};
+use time::parsing::{Defaults, Parsable, Parsed, WithDefaults};
+use time::relative::{self, Language, Vocabulary};
 use time::{
-    error, ext, Date, Duration, Error, Instant, Month, OffsetDateTime, PrimitiveDateTime, Time,
-    UtcOffset, Weekday,
+    error, ext, Date, Duration, Era, Error, Instant, Month, OffsetDateTime, PrimitiveDateTime,
+    Time, UtcOffset, Weekday,
 };
 
 #[test]
@@ -59,8 +62,27 @@ fn alignment() {
     assert_alignment!(modifier::WeekNumber, 1);
     assert_alignment!(modifier::Weekday, 1);
     assert_alignment!(modifier::Year, 1);
+    assert_alignment!(modifier::Quarter, 1);
+    assert_alignment!(modifier::Century, 1);
+    assert_alignment!(modifier::Era, 1);
+    assert_alignment!(modifier::ZoneAbbr, 1);
+    assert_alignment!(modifier::ZoneName, 1);
+    assert_alignment!(modifier::Sign, 1);
+    assert_alignment!(modifier::Days, 1);
+    assert_alignment!(modifier::Hours, 1);
+    assert_alignment!(modifier::Minutes, 1);
+    assert_alignment!(modifier::Seconds, 1);
+    assert_alignment!(locale::English, 1);
+    assert_alignment!(Localized<'_, [FormatItem<'_>]>, 8);
+    assert_alignment!(well_known::HttpDate, 1);
     assert_alignment!(well_known::Rfc2822, 1);
+    assert_alignment!(well_known::Rfc3164, 4);
     assert_alignment!(well_known::Rfc3339, 1);
+    assert_alignment!(well_known::Rfc5424, 1);
+    assert_alignment!(well_known::Rfc5545, 1);
+    assert_alignment!(well_known::UtcTime, 1);
+    assert_alignment!(well_known::GeneralizedTime, 1);
+    assert_alignment!(well_known::Sql, 1);
     assert_alignment!(
         well_known::Iso8601<{ iso8601::Config::DEFAULT.encode() }>,
         1
@@ -70,13 +92,24 @@ fn alignment() {
     assert_alignment!(iso8601::FormattedComponents, 1);
     assert_alignment!(iso8601::OffsetPrecision, 1);
     assert_alignment!(iso8601::TimePrecision, 1);
+    assert_alignment!(rfc3339::Config, 1);
+    assert_alignment!(rfc3339::Separator, 1);
+    assert_alignment!(rfc3339::SubsecondDigits, 1);
     assert_alignment!(Parsed, 8);
+    assert_alignment!(Defaults, 4);
+    assert_alignment!(WithDefaults<'_, [FormatItem<'_>]>, 8);
     assert_alignment!(Month, 1);
     assert_alignment!(Weekday, 1);
+    assert_alignment!(Era, 1);
+    assert_alignment!(relative::Unit, 1);
+    assert_alignment!(relative::Rounding, 1);
+    assert_alignment!(relative::Config<'_>, 8);
+    assert_alignment!(relative::Relative<'_>, 8);
     assert_alignment!(Error, 8);
     assert_alignment!(error::Format, 8);
     assert_alignment!(error::InvalidFormatDescription, 8);
     assert_alignment!(error::Parse, 8);
+    assert_alignment!(error::Located<error::Parse>, 8);
     assert_alignment!(error::ParseFromDescription, 8);
     assert_alignment!(error::TryFromParsed, 8);
     assert_alignment!(Component, 2);
@@ -87,6 +120,7 @@ fn alignment() {
     assert_alignment!(modifier::WeekNumberRepr, 1);
     assert_alignment!(modifier::WeekdayRepr, 1);
     assert_alignment!(modifier::YearRepr, 1);
+    assert_alignment!(modifier::EraRepr, 1);
 }
 
 #[test]
@@ -122,7 +156,7 @@ fn size() {
     assert_size!(error::ConversionRange, 0, 1);
     assert_size!(error::DifferentVariant, 0, 1);
     assert_size!(error::IndeterminateOffset, 0, 1);
-    assert_size!(modifier::Day, 1, 1);
+    assert_size!(modifier::Day, 3, 3);
     assert_size!(modifier::Hour, 2, 2);
     assert_size!(modifier::Minute, 1, 1);
     assert_size!(modifier::Month, 3, 3);
@@ -136,8 +170,27 @@ fn size() {
     assert_size!(modifier::WeekNumber, 2, 2);
     assert_size!(modifier::Weekday, 3, 3);
     assert_size!(modifier::Year, 4, 4);
+    assert_size!(modifier::Quarter, 0, 1);
+    assert_size!(modifier::Century, 1, 1);
+    assert_size!(modifier::Era, 2, 2);
+    assert_size!(modifier::ZoneAbbr, 0, 1);
+    assert_size!(modifier::ZoneName, 0, 1);
+    assert_size!(modifier::Sign, 1, 1);
+    assert_size!(modifier::Days, 1, 1);
+    assert_size!(modifier::Hours, 2, 2);
+    assert_size!(modifier::Minutes, 2, 2);
+    assert_size!(modifier::Seconds, 2, 2);
+    assert_size!(locale::English, 0, 1);
+    assert_size!(Localized<'_, [FormatItem<'_>]>, 32, 32);
+    assert_size!(well_known::HttpDate, 0, 1);
     assert_size!(well_known::Rfc2822, 0, 1);
+    assert_size!(well_known::Rfc3164, 8, 8);
     assert_size!(well_known::Rfc3339, 0, 1);
+    assert_size!(well_known::Rfc5424, 0, 1);
+    assert_size!(well_known::Rfc5545, 0, 1);
+    assert_size!(well_known::UtcTime, 0, 1);
+    assert_size!(well_known::GeneralizedTime, 0, 1);
+    assert_size!(well_known::Sql, 0, 1);
     assert_size!(
         well_known::Iso8601<{ iso8601::Config::DEFAULT.encode() }>,
         0,
@@ -148,13 +201,24 @@ fn size() {
     assert_size!(iso8601::FormattedComponents, 1, 1);
     assert_size!(iso8601::OffsetPrecision, 1, 1);
     assert_size!(iso8601::TimePrecision, 2, 2);
-    assert_size!(Parsed, 56, 56);
+    assert_size!(rfc3339::Config, 4, 4);
+    assert_size!(rfc3339::Separator, 1, 1);
+    assert_size!(rfc3339::SubsecondDigits, 2, 2);
+    assert_size!(Parsed, 112, 112);
+    assert_size!(Defaults, 20, 20);
+    assert_size!(WithDefaults<'_, [FormatItem<'_>]>, 40, 40);
     assert_size!(Month, 1, 1);
     assert_size!(Weekday, 1, 1);
-    assert_size!(Error, 56, 56);
+    assert_size!(Era, 1, 1);
+    assert_size!(relative::Unit, 1, 1);
+    assert_size!(relative::Rounding, 1, 1);
+    assert_size!(relative::Config<'_>, 40, 40);
+    assert_size!(relative::Relative<'_>, 56, 56);
+    assert_size!(Error, 96, 96);
     assert_size!(error::Format, 24, 24);
     assert_size!(error::InvalidFormatDescription, 48, 48);
     assert_size!(error::Parse, 48, 48);
+    assert_size!(error::Located<error::Parse>, 96, 96);
     assert_size!(error::ParseFromDescription, 16, 24);
     assert_size!(error::TryFromParsed, 48, 48);
     assert_size!(Component, 6, 6); // TODO Size is 4 starting with rustc 1.71.
@@ -165,6 +229,7 @@ fn size() {
     assert_size!(modifier::WeekNumberRepr, 1, 1);
     assert_size!(modifier::WeekdayRepr, 1, 1);
     assert_size!(modifier::YearRepr, 1, 1);
+    assert_size!(modifier::EraRepr, 1, 1);
 }
 
 macro_rules! assert_obj_safe {
@@ -244,6 +309,7 @@ assert_impl! { @'a; Duration:
     DivAssign<u16>,
     DivAssign<u32>,
     DivAssign<u8>,
+    FromStr<Err = error::InvalidDuration>,
     Hash,
     Mul<f32, Output = Duration>,
     Mul<f64, Output = Duration>,
@@ -670,6 +736,180 @@ assert_impl! { modifier::Year:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { modifier::Quarter:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::Quarter>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { modifier::Century:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::Century>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { modifier::Era:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::Era>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { modifier::ZoneAbbr:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::ZoneAbbr>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+
+assert_impl! { modifier::Sign:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::Sign>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+
+assert_impl! { modifier::Days:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::Days>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+
+assert_impl! { modifier::Hours:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::Hours>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+
+assert_impl! { modifier::Minutes:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::Minutes>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+
+assert_impl! { modifier::Seconds:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::Seconds>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { modifier::ZoneName:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::ZoneName>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { locale::English:
+    Clone,
+    Debug,
+    PartialEq<locale::English>,
+    Copy,
+    Eq,
+    Language,
+    Locale,
+    Vocabulary,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { Localized<'_, [FormatItem<'_>]>:
+    Clone,
+    Debug,
+    Copy,
+    Formattable,
+    Parsable,
+    Send,
+    Sync,
+    Unpin,
+}
+assert_impl! { well_known::HttpDate:
+    Clone,
+    Debug,
+    PartialEq<well_known::HttpDate>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { well_known::Rfc2822:
     Clone,
     Debug,
@@ -684,6 +924,20 @@ assert_impl! { well_known::Rfc2822:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { well_known::Rfc3164:
+    Clone,
+    Debug,
+    PartialEq<well_known::Rfc3164>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { well_known::Rfc3339:
     Clone,
     Debug,
@@ -698,6 +952,76 @@ assert_impl! { well_known::Rfc3339:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { well_known::Rfc5424:
+    Clone,
+    Debug,
+    PartialEq<well_known::Rfc5424>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { well_known::Rfc5545:
+    Clone,
+    Debug,
+    PartialEq<well_known::Rfc5545>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { well_known::UtcTime:
+    Clone,
+    Debug,
+    PartialEq<well_known::UtcTime>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { well_known::GeneralizedTime:
+    Clone,
+    Debug,
+    PartialEq<well_known::GeneralizedTime>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { well_known::Sql:
+    Clone,
+    Debug,
+    PartialEq<well_known::Sql>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { well_known::Iso8601::<{ iso8601::Config::DEFAULT.encode() }>:
     Clone,
     Debug,
@@ -768,6 +1092,38 @@ assert_impl! { iso8601::TimePrecision:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { rfc3339::Config:
+    Debug,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { rfc3339::Separator:
+    Clone,
+    Debug,
+    PartialEq<rfc3339::Separator>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { rfc3339::SubsecondDigits:
+    Clone,
+    Debug,
+    PartialEq<rfc3339::SubsecondDigits>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { Parsed:
     Clone,
     Debug,
@@ -778,6 +1134,30 @@ assert_impl! { Parsed:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { Defaults:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<Defaults>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { WithDefaults<'_, [FormatItem<'_>]>:
+    Clone,
+    Debug,
+    Copy,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { @'a; Month:
     Arbitrary,
     Clone,
@@ -812,6 +1192,67 @@ assert_impl! { @'a; Weekday:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { Era:
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    Ord,
+    PartialEq<Era>,
+    PartialOrd<Era>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { relative::Unit:
+    Clone,
+    Debug,
+    Hash,
+    Ord,
+    PartialEq<relative::Unit>,
+    PartialOrd<relative::Unit>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { relative::Rounding:
+    Clone,
+    Debug,
+    Hash,
+    PartialEq<relative::Rounding>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { relative::Config<'_>:
+    Clone,
+    Debug,
+    Copy,
+    Send,
+    Sync,
+    Unpin,
+}
+assert_impl! { relative::Relative<'_>:
+    Clone,
+    Debug,
+    Display,
+    Copy,
+    Send,
+    Sync,
+    Unpin,
+}
 assert_impl! { Error:
     Debug,
     Display,
@@ -823,6 +1264,7 @@ assert_impl! { Error:
     From<error::IndeterminateOffset>,
     From<error::InvalidFormatDescription>,
     From<error::Parse>,
+    From<error::Located<error::Parse>>,
     From<error::ParseFromDescription>,
     From<error::TryFromParsed>,
     Send,
@@ -860,6 +1302,7 @@ assert_impl! { error::Parse:
     StdError,
     From<error::ParseFromDescription>,
     From<error::TryFromParsed>,
+    From<error::Located<error::Parse>>,
     PartialEq<error::Parse>,
     TryFrom<Error, Error = error::DifferentVariant>,
     Copy,
@@ -870,6 +1313,23 @@ assert_impl! { error::Parse:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { error::Located<error::Parse>:
+    Clone,
+    Debug,
+    Display,
+    StdError,
+    From<error::Located<error::ParseFromDescription>>,
+    From<error::TryFromParsed>,
+    PartialEq<error::Located<error::Parse>>,
+    TryFrom<Error, Error = error::DifferentVariant>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { error::ParseFromDescription:
     Clone,
     Debug,
@@ -1016,6 +1476,19 @@ assert_impl! { modifier::YearRepr:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { modifier::EraRepr:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::EraRepr>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { Standard:
     Distribution<Date>,
     Distribution<Duration>,
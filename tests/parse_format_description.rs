@@ -79,11 +79,18 @@ mod iterator {
         [
             (YearRepr::Full, "repr:full"),
             (YearRepr::LastTwo, "repr:last_two"),
+            (YearRepr::Era, "repr:era"),
         ]
         .iter()
         .copied()
     }
 
+    pub(super) fn era_repr() -> impl Iterator<Item = (EraRepr, &'static str)> {
+        [(EraRepr::Ad, "repr:ad"), (EraRepr::Ce, "repr:ce")]
+            .iter()
+            .copied()
+    }
+
     pub(super) fn year_is_iso_week_based() -> impl Iterator<Item = (bool, &'static str)> {
         [(false, "base:calendar"), (true, "base:iso_week")]
             .iter()
@@ -96,6 +103,18 @@ mod iterator {
             .copied()
     }
 
+    pub(super) fn day_suffix() -> impl Iterator<Item = (bool, &'static str)> {
+        [(false, "suffix:false"), (true, "suffix:true")]
+            .iter()
+            .copied()
+    }
+
+    pub(super) fn duration_total() -> impl Iterator<Item = (bool, &'static str)> {
+        [(false, "total:false"), (true, "total:true")]
+            .iter()
+            .copied()
+    }
+
     pub(super) fn weekday_is_one_indexed() -> impl Iterator<Item = (bool, &'static str)> {
         [(true, "one_indexed:true"), (false, "one_indexed:false")]
             .iter()
@@ -188,7 +207,9 @@ fn simple_component() {
         format_description::parse("[day]"),
         Ok(vec![FormatItem::Component(Component::Day(modifier!(
             Day {
-                padding: Padding::Zero
+                padding: Padding::Zero,
+                suffix: false,
+                case_sensitive: true
             }
         )))])
     );
@@ -311,6 +332,84 @@ fn simple_component() {
             }
         )))])
     );
+    assert_eq!(
+        format_description::parse("[quarter]"),
+        Ok(vec![FormatItem::Component(Component::Quarter(
+            Quarter::default()
+        ))])
+    );
+    assert_eq!(
+        format_description::parse("[century]"),
+        Ok(vec![FormatItem::Component(Component::Century(modifier!(
+            Century {
+                padding: Padding::Zero
+            }
+        )))])
+    );
+    assert_eq!(
+        format_description::parse("[era]"),
+        Ok(vec![FormatItem::Component(Component::Era(modifier!(
+            Era {
+                repr: EraRepr::Ad,
+                case_sensitive: true
+            }
+        )))])
+    );
+    assert_eq!(
+        format_description::parse("[zone_name]"),
+        Ok(vec![FormatItem::Component(Component::ZoneName(
+            ZoneName::default()
+        ))])
+    );
+    assert_eq!(
+        format_description::parse("[zone_abbr]"),
+        Ok(vec![FormatItem::Component(Component::ZoneAbbr(
+            ZoneAbbr::default()
+        ))])
+    );
+    assert_eq!(
+        format_description::parse("[sign]"),
+        Ok(vec![FormatItem::Component(Component::Sign(modifier!(
+            Sign {
+                sign_is_mandatory: false
+            }
+        )))])
+    );
+    assert_eq!(
+        format_description::parse("[days]"),
+        Ok(vec![FormatItem::Component(Component::Days(modifier!(
+            Days {
+                padding: Padding::Zero
+            }
+        )))])
+    );
+    assert_eq!(
+        format_description::parse("[hours]"),
+        Ok(vec![FormatItem::Component(Component::Hours(modifier!(
+            Hours {
+                padding: Padding::Zero,
+                total: false
+            }
+        )))])
+    );
+    assert_eq!(
+        format_description::parse("[minutes]"),
+        Ok(vec![FormatItem::Component(Component::Minutes(modifier!(
+            Minutes {
+                padding: Padding::Zero,
+                total: false
+            }
+        )))])
+    );
+    assert_eq!(
+        format_description::parse("[seconds]"),
+        Ok(vec![FormatItem::Component(Component::Seconds(modifier!(
+            Seconds {
+                padding: Padding::Zero,
+                total: false
+            }
+        )))])
+    );
 }
 
 #[test]
@@ -342,18 +441,43 @@ fn errors() {
         "[day padding:invalid]", InvalidModifier { value, index: 13, .. } if value == "invalid",
         "[ignore]", MissingRequiredModifier { name: "count", index: 1, .. },
         "[ignore count:70000]", InvalidModifier { value, index: 14, .. } if value == "70000",
+        "[zone_abbr case:upper]", InvalidModifier { value, index: 11, .. } if value == "case",
+        "[era repr:bc]", InvalidModifier { value, index: 10, .. } if value == "bc",
+        "[day suffix:st]", InvalidModifier { value, index: 12, .. } if value == "st",
     }
 }
 
 #[test]
 fn component_with_modifiers() {
     for (padding, padding_str) in iterator::padding() {
+        assert_eq!(
+            format_description::parse(&format!("[century {padding_str}]")),
+            Ok(vec![FormatItem::Component(Component::Century(modifier!(
+                Century { padding }
+            )))])
+        );
         assert_eq!(
             format_description::parse(&format!("[day {padding_str}]")),
             Ok(vec![FormatItem::Component(Component::Day(modifier!(
                 Day { padding }
             )))])
         );
+        for (suffix, suffix_str) in iterator::day_suffix() {
+            for (case_sensitive, case_sensitive_str) in iterator::case_sensitive() {
+                assert_eq!(
+                    format_description::parse(&format!(
+                        "[day {padding_str} {suffix_str} {case_sensitive_str}]"
+                    )),
+                    Ok(vec![FormatItem::Component(Component::Day(modifier!(
+                        Day {
+                            padding,
+                            suffix,
+                            case_sensitive
+                        }
+                    )))])
+                );
+            }
+        }
         assert_eq!(
             format_description::parse(&format!("[minute {padding_str}]")),
             Ok(vec![FormatItem::Component(Component::Minute(modifier!(
@@ -501,6 +625,58 @@ fn component_with_modifiers() {
         }
     }
 
+    for (era_repr, era_repr_str) in iterator::era_repr() {
+        for (case_sensitive, case_sensitive_str) in iterator::case_sensitive() {
+            assert_eq!(
+                format_description::parse(&format!("[era {era_repr_str} {case_sensitive_str}]")),
+                Ok(vec![FormatItem::Component(Component::Era(modifier!(
+                    Era {
+                        repr: era_repr,
+                        case_sensitive
+                    }
+                )))])
+            );
+        }
+    }
+
+    for (sign_is_mandatory, sign_is_mandatory_str) in iterator::sign_is_mandatory() {
+        assert_eq!(
+            format_description::parse(&format!("[sign {sign_is_mandatory_str}]")),
+            Ok(vec![FormatItem::Component(Component::Sign(modifier!(
+                Sign { sign_is_mandatory }
+            )))])
+        );
+    }
+
+    for (padding, padding_str) in iterator::padding() {
+        assert_eq!(
+            format_description::parse(&format!("[days {padding_str}]")),
+            Ok(vec![FormatItem::Component(Component::Days(modifier!(
+                Days { padding }
+            )))])
+        );
+        for (total, total_str) in iterator::duration_total() {
+            assert_eq!(
+                format_description::parse(&format!("[hours {padding_str} {total_str}]")),
+                Ok(vec![FormatItem::Component(Component::Hours(modifier!(
+                    Hours { padding, total }
+                )))])
+            );
+            assert_eq!(
+                format_description::parse(&format!("[minutes {padding_str} {total_str}]")),
+                Ok(vec![FormatItem::Component(Component::Minutes(modifier!(
+                    Minutes { padding, total }
+                )))])
+            );
+            assert_eq!(
+                format_description::parse(&format!("[seconds {padding_str} {total_str}]")),
+                Ok(vec![FormatItem::Component(Component::Seconds(modifier!(
+                    Seconds { padding, total }
+                )))])
+            );
+        }
+    }
+
     for (digits, digits_str) in iterator::subsecond_digits() {
         assert_eq!(
             format_description::parse(&format!("[subsecond {digits_str}]")),
@@ -886,3 +1062,95 @@ fn rfc_3339() {
         ])
     );
 }
+
+#[test]
+fn strftime() {
+    assert_eq!(
+        format_description::parse_strftime_borrowed("%Y-%m-%dT%H:%M:%S"),
+        format_description::parse_borrowed::<2>("[year]-[month]-[day]T[hour]:[minute]:[second]")
+    );
+    assert_eq!(
+        format_description::parse_strftime_borrowed("%-d %_H %0e %e %k %l %-j"),
+        format_description::parse_borrowed::<2>(
+            "[day padding:none] [hour padding:space] [day] [day padding:space] [hour \
+             padding:space] [hour repr:12 padding:space] [ordinal padding:none]"
+        )
+    );
+    assert_eq!(
+        format_description::parse_strftime_borrowed("%a %A %b %h %B %p %P"),
+        format_description::parse_borrowed::<2>(
+            "[weekday repr:short case_sensitive:false] [weekday case_sensitive:false] [month \
+             repr:short case_sensitive:false] [month repr:short case_sensitive:false] [month \
+             repr:long case_sensitive:false] [period case_sensitive:false] [period case:lower \
+             case_sensitive:false]"
+        )
+    );
+    assert_eq!(
+        format_description::parse_strftime_borrowed("%C%y %G %g %U %W %V %u %w %s %Z"),
+        format_description::parse_borrowed::<2>(
+            "[century][year repr:last_two] [year base:iso_week] [year repr:last_two \
+             base:iso_week] [week_number repr:sunday] [week_number repr:monday] [week_number] \
+             [weekday repr:monday] [weekday repr:sunday one_indexed:false] [unix_timestamp] \
+             [zone_abbr]"
+        )
+    );
+    assert_eq!(
+        format_description::parse_strftime_borrowed("100%% %n%t"),
+        Ok(vec![
+            FormatItem::Literal(b"100"),
+            FormatItem::Literal(b"%"),
+            FormatItem::Literal(b" "),
+            FormatItem::Literal(b"\n"),
+            FormatItem::Literal(b"\t"),
+        ])
+    );
+    assert_eq!(
+        format_description::parse_strftime_borrowed("%z"),
+        Ok(vec![FormatItem::Compound(&[
+            FormatItem::Component(Component::OffsetHour(OffsetHour::default())),
+            FormatItem::Component(Component::OffsetMinute(OffsetMinute::default())),
+        ])])
+    );
+    assert_eq!(
+        format_description::parse_strftime_owned("%F"),
+        Ok(OwnedFormatItem::from(vec![FormatItem::Compound(
+            &format_description::parse_borrowed::<2>("[year]-[month]-[day]").unwrap()
+        )]))
+    );
+    assert_eq!(
+        format_description::parse_strftime_borrowed("%-a %_B %0p %-F %_%"),
+        format_description::parse_strftime_borrowed("%a %B %p %F %%")
+    );
+    assert_eq!(format_description::parse_strftime_borrowed(""), Ok(vec![]));
+}
+
+#[test]
+fn strftime_errors() {
+    assert!(matches!(
+        format_description::parse_strftime_borrowed("%Y %Q"),
+        Err(InvalidFormatDescription::InvalidComponentName { name, index: 4, .. })
+            if name == "Q"
+    ));
+    assert!(matches!(
+        format_description::parse_strftime_owned("%_é"),
+        Err(InvalidFormatDescription::InvalidComponentName { name, index: 2, .. })
+            if name == "é"
+    ));
+    assert!(matches!(
+        format_description::parse_strftime_borrowed("%H:%-Q"),
+        Err(InvalidFormatDescription::InvalidComponentName { name, index: 5, .. })
+            if name == "Q"
+    ));
+    assert!(matches!(
+        format_description::parse_strftime_borrowed("abc%"),
+        Err(InvalidFormatDescription::Expected {
+            what: "strftime directive",
+            index: 3,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_strftime_borrowed("%-"),
+        Err(InvalidFormatDescription::Expected { index: 0, .. })
+    ));
+}
@@ -1,13 +1,20 @@
-use std::num::{NonZeroU16, NonZeroU8};
+use std::num::{NonZeroU16, NonZeroU32, NonZeroU8};
 
+use time::format_description::locale::{
+    Dutch, French, German, Italian, Japanese, Locale, Localized, Portuguese, Spanish,
+};
 use time::format_description::modifier::Ignore;
-use time::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
-use time::format_description::{modifier, Component, FormatItem, OwnedFormatItem};
+use time::format_description::well_known::{
+    rfc3339, GeneralizedTime, HttpDate, Iso8601, Rfc2822, Rfc3164, Rfc3339, Rfc5424, Rfc5545, Sql,
+    UtcTime,
+};
+use time::format_description::{self, modifier, Component, FormatItem, OwnedFormatItem};
 use time::macros::{date, datetime, offset, time};
-use time::parsing::Parsed;
+use time::parsing::{Defaults, Parsed, WithDefaults};
+use time::zone::LocalTimeType;
 use time::{
-    error, format_description as fd, Date, Month, OffsetDateTime, PrimitiveDateTime, Time,
-    UtcOffset, Weekday,
+    error, format_description as fd, Date, Duration, Month, OffsetDateTime, PrimitiveDateTime,
+    Time, UtcOffset, Weekday,
 };
 
 macro_rules! invalid_literal {
@@ -231,826 +238,1439 @@ fn rfc_2822_err() {
 }
 
 #[test]
-fn rfc_3339() -> time::Result<()> {
-    assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05 UTC),
-    );
-    assert_eq!(
-        OffsetDateTime::parse("2021-12-31T23:59:60Z", &Rfc3339)?,
-        datetime!(2021-12-31 23:59:59.999_999_999 UTC),
-    );
-    assert_eq!(
-        OffsetDateTime::parse("2015-07-01T00:59:60+01:00", &Rfc3339)?,
-        datetime!(2015-06-30 23:59:59.999_999_999 UTC),
-    );
-    assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.1Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.1 UTC),
-    );
-    assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.12Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.12 UTC),
-    );
-    assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.123Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123 UTC),
-    );
+fn http_date() -> time::Result<()> {
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.1234Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123_4 UTC),
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37 UTC),
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.12345Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123_45 UTC),
+        OffsetDateTime::parse("Sunday, 06-Nov-94 08:49:37 GMT", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37 UTC),
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.123456Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123_456 UTC),
+        OffsetDateTime::parse("Tuesday, 06-Nov-07 08:49:37 GMT", &HttpDate)?,
+        datetime!(2007-11-06 08:49:37 UTC),
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.1234567Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123_456_7 UTC),
+        OffsetDateTime::parse("Sun Nov  6 08:49:37 1994", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37 UTC),
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.12345678Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123_456_78 UTC),
+        OffsetDateTime::parse("Wed Nov 16 08:49:37 1994", &HttpDate)?,
+        datetime!(1994-11-16 08:49:37 UTC),
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.123456789Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123_456_789 UTC),
+        OffsetDateTime::parse("sun, 06 nov 1994 08:49:37 gmt", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37 UTC),
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.123456789-01:02", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123_456_789 -01:02),
+        OffsetDateTime::parse("Sat, 31 Dec 2016 23:59:60 GMT", &HttpDate)?,
+        datetime!(2016-12-31 23:59:59.999_999_999 UTC),
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.123456789+01:02", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123_456_789 +01:02),
+        PrimitiveDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37),
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.123-00:01", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123 -00:01),
+        Date::parse("Sun Nov  6 08:49:37 1994", &HttpDate)?,
+        date!(1994 - 11 - 06),
     );
 
+    Ok(())
+}
+
+#[test]
+fn http_date_err() {
+    assert!(matches!(
+        OffsetDateTime::parse("Sux, 06 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("weekday")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun,06 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 6 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nox 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 94 08:49:37 GMT", &HttpDate),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49 GMT", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 +0000", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sunday, 06-Nov-1994 08:49:37 GMT", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun Nov 6 08:49:37 1994", &HttpDate),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun Nov  6 08:49:37 94", &HttpDate),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun Nov  6 08:49:37 1994 GMT", &HttpDate),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:60 GMT", &HttpDate),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "second" && component.is_conditional()
+    ));
+}
+
+#[test]
+fn rfc_3164() -> time::Result<()> {
+    let reference = Rfc3164::with_year_reference(date!(2024 - 03 - 10));
     assert_eq!(
-        Date::parse("2021-01-02T03:04:05Z", &Rfc3339)?,
-        date!(2021 - 01 - 02),
+        PrimitiveDateTime::parse("Mar  3 14:05:09", &reference)?,
+        datetime!(2024-03-03 14:05:09)
     );
     assert_eq!(
-        Date::parse("2021-01-02T03:04:05.123+01:02", &Rfc3339)?,
-        date!(2021 - 01 - 02),
+        PrimitiveDateTime::parse("Mar 03 14:05:09", &reference)?,
+        datetime!(2024-03-03 14:05:09)
     );
     assert_eq!(
-        Date::parse("2021-01-02T03:04:05.123-01:02", &Rfc3339)?,
-        date!(2021 - 01 - 02),
+        PrimitiveDateTime::parse("Sep 10 00:00:00", &reference)?,
+        datetime!(2023-09-10 00:00:00)
     );
-
     assert_eq!(
-        UtcOffset::parse("2021-01-02T03:04:05Z", &Rfc3339)?,
-        offset!(UTC),
+        PrimitiveDateTime::parse("Sep  8 00:00:00", &reference)?,
+        datetime!(2024-09-08 00:00:00)
     );
     assert_eq!(
-        UtcOffset::parse("2021-01-02T03:04:05.123+01:02", &Rfc3339)?,
-        offset!(+01:02),
+        PrimitiveDateTime::parse(
+            "Jan  1 00:00:01",
+            &Rfc3164::with_year_reference(date!(2023 - 12 - 31))
+        )?,
+        datetime!(2024-01-01 00:00:01)
     );
     assert_eq!(
-        UtcOffset::parse("2021-01-02T03:04:05.123-01:02", &Rfc3339)?,
-        offset!(-01:02),
+        PrimitiveDateTime::parse(
+            "Feb 29 12:00:00",
+            &Rfc3164::with_year_reference(date!(2023 - 03 - 01))
+        )?,
+        datetime!(2024-02-29 12:00:00)
     );
     assert_eq!(
-        UtcOffset::parse("2021-01-02T03:04:05.123-00:01", &Rfc3339)?,
-        offset!(-00:01),
+        Time::parse("Mar  3 14:05:09", &Rfc3164::DEFAULT)?,
+        time!(14:05:09)
     );
+    assert!(matches!(
+        PrimitiveDateTime::parse("Mar  3 14:05:09", &Rfc3164::DEFAULT),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
 
     Ok(())
 }
 
 #[test]
-fn rfc_3339_err() {
-    assert!(matches!(
-        PrimitiveDateTime::parse("x", &Rfc3339),
-        invalid_component!("year")
-    ));
-    assert!(matches!(
-        PrimitiveDateTime::parse("2021x", &Rfc3339),
-        invalid_literal!()
-    ));
+fn rfc_3164_err() {
+    let reference = Rfc3164::with_year_reference(date!(2024 - 03 - 10));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-x", &Rfc3339),
+        PrimitiveDateTime::parse("Mrz  3 14:05:09", &reference),
         invalid_component!("month")
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-0", &Rfc3339),
-        invalid_component!("month")
+        PrimitiveDateTime::parse("Mar 3 14:05:09", &reference),
+        invalid_component!("day")
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01x", &Rfc3339),
-        invalid_literal!()
+        PrimitiveDateTime::parse("Mar  0 14:05:09", &reference),
+        invalid_component!("day")
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-0", &Rfc3339),
+        PrimitiveDateTime::parse("Feb 30 14:05:09", &reference),
         invalid_component!("day")
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01x", &Rfc3339),
+        PrimitiveDateTime::parse("Mar  3 14:05", &reference),
         invalid_literal!()
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T0", &Rfc3339),
-        invalid_component!("hour")
+        OffsetDateTime::parse("Mar  3 14:05:09", &reference),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
     ));
+}
+
+#[test]
+fn rfc_5424() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("2024-03-03T14:05:09Z", &Rfc5424)?,
+        datetime!(2024-03-03 14:05:09 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2024-03-03T14:05:09.123456+01:00", &Rfc5424)?,
+        datetime!(2024-03-03 14:05:09.123_456 +01:00)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2024-03-03T14:05:09.1-01:30", &Rfc5424)?,
+        datetime!(2024-03-03 14:05:09.1 -01:30)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2024-03-03T14:05:09.003Z", &Rfc5424)?,
+        datetime!(2024-03-03 14:05:09.003)
+    );
+    Ok(())
+}
+
+#[test]
+fn rfc_5424_err() {
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00x", &Rfc3339),
-        invalid_literal!()
+        OffsetDateTime::parse("-", &Rfc5424),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00:0", &Rfc3339),
-        invalid_component!("minute")
+        OffsetDateTime::parse("2024-03-03T14:05:09.1234567Z", &Rfc5424),
+        invalid_component!("subsecond")
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00:00x", &Rfc3339),
+        OffsetDateTime::parse("2024-03-03t14:05:09Z", &Rfc5424),
         invalid_literal!()
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00:00:0", &Rfc3339),
-        invalid_component!("second")
+        OffsetDateTime::parse("2024-03-03T14:05:09z", &Rfc5424),
+        invalid_component!("offset hour")
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00:00:00.x", &Rfc3339),
+        OffsetDateTime::parse("2024-03-03T14:05:09.Z", &Rfc5424),
         invalid_component!("subsecond")
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00:00:00x", &Rfc3339),
-        invalid_component!("offset hour")
-    ));
-    assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00:00:00+0", &Rfc3339),
-        invalid_component!("offset hour")
+        OffsetDateTime::parse("2024-12-31T23:59:60Z", &Rfc5424),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(_)
+        ))
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00:00:00+00x", &Rfc3339),
+        OffsetDateTime::parse("2024-03-03T14:05:09+01", &Rfc5424),
         invalid_literal!()
     ));
+}
+
+#[test]
+fn rfc_5545() -> time::Result<()> {
+    assert_eq!(Date::parse("20240101", &Rfc5545)?, date!(2024 - 01 - 01));
+    assert_eq!(
+        PrimitiveDateTime::parse("20240101T090005", &Rfc5545)?,
+        datetime!(2024-01-01 9:00:05)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("20240101T090005Z", &Rfc5545)?,
+        datetime!(2024-01-01 9:00:05)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("20240101T090005Z", &Rfc5545)?,
+        datetime!(2024-01-01 9:00:05 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("20161231T235960Z", &Rfc5545)?,
+        datetime!(2016-12-31 23:59:59.999_999_999 UTC)
+    );
+
+    let mut parsed = Parsed::new();
+    let remaining = parsed.parse_using(b"TZID=America/New_York:19980119T020000", &Rfc5545)?;
+    assert!(remaining.is_empty());
+    assert_eq!(parsed.zone_name(), Some("America/New_York"));
+    assert_eq!(
+        PrimitiveDateTime::try_from(parsed)?,
+        datetime!(1998-01-19 2:00)
+    );
+
+    let mut parsed = Parsed::new();
+    parsed.parse_using(b"TZID=\"Etc/GMT+5\":19980119T020000", &Rfc5545)?;
+    assert_eq!(parsed.zone_name(), Some("Etc/GMT+5"));
+
+    assert_eq!(Duration::parse("P7W", &Rfc5545)?, Duration::weeks(7));
+    assert_eq!(Duration::parse("+P15D", &Rfc5545)?, Duration::days(15));
+    assert_eq!(
+        Duration::parse("P15DT5H0M20S", &Rfc5545)?,
+        Duration::days(15) + Duration::hours(5) + Duration::seconds(20)
+    );
+    assert_eq!(
+        Duration::parse("P1DT2H", &Rfc5545)?,
+        Duration::days(1) + Duration::hours(2)
+    );
+    assert_eq!(Duration::parse("-PT15M", &Rfc5545)?, -Duration::minutes(15));
+    assert_eq!(Duration::parse("PT1M30S", &Rfc5545)?, Duration::seconds(90));
+    assert_eq!(Duration::parse("PT0S", &Rfc5545)?, Duration::ZERO);
+
+    Ok(())
+}
+
+#[test]
+fn rfc_5545_err() {
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00:00:00+00:0", &Rfc3339),
-        invalid_component!("offset minute")
+        OffsetDateTime::parse("20240101T090000", &Rfc5545),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-13-01T00:00:00Z", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "month"
+        PrimitiveDateTime::parse("20240101", &Rfc5545),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-02T03:04:60Z", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second"
+        Date::parse("2024-01-01", &Rfc5545),
+        invalid_component!("month")
     ));
-
-    // Conversions to offset-unaware types do not perform special treatment for leap seconds
-    // even if the input could refer to one.
     assert!(matches!(
-        PrimitiveDateTime::parse("2022-01-01T00:59:60+01:00", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second"
+        Date::parse("20241301", &Rfc5545),
+        invalid_component!("month")
     ));
     assert!(matches!(
-        Time::parse("2021-12-31T23:04:60Z", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second"
+        PrimitiveDateTime::parse("20240101T0900", &Rfc5545),
+        invalid_component!("second")
     ));
-
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-02T03:04:05Z ", &Rfc3339),
+        PrimitiveDateTime::parse("20240101T090000z", &Rfc5545),
         Err(error::Parse::UnexpectedTrailingCharacters { .. })
     ));
     assert!(matches!(
-        OffsetDateTime::parse("x", &Rfc3339),
-        invalid_component!("year")
+        PrimitiveDateTime::parse("TZID=Europe/Paris:20240101T090000Z", &Rfc5545),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021x", &Rfc3339),
+        PrimitiveDateTime::parse("TZID=Europe/Paris:20240101", &Rfc5545),
         invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-x", &Rfc3339),
-        invalid_component!("month")
+        PrimitiveDateTime::parse("TZID=:20240101T090000", &Rfc5545),
+        invalid_component!("zone name")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-0", &Rfc3339),
-        invalid_component!("month")
+        PrimitiveDateTime::parse("TZID=\"Europe/Paris:20240101T090000", &Rfc5545),
+        invalid_component!("zone name")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01x", &Rfc3339),
-        invalid_literal!()
+        OffsetDateTime::parse("20240101T120060Z", &Rfc5545),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(_)
+        ))
     ));
+
+    assert!(matches!(Duration::parse("P", &Rfc5545), invalid_literal!()));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-0", &Rfc3339),
-        invalid_component!("day")
+        Duration::parse("P1W2D", &Rfc5545),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01x", &Rfc3339),
+        Duration::parse("P1H", &Rfc5545),
         invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T0", &Rfc3339),
-        invalid_component!("hour")
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00x", &Rfc3339),
+        Duration::parse("PT", &Rfc5545),
         invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:0", &Rfc3339),
-        invalid_component!("minute")
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00x", &Rfc3339),
+        Duration::parse("PT1H20S", &Rfc5545),
         invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:0", &Rfc3339),
-        invalid_component!("second")
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00.x", &Rfc3339),
-        invalid_component!("subsecond")
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00x", &Rfc3339),
-        invalid_component!("offset hour")
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00+0", &Rfc3339),
-        invalid_component!("offset hour")
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00+00x", &Rfc3339),
-        invalid_literal!()
+        Duration::parse("P3000000000000000000W", &Rfc5545),
+        invalid_component!("days")
     ));
+}
+
+#[test]
+fn utc_time() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("240102030405Z", &UtcTime)?,
+        datetime!(2024-01-02 03:04:05 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("500101000000Z", &UtcTime)?,
+        datetime!(1950-01-01 0:00 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("491231235959Z", &UtcTime)?,
+        datetime!(2049-12-31 23:59:59 UTC)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("991231235959Z", &UtcTime)?,
+        datetime!(1999-12-31 23:59:59)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn utc_time_err() {
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00+00:0", &Rfc3339),
-        invalid_component!("offset minute")
+        OffsetDateTime::parse("2401020304Z", &UtcTime),
+        invalid_component!("second")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00+24:00", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "offset hour"
+        OffsetDateTime::parse("240102030405", &UtcTime),
+        invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00+00:60", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "offset minute"
+        OffsetDateTime::parse("240102030405+0000", &UtcTime),
+        invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-13-01T00:00:00Z", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "month"
+        OffsetDateTime::parse("240102030405z", &UtcTime),
+        invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-12-31T23:59:61Z", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second" && !component.is_conditional()
+        OffsetDateTime::parse("240102030405.5Z", &UtcTime),
+        invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-02T23:59:60Z", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second" && component.is_conditional()
+        OffsetDateTime::parse("241302030405Z", &UtcTime),
+        invalid_component!("month")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-12-31T03:04:60Z", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second" && component.is_conditional()
+        OffsetDateTime::parse("240230030405Z", &UtcTime),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(_)
+        ))
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-12-31T23:59:60+01:00", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second" && component.is_conditional()
+        OffsetDateTime::parse("241231235960Z", &UtcTime),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(_)
+        ))
     ));
 }
 
 #[test]
-fn iso_8601() {
-    assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05Z", &Iso8601::DEFAULT),
-        Ok(datetime!(2021-01-02 03:04:05 UTC))
-    );
-    assert_eq!(
-        OffsetDateTime::parse("2021-002T03:04:05Z", &Iso8601::DEFAULT),
-        Ok(datetime!(2021-002 03:04:05 UTC))
-    );
-    assert_eq!(
-        OffsetDateTime::parse("2021-W01-2T03:04:05Z", &Iso8601::DEFAULT),
-        Ok(datetime!(2021-W 01-2 03:04:05 UTC))
-    );
-    assert_eq!(
-        OffsetDateTime::parse("-002021-01-02T03:04:05+01:00", &Iso8601::DEFAULT),
-        Ok(datetime!(-002021-01-02 03:04:05 +01:00))
-    );
-    assert_eq!(
-        OffsetDateTime::parse("20210102T03.1Z", &Iso8601::DEFAULT),
-        Ok(datetime!(2021-01-02 03:06:00 UTC))
-    );
-    assert_eq!(
-        OffsetDateTime::parse("2021002T0304.1Z", &Iso8601::DEFAULT),
-        Ok(datetime!(2021-002 03:04:06 UTC))
-    );
+fn generalized_time() -> time::Result<()> {
     assert_eq!(
-        OffsetDateTime::parse("2021W012T030405.1-0100", &Iso8601::DEFAULT),
-        Ok(datetime!(2021-W 01-2 03:04:05.1 -01:00))
+        OffsetDateTime::parse("20240102030405Z", &GeneralizedTime)?,
+        datetime!(2024-01-02 03:04:05 UTC)
     );
     assert_eq!(
-        OffsetDateTime::parse("20210102T03Z", &Iso8601::DEFAULT),
-        Ok(datetime!(2021-01-02 03:00:00 UTC))
+        OffsetDateTime::parse("20240102030405.1Z", &GeneralizedTime)?,
+        datetime!(2024-01-02 03:04:05.1 UTC)
     );
     assert_eq!(
-        OffsetDateTime::parse("20210102T0304Z", &Iso8601::DEFAULT),
-        Ok(datetime!(2021-01-02 03:04:00 UTC))
+        OffsetDateTime::parse("20240102030405.000000001Z", &GeneralizedTime)?,
+        datetime!(2024-01-02 03:04:05.000_000_001 UTC)
     );
     assert_eq!(
-        UtcOffset::parse("+0304", &Iso8601::DEFAULT),
-        Ok(offset!(+03:04))
+        OffsetDateTime::parse("19491231235959Z", &GeneralizedTime)?,
+        datetime!(1949-12-31 23:59:59 UTC)
     );
     assert_eq!(
-        PrimitiveDateTime::parse("2022-07-22T12:52:50.349409", &Iso8601::DEFAULT),
-        Ok(datetime!(2022-07-22 12:52:50.349409000))
+        PrimitiveDateTime::parse("99991231235959.999Z", &GeneralizedTime)?,
+        datetime!(9999-12-31 23:59:59.999)
     );
+
+    Ok(())
 }
 
 #[test]
-fn iso_8601_error() {
-    assert!(matches!(
-        OffsetDateTime::parse("20210102T03:04Z", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("20210102T03.", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
-    ));
+fn generalized_time_err() {
     assert!(matches!(
-        OffsetDateTime::parse("2021-0102", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        OffsetDateTime::parse("20240102030405.10Z", &GeneralizedTime),
+        invalid_component!("subsecond")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-x", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        OffsetDateTime::parse("20240102030405.0Z", &GeneralizedTime),
+        invalid_component!("subsecond")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-Wx", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        OffsetDateTime::parse("20240102030405.Z", &GeneralizedTime),
+        invalid_component!("subsecond")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-W012", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        OffsetDateTime::parse("20240102030405.1234567891Z", &GeneralizedTime),
+        invalid_component!("subsecond")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-W01-x", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        OffsetDateTime::parse("20240102030405,5Z", &GeneralizedTime),
+        invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-02T03:x", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        OffsetDateTime::parse("20240102030405", &GeneralizedTime),
+        invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-02T03:04x", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        OffsetDateTime::parse("20240102030405-0500", &GeneralizedTime),
+        invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-02T03:04:", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        OffsetDateTime::parse("202401020304Z", &GeneralizedTime),
+        invalid_component!("second")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("01:02", &Iso8601::DEFAULT),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation { .. }
-        ))
+        OffsetDateTime::parse("240102030405Z", &GeneralizedTime),
+        invalid_component!("second")
     ));
 }
 
 #[test]
-fn parse_time() -> time::Result<()> {
-    let format_input_output = [
-        (fd::parse("[hour repr:12] [period]")?, "01 PM", time!(1 PM)),
-        (
-            fd::parse("[hour]:[minute]:[second]")?,
-            "13:02:03",
-            time!(13:02:03),
-        ),
-        (
-            fd::parse("[hour repr:12]:[minute] [period]")?,
-            "01:02 PM",
-            time!(1:02 PM),
-        ),
-        (fd::parse("[hour]:[minute]")?, "01:02", time!(1:02)),
-        (
-            fd::parse("[hour repr:12]:[minute] [period]")?,
-            "01:02 AM",
-            time!(1:02 AM),
-        ),
-        (fd::parse("[hour]:[minute]")?, "01:02", time!(1:02)),
-        (fd::parse("[hour repr:12] [period]")?, "12 AM", time!(12 AM)),
-        (fd::parse("[hour repr:12] [period]")?, "12 PM", time!(12 PM)),
-    ];
-
-    for (format_description, input, output) in &format_input_output {
-        assert_eq!(&Time::parse(input, format_description)?, output);
-        assert_eq!(
-            &Time::parse(input, &OwnedFormatItem::from(format_description))?,
-            output
-        );
-        assert_eq!(
-            &Time::parse(
-                input,
-                [OwnedFormatItem::from(format_description)].as_slice()
-            )?,
-            output
-        );
-    }
+fn sql() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("2024-01-02 03:04:05+00", &Sql)?,
+        datetime!(2024-01-02 03:04:05 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2024-01-02 03:04:05.123456-05:30", &Sql)?,
+        datetime!(2024-01-02 03:04:05.123_456 -5:30)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2024-01-02T03:04:05-0530", &Sql)?,
+        datetime!(2024-01-02 03:04:05 -5:30)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2024-01-02 03:04:05+01:02:03", &Sql)?,
+        datetime!(2024-01-02 03:04:05 +1:02:03)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2024-01-02 03:04+02", &Sql)?,
+        datetime!(2024-01-02 03:04 +2)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2024-01-02 03:04:05.123456789", &Sql)?,
+        datetime!(2024-01-02 03:04:05.123_456_789)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("0044-03-15 12:00:00 BC", &Sql)?,
+        datetime!(-0043-03-15 12:00)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("0001-12-31 23:59:59+00 BC", &Sql)?,
+        datetime!(0000-12-31 23:59:59 UTC)
+    );
+    assert_eq!(Date::parse("2024-01-02", &Sql)?, date!(2024 - 01 - 02));
+    assert_eq!(Time::parse("23:59:59.5", &Sql)?, time!(23:59:59.5));
 
     Ok(())
 }
 
 #[test]
-fn parse_time_err() -> time::Result<()> {
-    assert!(matches!(
-        Time::try_from(Parsed::new()),
-        Err(error::TryFromParsed::InsufficientInformation { .. })
-    ));
-    assert!(matches!(
-        Time::parse("", &fd::parse("")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation { .. }
-        ))
-    ));
-    assert!(matches!(
-        Time::parse("12", &fd::parse("[hour]")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation { .. }
-        ))
-    ));
-    assert!(matches!(
-        Time::parse("13 PM", &fd::parse("[hour repr:12] [period]")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::ComponentRange(component)
-        )) if component.name() == "hour"
-    ));
+fn sql_err() {
     assert!(matches!(
-        Time::parse(" ", &fd::parse("")?),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        Date::parse("0000-01-01", &Sql),
+        invalid_component!("year")
     ));
     assert!(matches!(
-        Time::parse("a", &fd::parse("[subsecond digits:1]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
-        ))
+        Date::parse("24-01-01", &Sql),
+        invalid_component!("year")
     ));
     assert!(matches!(
-        Time::parse("1a", &fd::parse("[subsecond digits:2]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
-        ))
+        Date::parse("2024/01/01", &Sql),
+        invalid_literal!()
     ));
     assert!(matches!(
-        Time::parse("1a", &fd::parse_owned::<2>("[subsecond digits:2]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
-        ))
+        Date::parse("2024-13-01", &Sql),
+        invalid_component!("month")
     ));
     assert!(matches!(
-        Time::parse(
-            "1a",
-            [fd::parse_owned::<2>("[subsecond digits:2]")?].as_slice()
-        ),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
-        ))
+        OffsetDateTime::parse("2024-01-02 03:04:05.+00", &Sql),
+        invalid_component!("subsecond")
     ));
     assert!(matches!(
-        Time::parse("12a", &fd::parse("[subsecond digits:3]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
-        ))
+        OffsetDateTime::parse("2024-01-02 03:04:05.1234567891+00", &Sql),
+        invalid_component!("subsecond")
     ));
     assert!(matches!(
-        Time::parse("123a", &fd::parse("[subsecond digits:4]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
-        ))
+        OffsetDateTime::parse("2024-01-02 03:04:05+", &Sql),
+        invalid_component!("offset hour")
     ));
     assert!(matches!(
-        Time::parse("1234a", &fd::parse("[subsecond digits:5]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
-        ))
+        OffsetDateTime::parse("2024-01-02 03:04:05+05:", &Sql),
+        invalid_component!("offset minute")
     ));
     assert!(matches!(
-        Time::parse("12345a", &fd::parse("[subsecond digits:6]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
-        ))
+        OffsetDateTime::parse("2024-01-02 24:00:00+00", &Sql),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "hour"
     ));
     assert!(matches!(
-        Time::parse("123456a", &fd::parse("[subsecond digits:7]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
-        ))
+        OffsetDateTime::parse("2024-01-02 03:04:05+00 AD", &Sql),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
     ));
-    assert!(matches!(
-        Time::parse("1234567a", &fd::parse("[subsecond digits:8]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
-        ))
-    ));
-    assert!(matches!(
-        Time::parse("12345678a", &fd::parse("[subsecond digits:9]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
-        ))
-    ));
-
-    Ok(())
 }
 
 #[test]
-fn parse_date() -> time::Result<()> {
-    let format_input_output = [
-        (
-            fd::parse("[year]-[month]-[day]")?,
-            "2021-01-02",
-            date!(2021 - 01 - 02),
-        ),
-        (
-            fd::parse("[year]-[ordinal]")?,
-            "2021-002",
-            date!(2021 - 002),
-        ),
-        (
-            fd::parse("[year base:iso_week]-W[week_number]-[weekday repr:monday]")?,
-            "2020-W53-6",
-            date!(2021 - 01 - 02),
-        ),
-        (
-            fd::parse("[year]-W[week_number repr:monday]-[weekday repr:monday]")?,
-            "2021-W00-6",
-            date!(2021 - 01 - 02),
-        ),
-        (
-            fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
-            "2021-W00-6",
-            date!(2021 - 01 - 02),
-        ),
-        (
-            fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
-            "2023-W01-1",
-            date!(2023 - 01 - 02),
-        ),
-        (
-            fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
-            "2022-W00-7",
-            date!(2022 - 01 - 02),
-        ),
-        (
-            fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
-            "2026-W00-5",
-            date!(2026 - 01 - 02),
-        ),
-        (
-            fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
-            "2025-W00-4",
-            date!(2025 - 01 - 02),
-        ),
-        (
-            fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
-            "2019-W00-3",
-            date!(2019 - 01 - 02),
-        ),
-        (
-            fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
-            "2018-W01-2",
-            date!(2018 - 01 - 02),
-        ),
-        (
-            fd::parse(
-                "[year padding:space]-W[week_number repr:sunday padding:none]-[weekday \
-                 repr:sunday]",
-            )?,
-            " 2018-W01-2",
-            date!(2018 - 01 - 02),
-        ),
-    ];
+fn rfc_3339() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-12-31T23:59:60Z", &Rfc3339)?,
+        datetime!(2021-12-31 23:59:59.999_999_999 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2015-07-01T00:59:60+01:00", &Rfc3339)?,
+        datetime!(2015-06-30 23:59:59.999_999_999 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.1Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.1 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.12Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.12 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.123Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.1234Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123_4 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.12345Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123_45 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.123456Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123_456 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.1234567Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123_456_7 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.12345678Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123_456_78 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.123456789Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123_456_789 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.123456789-01:02", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123_456_789 -01:02),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.123456789+01:02", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123_456_789 +01:02),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.123-00:01", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123 -00:01),
+    );
 
-    for (format_description, input, output) in &format_input_output {
-        assert_eq!(&Date::parse(input, format_description)?, output);
-        assert_eq!(
-            &Date::parse(input, &OwnedFormatItem::from(format_description))?,
-            output
-        );
-    }
+    assert_eq!(
+        Date::parse("2021-01-02T03:04:05Z", &Rfc3339)?,
+        date!(2021 - 01 - 02),
+    );
+    assert_eq!(
+        Date::parse("2021-01-02T03:04:05.123+01:02", &Rfc3339)?,
+        date!(2021 - 01 - 02),
+    );
+    assert_eq!(
+        Date::parse("2021-01-02T03:04:05.123-01:02", &Rfc3339)?,
+        date!(2021 - 01 - 02),
+    );
+
+    assert_eq!(
+        UtcOffset::parse("2021-01-02T03:04:05Z", &Rfc3339)?,
+        offset!(UTC),
+    );
+    assert_eq!(
+        UtcOffset::parse("2021-01-02T03:04:05.123+01:02", &Rfc3339)?,
+        offset!(+01:02),
+    );
+    assert_eq!(
+        UtcOffset::parse("2021-01-02T03:04:05.123-01:02", &Rfc3339)?,
+        offset!(-01:02),
+    );
+    assert_eq!(
+        UtcOffset::parse("2021-01-02T03:04:05.123-00:01", &Rfc3339)?,
+        offset!(-00:01),
+    );
 
     Ok(())
 }
 
 #[test]
-fn parse_date_err() -> time::Result<()> {
+fn rfc_3339_config() -> time::Result<()> {
+    const MILLIS: rfc3339::EncodedConfig = rfc3339::Config::DEFAULT
+        .set_subsecond_digits(rfc3339::SubsecondDigits::Fixed(3))
+        .set_use_z_for_utc(false)
+        .encode();
+    const SPACE: rfc3339::EncodedConfig = rfc3339::Config::DEFAULT
+        .set_separator(rfc3339::Separator::Space)
+        .encode();
+
+    // Only the separator affects parsing.
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.1Z", &Rfc3339::<MILLIS>::FORMAT)?,
+        datetime!(2021-01-02 03:04:05.1 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02t03:04:05+01:02", &Rfc3339::<MILLIS>::FORMAT)?,
+        datetime!(2021-01-02 03:04:05 +01:02)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02 03:04:05.123Z", &Rfc3339::<SPACE>::FORMAT)?,
+        datetime!(2021-01-02 03:04:05.123 UTC)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-01-02 03:04:05Z", &Rfc3339::<SPACE>::FORMAT)?,
+        datetime!(2021-01-02 03:04:05)
+    );
+
     assert!(matches!(
-        Date::try_from(Parsed::new()),
-        Err(error::TryFromParsed::InsufficientInformation { .. })
+        OffsetDateTime::parse("2021-01-02 03:04:05Z", &Rfc3339),
+        invalid_literal!()
     ));
     assert!(matches!(
-        Date::parse("", &fd::parse("")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation { .. }
-        ))
+        OffsetDateTime::parse("2021-01-02T03:04:05Z", &Rfc3339::<SPACE>::FORMAT),
+        invalid_literal!()
     ));
     assert!(matches!(
-        Date::parse("a", &fd::parse("[year]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("year")
-        ))
+        PrimitiveDateTime::parse("2021-01-02T03:04:05Z", &Rfc3339::<SPACE>::FORMAT),
+        invalid_literal!()
     ));
+
+    Ok(())
+}
+
+#[test]
+fn rfc_3339_err() {
     assert!(matches!(
-        Date::parse("0001", &fd::parse("[year sign:mandatory]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("year")
-        ))
+        PrimitiveDateTime::parse("x", &Rfc3339),
+        invalid_component!("year")
     ));
     assert!(matches!(
-        Date::parse("0a", &fd::parse("[year repr:last_two]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("year")
-        ))
+        PrimitiveDateTime::parse("2021x", &Rfc3339),
+        invalid_literal!()
     ));
     assert!(matches!(
-        Date::parse("2021-366", &fd::parse("[year]-[ordinal]")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::ComponentRange(component)
-        )) if component.name() == "ordinal"
+        PrimitiveDateTime::parse("2021-x", &Rfc3339),
+        invalid_component!("month")
     ));
     assert!(matches!(
-        Date::parse("2021-12-32", &fd::parse("[year]-[month]-[day]")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::ComponentRange(component)
-        )) if component.name() == "day"
+        PrimitiveDateTime::parse("2021-0", &Rfc3339),
+        invalid_component!("month")
     ));
     assert!(matches!(
-        Date::parse("2021-W54-1", &fd::parse("[year base:iso_week]-W[week_number]-[weekday repr:monday]")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::ComponentRange(component)
-        )) if component.name() == "week"
+        PrimitiveDateTime::parse("2021-01x", &Rfc3339),
+        invalid_literal!()
     ));
     assert!(matches!(
-        Date::parse("2021-W54-1", &fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:monday]")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::ComponentRange(component)
-        )) if component.name() == "ordinal"
+        PrimitiveDateTime::parse("2021-01-0", &Rfc3339),
+        invalid_component!("day")
     ));
     assert!(matches!(
-        Date::parse("2021-W54-1", &fd::parse("[year]-W[week_number repr:monday]-[weekday repr:monday]")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::ComponentRange(component)
-        )) if component.name() == "ordinal"
+        PrimitiveDateTime::parse("2021-01-01x", &Rfc3339),
+        invalid_literal!()
     ));
     assert!(matches!(
-        Date::parse("Ja", &fd::parse("[month repr:short]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("month")
-        ))
+        PrimitiveDateTime::parse("2021-01-01T0", &Rfc3339),
+        invalid_component!("hour")
     ));
     assert!(matches!(
-        Date::parse("  2a21", &fd::parse("[year padding:space]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("year")
-        ))
+        PrimitiveDateTime::parse("2021-01-01T00x", &Rfc3339),
+        invalid_literal!()
     ));
-
-    Ok(())
-}
-
-#[test]
-fn parse_offset() -> time::Result<()> {
-    // Regression check for #522.
-    assert_eq!(
-        UtcOffset::parse(
-            "-00:01",
-            &fd::parse("[offset_hour sign:mandatory]:[offset_minute]")?,
-        ),
-        Ok(offset!(-00:01)),
-    );
-    assert_eq!(
-        UtcOffset::parse(
-            "-00:00:01",
-            &fd::parse("[offset_hour sign:mandatory]:[offset_minute]:[offset_second]")?,
-        ),
-        Ok(offset!(-00:00:01)),
-    );
-
-    Ok(())
-}
-
-#[test]
-fn parse_offset_err() -> time::Result<()> {
     assert!(matches!(
-        UtcOffset::parse("", &fd::parse("")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation { .. }
-        ))
+        PrimitiveDateTime::parse("2021-01-01T00:0", &Rfc3339),
+        invalid_component!("minute")
     ));
-    assert_eq!(
-        UtcOffset::parse("01", &fd::parse("[offset_hour sign:mandatory]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("offset hour")
-        ))
-    );
     assert!(matches!(
-        UtcOffset::parse("24", &fd::parse("[offset_hour]")?),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "offset hour"
+        PrimitiveDateTime::parse("2021-01-01T00:00x", &Rfc3339),
+        invalid_literal!()
     ));
     assert!(matches!(
-        UtcOffset::parse("00:60", &fd::parse("[offset_hour]:[offset_minute]")?),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "offset minute"
+        PrimitiveDateTime::parse("2021-01-01T00:00:0", &Rfc3339),
+        invalid_component!("second")
     ));
     assert!(matches!(
-        UtcOffset::parse("00:00:60", &fd::parse("[offset_hour]:[offset_minute]:[offset_second]")?),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "offset second"
+        PrimitiveDateTime::parse("2021-01-01T00:00:00.x", &Rfc3339),
+        invalid_component!("subsecond")
     ));
-
-    Ok(())
-}
-
-#[test]
-fn parse_primitive_date_time_err() -> time::Result<()> {
     assert!(matches!(
-        PrimitiveDateTime::parse("", &fd::parse("")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation { .. }
-        ))
+        PrimitiveDateTime::parse("2021-01-01T00:00:00x", &Rfc3339),
+        invalid_component!("offset hour")
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-001 13 PM", &fd::parse("[year]-[ordinal] [hour repr:12] [period]")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::ComponentRange(component)
-        )) if component.name() == "hour"
+        PrimitiveDateTime::parse("2021-01-01T00:00:00+0", &Rfc3339),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-01T00:00:00+00x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-01T00:00:00+00:0", &Rfc3339),
+        invalid_component!("offset minute")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-13-01T00:00:00Z", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "month"
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-02T03:04:60Z", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second"
     ));
 
-    Ok(())
-}
+    // Conversions to offset-unaware types do not perform special treatment for leap seconds
+    // even if the input could refer to one.
+    assert!(matches!(
+        PrimitiveDateTime::parse("2022-01-01T00:59:60+01:00", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second"
+    ));
+    assert!(matches!(
+        Time::parse("2021-12-31T23:04:60Z", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second"
+    ));
 
-#[test]
-fn parse_offset_date_time_err() -> time::Result<()> {
     assert!(matches!(
-        OffsetDateTime::parse("", &fd::parse("")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation { .. }
-        ))
+        OffsetDateTime::parse("2021-01-02T03:04:05Z ", &Rfc3339),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
     ));
     assert!(matches!(
-        OffsetDateTime::parse("x", &fd::parse("[year]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("year")
-        ))
+        OffsetDateTime::parse("x", &Rfc3339),
+        invalid_component!("year")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-001 12 PM +25", &fd::parse("[year]-[ordinal] [hour repr:12] [period] [offset_hour sign:mandatory]")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::ComponentRange(component)
-        )) if component.name() == "offset hour"
+        OffsetDateTime::parse("2021x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-x", &Rfc3339),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-0", &Rfc3339),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-0", &Rfc3339),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T0", &Rfc3339),
+        invalid_component!("hour")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:0", &Rfc3339),
+        invalid_component!("minute")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:0", &Rfc3339),
+        invalid_component!("second")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00.x", &Rfc3339),
+        invalid_component!("subsecond")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00x", &Rfc3339),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00+0", &Rfc3339),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00+00x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00+00:0", &Rfc3339),
+        invalid_component!("offset minute")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00+24:00", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "offset hour"
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00+00:60", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "offset minute"
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-13-01T00:00:00Z", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "month"
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-12-31T23:59:61Z", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second" && !component.is_conditional()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T23:59:60Z", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second" && component.is_conditional()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-12-31T03:04:60Z", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second" && component.is_conditional()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-12-31T23:59:60+01:00", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second" && component.is_conditional()
     ));
-
-    Ok(())
 }
 
 #[test]
-fn parse_components() -> time::Result<()> {
-    macro_rules! parse_component {
-        ($component:expr, $input:expr,_. $property:ident() == $expected:expr) => {
-            let mut parsed = Parsed::new();
-            parsed.parse_component($input, $component)?;
-            assert_eq!(parsed.$property(), $expected);
-        };
-    }
-
-    parse_component!(
-        Component::Year(modifier!(Year {
-            padding: modifier::Padding::Zero,
-            repr: modifier::YearRepr::Full,
-            iso_week_based: false,
-            sign_is_mandatory: false,
-        })),
-        b"2021",
-        _.year() == Some(2021)
+fn iso_8601() {
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z", &Iso8601::DEFAULT),
+        Ok(datetime!(2021-01-02 03:04:05 UTC))
     );
-    parse_component!(
-        Component::Year(modifier!(Year {
-            padding: modifier::Padding::Zero,
-            repr: modifier::YearRepr::LastTwo,
-            iso_week_based: false,
-            sign_is_mandatory: false,
-        })),
-        b"21",
-        _.year_last_two() == Some(21)
+    assert_eq!(
+        OffsetDateTime::parse("2021-002T03:04:05Z", &Iso8601::DEFAULT),
+        Ok(datetime!(2021-002 03:04:05 UTC))
     );
-    parse_component!(
-        Component::Year(modifier!(Year {
-            padding: modifier::Padding::Zero,
-            repr: modifier::YearRepr::Full,
-            iso_week_based: true,
-            sign_is_mandatory: false,
-        })),
-        b"2021",
-        _.iso_year() == Some(2021)
+    assert_eq!(
+        OffsetDateTime::parse("2021-W01-2T03:04:05Z", &Iso8601::DEFAULT),
+        Ok(datetime!(2021-W 01-2 03:04:05 UTC))
     );
-    parse_component!(
-        Component::Year(modifier!(Year {
-            padding: modifier::Padding::Zero,
+    assert_eq!(
+        OffsetDateTime::parse("-002021-01-02T03:04:05+01:00", &Iso8601::DEFAULT),
+        Ok(datetime!(-002021-01-02 03:04:05 +01:00))
+    );
+    assert_eq!(
+        OffsetDateTime::parse("20210102T03.1Z", &Iso8601::DEFAULT),
+        Ok(datetime!(2021-01-02 03:06:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021002T0304.1Z", &Iso8601::DEFAULT),
+        Ok(datetime!(2021-002 03:04:06 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021W012T030405.1-0100", &Iso8601::DEFAULT),
+        Ok(datetime!(2021-W 01-2 03:04:05.1 -01:00))
+    );
+    assert_eq!(
+        OffsetDateTime::parse("20210102T03Z", &Iso8601::DEFAULT),
+        Ok(datetime!(2021-01-02 03:00:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::parse("20210102T0304Z", &Iso8601::DEFAULT),
+        Ok(datetime!(2021-01-02 03:04:00 UTC))
+    );
+    assert_eq!(
+        UtcOffset::parse("+0304", &Iso8601::DEFAULT),
+        Ok(offset!(+03:04))
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2022-07-22T12:52:50.349409", &Iso8601::DEFAULT),
+        Ok(datetime!(2022-07-22 12:52:50.349409000))
+    );
+}
+
+#[test]
+fn iso_8601_error() {
+    assert!(matches!(
+        OffsetDateTime::parse("20210102T03:04Z", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("20210102T03.", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-0102", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-x", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-Wx", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-W012", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-W01-x", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:x", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04x", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("01:02", &Iso8601::DEFAULT),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation { .. }
+        ))
+    ));
+}
+
+#[test]
+fn parse_time() -> time::Result<()> {
+    let format_input_output = [
+        (fd::parse("[hour repr:12] [period]")?, "01 PM", time!(1 PM)),
+        (
+            fd::parse("[hour]:[minute]:[second]")?,
+            "13:02:03",
+            time!(13:02:03),
+        ),
+        (
+            fd::parse("[hour repr:12]:[minute] [period]")?,
+            "01:02 PM",
+            time!(1:02 PM),
+        ),
+        (fd::parse("[hour]:[minute]")?, "01:02", time!(1:02)),
+        (
+            fd::parse("[hour repr:12]:[minute] [period]")?,
+            "01:02 AM",
+            time!(1:02 AM),
+        ),
+        (fd::parse("[hour]:[minute]")?, "01:02", time!(1:02)),
+        (fd::parse("[hour repr:12] [period]")?, "12 AM", time!(12 AM)),
+        (fd::parse("[hour repr:12] [period]")?, "12 PM", time!(12 PM)),
+    ];
+
+    for (format_description, input, output) in &format_input_output {
+        assert_eq!(&Time::parse(input, format_description)?, output);
+        assert_eq!(
+            &Time::parse(input, &OwnedFormatItem::from(format_description))?,
+            output
+        );
+        assert_eq!(
+            &Time::parse(
+                input,
+                [OwnedFormatItem::from(format_description)].as_slice()
+            )?,
+            output
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parse_time_err() -> time::Result<()> {
+    assert!(matches!(
+        Time::try_from(Parsed::new()),
+        Err(error::TryFromParsed::InsufficientInformation { .. })
+    ));
+    assert!(matches!(
+        Time::parse("", &fd::parse("")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation { .. }
+        ))
+    ));
+    assert!(matches!(
+        Time::parse("12", &fd::parse("[hour]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation { .. }
+        ))
+    ));
+    assert!(matches!(
+        Time::parse("13 PM", &fd::parse("[hour repr:12] [period]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "hour"
+    ));
+    assert!(matches!(
+        Time::parse(" ", &fd::parse("")?),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        Time::parse("a", &fd::parse("[subsecond digits:1]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("subsecond")
+        ))
+    ));
+    assert!(matches!(
+        Time::parse("1a", &fd::parse("[subsecond digits:2]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("subsecond")
+        ))
+    ));
+    assert!(matches!(
+        Time::parse("1a", &fd::parse_owned::<2>("[subsecond digits:2]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("subsecond")
+        ))
+    ));
+    assert!(matches!(
+        Time::parse(
+            "1a",
+            [fd::parse_owned::<2>("[subsecond digits:2]")?].as_slice()
+        ),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("subsecond")
+        ))
+    ));
+    assert!(matches!(
+        Time::parse("12a", &fd::parse("[subsecond digits:3]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("subsecond")
+        ))
+    ));
+    assert!(matches!(
+        Time::parse("123a", &fd::parse("[subsecond digits:4]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("subsecond")
+        ))
+    ));
+    assert!(matches!(
+        Time::parse("1234a", &fd::parse("[subsecond digits:5]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("subsecond")
+        ))
+    ));
+    assert!(matches!(
+        Time::parse("12345a", &fd::parse("[subsecond digits:6]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("subsecond")
+        ))
+    ));
+    assert!(matches!(
+        Time::parse("123456a", &fd::parse("[subsecond digits:7]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("subsecond")
+        ))
+    ));
+    assert!(matches!(
+        Time::parse("1234567a", &fd::parse("[subsecond digits:8]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("subsecond")
+        ))
+    ));
+    assert!(matches!(
+        Time::parse("12345678a", &fd::parse("[subsecond digits:9]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("subsecond")
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_date() -> time::Result<()> {
+    let format_input_output = [
+        (
+            fd::parse("[year]-[month]-[day]")?,
+            "2021-01-02",
+            date!(2021 - 01 - 02),
+        ),
+        (
+            fd::parse("[year]-[ordinal]")?,
+            "2021-002",
+            date!(2021 - 002),
+        ),
+        (
+            fd::parse("[year base:iso_week]-W[week_number]-[weekday repr:monday]")?,
+            "2020-W53-6",
+            date!(2021 - 01 - 02),
+        ),
+        (
+            fd::parse("[year]-W[week_number repr:monday]-[weekday repr:monday]")?,
+            "2021-W00-6",
+            date!(2021 - 01 - 02),
+        ),
+        (
+            fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
+            "2021-W00-6",
+            date!(2021 - 01 - 02),
+        ),
+        (
+            fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
+            "2023-W01-1",
+            date!(2023 - 01 - 02),
+        ),
+        (
+            fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
+            "2022-W00-7",
+            date!(2022 - 01 - 02),
+        ),
+        (
+            fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
+            "2026-W00-5",
+            date!(2026 - 01 - 02),
+        ),
+        (
+            fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
+            "2025-W00-4",
+            date!(2025 - 01 - 02),
+        ),
+        (
+            fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
+            "2019-W00-3",
+            date!(2019 - 01 - 02),
+        ),
+        (
+            fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
+            "2018-W01-2",
+            date!(2018 - 01 - 02),
+        ),
+        (
+            fd::parse(
+                "[year padding:space]-W[week_number repr:sunday padding:none]-[weekday \
+                 repr:sunday]",
+            )?,
+            " 2018-W01-2",
+            date!(2018 - 01 - 02),
+        ),
+    ];
+
+    for (format_description, input, output) in &format_input_output {
+        assert_eq!(&Date::parse(input, format_description)?, output);
+        assert_eq!(
+            &Date::parse(input, &OwnedFormatItem::from(format_description))?,
+            output
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parse_date_err() -> time::Result<()> {
+    assert!(matches!(
+        Date::try_from(Parsed::new()),
+        Err(error::TryFromParsed::InsufficientInformation { .. })
+    ));
+    assert!(matches!(
+        Date::parse("", &fd::parse("")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation { .. }
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("a", &fd::parse("[year]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("year")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("0001", &fd::parse("[year sign:mandatory]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("year")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("0a", &fd::parse("[year repr:last_two]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("year")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("2021-366", &fd::parse("[year]-[ordinal]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "ordinal"
+    ));
+    assert!(matches!(
+        Date::parse("2021-12-32", &fd::parse("[year]-[month]-[day]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "day"
+    ));
+    assert!(matches!(
+        Date::parse("2021-W54-1", &fd::parse("[year base:iso_week]-W[week_number]-[weekday repr:monday]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "week"
+    ));
+    assert!(matches!(
+        Date::parse("2021-W54-1", &fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:monday]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "ordinal"
+    ));
+    assert!(matches!(
+        Date::parse("2021-W54-1", &fd::parse("[year]-W[week_number repr:monday]-[weekday repr:monday]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "ordinal"
+    ));
+    assert!(matches!(
+        Date::parse("Ja", &fd::parse("[month repr:short]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("month")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("  2a21", &fd::parse("[year padding:space]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("year")
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_offset() -> time::Result<()> {
+    // Regression check for #522.
+    assert_eq!(
+        UtcOffset::parse(
+            "-00:01",
+            &fd::parse("[offset_hour sign:mandatory]:[offset_minute]")?,
+        ),
+        Ok(offset!(-00:01)),
+    );
+    assert_eq!(
+        UtcOffset::parse(
+            "-00:00:01",
+            &fd::parse("[offset_hour sign:mandatory]:[offset_minute]:[offset_second]")?,
+        ),
+        Ok(offset!(-00:00:01)),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parse_offset_err() -> time::Result<()> {
+    assert!(matches!(
+        UtcOffset::parse("", &fd::parse("")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation { .. }
+        ))
+    ));
+    assert_eq!(
+        UtcOffset::parse("01", &fd::parse("[offset_hour sign:mandatory]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("offset hour")
+        ))
+    );
+    assert!(matches!(
+        UtcOffset::parse("24", &fd::parse("[offset_hour]")?),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "offset hour"
+    ));
+    assert!(matches!(
+        UtcOffset::parse("00:60", &fd::parse("[offset_hour]:[offset_minute]")?),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "offset minute"
+    ));
+    assert!(matches!(
+        UtcOffset::parse("00:00:60", &fd::parse("[offset_hour]:[offset_minute]:[offset_second]")?),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "offset second"
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_primitive_date_time_err() -> time::Result<()> {
+    assert!(matches!(
+        PrimitiveDateTime::parse("", &fd::parse("")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation { .. }
+        ))
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-001 13 PM", &fd::parse("[year]-[ordinal] [hour repr:12] [period]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "hour"
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_offset_date_time_err() -> time::Result<()> {
+    assert!(matches!(
+        OffsetDateTime::parse("", &fd::parse("")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation { .. }
+        ))
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("x", &fd::parse("[year]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("year")
+        ))
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-001 12 PM +25", &fd::parse("[year]-[ordinal] [hour repr:12] [period] [offset_hour sign:mandatory]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "offset hour"
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_components() -> time::Result<()> {
+    macro_rules! parse_component {
+        ($component:expr, $input:expr,_. $property:ident() == $expected:expr) => {
+            let mut parsed = Parsed::new();
+            parsed.parse_component($input, $component)?;
+            assert_eq!(parsed.$property(), $expected);
+        };
+    }
+
+    parse_component!(
+        Component::Year(modifier!(Year {
+            padding: modifier::Padding::Zero,
+            repr: modifier::YearRepr::Full,
+            iso_week_based: false,
+            sign_is_mandatory: false,
+        })),
+        b"2021",
+        _.year() == Some(2021)
+    );
+    parse_component!(
+        Component::Year(modifier!(Year {
+            padding: modifier::Padding::Zero,
+            repr: modifier::YearRepr::LastTwo,
+            iso_week_based: false,
+            sign_is_mandatory: false,
+        })),
+        b"21",
+        _.year_last_two() == Some(21)
+    );
+    parse_component!(
+        Component::Year(modifier!(Year {
+            padding: modifier::Padding::Zero,
+            repr: modifier::YearRepr::Full,
+            iso_week_based: true,
+            sign_is_mandatory: false,
+        })),
+        b"2021",
+        _.iso_year() == Some(2021)
+    );
+    parse_component!(
+        Component::Year(modifier!(Year {
+            padding: modifier::Padding::Zero,
             repr: modifier::YearRepr::LastTwo,
             iso_week_based: true,
             sign_is_mandatory: false,
@@ -1063,500 +1683,1421 @@ fn parse_components() -> time::Result<()> {
             padding: modifier::Padding::Space,
             repr: modifier::MonthRepr::Numerical,
         })),
-        b" 1",
-        _.month() == Some(Month::January)
+        b" 1",
+        _.month() == Some(Month::January)
+    );
+    parse_component!(
+        Component::Month(modifier!(Month {
+            padding: modifier::Padding::None,
+            repr: modifier::MonthRepr::Short,
+            case_sensitive: true,
+        })),
+        b"Jan",
+        _.month() == Some(Month::January)
+    );
+    parse_component!(
+        Component::Month(modifier!(Month {
+            padding: modifier::Padding::None,
+            repr: modifier::MonthRepr::Short,
+            case_sensitive: false,
+        })),
+        b"jAn",
+        _.month() == Some(Month::January)
+    );
+    parse_component!(
+        Component::Month(modifier!(Month {
+            padding: modifier::Padding::None,
+            repr: modifier::MonthRepr::Long,
+            case_sensitive: true,
+        })),
+        b"January",
+        _.month() == Some(Month::January)
+    );
+    parse_component!(
+        Component::Month(modifier!(Month {
+            padding: modifier::Padding::None,
+            repr: modifier::MonthRepr::Long,
+            case_sensitive: false,
+        })),
+        b"jAnUaRy",
+        _.month() == Some(Month::January)
+    );
+    parse_component!(
+        Component::Ordinal(modifier!(Ordinal {
+            padding: modifier::Padding::Zero,
+        })),
+        b"012",
+        _.ordinal() == 12.try_into().ok()
+    );
+    parse_component!(
+        Component::Weekday(modifier!(Weekday {
+            repr: modifier::WeekdayRepr::Short,
+            one_indexed: false,
+            case_sensitive: true,
+        })),
+        b"Sun",
+        _.weekday() == Some(Weekday::Sunday)
+    );
+    parse_component!(
+        Component::Weekday(modifier!(Weekday {
+            repr: modifier::WeekdayRepr::Short,
+            one_indexed: false,
+            case_sensitive: false,
+        })),
+        b"sUn",
+        _.weekday() == Some(Weekday::Sunday)
+    );
+    parse_component!(
+        Component::Weekday(modifier!(Weekday {
+            repr: modifier::WeekdayRepr::Long,
+            one_indexed: false,
+            case_sensitive: true,
+        })),
+        b"Sunday",
+        _.weekday() == Some(Weekday::Sunday)
+    );
+    parse_component!(
+        Component::Weekday(modifier!(Weekday {
+            repr: modifier::WeekdayRepr::Long,
+            one_indexed: false,
+            case_sensitive: false,
+        })),
+        b"sUnDaY",
+        _.weekday() == Some(Weekday::Sunday)
+    );
+    parse_component!(
+        Component::Weekday(modifier!(Weekday {
+            repr: modifier::WeekdayRepr::Sunday,
+            one_indexed: false,
+        })),
+        b"0",
+        _.weekday() == Some(Weekday::Sunday)
+    );
+    parse_component!(
+        Component::Weekday(modifier!(Weekday {
+            repr: modifier::WeekdayRepr::Sunday,
+            one_indexed: true,
+        })),
+        b"1",
+        _.weekday() == Some(Weekday::Sunday)
+    );
+    parse_component!(
+        Component::Weekday(modifier!(Weekday {
+            repr: modifier::WeekdayRepr::Monday,
+            one_indexed: false,
+        })),
+        b"6",
+        _.weekday() == Some(Weekday::Sunday)
+    );
+    parse_component!(
+        Component::Weekday(modifier!(Weekday {
+            repr: modifier::WeekdayRepr::Monday,
+            one_indexed: true,
+        })),
+        b"7",
+        _.weekday() == Some(Weekday::Sunday)
+    );
+    parse_component!(
+        Component::WeekNumber(modifier!(WeekNumber {
+            padding: modifier::Padding::None,
+            repr: modifier::WeekNumberRepr::Sunday,
+        })),
+        b"2",
+        _.sunday_week_number() == Some(2)
+    );
+    parse_component!(
+        Component::WeekNumber(modifier!(WeekNumber {
+            padding: modifier::Padding::None,
+            repr: modifier::WeekNumberRepr::Monday,
+        })),
+        b"2",
+        _.monday_week_number() == Some(2)
+    );
+    parse_component!(
+        Component::WeekNumber(modifier!(WeekNumber {
+            padding: modifier::Padding::None,
+            repr: modifier::WeekNumberRepr::Iso,
+        })),
+        b"2",
+        _.iso_week_number() == 2.try_into().ok()
+    );
+    parse_component!(
+        Component::Subsecond(modifier!(Subsecond {
+            digits: modifier::SubsecondDigits::One
+        })),
+        b"1",
+        _.subsecond() == Some(100_000_000)
+    );
+    parse_component!(
+        Component::Subsecond(modifier!(Subsecond {
+            digits: modifier::SubsecondDigits::Two
+        })),
+        b"12",
+        _.subsecond() == Some(120_000_000)
+    );
+    parse_component!(
+        Component::Subsecond(modifier!(Subsecond {
+            digits: modifier::SubsecondDigits::Three
+        })),
+        b"123",
+        _.subsecond() == Some(123_000_000)
+    );
+    parse_component!(
+        Component::Subsecond(modifier!(Subsecond {
+            digits: modifier::SubsecondDigits::Four
+        })),
+        b"1234",
+        _.subsecond() == Some(123_400_000)
+    );
+    parse_component!(
+        Component::Subsecond(modifier!(Subsecond {
+            digits: modifier::SubsecondDigits::Five
+        })),
+        b"12345",
+        _.subsecond() == Some(123_450_000)
+    );
+    parse_component!(
+        Component::Subsecond(modifier!(Subsecond {
+            digits: modifier::SubsecondDigits::Six
+        })),
+        b"123456",
+        _.subsecond() == Some(123_456_000)
+    );
+    parse_component!(
+        Component::Subsecond(modifier!(Subsecond {
+            digits: modifier::SubsecondDigits::Seven
+        })),
+        b"1234567",
+        _.subsecond() == Some(123_456_700)
+    );
+    parse_component!(
+        Component::Subsecond(modifier!(Subsecond {
+            digits: modifier::SubsecondDigits::Eight
+        })),
+        b"12345678",
+        _.subsecond() == Some(123_456_780)
+    );
+    parse_component!(
+        Component::Subsecond(modifier!(Subsecond {
+            digits: modifier::SubsecondDigits::Nine
+        })),
+        b"123456789",
+        _.subsecond() == Some(123_456_789)
+    );
+    parse_component!(
+        Component::Subsecond(modifier!(Subsecond {
+            digits: modifier::SubsecondDigits::OneOrMore
+        })),
+        b"123456789",
+        _.subsecond() == Some(123_456_789)
     );
     parse_component!(
-        Component::Month(modifier!(Month {
-            padding: modifier::Padding::None,
-            repr: modifier::MonthRepr::Short,
+        Component::Period(modifier!(Period {
+            is_uppercase: false,
             case_sensitive: true,
         })),
-        b"Jan",
-        _.month() == Some(Month::January)
+        b"am",
+        _.hour_12_is_pm() == Some(false)
     );
     parse_component!(
-        Component::Month(modifier!(Month {
-            padding: modifier::Padding::None,
-            repr: modifier::MonthRepr::Short,
+        Component::Period(modifier!(Period {
+            is_uppercase: false,
             case_sensitive: false,
         })),
-        b"jAn",
-        _.month() == Some(Month::January)
+        b"aM",
+        _.hour_12_is_pm() == Some(false)
+    );
+    let mut parsed = Parsed::new();
+    let result = parsed.parse_component(
+        b"abcdef",
+        Component::Ignore(Ignore::count(NonZeroU16::new(3).unwrap())),
+    )?;
+    assert_eq!(result, b"def");
+    let mut parsed = Parsed::new();
+    let result = parsed.parse_component(
+        b"abcdef",
+        Component::Ignore(Ignore::count(NonZeroU16::new(7).unwrap())),
     );
+    assert!(matches!(
+        result,
+        Err(error::ParseFromDescription::InvalidComponent("ignore"))
+    ));
     parse_component!(
-        Component::Month(modifier!(Month {
-            padding: modifier::Padding::None,
-            repr: modifier::MonthRepr::Long,
-            case_sensitive: true,
+        Component::UnixTimestamp(modifier!(UnixTimestamp {
+            precision: modifier::UnixTimestampPrecision::Second,
+            sign_is_mandatory: false,
         })),
-        b"January",
-        _.month() == Some(Month::January)
+        b"1234567890",
+        _.unix_timestamp_nanos() == Some(1_234_567_890_000_000_000)
     );
     parse_component!(
-        Component::Month(modifier!(Month {
-            padding: modifier::Padding::None,
-            repr: modifier::MonthRepr::Long,
-            case_sensitive: false,
+        Component::UnixTimestamp(modifier!(UnixTimestamp {
+            precision: modifier::UnixTimestampPrecision::Millisecond,
+            sign_is_mandatory: false,
         })),
-        b"jAnUaRy",
-        _.month() == Some(Month::January)
+        b"1234567890123",
+        _.unix_timestamp_nanos() == Some(1_234_567_890_123_000_000)
     );
     parse_component!(
-        Component::Ordinal(modifier!(Ordinal {
-            padding: modifier::Padding::Zero,
+        Component::UnixTimestamp(modifier!(UnixTimestamp {
+            precision: modifier::UnixTimestampPrecision::Microsecond,
+            sign_is_mandatory: false,
         })),
-        b"012",
-        _.ordinal() == 12.try_into().ok()
+        b"1234567890123456",
+        _.unix_timestamp_nanos() == Some(1_234_567_890_123_456_000)
     );
     parse_component!(
-        Component::Weekday(modifier!(Weekday {
-            repr: modifier::WeekdayRepr::Short,
-            one_indexed: false,
-            case_sensitive: true,
+        Component::UnixTimestamp(modifier!(UnixTimestamp {
+            precision: modifier::UnixTimestampPrecision::Nanosecond,
+            sign_is_mandatory: false,
         })),
-        b"Sun",
-        _.weekday() == Some(Weekday::Sunday)
+        b"1234567890123456789",
+        _.unix_timestamp_nanos() == Some(1_234_567_890_123_456_789)
     );
     parse_component!(
-        Component::Weekday(modifier!(Weekday {
-            repr: modifier::WeekdayRepr::Short,
-            one_indexed: false,
-            case_sensitive: false,
+        Component::UnixTimestamp(modifier!(UnixTimestamp {
+            precision: modifier::UnixTimestampPrecision::Nanosecond,
+            sign_is_mandatory: false,
         })),
-        b"sUn",
-        _.weekday() == Some(Weekday::Sunday)
+        b"-1234567890123456789",
+        _.unix_timestamp_nanos() == Some(-1_234_567_890_123_456_789)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parse_optional() -> time::Result<()> {
+    // Ensure full parsing works as expected.
+    let mut parsed = Parsed::new();
+    let remaining_input = parsed.parse_item(
+        b"2021-01-02",
+        &FormatItem::Optional(&FormatItem::Compound(&fd::parse("[year]-[month]-[day]")?)),
+    )?;
+    assert!(remaining_input.is_empty());
+    assert_eq!(parsed.year(), Some(2021));
+    assert_eq!(parsed.month(), Some(Month::January));
+    assert_eq!(parsed.day().map(NonZeroU8::get), Some(2));
+
+    let mut parsed = Parsed::new();
+    let remaining_input = parsed.parse_item(
+        b"2021-01-02",
+        &OwnedFormatItem::from(FormatItem::Optional(&FormatItem::Compound(&fd::parse(
+            "[year]-[month]-[day]",
+        )?))),
+    )?;
+    assert!(remaining_input.is_empty());
+    assert_eq!(parsed.year(), Some(2021));
+    assert_eq!(parsed.month(), Some(Month::January));
+    assert_eq!(parsed.day().map(NonZeroU8::get), Some(2));
+
+    // Ensure a successful partial parse *does not* mutate `parsed`.
+    let mut parsed = Parsed::new();
+    let remaining_input = parsed.parse_item(
+        b"2021-01",
+        &FormatItem::Optional(&FormatItem::Compound(&fd::parse("[year]-[month]-[day]")?)),
+    )?;
+    assert_eq!(remaining_input, b"2021-01");
+    assert!(parsed.year().is_none());
+    assert!(parsed.month().is_none());
+    assert!(parsed.day().is_none());
+
+    let mut parsed = Parsed::new();
+    let remaining_input = parsed.parse_item(
+        b"2021-01",
+        &OwnedFormatItem::from(FormatItem::Optional(&FormatItem::Compound(&fd::parse(
+            "[year]-[month]-[day]",
+        )?))),
+    )?;
+    assert_eq!(remaining_input, b"2021-01");
+    assert!(parsed.year().is_none());
+    assert!(parsed.month().is_none());
+    assert!(parsed.day().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn parse_first() -> time::Result<()> {
+    // Ensure the first item is parsed correctly.
+    let mut parsed = Parsed::new();
+    let remaining_input = parsed.parse_item(
+        b"2021-01-02",
+        &FormatItem::First(&[FormatItem::Compound(&fd::parse("[year]-[month]-[day]")?)]),
+    )?;
+    assert!(remaining_input.is_empty());
+    assert_eq!(parsed.year(), Some(2021));
+    assert_eq!(parsed.month(), Some(Month::January));
+    assert_eq!(parsed.day().map(NonZeroU8::get), Some(2));
+
+    let mut parsed = Parsed::new();
+    let remaining_input = parsed.parse_item(
+        b"2021-01-02",
+        &OwnedFormatItem::from(FormatItem::First(&[FormatItem::Compound(&fd::parse(
+            "[year]-[month]-[day]",
+        )?)])),
+    )?;
+    assert!(remaining_input.is_empty());
+    assert_eq!(parsed.year(), Some(2021));
+    assert_eq!(parsed.month(), Some(Month::January));
+    assert_eq!(parsed.day().map(NonZeroU8::get), Some(2));
+
+    // Ensure an empty slice is a no-op success.
+    let mut parsed = Parsed::new();
+    let remaining_input = parsed.parse_item(b"2021-01-02", &FormatItem::First(&[]))?;
+    assert_eq!(remaining_input, b"2021-01-02");
+    assert!(parsed.year().is_none());
+    assert!(parsed.month().is_none());
+    assert!(parsed.day().is_none());
+
+    let mut parsed = Parsed::new();
+    let remaining_input =
+        parsed.parse_item(b"2021-01-02", &OwnedFormatItem::First(Box::new([])))?;
+    assert_eq!(remaining_input, b"2021-01-02");
+    assert!(parsed.year().is_none());
+    assert!(parsed.month().is_none());
+    assert!(parsed.day().is_none());
+
+    // Ensure success when the first item fails.
+    let mut parsed = Parsed::new();
+    let remaining_input = parsed.parse_item(
+        b"2021-01-02",
+        &FormatItem::First(&[
+            FormatItem::Compound(&fd::parse("[period]")?),
+            FormatItem::Compound(&fd::parse("x")?),
+            FormatItem::Compound(&fd::parse("[year]-[month]-[day]")?),
+        ]),
+    )?;
+    assert!(remaining_input.is_empty());
+    assert_eq!(parsed.year(), Some(2021));
+    assert_eq!(parsed.month(), Some(Month::January));
+    assert_eq!(parsed.day().map(NonZeroU8::get), Some(2));
+
+    let mut parsed = Parsed::new();
+    let remaining_input = parsed.parse_item(
+        b"2021-01-02",
+        &OwnedFormatItem::from(FormatItem::First(&[
+            FormatItem::Compound(&fd::parse("[period]")?),
+            FormatItem::Compound(&fd::parse("x")?),
+            FormatItem::Compound(&fd::parse("[year]-[month]-[day]")?),
+        ])),
+    )?;
+    assert!(remaining_input.is_empty());
+    assert_eq!(parsed.year(), Some(2021));
+    assert_eq!(parsed.month(), Some(Month::January));
+    assert_eq!(parsed.day().map(NonZeroU8::get), Some(2));
+
+    // Ensure the first error is returned.
+    let mut parsed = Parsed::new();
+    let err = parsed
+        .parse_item(
+            b"2021-01-02",
+            &FormatItem::First(&[
+                FormatItem::Compound(&fd::parse("[period]")?),
+                FormatItem::Compound(&fd::parse("x")?),
+            ]),
+        )
+        .unwrap_err();
+    assert_eq!(err, error::ParseFromDescription::InvalidComponent("period"));
+
+    let mut parsed = Parsed::new();
+    let err = parsed
+        .parse_item(
+            b"2021-01-02",
+            &OwnedFormatItem::from(FormatItem::First(&[
+                FormatItem::Compound(&fd::parse("[period]")?),
+                FormatItem::Compound(&fd::parse("x")?),
+            ])),
+        )
+        .unwrap_err();
+    assert_eq!(err, error::ParseFromDescription::InvalidComponent("period"));
+
+    Ok(())
+}
+
+#[test]
+fn parse_unix_timestamp() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("1234567890", &fd::parse("[unix_timestamp]")?)?,
+        datetime!(2009-02-13 23:31:30 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "1234567890123",
+            &fd::parse("[unix_timestamp precision:millisecond]")?
+        )?,
+        datetime!(2009-02-13 23:31:30.123 UTC)
     );
-    parse_component!(
-        Component::Weekday(modifier!(Weekday {
-            repr: modifier::WeekdayRepr::Long,
-            one_indexed: false,
-            case_sensitive: true,
-        })),
-        b"Sunday",
-        _.weekday() == Some(Weekday::Sunday)
+    assert_eq!(
+        OffsetDateTime::parse(
+            "1234567890123456",
+            &fd::parse("[unix_timestamp precision:microsecond]")?
+        )?,
+        datetime!(2009-02-13 23:31:30.123456 UTC)
     );
-    parse_component!(
-        Component::Weekday(modifier!(Weekday {
-            repr: modifier::WeekdayRepr::Long,
-            one_indexed: false,
-            case_sensitive: false,
-        })),
-        b"sUnDaY",
-        _.weekday() == Some(Weekday::Sunday)
+    assert_eq!(
+        OffsetDateTime::parse(
+            "1234567890123456789",
+            &fd::parse("[unix_timestamp precision:nanosecond]")?
+        )?,
+        datetime!(2009-02-13 23:31:30.123456789 UTC)
     );
-    parse_component!(
-        Component::Weekday(modifier!(Weekday {
-            repr: modifier::WeekdayRepr::Sunday,
-            one_indexed: false,
-        })),
-        b"0",
-        _.weekday() == Some(Weekday::Sunday)
+
+    Ok(())
+}
+
+#[test]
+fn parse_unix_timestamp_err() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("1234567890", &fd::parse("[unix_timestamp sign:mandatory]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("unix_timestamp")
+        ))
     );
-    parse_component!(
-        Component::Weekday(modifier!(Weekday {
-            repr: modifier::WeekdayRepr::Sunday,
-            one_indexed: true,
-        })),
-        b"1",
-        _.weekday() == Some(Weekday::Sunday)
+    assert_eq!(
+        OffsetDateTime::parse("a", &fd::parse("[unix_timestamp precision:second]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("unix_timestamp")
+        ))
     );
-    parse_component!(
-        Component::Weekday(modifier!(Weekday {
-            repr: modifier::WeekdayRepr::Monday,
-            one_indexed: false,
-        })),
-        b"6",
-        _.weekday() == Some(Weekday::Sunday)
+    assert_eq!(
+        OffsetDateTime::parse("a", &fd::parse("[unix_timestamp precision:millisecond]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("unix_timestamp")
+        ))
     );
-    parse_component!(
-        Component::Weekday(modifier!(Weekday {
-            repr: modifier::WeekdayRepr::Monday,
-            one_indexed: true,
-        })),
-        b"7",
-        _.weekday() == Some(Weekday::Sunday)
+    assert_eq!(
+        OffsetDateTime::parse("a", &fd::parse("[unix_timestamp precision:microsecond]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("unix_timestamp")
+        ))
     );
-    parse_component!(
-        Component::WeekNumber(modifier!(WeekNumber {
-            padding: modifier::Padding::None,
-            repr: modifier::WeekNumberRepr::Sunday,
-        })),
-        b"2",
-        _.sunday_week_number() == Some(2)
+    assert_eq!(
+        OffsetDateTime::parse("a", &fd::parse("[unix_timestamp precision:nanosecond]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("unix_timestamp")
+        ))
     );
-    parse_component!(
-        Component::WeekNumber(modifier!(WeekNumber {
-            padding: modifier::Padding::None,
-            repr: modifier::WeekNumberRepr::Monday,
-        })),
-        b"2",
-        _.monday_week_number() == Some(2)
+
+    Ok(())
+}
+
+#[test]
+fn parse_zone_name() -> time::Result<()> {
+    let mut parsed = Parsed::new();
+    let remaining = parsed.parse_items(
+        b"America/Argentina/Buenos_Aires)",
+        &fd::parse("[zone_name]")?,
+    )?;
+    assert_eq!(remaining, b")");
+    assert_eq!(parsed.zone_name(), Some("America/Argentina/Buenos_Aires"));
+
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"Etc/GMT+5 ", &fd::parse("[zone_name] ")?)?;
+    assert_eq!(parsed.zone_name(), Some("Etc/GMT+5"));
+    assert_eq!(Parsed::new().zone_name(), None);
+
+    assert!(matches!(
+        PrimitiveDateTime::parse(
+            "2023-01-01 00:00 /Paris",
+            &fd::parse("[year]-[month]-[day] [hour]:[minute] [zone_name]")?
+        ),
+        invalid_component!("zone name")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse(
+            "2023-01-01 00:00 America/Argentina/ComodRivadavia/Extra",
+            &fd::parse("[year]-[month]-[day] [hour]:[minute] [zone_name]")?
+        ),
+        invalid_component!("zone name")
+    ));
+    assert_eq!(
+        Parsed::new()
+            .with_zone_name("America/Argentina/Buenos_Aires")
+            .and_then(|parsed| parsed.zone_name().map(str::len)),
+        Some(30)
     );
-    parse_component!(
-        Component::WeekNumber(modifier!(WeekNumber {
-            padding: modifier::Padding::None,
-            repr: modifier::WeekNumberRepr::Iso,
-        })),
-        b"2",
-        _.iso_week_number() == 2.try_into().ok()
+    let mut parsed = Parsed::new();
+    assert_eq!(parsed.set_zone_name("Europe/Paris"), Some(()));
+    assert_eq!(parsed.zone_name(), Some("Europe/Paris"));
+    assert!(Parsed::new().with_zone_name(&"A".repeat(33)).is_none());
+    assert!(Parsed::new().with_zone_name("").is_none());
+    assert!(Parsed::new().with_zone_name("Europe/\0").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn parse_zone_abbr() -> time::Result<()> {
+    let format = fd::parse("[year]-[month]-[day] [hour]:[minute] [zone_abbr]")?;
+
+    assert_eq!(
+        OffsetDateTime::parse("2023-01-01 00:00 UTC", &format)?,
+        datetime!(2023-01-01 0:00 UTC)
     );
-    parse_component!(
-        Component::Subsecond(modifier!(Subsecond {
-            digits: modifier::SubsecondDigits::One
-        })),
-        b"1",
-        _.subsecond() == Some(100_000_000)
+    assert_eq!(
+        OffsetDateTime::parse("2023-01-01 00:00 GMT", &format)?,
+        datetime!(2023-01-01 0:00 UTC)
     );
-    parse_component!(
-        Component::Subsecond(modifier!(Subsecond {
-            digits: modifier::SubsecondDigits::Two
-        })),
-        b"12",
-        _.subsecond() == Some(120_000_000)
+    assert_eq!(
+        OffsetDateTime::parse("2023-01-01 00:00 +0530", &format)?,
+        datetime!(2023-01-01 0:00 +5:30)
     );
-    parse_component!(
-        Component::Subsecond(modifier!(Subsecond {
-            digits: modifier::SubsecondDigits::Three
-        })),
-        b"123",
-        _.subsecond() == Some(123_000_000)
+    assert_eq!(
+        OffsetDateTime::parse("2023-01-01 00:00 -03", &format)?,
+        datetime!(2023-01-01 0:00 -3)
     );
-    parse_component!(
-        Component::Subsecond(modifier!(Subsecond {
-            digits: modifier::SubsecondDigits::Four
-        })),
-        b"1234",
-        _.subsecond() == Some(123_400_000)
+    assert!(matches!(
+        OffsetDateTime::parse("2023-01-01 00:00 CET", &format),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation { .. }
+        ))
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2023-01-01 00:00 +053", &format),
+        invalid_component!("zone abbreviation")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2023-01-01 00:00 ABCDEFGHI", &format),
+        invalid_component!("zone abbreviation")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2023-01-01 00:00 +2400", &format),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation { .. }
+        ))
+    ));
+
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"2023-01-01 00:00 CEST", &format)?;
+    assert_eq!(parsed.zone_abbr(), Some("CEST"));
+    assert_eq!(parsed.offset_hour(), None);
+
+    assert_eq!(
+        Parsed::new()
+            .with_zone_abbr("ABCDEFGH")
+            .and_then(|parsed| parsed.zone_abbr().map(str::len)),
+        Some(8)
+    );
+    assert!(Parsed::new().with_zone_abbr("ABCDEFGHI").is_none());
+    assert!(Parsed::new().with_zone_abbr("").is_none());
+    assert!(Parsed::new().with_zone_abbr("A\0").is_none());
+    assert_eq!(Parsed::new().zone_abbr(), None);
+
+    Ok(())
+}
+
+#[test]
+fn set_offset_from_zone_abbr() -> time::Result<()> {
+    let format = fd::parse("[year]-[month]-[day] [hour]:[minute] [zone_abbr]")?;
+    let table = [
+        LocalTimeType::new(offset!(+1), false, "CET")?,
+        LocalTimeType::new(offset!(+2), true, "CEST")?,
+        LocalTimeType::new(offset!(+1), false, "CET")?,
+        LocalTimeType::new(offset!(+5:30), false, "IST")?,
+        LocalTimeType::new(offset!(+1), true, "IST")?,
+        LocalTimeType::new(offset!(-3:30), false, "NST")?,
+    ];
+
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"2023-07-01 12:00 CEST", &format)?;
+    assert_eq!(parsed.set_offset_from_zone_abbr(&table), Some(()));
+    assert_eq!(
+        OffsetDateTime::try_from(parsed)?,
+        datetime!(2023-07-01 12:00 +2)
     );
-    parse_component!(
-        Component::Subsecond(modifier!(Subsecond {
-            digits: modifier::SubsecondDigits::Five
-        })),
-        b"12345",
-        _.subsecond() == Some(123_450_000)
+
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"2023-01-01 12:00 CET", &format)?;
+    assert_eq!(parsed.set_offset_from_zone_abbr(&table), Some(()));
+    assert_eq!(
+        OffsetDateTime::try_from(parsed)?,
+        datetime!(2023-01-01 12:00 +1)
     );
-    parse_component!(
-        Component::Subsecond(modifier!(Subsecond {
-            digits: modifier::SubsecondDigits::Six
-        })),
-        b"123456",
-        _.subsecond() == Some(123_456_000)
+
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"2023-01-01 12:00 NST", &format)?;
+    assert_eq!(parsed.set_offset_from_zone_abbr(&table), Some(()));
+    assert_eq!(
+        OffsetDateTime::try_from(parsed)?,
+        datetime!(2023-01-01 12:00 -3:30)
     );
-    parse_component!(
-        Component::Subsecond(modifier!(Subsecond {
-            digits: modifier::SubsecondDigits::Seven
-        })),
-        b"1234567",
-        _.subsecond() == Some(123_456_700)
+
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"2023-01-01 12:00 IST", &format)?;
+    assert_eq!(parsed.set_offset_from_zone_abbr(&table), None);
+    assert_eq!(parsed.offset_hour(), None);
+
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"2023-01-01 12:00 PST", &format)?;
+    assert_eq!(parsed.set_offset_from_zone_abbr(&table), None);
+    assert_eq!(Parsed::new().set_offset_from_zone_abbr(&table), None);
+
+    Ok(())
+}
+
+#[test]
+fn localized() -> time::Result<()> {
+    let format = fd::parse("[weekday], [day] [month repr:long] [year]")?;
+    assert_eq!(
+        Date::parse("Mittwoch, 01 März 2023", &Localized::new(&format, &German))?,
+        date!(2023 - 03 - 01)
     );
-    parse_component!(
-        Component::Subsecond(modifier!(Subsecond {
-            digits: modifier::SubsecondDigits::Eight
-        })),
-        b"12345678",
-        _.subsecond() == Some(123_456_780)
+    assert_eq!(
+        Date::parse(
+            "mercredi, 01 février 2023",
+            &Localized::new(&format, &French)
+        )?,
+        date!(2023 - 02 - 01)
     );
-    parse_component!(
-        Component::Subsecond(modifier!(Subsecond {
-            digits: modifier::SubsecondDigits::Nine
-        })),
-        b"123456789",
-        _.subsecond() == Some(123_456_789)
+    assert_eq!(
+        Date::parse(
+            "miércoles, 01 febrero 2023",
+            &Localized::new(&format, &Spanish)
+        )?,
+        date!(2023 - 02 - 01)
     );
-    parse_component!(
-        Component::Subsecond(modifier!(Subsecond {
-            digits: modifier::SubsecondDigits::OneOrMore
-        })),
-        b"123456789",
-        _.subsecond() == Some(123_456_789)
+    assert_eq!(
+        Date::parse(
+            "mercoledì, 01 febbraio 2023",
+            &Localized::new(&format, &Italian)
+        )?,
+        date!(2023 - 02 - 01)
     );
-    parse_component!(
-        Component::Period(modifier!(Period {
-            is_uppercase: false,
-            case_sensitive: true,
-        })),
-        b"am",
-        _.hour_12_is_pm() == Some(false)
+    assert_eq!(
+        Date::parse(
+            "quarta-feira, 01 fevereiro 2023",
+            &Localized::new(&format, &Portuguese)
+        )?,
+        date!(2023 - 02 - 01)
     );
-    parse_component!(
-        Component::Period(modifier!(Period {
-            is_uppercase: false,
-            case_sensitive: false,
-        })),
-        b"aM",
-        _.hour_12_is_pm() == Some(false)
+    assert_eq!(
+        Date::parse("woensdag, 01 maart 2023", &Localized::new(&format, &Dutch))?,
+        date!(2023 - 03 - 01)
     );
-    let mut parsed = Parsed::new();
-    let result = parsed.parse_component(
-        b"abcdef",
-        Component::Ignore(Ignore::count(NonZeroU16::new(3).unwrap())),
-    )?;
-    assert_eq!(result, b"def");
-    let mut parsed = Parsed::new();
-    let result = parsed.parse_component(
-        b"abcdef",
-        Component::Ignore(Ignore::count(NonZeroU16::new(7).unwrap())),
+    assert_eq!(
+        Date::parse("水曜日, 01 11月 2023", &Localized::new(&format, &Japanese))?,
+        date!(2023 - 11 - 01)
     );
+
+    // The English names are not accepted when a locale is provided.
     assert!(matches!(
-        result,
-        Err(error::ParseFromDescription::InvalidComponent("ignore"))
+        Date::parse(
+            "Wednesday, 01 March 2023",
+            &Localized::new(&format, &German)
+        ),
+        invalid_component!("weekday")
     ));
-    parse_component!(
-        Component::UnixTimestamp(modifier!(UnixTimestamp {
-            precision: modifier::UnixTimestampPrecision::Second,
-            sign_is_mandatory: false,
-        })),
-        b"1234567890",
-        _.unix_timestamp_nanos() == Some(1_234_567_890_000_000_000)
+    assert!(matches!(
+        Date::parse("Mittwoch, 01 March 2023", &Localized::new(&format, &German)),
+        invalid_component!("month")
+    ));
+    // Names are case-sensitive by default.
+    assert!(matches!(
+        Date::parse("mittwoch, 01 märz 2023", &Localized::new(&format, &German)),
+        invalid_component!("weekday")
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn localized_case_insensitive() -> time::Result<()> {
+    let format = fd::parse(
+        "[weekday repr:short case_sensitive:false] [day] [month repr:short case_sensitive:false] \
+         [year]",
+    )?;
+    assert_eq!(
+        Date::parse("MER. 01 FÉVR. 2023", &Localized::new(&format, &French))?,
+        date!(2023 - 02 - 01)
     );
-    parse_component!(
-        Component::UnixTimestamp(modifier!(UnixTimestamp {
-            precision: modifier::UnixTimestampPrecision::Millisecond,
-            sign_is_mandatory: false,
-        })),
-        b"1234567890123",
-        _.unix_timestamp_nanos() == Some(1_234_567_890_123_000_000)
+    assert_eq!(
+        Date::parse("mi. 01 MÄRZ 2023", &Localized::new(&format, &German))?,
+        date!(2023 - 03 - 01)
     );
-    parse_component!(
-        Component::UnixTimestamp(modifier!(UnixTimestamp {
-            precision: modifier::UnixTimestampPrecision::Microsecond,
-            sign_is_mandatory: false,
-        })),
-        b"1234567890123456",
-        _.unix_timestamp_nanos() == Some(1_234_567_890_123_456_000)
+
+    let format = fd::parse("[day] [month repr:long case_sensitive:false] [year]")?;
+    assert_eq!(
+        Date::parse("01 DÉCEMBRE 2023", &Localized::new(&format, &French))?,
+        date!(2023 - 12 - 01)
     );
-    parse_component!(
-        Component::UnixTimestamp(modifier!(UnixTimestamp {
-            precision: modifier::UnixTimestampPrecision::Nanosecond,
-            sign_is_mandatory: false,
-        })),
-        b"1234567890123456789",
-        _.unix_timestamp_nanos() == Some(1_234_567_890_123_456_789)
+    assert!(matches!(
+        Date::parse("01 DECEMBRE 2023", &Localized::new(&format, &French)),
+        invalid_component!("month")
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn localized_custom() -> time::Result<()> {
+    /// Month names that are prefixes of one another.
+    struct Numbered;
+
+    impl Locale for Numbered {
+        fn month_names(&self) -> &[&str; 12] {
+            &[
+                "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12",
+            ]
+        }
+
+        fn month_abbreviations(&self) -> &[&str; 12] {
+            self.month_names()
+        }
+
+        fn weekday_names(&self) -> &[&str; 7] {
+            &["1", "2", "3", "4", "5", "6", "7"]
+        }
+
+        fn weekday_abbreviations(&self) -> &[&str; 7] {
+            self.weekday_names()
+        }
+    }
+
+    let format = fd::parse("[year] [month repr:long] [day]")?;
+    assert_eq!(
+        Date::parse("2023 1 05", &Localized::new(&format, &Numbered))?,
+        date!(2023 - 01 - 05)
     );
-    parse_component!(
-        Component::UnixTimestamp(modifier!(UnixTimestamp {
-            precision: modifier::UnixTimestampPrecision::Nanosecond,
-            sign_is_mandatory: false,
-        })),
-        b"-1234567890123456789",
-        _.unix_timestamp_nanos() == Some(-1_234_567_890_123_456_789)
+    assert_eq!(
+        Date::parse("2023 12 05", &Localized::new(&format, &Numbered))?,
+        date!(2023 - 12 - 05)
     );
 
     Ok(())
 }
 
 #[test]
-fn parse_optional() -> time::Result<()> {
-    // Ensure full parsing works as expected.
+fn parse_quarter_century_era() -> time::Result<()> {
     let mut parsed = Parsed::new();
-    let remaining_input = parsed.parse_item(
-        b"2021-01-02",
-        &FormatItem::Optional(&FormatItem::Compound(&fd::parse("[year]-[month]-[day]")?)),
-    )?;
-    assert!(remaining_input.is_empty());
-    assert_eq!(parsed.year(), Some(2021));
-    assert_eq!(parsed.month(), Some(Month::January));
-    assert_eq!(parsed.day().map(NonZeroU8::get), Some(2));
+    let remaining = parsed.parse_items(b"Q3 2024", &fd::parse("Q[quarter] [year]")?)?;
+    assert!(remaining.is_empty());
+    assert_eq!(parsed.quarter(), NonZeroU8::new(3));
+    assert_eq!(parsed.year(), Some(2024));
+    assert!(matches!(
+        Parsed::new().parse_items(b"Q5", &fd::parse("Q[quarter]")?),
+        Err(error::ParseFromDescription::InvalidComponent("quarter"))
+    ));
+    assert!(matches!(
+        Parsed::new().parse_items(b"Q0", &fd::parse("Q[quarter]")?),
+        Err(error::ParseFromDescription::InvalidComponent("quarter"))
+    ));
 
-    let mut parsed = Parsed::new();
-    let remaining_input = parsed.parse_item(
-        b"2021-01-02",
-        &OwnedFormatItem::from(FormatItem::Optional(&FormatItem::Compound(&fd::parse(
-            "[year]-[month]-[day]",
-        )?))),
-    )?;
-    assert!(remaining_input.is_empty());
-    assert_eq!(parsed.year(), Some(2021));
-    assert_eq!(parsed.month(), Some(Month::January));
-    assert_eq!(parsed.day().map(NonZeroU8::get), Some(2));
+    let format = fd::parse("[century][year repr:last_two]-[month]-[day]")?;
+    assert_eq!(Date::parse("2024-03-15", &format)?, date!(2024 - 03 - 15));
+    assert_eq!(Date::parse("0044-03-15", &format)?, date!(0044 - 03 - 15));
+    assert_eq!(Date::parse("-0044-03-15", &format)?, date!(-0044 - 03 - 15));
+    assert_eq!(Date::parse("-1999-03-15", &format)?, date!(-1999 - 03 - 15));
+    assert_eq!(Date::parse("+0044-03-15", &format)?, date!(0044 - 03 - 15));
+    assert!(matches!(
+        Date::parse("10000-03-15", &format),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        Date::parse("20-03-15", &fd::parse("[century]-[month]-[day]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
 
-    // Ensure a successful partial parse *does not* mutate `parsed`.
-    let mut parsed = Parsed::new();
-    let remaining_input = parsed.parse_item(
-        b"2021-01",
-        &FormatItem::Optional(&FormatItem::Compound(&fd::parse("[year]-[month]-[day]")?)),
-    )?;
-    assert_eq!(remaining_input, b"2021-01");
-    assert!(parsed.year().is_none());
-    assert!(parsed.month().is_none());
-    assert!(parsed.day().is_none());
+    let format = fd::parse("[day] [month repr:short] [year repr:era padding:none] [era]")?;
+    assert_eq!(
+        Date::parse("15 Mar 44 BC", &format)?,
+        date!(-0043 - 03 - 15)
+    );
+    assert_eq!(Date::parse("31 Dec 1 BC", &format)?, date!(0000 - 12 - 31));
+    assert_eq!(Date::parse("01 Jan 1 AD", &format)?, date!(0001 - 01 - 01));
+    assert_eq!(
+        Date::parse("15 Mar 2024 AD", &format)?,
+        date!(2024 - 03 - 15)
+    );
+    assert!(matches!(
+        Date::parse("15 Mar 0 BC", &format),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        Date::parse("15 Mar 44 bc", &format),
+        invalid_component!("era")
+    ));
+    assert!(matches!(
+        Date::parse("15 Mar 44 BCE", &format),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
 
+    let format = fd::parse("[year repr:era padding:none] [era repr:ce case_sensitive:false]")?;
     let mut parsed = Parsed::new();
-    let remaining_input = parsed.parse_item(
-        b"2021-01",
-        &OwnedFormatItem::from(FormatItem::Optional(&FormatItem::Compound(&fd::parse(
-            "[year]-[month]-[day]",
-        )?))),
-    )?;
-    assert_eq!(remaining_input, b"2021-01");
-    assert!(parsed.year().is_none());
-    assert!(parsed.month().is_none());
-    assert!(parsed.day().is_none());
+    parsed.parse_items(b"44 bce", &format)?;
+    assert_eq!(parsed.era(), Some(time::Era::Bce));
+    assert_eq!(parsed.year_of_era(), NonZeroU32::new(44));
+    assert_eq!(parsed.year(), None);
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"2024 Ce", &format)?;
+    assert_eq!(parsed.era(), Some(time::Era::Ce));
+
+    // Without an era, the common era is assumed.
+    assert_eq!(
+        Date::parse("2024-03-15", &fd::parse("[year repr:era]-[month]-[day]")?)?,
+        date!(2024 - 03 - 15)
+    );
+    // An astronomical year takes precedence over the era.
+    assert_eq!(
+        Date::parse("2024-03-15 BC", &fd::parse("[year]-[month]-[day] [era]")?)?,
+        date!(2024 - 03 - 15)
+    );
+    assert!(matches!(
+        Date::parse(
+            "2024-W11-5",
+            &fd::parse("[year repr:era base:iso_week]-W[week_number]-[weekday repr:monday]")?
+        ),
+        invalid_component!("year")
+    ));
+
+    let format =
+        fd::parse("[year repr:era padding:none] [era] [month]/[day] Q[quarter] [century]")?;
+    for date in [
+        date!(2024 - 08 - 15),
+        date!(0001 - 01 - 01),
+        date!(0000 - 12 - 31),
+        date!(-0043 - 03 - 15),
+        date!(-9999 - 12 - 31),
+    ] {
+        assert_eq!(Date::parse(&date.format(&format)?, &format)?, date);
+    }
 
     Ok(())
 }
 
 #[test]
-fn parse_first() -> time::Result<()> {
-    // Ensure the first item is parsed correctly.
-    let mut parsed = Parsed::new();
-    let remaining_input = parsed.parse_item(
-        b"2021-01-02",
-        &FormatItem::First(&[FormatItem::Compound(&fd::parse("[year]-[month]-[day]")?)]),
-    )?;
-    assert!(remaining_input.is_empty());
-    assert_eq!(parsed.year(), Some(2021));
-    assert_eq!(parsed.month(), Some(Month::January));
-    assert_eq!(parsed.day().map(NonZeroU8::get), Some(2));
+fn parse_day_suffix() -> time::Result<()> {
+    let format = fd::parse("[month repr:long] [day padding:none suffix:true], [year]")?;
+    assert_eq!(
+        Date::parse("March 1st, 2024", &format)?,
+        date!(2024 - 03 - 01)
+    );
+    assert_eq!(
+        Date::parse("March 2nd, 2024", &format)?,
+        date!(2024 - 03 - 02)
+    );
+    assert_eq!(
+        Date::parse("March 3rd, 2024", &format)?,
+        date!(2024 - 03 - 03)
+    );
+    assert_eq!(
+        Date::parse("March 11th, 2024", &format)?,
+        date!(2024 - 03 - 11)
+    );
+    assert_eq!(
+        Date::parse("March 23rd, 2024", &format)?,
+        date!(2024 - 03 - 23)
+    );
+    assert_eq!(
+        Date::parse("March 31st, 2024", &format)?,
+        date!(2024 - 03 - 31)
+    );
+    assert!(matches!(
+        Date::parse("March 3th, 2024", &format),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        Date::parse("March 11st, 2024", &format),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        Date::parse("March 3, 2024", &format),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        Date::parse("March 32nd, 2024", &format),
+        invalid_component!("day")
+    ));
+    assert_eq!(
+        Date::parse(
+            "3rd 03 2024",
+            &fd::parse("[day suffix:true] [month] [year]")?
+        )?,
+        date!(2024 - 03 - 03)
+    );
+    assert!(matches!(
+        Date::parse("March 3RD, 2024", &format),
+        invalid_component!("day")
+    ));
+    assert_eq!(
+        Date::parse(
+            "March 3RD, 2024",
+            &fd::parse("[month repr:long] [day suffix:true case_sensitive:false], [year]")?
+        )?,
+        date!(2024 - 03 - 03)
+    );
 
-    let mut parsed = Parsed::new();
-    let remaining_input = parsed.parse_item(
-        b"2021-01-02",
-        &OwnedFormatItem::from(FormatItem::First(&[FormatItem::Compound(&fd::parse(
-            "[year]-[month]-[day]",
-        )?)])),
-    )?;
-    assert!(remaining_input.is_empty());
-    assert_eq!(parsed.year(), Some(2021));
-    assert_eq!(parsed.month(), Some(Month::January));
-    assert_eq!(parsed.day().map(NonZeroU8::get), Some(2));
+    let format = fd::parse("[day padding:none suffix:true] [month repr:long] [year]")?;
+    assert_eq!(
+        Date::parse("1er mai 2024", &Localized::new(&format, &French))?,
+        date!(2024 - 05 - 01)
+    );
+    assert_eq!(
+        Date::parse("2 mai 2024", &Localized::new(&format, &French))?,
+        date!(2024 - 05 - 02)
+    );
+    assert!(matches!(
+        Date::parse("2nd mai 2024", &Localized::new(&format, &French)),
+        invalid_literal!()
+    ));
+    assert_eq!(
+        Date::parse("2. Mai 2024", &Localized::new(&format, &German))?,
+        date!(2024 - 05 - 02)
+    );
+    assert_eq!(
+        Date::parse("1.º mayo 2024", &Localized::new(&format, &Spanish))?,
+        date!(2024 - 05 - 01)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parse_strftime() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2023-07-04T09:05:03+0200",
+            &format_description::parse_strftime_borrowed("%Y-%m-%dT%H:%M:%S%z")?
+        )?,
+        datetime!(2023-07-04 09:05:03 +02:00)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse(
+            "tue JUL  4 09:05:03 2023",
+            &format_description::parse_strftime_owned("%c")?
+        )?,
+        datetime!(2023-07-04 09:05:03)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse(
+            "4/7/2023 9:05 pm",
+            &format_description::parse_strftime_borrowed("%-d/%-m/%Y %-I:%M %p")?
+        )?,
+        datetime!(2023-07-04 21:05)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse(
+            "2023-07-04 09:05 EST",
+            &format_description::parse_strftime_borrowed("%F %R %Z")?
+        )?,
+        datetime!(2023-07-04 09:05)
+    );
+    assert_eq!(
+        Date::parse(
+            "Tue 4 Jul 2023",
+            &format_description::parse_strftime_borrowed("%-a %-d %_b %Y")?
+        )?,
+        date!(2023 - 07 - 04)
+    );
+
+    Ok(())
+}
 
-    // Ensure an empty slice is a no-op success.
-    let mut parsed = Parsed::new();
-    let remaining_input = parsed.parse_item(b"2021-01-02", &FormatItem::First(&[]))?;
-    assert_eq!(remaining_input, b"2021-01-02");
-    assert!(parsed.year().is_none());
-    assert!(parsed.month().is_none());
-    assert!(parsed.day().is_none());
+#[test]
+fn parse_duration() -> time::Result<()> {
+    let format = fd::parse("[sign][hours total:true]:[minutes]:[seconds].[subsecond]")?;
+    assert_eq!(
+        Duration::parse("26:03:04.005", &format)?,
+        Duration::new(93_784, 5_000_000)
+    );
+    assert_eq!(
+        Duration::parse("-26:03:04.005", &format)?,
+        Duration::new(-93_784, -5_000_000)
+    );
+    assert_eq!(
+        Duration::parse("+00:00:00.5", &format)?,
+        Duration::milliseconds(500)
+    );
+    assert_eq!(
+        Duration::parse("-00:00:00.5", &format)?,
+        Duration::milliseconds(-500)
+    );
+    assert_eq!(
+        Duration::parse("1234:00:00.0", &format)?,
+        Duration::hours(1_234)
+    );
 
-    let mut parsed = Parsed::new();
-    let remaining_input =
-        parsed.parse_item(b"2021-01-02", &OwnedFormatItem::First(Box::new([])))?;
-    assert_eq!(remaining_input, b"2021-01-02");
-    assert!(parsed.year().is_none());
-    assert!(parsed.month().is_none());
-    assert!(parsed.day().is_none());
+    let format = fd::parse("[days padding:none]d [hours]h [minutes]m [seconds]s")?;
+    assert_eq!(
+        Duration::parse("1d 02h 03m 04s", &format)?,
+        Duration::seconds(93_784)
+    );
+    assert!(matches!(
+        Duration::parse("1d 24h 03m 04s", &format),
+        invalid_component!("hours")
+    ));
+    assert!(matches!(
+        Duration::parse("1d 02h 60m 04s", &format),
+        invalid_component!("minutes")
+    ));
+    assert!(matches!(
+        Duration::parse("1d 02h 03m 60s", &format),
+        invalid_component!("seconds")
+    ));
+    assert!(matches!(
+        Duration::parse("99999999999999999999d 02h 03m 04s", &format),
+        invalid_component!("days")
+    ));
 
-    // Ensure success when the first item fails.
-    let mut parsed = Parsed::new();
-    let remaining_input = parsed.parse_item(
-        b"2021-01-02",
-        &FormatItem::First(&[
-            FormatItem::Compound(&fd::parse("[period]")?),
-            FormatItem::Compound(&fd::parse("x")?),
-            FormatItem::Compound(&fd::parse("[year]-[month]-[day]")?),
-        ]),
-    )?;
-    assert!(remaining_input.is_empty());
-    assert_eq!(parsed.year(), Some(2021));
-    assert_eq!(parsed.month(), Some(Month::January));
-    assert_eq!(parsed.day().map(NonZeroU8::get), Some(2));
+    assert_eq!(
+        Duration::parse("90", &fd::parse("[minutes total:true]")?)?,
+        Duration::minutes(90)
+    );
+    // Without a sign component, a leading `-` is accepted, as it is written when formatting.
+    let format = fd::parse("[hours]:[minutes]:[seconds]")?;
+    assert_eq!(
+        Duration::parse("-01:01:01", &format)?,
+        Duration::seconds(-3_661)
+    );
+    assert_eq!(
+        Duration::parse(&Duration::seconds(-3_661).format(&format)?, &format)?,
+        Duration::seconds(-3_661)
+    );
+    assert!(matches!(
+        Duration::parse("+01:01:01", &format),
+        invalid_component!("hours")
+    ));
+    assert_eq!(
+        Duration::parse(
+            "-9223372036854775808.999999999",
+            &fd::parse("[sign][seconds total:true].[subsecond]")?
+        )?,
+        Duration::MIN
+    );
+    assert!(matches!(
+        Duration::parse(
+            "9223372036854775808",
+            &fd::parse("[seconds total:true]")?
+        ),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "seconds"
+    ));
+    assert!(matches!(
+        Duration::parse("+01", &fd::parse("[hours]")?),
+        invalid_component!("hours")
+    ));
+    assert!(matches!(
+        Duration::parse("01", &fd::parse("[sign sign:mandatory][hours]")?),
+        invalid_component!("sign")
+    ));
+    assert!(matches!(
+        Duration::parse("-", &fd::parse("[sign]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
+    assert!(matches!(
+        Duration::parse("2024", &fd::parse("[year]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
 
-    let mut parsed = Parsed::new();
-    let remaining_input = parsed.parse_item(
-        b"2021-01-02",
-        &OwnedFormatItem::from(FormatItem::First(&[
-            FormatItem::Compound(&fd::parse("[period]")?),
-            FormatItem::Compound(&fd::parse("x")?),
-            FormatItem::Compound(&fd::parse("[year]-[month]-[day]")?),
-        ])),
-    )?;
-    assert!(remaining_input.is_empty());
-    assert_eq!(parsed.year(), Some(2021));
-    assert_eq!(parsed.month(), Some(Month::January));
-    assert_eq!(parsed.day().map(NonZeroU8::get), Some(2));
+    assert!(matches!(
+        Duration::parse("-1:30", &fd::parse("[sign][hours]:[minutes]")?),
+        invalid_component!("hours")
+    ));
+    assert_eq!(
+        Duration::parse_prefix("-01:30:15", &fd::parse("[sign][hours]:[minutes]")?)?,
+        (Duration::minutes(-90), ":15")
+    );
+    assert_eq!(
+        Duration::parse("P1D", &Localized::new(&Rfc5545, &French))?,
+        Duration::days(1)
+    );
 
-    // Ensure the first error is returned.
-    let mut parsed = Parsed::new();
-    let err = parsed
-        .parse_item(
-            b"2021-01-02",
-            &FormatItem::First(&[
-                FormatItem::Compound(&fd::parse("[period]")?),
-                FormatItem::Compound(&fd::parse("x")?),
-            ]),
-        )
-        .unwrap_err();
-    assert_eq!(err, error::ParseFromDescription::InvalidComponent("period"));
+    // The components of a duration are accepted, but not retained, when parsing any other type.
+    assert_eq!(
+        Time::parse(
+            "12:00 -01:30",
+            &fd::parse("[hour]:[minute] [sign][hours]:[minutes]")?
+        )?,
+        time!(12:00)
+    );
+    assert!(Parsed::new().parse_using(b"P1D", &Rfc5545).is_err());
 
-    let mut parsed = Parsed::new();
-    let err = parsed
-        .parse_item(
-            b"2021-01-02",
-            &OwnedFormatItem::from(FormatItem::First(&[
-                FormatItem::Compound(&fd::parse("[period]")?),
-                FormatItem::Compound(&fd::parse("x")?),
-            ])),
-        )
-        .unwrap_err();
-    assert_eq!(err, error::ParseFromDescription::InvalidComponent("period"));
+    let format = fd::parse("[sign][days]:[hours]:[minutes]:[seconds].[subsecond]")?;
+    for duration in [
+        Duration::ZERO,
+        Duration::new(93_784, 5_006_007),
+        Duration::new(-93_784, -5_006_007),
+        Duration::MAX,
+        Duration::MIN,
+    ] {
+        assert_eq!(
+            Duration::parse(&duration.format(&format)?, &format)?,
+            duration
+        );
+    }
 
     Ok(())
 }
 
 #[test]
-fn parse_unix_timestamp() -> time::Result<()> {
+fn parse_prefix() -> time::Result<()> {
+    let format = fd::parse("[year]-[month]-[day]")?;
     assert_eq!(
-        OffsetDateTime::parse("1234567890", &fd::parse("[unix_timestamp]")?)?,
-        datetime!(2009-02-13 23:31:30 UTC)
+        Date::parse_prefix("2021-01-02 rest", &format)?,
+        (date!(2021 - 01 - 02), " rest")
     );
     assert_eq!(
-        OffsetDateTime::parse(
-            "1234567890123",
-            &fd::parse("[unix_timestamp precision:millisecond]")?
+        Date::parse_prefix("2021-01-02", &format)?,
+        (date!(2021 - 01 - 02), "")
+    );
+    assert_eq!(
+        Time::parse_prefix("13:02:03é", &fd::parse("[hour]:[minute]:[second]")?)?,
+        (time!(13:02:03), "é")
+    );
+    assert_eq!(
+        UtcOffset::parse_prefix("+01:02:03", &fd::parse("[offset_hour]:[offset_minute]")?)?,
+        (offset!(+01:02), ":03")
+    );
+    assert_eq!(
+        Duration::parse_prefix("26:03 elapsed", &fd::parse("[hours total:true]:[minutes]")?)?,
+        (Duration::minutes(1_563), " elapsed")
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse_prefix(
+            "2021-01-02 03:04:05 GET /",
+            &fd::parse("[year]-[month]-[day] [hour]:[minute]:[second]")?
         )?,
-        datetime!(2009-02-13 23:31:30.123 UTC)
+        (datetime!(2021-01-02 03:04:05), " GET /")
     );
     assert_eq!(
-        OffsetDateTime::parse(
-            "1234567890123456",
-            &fd::parse("[unix_timestamp precision:microsecond]")?
+        OffsetDateTime::parse_prefix(
+            "2021-01-02 03:04:05 +01 GET /",
+            &fd::parse("[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour]")?
         )?,
-        datetime!(2009-02-13 23:31:30.123456 UTC)
+        (datetime!(2021-01-02 03:04:05 +01), " GET /")
     );
     assert_eq!(
-        OffsetDateTime::parse(
-            "1234567890123456789",
-            &fd::parse("[unix_timestamp precision:nanosecond]")?
+        OffsetDateTime::parse_prefix(
+            "2021-01-02 03:04:05 +01 GET /",
+            &OwnedFormatItem::from(fd::parse(
+                "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour]"
+            )?)
         )?,
-        datetime!(2009-02-13 23:31:30.123456789 UTC)
+        (datetime!(2021-01-02 03:04:05 +01), " GET /")
+    );
+
+    assert_eq!(
+        OffsetDateTime::parse_prefix("2021-01-02T03:04:05Z [INFO] started", &Rfc3339)?,
+        (datetime!(2021-01-02 03:04:05 UTC), " [INFO] started")
+    );
+    assert_eq!(
+        OffsetDateTime::parse_prefix("2021-12-31T23:59:60Z [INFO] leap", &Rfc3339)?,
+        (
+            datetime!(2021-12-31 23:59:59.999_999_999 UTC),
+            " [INFO] leap"
+        )
+    );
+    assert_eq!(
+        OffsetDateTime::parse_prefix("Sat, 02 Jan 2021 03:04:05 +0000\r\nrest", &Rfc2822)?,
+        (datetime!(2021-01-02 03:04:05 UTC), "\r\nrest")
     );
+    assert_eq!(
+        OffsetDateTime::parse_prefix("2021-01-02T03:04:05Z|rest", &Iso8601::DEFAULT)?,
+        (datetime!(2021-01-02 03:04:05 UTC), "|rest")
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse_prefix("Sat, 02 Jan 2021 03:04:05 GMT;rest", &HttpDate)?,
+        (datetime!(2021-01-02 03:04:05), ";rest")
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse_prefix("2021-01-02 03:04:05,rest", &Sql)?,
+        (datetime!(2021-01-02 03:04:05), ",rest")
+    );
+
+    let err = Date::parse_prefix("2021-Foo-02 rest", &format);
+    assert!(matches!(
+        err.map_err(error::Located::into_inner),
+        invalid_component!("month")
+    ));
+    assert_eq!(err.unwrap_err().offset(), Some(5));
+    let err = OffsetDateTime::parse_prefix("2021-01-02T03:04:05 rest", &Rfc3339).unwrap_err();
+    assert_eq!(err.offset(), Some(19));
+    assert!(matches!(
+        Date::parse_prefix("2021-02-30 rest", &format).map_err(error::Located::into_inner),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "day"
+    ));
+
+    // Parsing must not stop partway through a character.
+    let err = Date::parse_prefix(
+        "2021-01-02é",
+        &[
+            FormatItem::Compound(&format),
+            FormatItem::Literal("é".as_bytes().split_at(1).0),
+        ][..],
+    );
+    assert!(matches!(
+        err.map_err(error::Located::into_inner),
+        invalid_literal!()
+    ));
+    assert_eq!(err.unwrap_err().offset(), Some(11));
 
     Ok(())
 }
 
 #[test]
-fn parse_unix_timestamp_err() -> time::Result<()> {
+fn parse_with_defaults() -> time::Result<()> {
+    let format = fd::parse("[month repr:short] [day padding:space] [hour]:[minute]:[second]")?;
+    let nearest =
+        |reference| WithDefaults::new(&format, Defaults::new().with_year_nearest_to(reference));
     assert_eq!(
-        OffsetDateTime::parse("1234567890", &fd::parse("[unix_timestamp sign:mandatory]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("unix_timestamp")
-        ))
+        PrimitiveDateTime::parse("Mar  3 12:00:00", &nearest(date!(2024 - 03 - 10)))?,
+        datetime!(2024-03-03 12:00)
     );
     assert_eq!(
-        OffsetDateTime::parse("a", &fd::parse("[unix_timestamp precision:second]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("unix_timestamp")
-        ))
+        PrimitiveDateTime::parse("Dec 31 23:59:59", &nearest(date!(2024 - 01 - 01)))?,
+        datetime!(2023-12-31 23:59:59)
     );
     assert_eq!(
-        OffsetDateTime::parse("a", &fd::parse("[unix_timestamp precision:millisecond]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("unix_timestamp")
-        ))
+        PrimitiveDateTime::parse("Jan  1 00:00:00", &nearest(date!(2023 - 12 - 31)))?,
+        datetime!(2024-01-01 0:00)
     );
     assert_eq!(
-        OffsetDateTime::parse("a", &fd::parse("[unix_timestamp precision:microsecond]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("unix_timestamp")
+        PrimitiveDateTime::parse("Feb 29 00:00:00", &nearest(date!(2025 - 01 - 01)))?,
+        datetime!(2024-02-29 0:00)
+    );
+    assert!(matches!(
+        PrimitiveDateTime::parse("Feb 29 00:00:00", &nearest(date!(2022 - 01 - 01))),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "day"
+    ));
+    assert_eq!(
+        Date::parse(
+            "100",
+            &WithDefaults::new(
+                &fd::parse("[ordinal]")?,
+                Defaults::new().with_year_nearest_to(date!(2024 - 12 - 31))
+            )
+        )?,
+        date!(2025 - 100)
+    );
+
+    let format = fd::parse("[month]-[day]")?;
+    let defaults = Defaults::new().with_year(2021);
+    assert_eq!(
+        Date::parse("03-03", &WithDefaults::new(&format, defaults))?,
+        date!(2021 - 03 - 03)
+    );
+    assert!(matches!(
+        Date::parse("03-03", &format),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
         ))
+    ));
+    // A year that is present is never replaced.
+    assert_eq!(
+        Date::parse(
+            "2020-03-03",
+            &WithDefaults::new(&fd::parse("[year]-[month]-[day]")?, defaults)
+        )?,
+        date!(2020 - 03 - 03)
+    );
+    assert!(matches!(
+        Date::parse(
+            "20-03-03",
+            &WithDefaults::new(&fd::parse("[year repr:last_two]-[month]-[day]")?, defaults)
+        ),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
+
+    let format = fd::parse("[year]-[month]-[day]")?;
+    let defaults = Defaults::new()
+        .with_time(time!(12:34:56.789))
+        .with_offset(offset!(-5:30));
+    let format = WithDefaults::new(&format, defaults);
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02", &format)?,
+        datetime!(2021-01-02 12:34:56.789 -5:30)
     );
     assert_eq!(
-        OffsetDateTime::parse("a", &fd::parse("[unix_timestamp precision:nanosecond]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("unix_timestamp")
+        PrimitiveDateTime::parse("2021-01-02", &format)?,
+        datetime!(2021-01-02 12:34:56.789)
+    );
+    assert_eq!(
+        Time::parse("", &WithDefaults::new(&fd::parse("")?, defaults))?,
+        time!(12:34:56.789)
+    );
+    assert_eq!(
+        UtcOffset::parse("", &WithDefaults::new(&fd::parse("")?, defaults))?,
+        offset!(-5:30)
+    );
+    // A partially present time is not completed from the defaults.
+    assert!(matches!(
+        Time::parse("13", &WithDefaults::new(&fd::parse("[hour]")?, defaults)),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
         ))
+    ));
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2021-01-02 03:04 +01",
+            &WithDefaults::new(
+                &fd::parse("[year]-[month]-[day] [hour]:[minute] [offset_hour]")?,
+                defaults
+            )
+        )?,
+        datetime!(2021-01-02 03:04 +01)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2021-01-02 GMT",
+            &WithDefaults::new(&fd::parse("[year]-[month]-[day] [zone_abbr]")?, defaults)
+        )?,
+        datetime!(2021-01-02 12:34:56.789 UTC)
+    );
+    // A Unix timestamp determines the full value.
+    assert_eq!(
+        OffsetDateTime::parse(
+            "0",
+            &WithDefaults::new(
+                &fd::parse("[unix_timestamp]")?,
+                Defaults::new().with_year(2021).with_offset(offset!(+1))
+            )
+        )?,
+        datetime!(1970-01-01 0:00 UTC)
+    );
+
+    let defaults = Defaults::from_reference(datetime!(2024-01-01 01:02:03 +04));
+    assert_eq!(
+        OffsetDateTime::parse(
+            "Dec 31 23:59:59",
+            &WithDefaults::new(&Rfc3164::DEFAULT, defaults)
+        )?,
+        datetime!(2023-12-31 23:59:59 +04)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "12-31",
+            &WithDefaults::new(&fd::parse("[month]-[day]")?, defaults)
+        )?,
+        datetime!(2023-12-31 01:02:03 +04)
+    );
+    assert_eq!(
+        OffsetDateTime::parse_prefix(
+            "Dec 31 23:59:59 host app: message",
+            &WithDefaults::new(&Rfc3164::DEFAULT, defaults)
+        )?,
+        (datetime!(2023-12-31 23:59:59 +04), " host app: message")
+    );
+    assert_eq!(
+        Date::parse(
+            "31 décembre",
+            &WithDefaults::new(
+                &Localized::new(&fd::parse("[day] [month repr:long]")?, &French),
+                defaults
+            )
+        )?,
+        date!(2023 - 12 - 31)
     );
 
     Ok(())
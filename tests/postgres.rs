@@ -0,0 +1,140 @@
+use time::macros::{date, datetime};
+use time::{postgres, Date};
+
+#[test]
+fn encode_date() {
+    assert_eq!(postgres::encode_date(date!(2000 - 01 - 01)), [0x00; 4]);
+    assert_eq!(
+        postgres::encode_date(date!(2024 - 01 - 02)),
+        [0x00, 0x00, 0x22, 0x3F]
+    );
+    assert_eq!(postgres::encode_date(date!(1999 - 12 - 31)), [0xFF; 4]);
+    assert_eq!(
+        postgres::encode_date(date!(1970 - 01 - 01)),
+        [0xFF, 0xFF, 0xD5, 0x33]
+    );
+}
+
+#[test]
+fn decode_date() -> time::Result<()> {
+    assert_eq!(postgres::decode_date([0x00; 4])?, date!(2000 - 01 - 01));
+    assert_eq!(
+        postgres::decode_date([0x00, 0x00, 0x22, 0x3F])?,
+        date!(2024 - 01 - 02)
+    );
+    assert_eq!(postgres::decode_date([0xFF; 4])?, date!(1999 - 12 - 31));
+    assert_eq!(
+        postgres::decode_date([0xFF, 0xFF, 0xD5, 0x33])?,
+        date!(1970 - 01 - 01)
+    );
+    assert_eq!(
+        postgres::decode_date(postgres::encode_date(Date::MIN))?,
+        Date::MIN
+    );
+    assert_eq!(
+        postgres::decode_date(postgres::encode_date(Date::MAX))?,
+        Date::MAX
+    );
+
+    Ok(())
+}
+
+#[test]
+fn decode_date_err() {
+    // `infinity` and `-infinity`
+    assert!(postgres::decode_date([0x7F, 0xFF, 0xFF, 0xFF]).is_err());
+    assert!(postgres::decode_date([0x80, 0x00, 0x00, 0x00]).is_err());
+    // out of range
+    assert!(postgres::decode_date([0x7F, 0xFF, 0xFF, 0xFE]).is_err());
+    assert!(postgres::decode_date([0x80, 0x00, 0x00, 0x01]).is_err());
+}
+
+#[test]
+fn encode_timestamp() -> time::Result<()> {
+    assert_eq!(
+        postgres::encode_timestamp(datetime!(2000-01-01 0:00))?,
+        [0x00; 8]
+    );
+    assert_eq!(
+        postgres::encode_timestamp(datetime!(2024-01-02 03:04:05.123456))?,
+        [0x00, 0x02, 0xB0, 0xEC, 0x85, 0x17, 0xD5, 0x80]
+    );
+    assert_eq!(
+        postgres::encode_timestamp(datetime!(1999-12-31 23:59:59.999999))?,
+        [0xFF; 8]
+    );
+    assert_eq!(
+        postgres::encode_timestamp(datetime!(1970-01-01 0:00))?,
+        [0xFF, 0xFC, 0xA2, 0xFE, 0xC4, 0xC8, 0x20, 0x00]
+    );
+    // Precision beyond microseconds is truncated towards negative infinity.
+    assert_eq!(
+        postgres::encode_timestamp(datetime!(2000-01-01 0:00:00.000_000_999))?,
+        [0x00; 8]
+    );
+    assert_eq!(
+        postgres::encode_timestamp(datetime!(1999-12-31 23:59:59.999_999_999))?,
+        [0xFF; 8]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn decode_timestamp() -> time::Result<()> {
+    assert_eq!(
+        postgres::decode_timestamp([0x00; 8])?,
+        datetime!(2000-01-01 0:00)
+    );
+    assert_eq!(
+        postgres::decode_timestamp([0x00, 0x02, 0xB0, 0xEC, 0x85, 0x17, 0xD5, 0x80])?,
+        datetime!(2024-01-02 03:04:05.123456)
+    );
+    assert_eq!(
+        postgres::decode_timestamp([0xFF; 8])?,
+        datetime!(1999-12-31 23:59:59.999999)
+    );
+    assert_eq!(
+        postgres::decode_timestamp([0xFF, 0xFC, 0xA2, 0xFE, 0xC4, 0xC8, 0x20, 0x00])?,
+        datetime!(1970-01-01 0:00)
+    );
+    // The earliest timestamp supported by PostgreSQL.
+    assert_eq!(
+        postgres::decode_timestamp([0xFD, 0x0F, 0x7C, 0xC1, 0x41, 0x1F, 0xA0, 0x00])?,
+        datetime!(-4713-11-24 0:00)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn decode_timestamp_err() {
+    // `infinity` and `-infinity`
+    assert!(postgres::decode_timestamp([0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]).is_err());
+    assert!(postgres::decode_timestamp([0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]).is_err());
+}
+
+#[test]
+fn timestamptz() -> time::Result<()> {
+    assert_eq!(
+        postgres::encode_timestamptz(datetime!(2024-01-02 03:04:05.123456 UTC))?,
+        [0x00, 0x02, 0xB0, 0xEC, 0x85, 0x17, 0xD5, 0x80]
+    );
+    assert_eq!(
+        postgres::encode_timestamptz(datetime!(2024-01-01 22:04:05.123456 -5))?,
+        [0x00, 0x02, 0xB0, 0xEC, 0x85, 0x17, 0xD5, 0x80]
+    );
+    assert_eq!(
+        postgres::encode_timestamptz(datetime!(2000-01-01 1:00 +1))?,
+        [0x00; 8]
+    );
+    assert_eq!(
+        postgres::decode_timestamptz([0x00, 0x02, 0xB0, 0xEC, 0x85, 0x17, 0xD5, 0x80])?,
+        datetime!(2024-01-02 03:04:05.123456 UTC)
+    );
+    assert!(
+        postgres::decode_timestamptz([0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]).is_err()
+    );
+
+    Ok(())
+}
@@ -1,5 +1,9 @@
-use quickcheck::{Arbitrary, TestResult};
+use std::num::NonZeroU16;
+
+use quickcheck::{Arbitrary, Gen, TestResult};
 use quickcheck_macros::quickcheck;
+use time::format_description::modifier::*;
+use time::format_description::{self, Component, OwnedFormatItem};
 use time::Weekday::*;
 use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
@@ -333,3 +337,230 @@ test_shrink!(
     offset_date_time_can_shrink_nanosecond,
     nanosecond()
 );
+
+/// An arbitrary format description, used to test rendering and parsing round-trips.
+#[derive(Debug, Clone)]
+struct Description(OwnedFormatItem);
+
+impl Arbitrary for Description {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self(arbitrary_items(g, 2))
+    }
+}
+
+/// Generate a sequence of format items, nesting at most `depth` levels.
+fn arbitrary_items(g: &mut Gen, depth: u8) -> OwnedFormatItem {
+    let len = usize::arbitrary(g) % 6;
+    OwnedFormatItem::Compound((0..len).map(|_| arbitrary_item(g, depth)).collect())
+}
+
+/// Generate a single format item, nesting at most `depth` levels.
+fn arbitrary_item(g: &mut Gen, depth: u8) -> OwnedFormatItem {
+    match u8::arbitrary(g) % if depth == 0 { 2 } else { 4 } {
+        0 => {
+            let len = usize::arbitrary(g) % 4 + 1;
+            let literal = (0..len)
+                .map(|_| *g.choose(&['a', ' ', ':', '[', ']', '\\', 'é']).unwrap())
+                .collect::<String>();
+            OwnedFormatItem::Literal(literal.into_bytes().into_boxed_slice())
+        }
+        1 => OwnedFormatItem::Component(arbitrary_component(g)),
+        2 => OwnedFormatItem::Optional(Box::new(arbitrary_items(g, depth - 1))),
+        _ => OwnedFormatItem::First(
+            (0..usize::arbitrary(g) % 3)
+                .map(|_| arbitrary_items(g, depth - 1))
+                .collect(),
+        ),
+    }
+}
+
+/// Generate a component with arbitrary modifiers.
+fn arbitrary_component(g: &mut Gen) -> Component {
+    let padding = *g
+        .choose(&[Padding::Space, Padding::Zero, Padding::None])
+        .unwrap();
+    let case_sensitive = bool::arbitrary(g);
+    let sign_is_mandatory = bool::arbitrary(g);
+
+    match u8::arbitrary(g) % 26 {
+        0 => Component::Day(modifier!(Day {
+            padding,
+            suffix: bool::arbitrary(g),
+            case_sensitive,
+        })),
+        1 => Component::Month(modifier!(Month {
+            padding,
+            repr: *g
+                .choose(&[MonthRepr::Numerical, MonthRepr::Long, MonthRepr::Short])
+                .unwrap(),
+            case_sensitive,
+        })),
+        2 => Component::Ordinal(modifier!(Ordinal { padding })),
+        3 => Component::Weekday(modifier!(Weekday {
+            repr: *g
+                .choose(&[
+                    WeekdayRepr::Short,
+                    WeekdayRepr::Long,
+                    WeekdayRepr::Sunday,
+                    WeekdayRepr::Monday,
+                ])
+                .unwrap(),
+            one_indexed: bool::arbitrary(g),
+            case_sensitive,
+        })),
+        4 => Component::WeekNumber(modifier!(WeekNumber {
+            padding,
+            repr: *g
+                .choose(&[
+                    WeekNumberRepr::Iso,
+                    WeekNumberRepr::Sunday,
+                    WeekNumberRepr::Monday,
+                ])
+                .unwrap(),
+        })),
+        5 => Component::Year(modifier!(Year {
+            padding,
+            repr: *g
+                .choose(&[YearRepr::Full, YearRepr::LastTwo, YearRepr::Era])
+                .unwrap(),
+            iso_week_based: bool::arbitrary(g),
+            sign_is_mandatory,
+        })),
+        6 => Component::Quarter(Quarter::default()),
+        7 => Component::Century(modifier!(Century { padding })),
+        8 => Component::Era(modifier!(Era {
+            repr: *g.choose(&[EraRepr::Ad, EraRepr::Ce]).unwrap(),
+            case_sensitive,
+        })),
+        9 => Component::Hour(modifier!(Hour {
+            padding,
+            is_12_hour_clock: bool::arbitrary(g),
+        })),
+        10 => Component::Minute(modifier!(Minute { padding })),
+        11 => Component::Period(modifier!(Period {
+            is_uppercase: bool::arbitrary(g),
+            case_sensitive,
+        })),
+        12 => Component::Second(modifier!(Second { padding })),
+        13 => Component::Subsecond(modifier!(Subsecond {
+            digits: *g
+                .choose(&[
+                    SubsecondDigits::One,
+                    SubsecondDigits::Two,
+                    SubsecondDigits::Three,
+                    SubsecondDigits::Four,
+                    SubsecondDigits::Five,
+                    SubsecondDigits::Six,
+                    SubsecondDigits::Seven,
+                    SubsecondDigits::Eight,
+                    SubsecondDigits::Nine,
+                    SubsecondDigits::OneOrMore,
+                ])
+                .unwrap(),
+        })),
+        14 => Component::OffsetHour(modifier!(OffsetHour {
+            sign_is_mandatory,
+            padding,
+        })),
+        15 => Component::OffsetMinute(modifier!(OffsetMinute { padding })),
+        16 => Component::OffsetSecond(modifier!(OffsetSecond { padding })),
+        17 => Component::ZoneName(ZoneName::default()),
+        18 => Component::ZoneAbbr(ZoneAbbr::default()),
+        19 => Component::Sign(modifier!(Sign { sign_is_mandatory })),
+        20 => Component::Days(modifier!(Days { padding })),
+        21 => Component::Hours(modifier!(Hours {
+            padding,
+            total: bool::arbitrary(g),
+        })),
+        22 => Component::Minutes(modifier!(Minutes {
+            padding,
+            total: bool::arbitrary(g),
+        })),
+        23 => Component::Seconds(modifier!(Seconds {
+            padding,
+            total: bool::arbitrary(g),
+        })),
+        24 => Component::Ignore(Ignore::count(
+            NonZeroU16::new(u16::arbitrary(g).max(1)).unwrap(),
+        )),
+        _ => Component::UnixTimestamp(modifier!(UnixTimestamp {
+            precision: *g
+                .choose(&[
+                    UnixTimestampPrecision::Second,
+                    UnixTimestampPrecision::Millisecond,
+                    UnixTimestampPrecision::Microsecond,
+                    UnixTimestampPrecision::Nanosecond,
+                ])
+                .unwrap(),
+            sign_is_mandatory,
+        })),
+    }
+}
+
+/// Flatten compound items and merge adjacent literals, as neither is preserved when rendering.
+fn normalize(item: &OwnedFormatItem) -> Vec<OwnedFormatItem> {
+    fn normalize_into(item: &OwnedFormatItem, output: &mut Vec<OwnedFormatItem>) {
+        match item {
+            OwnedFormatItem::Literal(literal) => {
+                if let Some(OwnedFormatItem::Literal(last)) = output.last_mut() {
+                    *last = [&**last, &**literal].concat().into_boxed_slice();
+                } else {
+                    output.push(item.clone());
+                }
+            }
+            OwnedFormatItem::Compound(items) => {
+                items.iter().for_each(|item| normalize_into(item, output))
+            }
+            OwnedFormatItem::Optional(item) => {
+                output.push(OwnedFormatItem::Optional(Box::new(normalize(item).into())))
+            }
+            OwnedFormatItem::First(items) => output.push(OwnedFormatItem::First(
+                items.iter().map(|item| normalize(item).into()).collect(),
+            )),
+            _ => output.push(item.clone()),
+        }
+    }
+
+    let mut output = Vec::new();
+    normalize_into(item, &mut output);
+    output
+}
+
+/// Render the description, parse it again, and check that the result is equivalent. Rendering
+/// the parsed description must produce the same string.
+fn description_roundtrip<const VERSION: usize>(Description(item): Description) -> TestResult {
+    let rendered = match item.to_description_string::<VERSION>() {
+        Ok(rendered) => rendered,
+        // Not all descriptions are representable in version 1.
+        Err(_) if VERSION == 1 => return TestResult::discard(),
+        Err(_) => return TestResult::failed(),
+    };
+    let Ok(parsed) = format_description::parse_owned::<VERSION>(&rendered) else {
+        return TestResult::failed();
+    };
+    if normalize(&parsed) != normalize(&item)
+        || parsed.to_description_string::<VERSION>().as_ref() != Ok(&rendered)
+    {
+        return TestResult::failed();
+    }
+
+    // Descriptions without nested items can also be parsed as borrowed items.
+    if let Ok(items) = format_description::parse_borrowed::<VERSION>(&rendered) {
+        let borrowed = format_description::FormatItem::Compound(&items);
+        return TestResult::from_bool(
+            borrowed.to_description_string::<VERSION>().as_ref() == Ok(&rendered),
+        );
+    }
+
+    TestResult::passed()
+}
+
+#[quickcheck]
+fn format_description_v1_roundtrip(description: Description) -> TestResult {
+    description_roundtrip::<1>(description)
+}
+
+#[quickcheck]
+fn format_description_v2_roundtrip(description: Description) -> TestResult {
+    description_roundtrip::<2>(description)
+}
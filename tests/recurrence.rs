@@ -0,0 +1,925 @@
+//! Most of these tests are the examples from RFC 5545, section 3.8.5.3. The examples are in
+//! America/New_York, but all occurrences fall within either standard or daylight saving time, so
+//! they are expanded from a `PrimitiveDateTime`.
+
+use time::error::InvalidRecurrenceRule;
+use time::macros::{date, datetime, time};
+use time::recurrence::{Frequency, RecurrenceRule};
+use time::{Date, Month, PrimitiveDateTime, Result};
+
+/// Expand the rule from 09:00 on the provided date, returning the dates of at most `limit`
+/// occurrences. Every occurrence must be at 09:00.
+fn dates(rule: &str, start: Date, limit: usize) -> Vec<Date> {
+    RecurrenceRule::parse(rule)
+        .unwrap()
+        .occurrences(start.with_time(time!(9:00)))
+        .take(limit)
+        .map(|occurrence| {
+            assert_eq!(occurrence.time(), time!(9:00));
+            occurrence.date()
+        })
+        .collect()
+}
+
+/// Expand the rule from the provided start, returning at most `limit` occurrences.
+fn datetimes(rule: &str, start: PrimitiveDateTime, limit: usize) -> Vec<PrimitiveDateTime> {
+    RecurrenceRule::parse(rule)
+        .unwrap()
+        .occurrences(start)
+        .take(limit)
+        .collect()
+}
+
+fn malformed(rule: &str) -> Option<usize> {
+    match RecurrenceRule::parse(rule) {
+        Err(InvalidRecurrenceRule::Malformed { index, .. }) => Some(index),
+        _ => None,
+    }
+}
+
+fn conflict(rule: &str) -> Option<&'static str> {
+    match RecurrenceRule::parse(rule) {
+        Err(InvalidRecurrenceRule::Conflict { reason, .. }) => Some(reason),
+        _ => None,
+    }
+}
+
+#[test]
+fn parse() -> Result<()> {
+    let rule = RecurrenceRule::parse("FREQ=MONTHLY;BYDAY=2TU;COUNT=10")?;
+    assert_eq!(rule.frequency(), Frequency::Monthly);
+    assert_eq!(rule.interval(), 1);
+    assert_eq!(rule.count(), Some(10));
+
+    let rule: RecurrenceRule = "rrule:freq=weekly;interval=2;wkst=su;byday=tu,th".parse()?;
+    assert_eq!(rule.frequency(), Frequency::Weekly);
+    assert_eq!(rule.interval(), 2);
+    assert_eq!(rule.count(), None);
+
+    assert_eq!(
+        RecurrenceRule::parse("BYMONTH=1;FREQ=YEARLY")?,
+        RecurrenceRule::parse("FREQ=YEARLY;BYMONTH=1")?
+    );
+    assert!(RecurrenceRule::parse("FREQ=DAILY;UNTIL=19971224").is_ok());
+    assert!(RecurrenceRule::parse("FREQ=DAILY;UNTIL=19971224T000000").is_ok());
+    assert!(RecurrenceRule::parse("FREQ=YEARLY;BYYEARDAY=-366,+1;BYSETPOS=1").is_ok());
+    assert!(RecurrenceRule::parse("FREQ=MONTHLY;BYDAY=+1MO,-53SU,FR").is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn parse_err() {
+    assert_eq!(malformed(""), Some(0));
+    assert_eq!(malformed("FREQ"), Some(4));
+    assert_eq!(malformed("FREQ=FORTNIGHTLY"), Some(5));
+    assert_eq!(malformed("FREQ=DAILY;"), Some(11));
+    assert_eq!(malformed("FREQ=DAILY;;COUNT=1"), Some(11));
+    assert_eq!(malformed("FREQ=DAILY,COUNT=1"), Some(10));
+    assert_eq!(malformed("FREQ=DAILY;FREQ=DAILY"), Some(11));
+    assert_eq!(malformed("FREQ=DAILY;X-NAME=1"), Some(11));
+    assert_eq!(malformed("FREQ=DAILY;INTERVAL=0"), Some(20));
+    assert_eq!(malformed("FREQ=DAILY;COUNT=4294967296"), Some(17));
+    assert_eq!(malformed("FREQ=DAILY;COUNT=-1"), Some(17));
+    assert_eq!(malformed("FREQ=DAILY;BYSECOND=60"), Some(20));
+    assert_eq!(malformed("FREQ=DAILY;BYMINUTE=1,,2"), Some(22));
+    assert_eq!(malformed("FREQ=DAILY;BYHOUR=24"), Some(18));
+    assert_eq!(malformed("FREQ=DAILY;BYMONTHDAY=0"), Some(22));
+    assert_eq!(malformed("FREQ=DAILY;BYMONTHDAY=-32"), Some(22));
+    assert_eq!(malformed("FREQ=YEARLY;BYYEARDAY=367"), Some(22));
+    assert_eq!(malformed("FREQ=YEARLY;BYWEEKNO=+54"), Some(21));
+    assert_eq!(malformed("FREQ=YEARLY;BYMONTH=13"), Some(20));
+    assert_eq!(malformed("FREQ=YEARLY;BYDAY=54MO"), Some(18));
+    assert_eq!(malformed("FREQ=YEARLY;BYDAY=1XX"), Some(19));
+    assert_eq!(malformed("FREQ=DAILY;WKST=M"), Some(16));
+    assert_eq!(malformed("FREQ=DAILY;UNTIL=1997122"), Some(23));
+    assert_eq!(malformed("FREQ=DAILY;UNTIL=19970230"), Some(17));
+    assert_eq!(malformed("FREQ=DAILY;UNTIL=19971224T25"), Some(28));
+    assert_eq!(malformed("FREQ=DAILY;UNTIL=19971224T250000Z"), Some(17));
+    assert_eq!(malformed("FREQ=DAILY;UNTIL=19971224T000000+0100"), Some(32));
+
+    assert_eq!(conflict("COUNT=1"), Some("FREQ is required"));
+    assert_eq!(
+        conflict("FREQ=DAILY;COUNT=1;UNTIL=19971224"),
+        Some("COUNT and UNTIL cannot both be present")
+    );
+    assert_eq!(
+        conflict("FREQ=MONTHLY;BYWEEKNO=1"),
+        Some("BYWEEKNO is only permitted with FREQ=YEARLY")
+    );
+    assert_eq!(
+        conflict("FREQ=WEEKLY;BYYEARDAY=1"),
+        Some("BYYEARDAY is not permitted with FREQ=DAILY, WEEKLY, or MONTHLY")
+    );
+    assert_eq!(
+        conflict("FREQ=WEEKLY;BYMONTHDAY=1"),
+        Some("BYMONTHDAY is not permitted with FREQ=WEEKLY")
+    );
+    assert_eq!(
+        conflict("FREQ=WEEKLY;BYDAY=1MO"),
+        Some("BYDAY ordinals are only permitted with FREQ=MONTHLY or YEARLY")
+    );
+    assert_eq!(
+        conflict("FREQ=YEARLY;BYWEEKNO=1;BYDAY=1MO"),
+        Some("BYDAY ordinals are not permitted with BYWEEKNO")
+    );
+    assert_eq!(
+        conflict("FREQ=MONTHLY;BYSETPOS=1"),
+        Some("BYSETPOS requires another BYxxx rule part")
+    );
+}
+
+#[test]
+fn daily() {
+    assert_eq!(
+        dates("FREQ=DAILY;COUNT=10", date!(1997 - 09 - 02), 100),
+        [
+            date!(1997 - 09 - 02),
+            date!(1997 - 09 - 03),
+            date!(1997 - 09 - 04),
+            date!(1997 - 09 - 05),
+            date!(1997 - 09 - 06),
+            date!(1997 - 09 - 07),
+            date!(1997 - 09 - 08),
+            date!(1997 - 09 - 09),
+            date!(1997 - 09 - 10),
+            date!(1997 - 09 - 11),
+        ]
+    );
+
+    let until = dates(
+        "FREQ=DAILY;UNTIL=19971224T000000Z",
+        date!(1997 - 09 - 02),
+        1_000,
+    );
+    assert_eq!(until.len(), 113);
+    assert_eq!(until.last(), Some(&date!(1997 - 12 - 23)));
+
+    assert_eq!(
+        dates("FREQ=DAILY;INTERVAL=2", date!(1997 - 09 - 02), 5),
+        [
+            date!(1997 - 09 - 02),
+            date!(1997 - 09 - 04),
+            date!(1997 - 09 - 06),
+            date!(1997 - 09 - 08),
+            date!(1997 - 09 - 10),
+        ]
+    );
+    assert_eq!(
+        dates("FREQ=DAILY;INTERVAL=10;COUNT=5", date!(1997 - 09 - 02), 100),
+        [
+            date!(1997 - 09 - 02),
+            date!(1997 - 09 - 12),
+            date!(1997 - 09 - 22),
+            date!(1997 - 10 - 02),
+            date!(1997 - 10 - 12),
+        ]
+    );
+}
+
+#[test]
+fn every_day_in_january() {
+    let expected = (1998..=2000)
+        .flat_map(|year| {
+            (1..=31).map(move |day| Date::from_calendar_date(year, Month::January, day).unwrap())
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        dates(
+            "FREQ=YEARLY;UNTIL=20000131T140000Z;BYMONTH=1;BYDAY=SU,MO,TU,WE,TH,FR,SA",
+            date!(1998 - 01 - 01),
+            1_000
+        ),
+        expected
+    );
+    assert_eq!(
+        dates(
+            "FREQ=DAILY;UNTIL=20000131T140000Z;BYMONTH=1",
+            date!(1998 - 01 - 01),
+            1_000
+        ),
+        expected
+    );
+}
+
+#[test]
+fn weekly() {
+    assert_eq!(
+        dates("FREQ=WEEKLY;COUNT=10", date!(1997 - 09 - 02), 100),
+        [
+            date!(1997 - 09 - 02),
+            date!(1997 - 09 - 09),
+            date!(1997 - 09 - 16),
+            date!(1997 - 09 - 23),
+            date!(1997 - 09 - 30),
+            date!(1997 - 10 - 07),
+            date!(1997 - 10 - 14),
+            date!(1997 - 10 - 21),
+            date!(1997 - 10 - 28),
+            date!(1997 - 11 - 04),
+        ]
+    );
+
+    let until = dates(
+        "FREQ=WEEKLY;UNTIL=19971224T000000Z",
+        date!(1997 - 09 - 02),
+        100,
+    );
+    assert_eq!(until.len(), 17);
+    assert_eq!(until.last(), Some(&date!(1997 - 12 - 23)));
+
+    let expected = [
+        date!(1997 - 09 - 02),
+        date!(1997 - 09 - 04),
+        date!(1997 - 09 - 09),
+        date!(1997 - 09 - 11),
+        date!(1997 - 09 - 16),
+        date!(1997 - 09 - 18),
+        date!(1997 - 09 - 23),
+        date!(1997 - 09 - 25),
+        date!(1997 - 09 - 30),
+        date!(1997 - 10 - 02),
+    ];
+    assert_eq!(
+        dates(
+            "FREQ=WEEKLY;UNTIL=19971007T000000Z;WKST=SU;BYDAY=TU,TH",
+            date!(1997 - 09 - 02),
+            100
+        ),
+        expected
+    );
+    assert_eq!(
+        dates(
+            "FREQ=WEEKLY;COUNT=10;WKST=SU;BYDAY=TU,TH",
+            date!(1997 - 09 - 02),
+            100
+        ),
+        expected
+    );
+
+    assert_eq!(
+        dates(
+            "FREQ=WEEKLY;INTERVAL=2;UNTIL=19971224T000000Z;WKST=SU;BYDAY=MO,WE,FR",
+            date!(1997 - 09 - 01),
+            100
+        ),
+        [
+            date!(1997 - 09 - 01),
+            date!(1997 - 09 - 03),
+            date!(1997 - 09 - 05),
+            date!(1997 - 09 - 15),
+            date!(1997 - 09 - 17),
+            date!(1997 - 09 - 19),
+            date!(1997 - 09 - 29),
+            date!(1997 - 10 - 01),
+            date!(1997 - 10 - 03),
+            date!(1997 - 10 - 13),
+            date!(1997 - 10 - 15),
+            date!(1997 - 10 - 17),
+            date!(1997 - 10 - 27),
+            date!(1997 - 10 - 29),
+            date!(1997 - 10 - 31),
+            date!(1997 - 11 - 10),
+            date!(1997 - 11 - 12),
+            date!(1997 - 11 - 14),
+            date!(1997 - 11 - 24),
+            date!(1997 - 11 - 26),
+            date!(1997 - 11 - 28),
+            date!(1997 - 12 - 08),
+            date!(1997 - 12 - 10),
+            date!(1997 - 12 - 12),
+            date!(1997 - 12 - 22),
+        ]
+    );
+    assert_eq!(
+        dates(
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=8;WKST=SU;BYDAY=TU,TH",
+            date!(1997 - 09 - 02),
+            100
+        ),
+        [
+            date!(1997 - 09 - 02),
+            date!(1997 - 09 - 04),
+            date!(1997 - 09 - 16),
+            date!(1997 - 09 - 18),
+            date!(1997 - 09 - 30),
+            date!(1997 - 10 - 02),
+            date!(1997 - 10 - 14),
+            date!(1997 - 10 - 16),
+        ]
+    );
+}
+
+#[test]
+fn week_start() {
+    assert_eq!(
+        dates(
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO",
+            date!(1997 - 08 - 05),
+            100
+        ),
+        [
+            date!(1997 - 08 - 05),
+            date!(1997 - 08 - 10),
+            date!(1997 - 08 - 19),
+            date!(1997 - 08 - 24),
+        ]
+    );
+    assert_eq!(
+        dates(
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU",
+            date!(1997 - 08 - 05),
+            100
+        ),
+        [
+            date!(1997 - 08 - 05),
+            date!(1997 - 08 - 17),
+            date!(1997 - 08 - 19),
+            date!(1997 - 08 - 31),
+        ]
+    );
+}
+
+#[test]
+fn monthly_by_day() {
+    assert_eq!(
+        dates(
+            "FREQ=MONTHLY;COUNT=10;BYDAY=1FR",
+            date!(1997 - 09 - 05),
+            100
+        ),
+        [
+            date!(1997 - 09 - 05),
+            date!(1997 - 10 - 03),
+            date!(1997 - 11 - 07),
+            date!(1997 - 12 - 05),
+            date!(1998 - 01 - 02),
+            date!(1998 - 02 - 06),
+            date!(1998 - 03 - 06),
+            date!(1998 - 04 - 03),
+            date!(1998 - 05 - 01),
+            date!(1998 - 06 - 05),
+        ]
+    );
+    assert_eq!(
+        dates(
+            "FREQ=MONTHLY;UNTIL=19971224T000000Z;BYDAY=1FR",
+            date!(1997 - 09 - 05),
+            100
+        ),
+        [
+            date!(1997 - 09 - 05),
+            date!(1997 - 10 - 03),
+            date!(1997 - 11 - 07),
+            date!(1997 - 12 - 05),
+        ]
+    );
+    assert_eq!(
+        dates(
+            "FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=1SU,-1SU",
+            date!(1997 - 09 - 07),
+            100
+        ),
+        [
+            date!(1997 - 09 - 07),
+            date!(1997 - 09 - 28),
+            date!(1997 - 11 - 02),
+            date!(1997 - 11 - 30),
+            date!(1998 - 01 - 04),
+            date!(1998 - 01 - 25),
+            date!(1998 - 03 - 01),
+            date!(1998 - 03 - 29),
+            date!(1998 - 05 - 03),
+            date!(1998 - 05 - 31),
+        ]
+    );
+    assert_eq!(
+        dates(
+            "FREQ=MONTHLY;COUNT=6;BYDAY=-2MO",
+            date!(1997 - 09 - 22),
+            100
+        ),
+        [
+            date!(1997 - 09 - 22),
+            date!(1997 - 10 - 20),
+            date!(1997 - 11 - 17),
+            date!(1997 - 12 - 22),
+            date!(1998 - 01 - 19),
+            date!(1998 - 02 - 16),
+        ]
+    );
+    assert_eq!(
+        dates(
+            "FREQ=MONTHLY;INTERVAL=2;BYDAY=TU",
+            date!(1997 - 09 - 02),
+            18
+        ),
+        [
+            date!(1997 - 09 - 02),
+            date!(1997 - 09 - 09),
+            date!(1997 - 09 - 16),
+            date!(1997 - 09 - 23),
+            date!(1997 - 09 - 30),
+            date!(1997 - 11 - 04),
+            date!(1997 - 11 - 11),
+            date!(1997 - 11 - 18),
+            date!(1997 - 11 - 25),
+            date!(1998 - 01 - 06),
+            date!(1998 - 01 - 13),
+            date!(1998 - 01 - 20),
+            date!(1998 - 01 - 27),
+            date!(1998 - 03 - 03),
+            date!(1998 - 03 - 10),
+            date!(1998 - 03 - 17),
+            date!(1998 - 03 - 24),
+            date!(1998 - 03 - 31),
+        ]
+    );
+}
+
+#[test]
+fn monthly_by_month_day() {
+    assert_eq!(
+        dates("FREQ=MONTHLY;BYMONTHDAY=-3", date!(1997 - 09 - 28), 6),
+        [
+            date!(1997 - 09 - 28),
+            date!(1997 - 10 - 29),
+            date!(1997 - 11 - 28),
+            date!(1997 - 12 - 29),
+            date!(1998 - 01 - 29),
+            date!(1998 - 02 - 26),
+        ]
+    );
+    assert_eq!(
+        dates(
+            "FREQ=MONTHLY;COUNT=10;BYMONTHDAY=2,15",
+            date!(1997 - 09 - 02),
+            100
+        ),
+        [
+            date!(1997 - 09 - 02),
+            date!(1997 - 09 - 15),
+            date!(1997 - 10 - 02),
+            date!(1997 - 10 - 15),
+            date!(1997 - 11 - 02),
+            date!(1997 - 11 - 15),
+            date!(1997 - 12 - 02),
+            date!(1997 - 12 - 15),
+            date!(1998 - 01 - 02),
+            date!(1998 - 01 - 15),
+        ]
+    );
+    assert_eq!(
+        dates(
+            "FREQ=MONTHLY;COUNT=10;BYMONTHDAY=1,-1",
+            date!(1997 - 09 - 30),
+            100
+        ),
+        [
+            date!(1997 - 09 - 30),
+            date!(1997 - 10 - 01),
+            date!(1997 - 10 - 31),
+            date!(1997 - 11 - 01),
+            date!(1997 - 11 - 30),
+            date!(1997 - 12 - 01),
+            date!(1997 - 12 - 31),
+            date!(1998 - 01 - 01),
+            date!(1998 - 01 - 31),
+            date!(1998 - 02 - 01),
+        ]
+    );
+    assert_eq!(
+        dates(
+            "FREQ=MONTHLY;INTERVAL=18;COUNT=10;BYMONTHDAY=10,11,12,13,14,15",
+            date!(1997 - 09 - 10),
+            100
+        ),
+        [
+            date!(1997 - 09 - 10),
+            date!(1997 - 09 - 11),
+            date!(1997 - 09 - 12),
+            date!(1997 - 09 - 13),
+            date!(1997 - 09 - 14),
+            date!(1997 - 09 - 15),
+            date!(1999 - 03 - 10),
+            date!(1999 - 03 - 11),
+            date!(1999 - 03 - 12),
+            date!(1999 - 03 - 13),
+        ]
+    );
+
+    // Invalid dates, such as February 30, are ignored.
+    assert_eq!(
+        datetimes(
+            "FREQ=MONTHLY;BYMONTHDAY=15,30;COUNT=5",
+            datetime!(2007-01-15 9:00),
+            100
+        ),
+        [
+            datetime!(2007-01-15 9:00),
+            datetime!(2007-01-30 9:00),
+            datetime!(2007-02-15 9:00),
+            datetime!(2007-03-15 9:00),
+            datetime!(2007-03-30 9:00),
+        ]
+    );
+}
+
+#[test]
+fn yearly() {
+    assert_eq!(
+        dates(
+            "FREQ=YEARLY;COUNT=10;BYMONTH=6,7",
+            date!(1997 - 06 - 10),
+            100
+        ),
+        [
+            date!(1997 - 06 - 10),
+            date!(1997 - 07 - 10),
+            date!(1998 - 06 - 10),
+            date!(1998 - 07 - 10),
+            date!(1999 - 06 - 10),
+            date!(1999 - 07 - 10),
+            date!(2000 - 06 - 10),
+            date!(2000 - 07 - 10),
+            date!(2001 - 06 - 10),
+            date!(2001 - 07 - 10),
+        ]
+    );
+    assert_eq!(
+        dates(
+            "FREQ=YEARLY;INTERVAL=2;COUNT=10;BYMONTH=1,2,3",
+            date!(1997 - 03 - 10),
+            100
+        ),
+        [
+            date!(1997 - 03 - 10),
+            date!(1999 - 01 - 10),
+            date!(1999 - 02 - 10),
+            date!(1999 - 03 - 10),
+            date!(2001 - 01 - 10),
+            date!(2001 - 02 - 10),
+            date!(2001 - 03 - 10),
+            date!(2003 - 01 - 10),
+            date!(2003 - 02 - 10),
+            date!(2003 - 03 - 10),
+        ]
+    );
+    assert_eq!(
+        dates(
+            "FREQ=YEARLY;INTERVAL=3;COUNT=10;BYYEARDAY=1,100,200",
+            date!(1997 - 01 - 01),
+            100
+        ),
+        [
+            date!(1997 - 01 - 01),
+            date!(1997 - 04 - 10),
+            date!(1997 - 07 - 19),
+            date!(2000 - 01 - 01),
+            date!(2000 - 04 - 09),
+            date!(2000 - 07 - 18),
+            date!(2003 - 01 - 01),
+            date!(2003 - 04 - 10),
+            date!(2003 - 07 - 19),
+            date!(2006 - 01 - 01),
+        ]
+    );
+    assert_eq!(
+        dates("FREQ=YEARLY;BYDAY=20MO", date!(1997 - 05 - 19), 3),
+        [
+            date!(1997 - 05 - 19),
+            date!(1998 - 05 - 18),
+            date!(1999 - 05 - 17),
+        ]
+    );
+    assert_eq!(
+        dates("FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO", date!(1997 - 05 - 12), 3),
+        [
+            date!(1997 - 05 - 12),
+            date!(1998 - 05 - 11),
+            date!(1999 - 05 - 17),
+        ]
+    );
+    assert_eq!(
+        dates("FREQ=YEARLY;BYMONTH=3;BYDAY=TH", date!(1997 - 03 - 13), 11),
+        [
+            date!(1997 - 03 - 13),
+            date!(1997 - 03 - 20),
+            date!(1997 - 03 - 27),
+            date!(1998 - 03 - 05),
+            date!(1998 - 03 - 12),
+            date!(1998 - 03 - 19),
+            date!(1998 - 03 - 26),
+            date!(1999 - 03 - 04),
+            date!(1999 - 03 - 11),
+            date!(1999 - 03 - 18),
+            date!(1999 - 03 - 25),
+        ]
+    );
+    assert_eq!(
+        dates(
+            "FREQ=YEARLY;BYDAY=TH;BYMONTH=6,7,8",
+            date!(1997 - 06 - 05),
+            14
+        ),
+        [
+            date!(1997 - 06 - 05),
+            date!(1997 - 06 - 12),
+            date!(1997 - 06 - 19),
+            date!(1997 - 06 - 26),
+            date!(1997 - 07 - 03),
+            date!(1997 - 07 - 10),
+            date!(1997 - 07 - 17),
+            date!(1997 - 07 - 24),
+            date!(1997 - 07 - 31),
+            date!(1997 - 08 - 07),
+            date!(1997 - 08 - 14),
+            date!(1997 - 08 - 21),
+            date!(1997 - 08 - 28),
+            date!(1998 - 06 - 04),
+        ]
+    );
+    assert_eq!(
+        dates(
+            "FREQ=YEARLY;INTERVAL=4;BYMONTH=11;BYDAY=TU;BYMONTHDAY=2,3,4,5,6,7,8",
+            date!(1996 - 11 - 05),
+            3
+        ),
+        [
+            date!(1996 - 11 - 05),
+            date!(2000 - 11 - 07),
+            date!(2004 - 11 - 02),
+        ]
+    );
+}
+
+#[test]
+fn week_numbers() {
+    // Week 1 of 1998 begins on Monday, December 29, 1997.
+    assert_eq!(
+        dates("FREQ=YEARLY;BYWEEKNO=1;BYDAY=MO", date!(1997 - 01 - 01), 3),
+        [
+            date!(1997 - 01 - 01),
+            date!(1997 - 12 - 29),
+            date!(1999 - 01 - 04),
+        ]
+    );
+    // 1998 has 53 weeks, the last of which ends on Sunday, January 3, 1999. The last week of 1999
+    // ends on Sunday, January 2, 2000.
+    assert_eq!(
+        dates("FREQ=YEARLY;BYWEEKNO=-1;BYDAY=SU", date!(1998 - 01 - 01), 3),
+        [
+            date!(1998 - 01 - 01),
+            date!(1999 - 01 - 03),
+            date!(2000 - 01 - 02),
+        ]
+    );
+}
+
+#[test]
+fn friday_the_13th() {
+    let rule = RecurrenceRule::parse("FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13").unwrap();
+    let start = datetime!(1997-09-02 9:00);
+    assert_eq!(
+        rule.occurrences(start)
+            .excluding([start])
+            .take(5)
+            .collect::<Vec<_>>(),
+        [
+            datetime!(1998-02-13 9:00),
+            datetime!(1998-03-13 9:00),
+            datetime!(1998-11-13 9:00),
+            datetime!(1999-08-13 9:00),
+            datetime!(2000-10-13 9:00),
+        ]
+    );
+}
+
+#[test]
+fn saturday_after_first_sunday() {
+    assert_eq!(
+        dates(
+            "FREQ=MONTHLY;BYDAY=SA;BYMONTHDAY=7,8,9,10,11,12,13",
+            date!(1997 - 09 - 13),
+            10
+        ),
+        [
+            date!(1997 - 09 - 13),
+            date!(1997 - 10 - 11),
+            date!(1997 - 11 - 08),
+            date!(1997 - 12 - 13),
+            date!(1998 - 01 - 10),
+            date!(1998 - 02 - 07),
+            date!(1998 - 03 - 07),
+            date!(1998 - 04 - 11),
+            date!(1998 - 05 - 09),
+            date!(1998 - 06 - 13),
+        ]
+    );
+}
+
+#[test]
+fn set_position() {
+    assert_eq!(
+        dates(
+            "FREQ=MONTHLY;COUNT=3;BYDAY=TU,WE,TH;BYSETPOS=3",
+            date!(1997 - 09 - 04),
+            100
+        ),
+        [
+            date!(1997 - 09 - 04),
+            date!(1997 - 10 - 07),
+            date!(1997 - 11 - 06),
+        ]
+    );
+    assert_eq!(
+        dates(
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-2",
+            date!(1997 - 09 - 29),
+            7
+        ),
+        [
+            date!(1997 - 09 - 29),
+            date!(1997 - 10 - 30),
+            date!(1997 - 11 - 27),
+            date!(1997 - 12 - 30),
+            date!(1998 - 01 - 29),
+            date!(1998 - 02 - 26),
+            date!(1998 - 03 - 30),
+        ]
+    );
+}
+
+#[test]
+fn sub_daily() {
+    assert_eq!(
+        datetimes(
+            "FREQ=HOURLY;INTERVAL=3;UNTIL=19970902T170000Z",
+            datetime!(1997-09-02 9:00),
+            100
+        ),
+        [
+            datetime!(1997-09-02 9:00),
+            datetime!(1997-09-02 12:00),
+            datetime!(1997-09-02 15:00),
+        ]
+    );
+    assert_eq!(
+        datetimes(
+            "FREQ=MINUTELY;INTERVAL=15;COUNT=6",
+            datetime!(1997-09-02 9:00),
+            100
+        ),
+        [
+            datetime!(1997-09-02 9:00),
+            datetime!(1997-09-02 9:15),
+            datetime!(1997-09-02 9:30),
+            datetime!(1997-09-02 9:45),
+            datetime!(1997-09-02 10:00),
+            datetime!(1997-09-02 10:15),
+        ]
+    );
+    assert_eq!(
+        datetimes(
+            "FREQ=MINUTELY;INTERVAL=90;COUNT=4",
+            datetime!(1997-09-02 9:00),
+            100
+        ),
+        [
+            datetime!(1997-09-02 9:00),
+            datetime!(1997-09-02 10:30),
+            datetime!(1997-09-02 12:00),
+            datetime!(1997-09-02 13:30),
+        ]
+    );
+
+    let expected = [
+        datetime!(1997-09-02 9:00),
+        datetime!(1997-09-02 9:20),
+        datetime!(1997-09-02 9:40),
+        datetime!(1997-09-02 10:00),
+    ];
+    let daily = datetimes(
+        "FREQ=DAILY;BYHOUR=9,10,11,12,13,14,15,16;BYMINUTE=0,20,40",
+        datetime!(1997-09-02 9:00),
+        48,
+    );
+    let minutely = datetimes(
+        "FREQ=MINUTELY;INTERVAL=20;BYHOUR=9,10,11,12,13,14,15,16",
+        datetime!(1997-09-02 9:00),
+        48,
+    );
+    assert_eq!(daily[..4], expected);
+    assert_eq!(daily[23], datetime!(1997-09-02 16:40));
+    assert_eq!(daily[24], datetime!(1997-09-03 9:00));
+    assert_eq!(daily[47], datetime!(1997-09-03 16:40));
+    assert_eq!(daily, minutely);
+
+    assert_eq!(
+        datetimes(
+            "FREQ=SECONDLY;BYMONTH=2;BYMONTHDAY=29;BYHOUR=0;BYMINUTE=0",
+            datetime!(2023-01-01 0:00),
+            3
+        ),
+        [
+            datetime!(2023-01-01 0:00),
+            datetime!(2024-02-29 0:00:00),
+            datetime!(2024-02-29 0:00:01),
+        ]
+    );
+}
+
+#[test]
+fn by_hour_and_minute() {
+    assert_eq!(
+        datetimes(
+            "FREQ=YEARLY;INTERVAL=2;BYMONTH=1;BYDAY=SU;BYHOUR=8,9;BYMINUTE=30",
+            datetime!(1997-01-05 8:30),
+            5
+        ),
+        [
+            datetime!(1997-01-05 8:30),
+            datetime!(1997-01-05 9:30),
+            datetime!(1997-01-12 8:30),
+            datetime!(1997-01-12 9:30),
+            datetime!(1997-01-19 8:30),
+        ]
+    );
+}
+
+#[test]
+fn offset_date_time() {
+    let rule = RecurrenceRule::parse("FREQ=HOURLY;INTERVAL=3;UNTIL=19970902T170000Z").unwrap();
+    assert_eq!(
+        rule.occurrences(datetime!(1997-09-02 9:00 -4))
+            .collect::<Vec<_>>(),
+        [
+            datetime!(1997-09-02 9:00 -4),
+            datetime!(1997-09-02 12:00 -4),
+        ]
+    );
+
+    let rule = RecurrenceRule::parse("FREQ=DAILY;UNTIL=19970902T170000").unwrap();
+    assert_eq!(
+        rule.occurrences(datetime!(1997-09-01 16:00 -4))
+            .collect::<Vec<_>>(),
+        [
+            datetime!(1997-09-01 16:00 -4),
+            datetime!(1997-09-02 16:00 -4),
+        ]
+    );
+
+    let rule = RecurrenceRule::parse("FREQ=DAILY;COUNT=3").unwrap();
+    assert_eq!(
+        rule.occurrences(datetime!(1997-09-02 9:00:00.5 +1))
+            .excluding([datetime!(1997-09-03 8:00:00.5 UTC)])
+            .collect::<Vec<_>>(),
+        [
+            datetime!(1997-09-02 9:00:00.5 +1),
+            datetime!(1997-09-04 9:00:00.5 +1),
+        ]
+    );
+}
+
+#[test]
+fn start_is_first_occurrence() {
+    // The start does not match the rule, but is still an occurrence.
+    assert_eq!(
+        dates(
+            "FREQ=MONTHLY;BYMONTHDAY=1;COUNT=3",
+            date!(2024 - 01 - 15),
+            100
+        ),
+        [
+            date!(2024 - 01 - 15),
+            date!(2024 - 02 - 01),
+            date!(2024 - 03 - 01),
+        ]
+    );
+    assert_eq!(
+        dates("FREQ=DAILY;UNTIL=20240101", date!(2024 - 01 - 01), 100),
+        [date!(2024 - 01 - 01)]
+    );
+    assert!(dates("FREQ=DAILY;COUNT=0", date!(2024 - 01 - 01), 100).is_empty());
+}
+
+#[test]
+fn unbounded() -> Result<()> {
+    // No date matches the rule, so iteration ends at the end of the supported range.
+    let last_year = Date::MAX.year();
+    let start = Date::from_calendar_date(last_year - 10, Month::January, 1)?.midnight();
+    let mut occurrences =
+        RecurrenceRule::parse("FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30")?.occurrences(start);
+    assert_eq!(occurrences.next(), Some(start));
+    assert_eq!(occurrences.next(), None);
+    assert_eq!(occurrences.next(), None);
+
+    let start = Date::from_calendar_date(last_year - 1, Month::June, 1)?.midnight();
+    assert_eq!(
+        datetimes("FREQ=YEARLY", start, 100),
+        [start, start.replace_year(last_year)?,]
+    );
+
+    Ok(())
+}
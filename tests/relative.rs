@@ -0,0 +1,479 @@
+use time::error::InvalidRelativeTime;
+use time::format_description::locale::{Dutch, French, German, Japanese, Spanish};
+use time::macros::datetime;
+use time::relative::{self, Config, Language, Rounding, Unit, Vocabulary};
+use time::{Duration, OffsetDateTime, Weekday};
+
+fn format(config: Config<'_>, duration: Duration) -> String {
+    config.format(duration).to_string()
+}
+
+fn unexpected(
+    result: Result<OffsetDateTime, InvalidRelativeTime>,
+) -> Option<(usize, &'static [&'static str])> {
+    match result {
+        Err(InvalidRelativeTime::Unexpected {
+            index, expected, ..
+        }) => Some((index, expected)),
+        _ => None,
+    }
+}
+
+fn out_of_range(result: Result<OffsetDateTime, InvalidRelativeTime>) -> Option<usize> {
+    match result {
+        Err(InvalidRelativeTime::OutOfRange { index, .. }) => Some(index),
+        _ => None,
+    }
+}
+
+#[test]
+fn default() {
+    let config = Config::DEFAULT;
+    assert_eq!(format(config, Duration::hours(-3)), "3 hours ago");
+    assert_eq!(format(config, Duration::days(2)), "in 2 days");
+    assert_eq!(format(config, Duration::SECOND), "in 1 second");
+    assert_eq!(format(config, Duration::ZERO), "just now");
+    assert_eq!(format(config, Duration::milliseconds(-500)), "just now");
+    assert_eq!(
+        format(config, -(Duration::HOUR + Duration::minutes(50))),
+        "1 hour ago"
+    );
+    assert_eq!(format(config, Duration::WEEK), "in 1 week");
+    assert_eq!(format(config, Duration::days(-45)), "1 month ago");
+    assert_eq!(format(config, Duration::days(800)), "in 2 years");
+    assert_eq!(format(config, Duration::MIN), "292471208677 years ago");
+}
+
+#[test]
+fn rounding() {
+    let nearest = Config::DEFAULT.set_rounding(Rounding::Nearest);
+    assert_eq!(
+        format(nearest, Duration::minutes(59) + Duration::seconds(40)),
+        "in 1 hour"
+    );
+    assert_eq!(
+        format(nearest, Duration::HOUR + Duration::minutes(30)),
+        "in 2 hours"
+    );
+    assert_eq!(format(nearest, Duration::minutes(-30)), "30 minutes ago");
+    assert_eq!(format(nearest, Duration::days(-364)), "12 months ago");
+
+    let up = Config::DEFAULT.set_rounding(Rounding::Up);
+    assert_eq!(
+        format(up, Duration::HOUR + Duration::minutes(10)),
+        "in 2 hours"
+    );
+    assert_eq!(format(up, Duration::seconds(-61)), "2 minutes ago");
+    assert_eq!(
+        format(up, Duration::days(6) + Duration::SECOND),
+        "in 1 week"
+    );
+}
+
+#[test]
+fn granularity() {
+    let minutes = Config::DEFAULT.set_smallest_unit(Unit::Minute);
+    assert_eq!(format(minutes, Duration::seconds(30)), "just now");
+    assert_eq!(format(minutes, Duration::seconds(-90)), "1 minute ago");
+    assert_eq!(
+        format(
+            minutes.set_rounding(Rounding::Nearest),
+            Duration::seconds(30)
+        ),
+        "in 1 minute"
+    );
+    assert_eq!(
+        format(minutes.set_rounding(Rounding::Up), Duration::SECOND),
+        "in 1 minute"
+    );
+
+    let days = Config::DEFAULT.set_largest_unit(Unit::Day);
+    assert_eq!(format(days, Duration::days(-400)), "400 days ago");
+    assert_eq!(
+        format(
+            days.set_rounding(Rounding::Nearest),
+            Duration::hours(23) + Duration::minutes(30)
+        ),
+        "in 1 day"
+    );
+    assert_eq!(format(days, Duration::hours(5)), "in 5 hours");
+
+    let years = Config::DEFAULT.set_smallest_unit(Unit::Year);
+    assert_eq!(format(years, Duration::days(-800)), "2 years ago");
+    assert_eq!(format(years, Duration::days(-300)), "just now");
+
+    let seconds = Config::DEFAULT.set_largest_unit(Unit::Second);
+    assert_eq!(format(seconds, Duration::HOUR), "in 3600 seconds");
+    assert_eq!(
+        format(seconds.set_smallest_unit(Unit::Minute), Duration::HOUR),
+        "in 60 minutes"
+    );
+}
+
+#[test]
+fn just_now_threshold() {
+    let config = Config::DEFAULT.set_just_now_threshold(Duration::MINUTE);
+    assert_eq!(format(config, Duration::seconds(-59)), "just now");
+    assert_eq!(format(config, Duration::seconds(59)), "just now");
+    assert_eq!(format(config, Duration::seconds(-60)), "1 minute ago");
+    assert_eq!(
+        format(
+            config.set_just_now_threshold(-Duration::MINUTE),
+            Duration::seconds(30)
+        ),
+        "just now"
+    );
+}
+
+#[test]
+fn format_between() {
+    assert_eq!(
+        Config::DEFAULT
+            .format_between(
+                datetime!(2023-01-01 9:00 UTC),
+                datetime!(2023-01-01 13:00 +01:00)
+            )
+            .to_string(),
+        "3 hours ago"
+    );
+    assert_eq!(
+        Config::DEFAULT
+            .format_between(
+                datetime!(2023-01-08 0:00 UTC),
+                datetime!(2023-01-01 0:00 UTC)
+            )
+            .to_string(),
+        "in 1 week"
+    );
+}
+
+#[test]
+fn languages() {
+    let german = Config::DEFAULT.set_language(&German);
+    assert_eq!(format(german, Duration::hours(-3)), "vor 3 Stunden");
+    assert_eq!(format(german, Duration::days(-1)), "vor 1 Tag");
+    assert_eq!(format(german, Duration::days(2)), "in 2 Tagen");
+    assert_eq!(format(german, Duration::ZERO), "gerade eben");
+
+    let config = Config::DEFAULT;
+    assert_eq!(
+        format(config.set_language(&French), Duration::days(2)),
+        "dans 2 jours"
+    );
+    assert_eq!(
+        format(config.set_language(&Spanish), Duration::days(-30)),
+        "hace 1 mes"
+    );
+    assert_eq!(
+        format(config.set_language(&Dutch), Duration::hours(2)),
+        "over 2 uur"
+    );
+    assert_eq!(
+        format(config.set_language(&Japanese), Duration::hours(-3)),
+        "3時間前"
+    );
+}
+
+#[test]
+fn custom_language() {
+    struct Terse;
+
+    impl Language for Terse {
+        fn unit(&self, unit: Unit, _: u64) -> &str {
+            match unit {
+                Unit::Second => "{}s",
+                Unit::Minute => "{}m",
+                Unit::Hour => "{}h",
+                _ => "{}d+",
+            }
+        }
+
+        fn past(&self) -> &str {
+            "-{}"
+        }
+
+        fn future(&self) -> &str {
+            "later"
+        }
+
+        fn just_now(&self) -> &str {
+            "now"
+        }
+    }
+
+    let config = Config::DEFAULT.set_language(&Terse);
+    assert_eq!(format(config, Duration::minutes(-5)), "-5m");
+    assert_eq!(format(config, Duration::weeks(-5)), "-1d+");
+    assert_eq!(format(config, Duration::minutes(5)), "later");
+    assert_eq!(format(config, Duration::ZERO), "now");
+}
+
+#[test]
+fn debug() {
+    assert_eq!(
+        format!("{:?}", Config::DEFAULT),
+        "Config { smallest_unit: Second, largest_unit: Year, rounding: Down, just_now_threshold: \
+         Duration { seconds: 0, nanoseconds: 0 }, .. }"
+    );
+    assert!(format!("{:?}", Config::DEFAULT.format(Duration::ZERO)).starts_with("Relative"));
+}
+
+#[test]
+fn parse() {
+    // A Wednesday.
+    let reference = datetime!(2023-06-14 10:30:15.5 +2);
+    let parse = |input| relative::parse(input, reference);
+
+    assert_eq!(parse("now"), Ok(reference));
+    assert_eq!(parse("  Right   Now "), Ok(reference));
+    assert_eq!(parse("today"), Ok(datetime!(2023-06-14 0:00 +2)));
+    assert_eq!(parse("tomorrow 17:00"), Ok(datetime!(2023-06-15 17:00 +2)));
+    assert_eq!(parse("tomorrow at 5pm"), Ok(datetime!(2023-06-15 17:00 +2)));
+    assert_eq!(
+        parse("yesterday 9:05:30 a.m."),
+        Ok(datetime!(2023-06-13 9:05:30 +2))
+    );
+    assert_eq!(parse("today noon"), Ok(datetime!(2023-06-14 12:00 +2)));
+    assert_eq!(parse("12am"), Ok(datetime!(2023-06-14 0:00 +2)));
+    assert_eq!(parse("12 PM"), Ok(datetime!(2023-06-14 12:00 +2)));
+    assert_eq!(parse("5:45pm tomorrow"), Ok(datetime!(2023-06-15 17:45 +2)));
+    assert_eq!(parse("midnight"), Ok(datetime!(2023-06-14 0:00 +2)));
+    assert_eq!(parse("wednesday"), Ok(datetime!(2023-06-14 0:00 +2)));
+    assert_eq!(parse("fri 8:00"), Ok(datetime!(2023-06-16 8:00 +2)));
+    assert_eq!(parse("next monday"), Ok(datetime!(2023-06-19 0:00 +2)));
+    assert_eq!(parse("next wednesday"), Ok(datetime!(2023-06-21 0:00 +2)));
+    assert_eq!(parse("last wednesday"), Ok(datetime!(2023-06-07 0:00 +2)));
+    assert_eq!(
+        parse("last tue at noon"),
+        Ok(datetime!(2023-06-13 12:00 +2))
+    );
+    assert_eq!(parse("in 2 hours"), Ok(datetime!(2023-06-14 12:30:15.5 +2)));
+    assert_eq!(parse("in an hour"), Ok(datetime!(2023-06-14 11:30:15.5 +2)));
+    assert_eq!(parse("in 90mins"), Ok(datetime!(2023-06-14 12:00:15.5 +2)));
+    assert_eq!(parse("3 days ago"), Ok(datetime!(2023-06-11 10:30:15.5 +2)));
+    assert_eq!(parse("a week ago"), Ok(datetime!(2023-06-07 10:30:15.5 +2)));
+    assert_eq!(parse("in 1 year"), Ok(datetime!(2024-06-14 10:30:15.5 +2)));
+    assert_eq!(
+        parse("18 months ago"),
+        Ok(datetime!(2021-12-14 10:30:15.5 +2))
+    );
+    assert_eq!(
+        relative::parse("in 1 month", datetime!(2024-01-31 0:00 UTC)),
+        Ok(datetime!(2024-02-29 0:00 UTC))
+    );
+    assert_eq!(parse("start of day"), Ok(datetime!(2023-06-14 0:00 +2)));
+    assert_eq!(parse("start of hour"), Ok(datetime!(2023-06-14 10:00 +2)));
+    assert_eq!(
+        parse("beginning of the week"),
+        Ok(datetime!(2023-06-12 0:00 +2))
+    );
+    assert_eq!(
+        parse("end of week"),
+        Ok(datetime!(2023-06-18 23:59:59.999_999_999 +2))
+    );
+    assert_eq!(
+        parse("END OF MONTH"),
+        Ok(datetime!(2023-06-30 23:59:59.999_999_999 +2))
+    );
+    assert_eq!(
+        parse("end of the minute"),
+        Ok(datetime!(2023-06-14 10:30:59.999_999_999 +2))
+    );
+    assert_eq!(parse("start of year"), Ok(datetime!(2023-01-01 0:00 +2)));
+    assert_eq!(
+        parse("end of year"),
+        Ok(datetime!(2023-12-31 23:59:59.999_999_999 +2))
+    );
+}
+
+#[test]
+fn parse_error() {
+    let reference = datetime!(2023-06-14 10:30 UTC);
+    let parse = |input| relative::parse(input, reference);
+
+    assert_eq!(
+        unexpected(parse("")),
+        Some((
+            0,
+            &[
+                "a relative day",
+                "a weekday",
+                "a time of day",
+                "an offset",
+                "the start or end of a unit",
+            ][..]
+        ))
+    );
+    assert_eq!(
+        unexpected(parse("nowhere")).map(|(index, _)| index),
+        Some(0)
+    );
+    assert_eq!(
+        unexpected(parse("next week")),
+        Some((5, &["a weekday"][..]))
+    );
+    assert_eq!(
+        unexpected(parse("in two days")),
+        Some((3, &["a number"][..]))
+    );
+    assert_eq!(
+        unexpected(parse("in 2 fortnights")),
+        Some((5, &["a unit"][..]))
+    );
+    assert_eq!(
+        unexpected(parse("2 days")),
+        Some((6, &["a past marker"][..]))
+    );
+    assert_eq!(
+        unexpected(parse("tomorrow at")),
+        Some((11, &["a time of day"][..]))
+    );
+    assert_eq!(
+        unexpected(parse("tomorrow 25:00")),
+        Some((9, &["a time of day", "the end of input"][..]))
+    );
+    assert_eq!(
+        unexpected(parse("5pm soon")),
+        Some((4, &["a relative day", "a weekday", "the end of input"][..]))
+    );
+    assert_eq!(
+        unexpected(parse("now please")),
+        Some((4, &["the end of input"][..]))
+    );
+    assert_eq!(unexpected(parse("end of time")), Some((7, &["a unit"][..])));
+    assert_eq!(out_of_range(parse("in 99999999999 days")), Some(3));
+    assert_eq!(out_of_range(parse("in 4294967295 years")), Some(0));
+    assert_eq!(
+        out_of_range(relative::parse(
+            "tomorrow",
+            datetime!(+999999-12-31 0:00 UTC)
+        )),
+        Some(0)
+    );
+
+    assert_eq!(
+        parse("2 days").map_err(|err| err.to_string()),
+        Err("expected a past marker at byte index 6".to_owned())
+    );
+    assert_eq!(
+        parse("5pm soon").map_err(|err| err.to_string()),
+        Err("expected a relative day, a weekday or the end of input at byte index 4".to_owned())
+    );
+    assert_eq!(
+        parse("in 4294967295 years").map_err(|err| err.to_string()),
+        Err("relative time out of range at byte index 0".to_owned())
+    );
+}
+
+#[test]
+fn parse_custom_vocabulary() {
+    struct Spanish;
+
+    impl Vocabulary for Spanish {
+        fn now(&self) -> &[&str] {
+            &["ahora"]
+        }
+
+        fn today(&self) -> &[&str] {
+            &["hoy"]
+        }
+
+        fn tomorrow(&self) -> &[&str] {
+            &["mañana"]
+        }
+
+        fn yesterday(&self) -> &[&str] {
+            &["ayer"]
+        }
+
+        fn next(&self) -> &[&str] {
+            &["el próximo"]
+        }
+
+        fn last(&self) -> &[&str] {
+            &["el pasado"]
+        }
+
+        fn future(&self) -> &[&str] {
+            &["dentro de"]
+        }
+
+        fn past(&self) -> &[&str] {
+            &[]
+        }
+
+        fn one(&self) -> &[&str] {
+            &["un", "una"]
+        }
+
+        fn start_of(&self) -> &[&str] {
+            &["inicio del"]
+        }
+
+        fn end_of(&self) -> &[&str] {
+            &["fin del"]
+        }
+
+        fn at(&self) -> &[&str] {
+            &["a las"]
+        }
+
+        fn noon(&self) -> &[&str] {
+            &["mediodía"]
+        }
+
+        fn midnight(&self) -> &[&str] {
+            &["medianoche"]
+        }
+
+        fn am(&self) -> &[&str] {
+            &[]
+        }
+
+        fn pm(&self) -> &[&str] {
+            &[]
+        }
+
+        fn unit(&self, unit: Unit) -> &[&str] {
+            match unit {
+                Unit::Hour => &["hora", "horas"],
+                Unit::Day => &["día", "días"],
+                Unit::Month => &["mes", "meses"],
+                _ => &[],
+            }
+        }
+
+        fn weekday(&self, weekday: Weekday) -> &[&str] {
+            match weekday {
+                Weekday::Monday => &["lunes"],
+                Weekday::Friday => &["viernes"],
+                _ => &[],
+            }
+        }
+    }
+
+    let reference = datetime!(2023-06-14 10:30 UTC);
+    let parse = |input| relative::parse_with(input, reference, &Spanish);
+    assert_eq!(parse("ahora"), Ok(reference));
+    assert_eq!(
+        parse("MAÑANA a las 17:00"),
+        Ok(datetime!(2023-06-15 17:00 UTC))
+    );
+    assert_eq!(
+        parse("el próximo lunes"),
+        Ok(datetime!(2023-06-19 0:00 UTC))
+    );
+    assert_eq!(
+        parse("dentro de una hora"),
+        Ok(datetime!(2023-06-14 11:30 UTC))
+    );
+    assert_eq!(
+        parse("fin del mes"),
+        Ok(datetime!(2023-06-30 23:59:59.999_999_999 UTC))
+    );
+    assert_eq!(
+        unexpected(parse("3 días")),
+        Some((7, &["a past marker"][..]))
+    );
+    assert!(relative::parse_with("tomorrow", reference, &Spanish).is_err());
+}
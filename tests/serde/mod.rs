@@ -3,6 +3,7 @@ use time::macros::{date, datetime, offset, time};
 use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
 mod error_conditions;
+mod http_date;
 mod iso8601;
 mod json;
 mod macros;
@@ -139,3 +139,40 @@ fn issue_479() -> serde_json::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn fixed_precision() -> serde_json::Result<()> {
+    #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+    struct S {
+        #[serde(with = "time::serde::rfc3339::millis")]
+        millis: OffsetDateTime,
+        #[serde(with = "time::serde::rfc3339::micros")]
+        micros: OffsetDateTime,
+        #[serde(with = "time::serde::rfc3339::millis::option")]
+        option_millis: Option<OffsetDateTime>,
+        #[serde(with = "time::serde::rfc3339::micros::option")]
+        option_micros: Option<OffsetDateTime>,
+    }
+
+    let value = S {
+        millis: datetime!(2000-01-01 00:00:00.123_456_789 UTC),
+        micros: datetime!(2000-01-01 00:00:00 -01:00),
+        option_millis: Some(datetime!(2000-01-01 00:00:00 UTC)),
+        option_micros: None,
+    };
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(
+        json,
+        "{\"millis\":\"2000-01-01T00:00:00.123Z\",\"micros\":\"2000-01-01T00:00:00.000000-01:00\",\
+         \"option_millis\":\"2000-01-01T00:00:00.000Z\",\"option_micros\":null}"
+    );
+    assert_eq!(
+        serde_json::from_str::<S>(&json)?,
+        S {
+            millis: datetime!(2000-01-01 00:00:00.123 UTC),
+            ..value
+        }
+    );
+
+    Ok(())
+}
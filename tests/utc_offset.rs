@@ -174,22 +174,13 @@ fn current_local_offset() {
     unsafe { set_soundness(Soundness::Sound) };
 }
 
-// Note: This behavior is not guaranteed and will hopefully be changed in the future.
-#[test]
-#[cfg_attr(
-    any(
-        target_os = "macos",
-        target_os = "netbsd",
-        target_os = "illumos",
-        not(target_family = "unix")
-    ),
-    ignore
-)]
-fn local_offset_error_when_multithreaded() {
+#[test]
+#[cfg_attr(any(miri, not(target_family = "unix")), ignore)]
+fn local_offset_when_multithreaded() {
     let _guard = crate::SOUNDNESS_LOCK.lock().unwrap();
 
     std::thread::spawn(|| {
-        assert!(UtcOffset::current_local_offset().is_err());
+        assert!(UtcOffset::local_offset_at(OffsetDateTime::UNIX_EPOCH).is_ok());
     })
     .join()
     .expect("failed to join thread");
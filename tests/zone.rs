@@ -0,0 +1,925 @@
+use time::error::{InvalidTimeZone, UnresolvedLocalTime};
+use time::macros::{datetime, offset};
+use time::zone::{
+    Disambiguation, LocalResult, LocalTimeType, PosixTz, TimeZone, Transition, Tzif, ZoneRules,
+    ZoneTransition,
+};
+use time::{PrimitiveDateTime, Result};
+
+/// A zoneinfo directory containing a few time zones.
+const ZONEINFO: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../tests/fixtures/zoneinfo");
+
+fn est() -> LocalTimeType {
+    LocalTimeType::new(offset!(-5), false, "EST").unwrap()
+}
+
+fn edt() -> LocalTimeType {
+    LocalTimeType::new(offset!(-4), true, "EDT").unwrap()
+}
+
+/// Read a file from the fixture zoneinfo directory.
+fn read_fixture(name: &str) -> Vec<u8> {
+    std::fs::read(format!("{ZONEINFO}/{name}")).unwrap()
+}
+
+/// New York during 2024.
+fn new_york_2024(transitions: &mut Vec<Transition>) -> ZoneRules<'_> {
+    transitions.push(Transition::new(1_710_054_000, edt())); // 2024-03-10 07:00 UTC
+    transitions.push(Transition::new(1_730_613_600, est())); // 2024-11-03 06:00 UTC
+    ZoneRules::new(est(), transitions)
+}
+
+#[test]
+fn local_time_type() -> Result<()> {
+    let ty = LocalTimeType::new(offset!(+5:30), false, "IST")?;
+    assert_eq!(ty.offset(), offset!(+5:30));
+    assert!(!ty.is_dst());
+    assert_eq!(ty.abbreviation(), "IST");
+    assert_eq!(
+        LocalTimeType::new(offset!(-3), false, "-03")?.abbreviation(),
+        "-03"
+    );
+    assert_eq!(
+        LocalTimeType::new(offset!(UTC), false, "")?.abbreviation(),
+        ""
+    );
+    assert!(LocalTimeType::new(offset!(UTC), false, "ABCDEFGHI").is_err());
+    assert!(LocalTimeType::new(offset!(UTC), false, "É").is_err());
+    Ok(())
+}
+
+#[test]
+fn local_time_type_debug() {
+    assert_eq!(
+        format!("{:?}", est()),
+        r#"LocalTimeType { offset: -05:00:00, is_dst: false, abbreviation: "EST" }"#
+    );
+}
+
+#[test]
+fn transition() {
+    let transition = Transition::new(1_710_054_000, edt());
+    assert_eq!(transition.unix_timestamp(), 1_710_054_000);
+    assert_eq!(transition.local_time_type(), edt());
+}
+
+#[test]
+fn rules_accessors() {
+    let mut transitions = Vec::new();
+    let rules = new_york_2024(&mut transitions);
+    assert_eq!(rules.initial(), est());
+    assert_eq!(rules.transitions().len(), 2);
+    assert!(ZoneRules::fixed(est()).transitions().is_empty());
+}
+
+#[test]
+fn rules_unsorted() {
+    let transitions = [Transition::new(1, edt()), Transition::new(0, est())];
+    assert_panic!(ZoneRules::new(est(), &transitions));
+}
+
+#[test]
+fn local_time_type_at() {
+    let mut transitions = Vec::new();
+    let rules = new_york_2024(&mut transitions);
+    assert_eq!(
+        rules.local_time_type_at(datetime!(2024-01-01 0:00 UTC)),
+        est()
+    );
+    assert_eq!(
+        rules.local_time_type_at(datetime!(2024-03-10 6:59:59 UTC)),
+        est()
+    );
+    assert_eq!(
+        rules.local_time_type_at(datetime!(2024-03-10 7:00 UTC)),
+        edt()
+    );
+    assert_eq!(
+        rules.local_time_type_at(datetime!(2024-11-03 5:59:59 UTC)),
+        edt()
+    );
+    assert_eq!(
+        rules.local_time_type_at(datetime!(2024-11-03 6:00 UTC)),
+        est()
+    );
+    assert_eq!(
+        ZoneRules::fixed(edt()).local_time_type_at(datetime!(2024-01-01 0:00 UTC)),
+        edt()
+    );
+}
+
+#[test]
+fn offset_at_utc() {
+    let mut transitions = Vec::new();
+    let rules = new_york_2024(&mut transitions);
+    assert_eq!(
+        rules.offset_at_utc(datetime!(2024-07-01 0:00 UTC)),
+        offset!(-4)
+    );
+    assert_eq!(
+        rules.offset_at_utc(datetime!(2024-12-01 0:00 UTC)),
+        offset!(-5)
+    );
+    assert_eq!(
+        offset!(+1).offset_at_utc(datetime!(2024-12-01 0:00 UTC)),
+        offset!(+1)
+    );
+}
+
+#[test]
+fn offset_at_local() {
+    let mut transitions = Vec::new();
+    let rules = new_york_2024(&mut transitions);
+    assert_eq!(
+        rules.offset_at_local(datetime!(2024-07-01 12:00)),
+        LocalResult::Single(offset!(-4))
+    );
+    assert_eq!(
+        rules.offset_at_local(datetime!(2024-03-10 1:59:59)),
+        LocalResult::Single(offset!(-5))
+    );
+    assert_eq!(
+        rules.offset_at_local(datetime!(2024-03-10 2:30)),
+        LocalResult::Gap(offset!(-5), offset!(-4))
+    );
+    assert_eq!(
+        rules.offset_at_local(datetime!(2024-03-10 3:00)),
+        LocalResult::Single(offset!(-4))
+    );
+    assert_eq!(
+        rules.offset_at_local(datetime!(2024-11-03 0:59:59)),
+        LocalResult::Single(offset!(-4))
+    );
+    assert_eq!(
+        rules.offset_at_local(datetime!(2024-11-03 1:30)),
+        LocalResult::Ambiguous(offset!(-4), offset!(-5))
+    );
+    assert_eq!(
+        rules.offset_at_local(datetime!(2024-11-03 2:00)),
+        LocalResult::Single(offset!(-5))
+    );
+    assert_eq!(
+        TimeZone::offset_at_local(&&rules, datetime!(2024-11-03 1:30)),
+        LocalResult::Ambiguous(offset!(-4), offset!(-5))
+    );
+    assert_eq!(
+        offset!(-3).offset_at_local(datetime!(2024-11-03 1:30)),
+        LocalResult::Single(offset!(-3))
+    );
+}
+
+#[test]
+fn assume_zone() {
+    let mut transitions = Vec::new();
+    let rules = new_york_2024(&mut transitions);
+    assert_eq!(
+        datetime!(2024-07-01 12:00).assume_zone(&rules),
+        LocalResult::Single(datetime!(2024-07-01 12:00 -4))
+    );
+    assert_eq!(
+        datetime!(2024-11-03 1:30).assume_zone(&rules),
+        LocalResult::Ambiguous(datetime!(2024-11-03 1:30 -4), datetime!(2024-11-03 1:30 -5))
+    );
+    assert_eq!(
+        datetime!(2024-03-10 2:30).assume_zone(&rules),
+        LocalResult::Gap(datetime!(2024-03-10 2:30 -5), datetime!(2024-03-10 2:30 -4))
+    );
+}
+
+#[test]
+fn to_zone() {
+    let mut transitions = Vec::new();
+    let rules = new_york_2024(&mut transitions);
+    assert_eq!(
+        datetime!(2024-07-01 12:00 UTC).to_zone(&rules),
+        datetime!(2024-07-01 8:00 -4)
+    );
+    assert_eq!(
+        datetime!(2024-12-01 12:00 UTC).to_zone(&rules),
+        datetime!(2024-12-01 7:00 -5)
+    );
+}
+
+#[test]
+fn local_result_accessors() {
+    assert_eq!(LocalResult::Single(1).single(), Some(1));
+    assert_eq!(LocalResult::Ambiguous(1, 2).single(), None);
+    assert_eq!(LocalResult::Gap(1, 2).single(), None);
+    assert!(LocalResult::Ambiguous(1, 2).is_ambiguous());
+    assert!(!LocalResult::Gap(1, 2).is_ambiguous());
+    assert!(LocalResult::Gap(1, 2).is_gap());
+    assert!(!LocalResult::Single(1).is_gap());
+    assert_eq!(
+        LocalResult::Single(1).map(|v| v + 1),
+        LocalResult::Single(2)
+    );
+    assert_eq!(
+        LocalResult::Gap(1, 2).map(|v| v + 1),
+        LocalResult::Gap(2, 3)
+    );
+}
+
+#[test]
+fn resolve_single() {
+    let single = LocalResult::Single(datetime!(2024-07-01 12:00 -4));
+    for disambiguation in [
+        Disambiguation::Earliest,
+        Disambiguation::Latest,
+        Disambiguation::ShiftForward,
+        Disambiguation::Reject,
+    ] {
+        assert_eq!(
+            single.resolve(disambiguation),
+            Ok(datetime!(2024-07-01 12:00 -4))
+        );
+    }
+}
+
+#[test]
+fn resolve_ambiguous() {
+    let ambiguous =
+        LocalResult::Ambiguous(datetime!(2024-11-03 1:30 -4), datetime!(2024-11-03 1:30 -5));
+    assert_eq!(
+        ambiguous.resolve(Disambiguation::Earliest),
+        Ok(datetime!(2024-11-03 1:30 -4))
+    );
+    assert_eq!(
+        ambiguous.resolve(Disambiguation::Latest),
+        Ok(datetime!(2024-11-03 1:30 -5))
+    );
+    assert_eq!(
+        ambiguous.resolve(Disambiguation::ShiftForward),
+        Ok(datetime!(2024-11-03 1:30 -4))
+    );
+    assert_eq!(
+        ambiguous.resolve(Disambiguation::Reject),
+        Err(UnresolvedLocalTime::Ambiguous)
+    );
+}
+
+#[test]
+fn resolve_gap() {
+    let gap = LocalResult::Gap(datetime!(2024-03-10 2:30 -5), datetime!(2024-03-10 2:30 -4));
+    assert_eq!(
+        gap.resolve(Disambiguation::Earliest),
+        Ok(datetime!(2024-03-10 1:30 -5))
+    );
+    assert_eq!(
+        gap.resolve(Disambiguation::Latest),
+        Ok(datetime!(2024-03-10 3:30 -4))
+    );
+    assert_eq!(
+        gap.resolve(Disambiguation::ShiftForward),
+        Ok(datetime!(2024-03-10 3:30 -4))
+    );
+    assert_eq!(
+        gap.resolve(Disambiguation::Reject),
+        Err(UnresolvedLocalTime::Nonexistent)
+    );
+}
+
+#[test]
+fn posix_tz_parse() -> Result<()> {
+    let new_york = PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?;
+    assert_eq!(new_york.standard(), est());
+    assert_eq!(new_york.daylight(), Some(edt()));
+    assert_eq!(PosixTz::parse("EST5EDT")?, new_york);
+    assert_eq!(
+        PosixTz::parse("EST+5EDT4,M3.2.0/2,M11.1.0/2:00:00")?,
+        new_york
+    );
+
+    let kolkata = PosixTz::parse("IST-5:30")?;
+    assert_eq!(kolkata.standard().offset(), offset!(+5:30));
+    assert_eq!(kolkata.daylight(), None);
+
+    let quoted = PosixTz::parse("<-03>3<-02>,M3.5.0/-2,M10.5.0/-1")?;
+    assert_eq!(quoted.standard().abbreviation(), "-03");
+    assert_eq!(quoted.daylight().map(|ty| ty.offset()), Some(offset!(-2)));
+
+    assert!(matches!(
+        PosixTz::parse(""),
+        Err(InvalidTimeZone::PosixTz { index: 0, .. })
+    ));
+    assert!(matches!(
+        PosixTz::parse("EST"),
+        Err(InvalidTimeZone::PosixTz { index: 3, .. })
+    ));
+    assert!(matches!(
+        PosixTz::parse("ES5"),
+        Err(InvalidTimeZone::PosixTz { index: 0, .. })
+    ));
+    assert!(matches!(
+        PosixTz::parse("EST25"),
+        Err(InvalidTimeZone::PosixTz { index: 3, .. })
+    ));
+    assert!(matches!(
+        PosixTz::parse("EST5EDT,M13.2.0,M11.1.0"),
+        Err(InvalidTimeZone::PosixTz { index: 8, .. })
+    ));
+    assert!(matches!(
+        PosixTz::parse("EST5EDT,M3.2.0"),
+        Err(InvalidTimeZone::PosixTz { index: 14, .. })
+    ));
+    assert!(matches!(
+        PosixTz::parse("EST5EDT,J0,J365"),
+        Err(InvalidTimeZone::PosixTz { index: 8, .. })
+    ));
+    assert!(matches!(
+        PosixTz::parse("EST5EDT,M3.2.0,M11.1.0/168"),
+        Err(InvalidTimeZone::PosixTz { index: 23, .. })
+    ));
+    assert!(matches!(
+        PosixTz::parse("<EST5"),
+        Err(InvalidTimeZone::PosixTz { index: 5, .. })
+    ));
+    assert_eq!(
+        PosixTz::parse("EST").unwrap_err().to_string(),
+        "invalid POSIX TZ string at byte index 3"
+    );
+    assert!(matches!(
+        PosixTz::parse("UTC0 "),
+        Err(InvalidTimeZone::PosixTz { index: 4, .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn posix_tz_local_time_type_at() -> Result<()> {
+    let new_york = PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?;
+    assert_eq!(
+        new_york.local_time_type_at(datetime!(2050-03-13 6:59:59 UTC)),
+        est()
+    );
+    assert_eq!(
+        new_york.local_time_type_at(datetime!(2050-03-13 7:00 UTC)),
+        edt()
+    );
+    assert_eq!(
+        new_york.local_time_type_at(datetime!(2050-11-06 5:59:59 UTC)),
+        edt()
+    );
+    assert_eq!(
+        new_york.local_time_type_at(datetime!(2050-11-06 6:00 UTC)),
+        est()
+    );
+
+    // Daylight saving time spans the new year in the southern hemisphere.
+    let sydney = PosixTz::parse("AEST-10AEDT,M10.1.0,M4.1.0/3")?;
+    assert_eq!(
+        sydney.offset_at_utc(datetime!(2050-01-01 0:00 UTC)),
+        offset!(+11)
+    );
+    assert_eq!(
+        sydney.offset_at_utc(datetime!(2050-04-02 15:59:59 UTC)),
+        offset!(+11)
+    );
+    assert_eq!(
+        sydney.offset_at_utc(datetime!(2050-04-02 16:00 UTC)),
+        offset!(+10)
+    );
+    assert_eq!(
+        sydney.offset_at_utc(datetime!(2050-10-01 16:00 UTC)),
+        offset!(+11)
+    );
+
+    // The last week of the month and days of the year, with and without February 29.
+    let last_week = PosixTz::parse("WET0WEST,M3.5.0/1,M10.5.0")?;
+    assert_eq!(
+        last_week.offset_at_utc(datetime!(2048-03-29 0:59:59 UTC)),
+        offset!(UTC)
+    );
+    assert_eq!(
+        last_week.offset_at_utc(datetime!(2048-03-29 1:00 UTC)),
+        offset!(+1)
+    );
+    let julian = PosixTz::parse("AAA0BBB,J60,J300")?;
+    assert_eq!(
+        julian.offset_at_utc(datetime!(2048-03-01 1:59:59 UTC)),
+        offset!(UTC)
+    );
+    assert_eq!(
+        julian.offset_at_utc(datetime!(2048-03-01 2:00 UTC)),
+        offset!(+1)
+    );
+    let zero_based = PosixTz::parse("AAA0BBB,59,300")?;
+    assert_eq!(
+        zero_based.offset_at_utc(datetime!(2048-02-29 1:59:59 UTC)),
+        offset!(UTC)
+    );
+    assert_eq!(
+        zero_based.offset_at_utc(datetime!(2048-02-29 2:00 UTC)),
+        offset!(+1)
+    );
+
+    // Daylight saving time all year.
+    let permanent = PosixTz::parse("EST5EDT,0/0,J365/25")?;
+    assert_eq!(
+        permanent.offset_at_utc(datetime!(2050-06-01 0:00 UTC)),
+        offset!(-4)
+    );
+    assert_eq!(
+        permanent.offset_at_utc(datetime!(2050-12-31 23:00 UTC)),
+        offset!(-4)
+    );
+    Ok(())
+}
+
+#[test]
+fn posix_tz_local_time_type_at_range_limits() -> Result<()> {
+    let new_york = PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?;
+    assert_eq!(
+        new_york
+            .local_time_type_at(PrimitiveDateTime::MAX.assume_offset(offset!(-5)))
+            .abbreviation(),
+        "EST"
+    );
+    assert_eq!(
+        new_york
+            .local_time_type_at(PrimitiveDateTime::MIN.assume_offset(offset!(+5)))
+            .abbreviation(),
+        "EST"
+    );
+    assert_eq!(
+        new_york
+            .local_time_type_at(PrimitiveDateTime::MAX.assume_utc())
+            .abbreviation(),
+        "EST"
+    );
+    Ok(())
+}
+
+#[test]
+fn rules_with_posix_tz() -> Result<()> {
+    let mut transitions = Vec::new();
+    let posix_tz = PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?;
+    let rules = new_york_2024(&mut transitions).with_posix_tz(posix_tz);
+    assert_eq!(rules.posix_tz(), Some(posix_tz));
+    assert_eq!(
+        rules.offset_at_utc(datetime!(2024-07-01 0:00 UTC)),
+        offset!(-4)
+    );
+    assert_eq!(
+        rules.offset_at_utc(datetime!(2025-07-01 0:00 UTC)),
+        offset!(-4)
+    );
+    assert_eq!(
+        rules.offset_at_local(datetime!(2030-11-03 1:30)),
+        LocalResult::Ambiguous(offset!(-4), offset!(-5))
+    );
+    assert_eq!(new_york_2024(&mut Vec::new()).posix_tz(), None);
+    Ok(())
+}
+
+#[test]
+fn tzif_parse() -> Result<()> {
+    let new_york = Tzif::parse(&read_fixture("America/New_York"))?;
+    assert_eq!(new_york.name(), None);
+    assert_eq!(
+        new_york
+            .local_time_type_at(datetime!(1800-01-01 0:00 UTC))
+            .abbreviation(),
+        "LMT"
+    );
+    assert_eq!(
+        new_york.offset_at_utc(datetime!(1800-01-01 0:00 UTC)),
+        offset!(-4:56:02)
+    );
+    assert_eq!(
+        new_york.local_time_type_at(datetime!(2024-03-10 6:59:59 UTC)),
+        est()
+    );
+    assert_eq!(
+        new_york.local_time_type_at(datetime!(2024-03-10 7:00 UTC)),
+        edt()
+    );
+    // After the last transition, the footer is used.
+    assert_eq!(
+        new_york.local_time_type_at(datetime!(2100-07-01 0:00 UTC)),
+        edt()
+    );
+    assert_eq!(
+        new_york.rules().posix_tz(),
+        Some(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?)
+    );
+    assert_eq!(
+        datetime!(2050-11-06 1:30).assume_zone(&new_york),
+        LocalResult::Ambiguous(datetime!(2050-11-06 1:30 -4), datetime!(2050-11-06 1:30 -5))
+    );
+
+    let kolkata = Tzif::parse(&read_fixture("Asia/Kolkata"))?;
+    assert_eq!(
+        kolkata.offset_at_utc(datetime!(2050-01-01 0:00 UTC)),
+        offset!(+5:30)
+    );
+
+    let utc = Tzif::parse(&read_fixture("UTC"))?;
+    assert!(utc.rules().transitions().is_empty());
+    assert_eq!(
+        utc.offset_at_utc(datetime!(2050-01-01 0:00 UTC)),
+        offset!(UTC)
+    );
+    Ok(())
+}
+
+#[test]
+fn tzif_parse_error() {
+    let paris = read_fixture("Europe/Paris");
+    assert!(Tzif::parse(&paris).is_ok());
+
+    assert!(matches!(
+        Tzif::parse(b""),
+        Err(InvalidTimeZone::Tzif {
+            reason: "unexpected end of data",
+            ..
+        })
+    ));
+    assert!(matches!(
+        Tzif::parse(&paris[..paris.len() - 1]),
+        Err(InvalidTimeZone::Tzif {
+            reason: "invalid footer",
+            ..
+        })
+    ));
+    assert!(matches!(
+        Tzif::parse(&paris[..1000]),
+        Err(InvalidTimeZone::Tzif {
+            reason: "unexpected end of data",
+            ..
+        })
+    ));
+
+    assert_eq!(
+        Tzif::parse(b"").unwrap_err().to_string(),
+        "invalid TZif data: unexpected end of data"
+    );
+
+    let mut bad_magic = paris.clone();
+    bad_magic[0] = b'X';
+    assert!(matches!(
+        Tzif::parse(&bad_magic),
+        Err(InvalidTimeZone::Tzif {
+            reason: "missing magic bytes",
+            ..
+        })
+    ));
+
+    let mut bad_version = paris.clone();
+    bad_version[4] = b'9';
+    assert!(matches!(
+        Tzif::parse(&bad_version),
+        Err(InvalidTimeZone::Tzif {
+            reason: "unsupported version",
+            ..
+        })
+    ));
+
+    let mut bad_footer = paris;
+    let len = bad_footer.len();
+    bad_footer[len - 2] = b'!';
+    assert!(matches!(
+        Tzif::parse(&bad_footer),
+        Err(InvalidTimeZone::Tzif {
+            reason: "invalid footer",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn tzif_from_path() {
+    let sydney = Tzif::from_path(format!("{ZONEINFO}/Australia/Sydney")).unwrap();
+    assert_eq!(
+        sydney.offset_at_utc(datetime!(2024-01-01 0:00 UTC)),
+        offset!(+11)
+    );
+    assert_eq!(
+        sydney.offset_at_utc(datetime!(2024-07-01 0:00 UTC)),
+        offset!(+10)
+    );
+
+    assert_eq!(
+        Tzif::from_path(format!("{ZONEINFO}/Mars/Olympus_Mons"))
+            .unwrap_err()
+            .kind(),
+        std::io::ErrorKind::NotFound
+    );
+    assert_eq!(
+        Tzif::from_path(format!("{ZONEINFO}/../../zone.rs"))
+            .unwrap_err()
+            .kind(),
+        std::io::ErrorKind::InvalidData
+    );
+}
+
+#[test]
+fn tzif_from_tz() {
+    let paris = Tzif::from_tz("Europe/Paris", ZONEINFO).unwrap();
+    assert_eq!(paris.name(), Some("Europe/Paris"));
+    assert_eq!(
+        paris.offset_at_utc(datetime!(2024-07-01 0:00 UTC)),
+        offset!(+2)
+    );
+    assert_eq!(
+        Tzif::from_tz(":Europe/Paris", ZONEINFO).unwrap().name(),
+        Some("Europe/Paris")
+    );
+
+    let absolute = Tzif::from_tz(&format!("{ZONEINFO}/Europe/Paris"), "/nonexistent").unwrap();
+    assert_eq!(absolute.name(), None);
+    assert_eq!(absolute.rules(), paris.rules());
+
+    let utc = Tzif::from_tz("", ZONEINFO).unwrap();
+    assert_eq!(utc.name(), Some("UTC"));
+    assert_eq!(
+        utc.offset_at_utc(datetime!(2024-07-01 0:00 UTC)),
+        offset!(UTC)
+    );
+
+    let posix = Tzif::from_tz("CET-1CEST,M3.5.0,M10.5.0/3", ZONEINFO).unwrap();
+    assert_eq!(posix.name(), None);
+    assert_eq!(
+        posix.offset_at_utc(datetime!(2024-07-01 0:00 UTC)),
+        offset!(+2)
+    );
+
+    assert_eq!(
+        Tzif::from_tz("Mars/Olympus_Mons", ZONEINFO)
+            .unwrap_err()
+            .kind(),
+        std::io::ErrorKind::NotFound
+    );
+    assert_eq!(
+        Tzif::from_tz("../zone.rs", ZONEINFO).unwrap_err().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+}
+
+#[test]
+#[cfg_attr(any(miri, not(target_family = "unix")), ignore)]
+fn tzif_local() {
+    assert!(Tzif::local().is_ok());
+}
+
+#[test]
+fn next_transition() {
+    let mut transitions = Vec::new();
+    let rules = new_york_2024(&mut transitions);
+    let spring = rules
+        .next_transition(datetime!(2024-01-01 0:00 UTC))
+        .unwrap();
+    assert_eq!(spring.datetime(), datetime!(2024-03-10 3:00 -4));
+    assert_eq!(spring.old_offset(), offset!(-5));
+    assert_eq!(spring.new_offset(), offset!(-4));
+    assert!(spring.is_dst());
+    assert_eq!(spring.abbreviation(), "EDT");
+    assert_eq!(spring.old_local_time_type(), est());
+    assert_eq!(spring.new_local_time_type(), edt());
+
+    // The search is strictly after the provided instant.
+    assert_eq!(
+        rules
+            .next_transition(spring.datetime())
+            .map(ZoneTransition::datetime),
+        Some(datetime!(2024-11-03 1:00 -5))
+    );
+    assert_eq!(rules.next_transition(datetime!(2024-11-03 6:00 UTC)), None);
+    assert_eq!(
+        ZoneRules::fixed(est()).next_transition(datetime!(2024-01-01 0:00 UTC)),
+        None
+    );
+}
+
+#[test]
+fn previous_transition() {
+    let mut transitions = Vec::new();
+    let rules = new_york_2024(&mut transitions);
+    let fall = rules
+        .previous_transition(datetime!(2025-01-01 0:00 UTC))
+        .unwrap();
+    assert_eq!(fall.datetime(), datetime!(2024-11-03 1:00 -5));
+    assert_eq!(fall.old_offset(), offset!(-4));
+    assert_eq!(fall.new_offset(), offset!(-5));
+    assert!(!fall.is_dst());
+    assert_eq!(fall.abbreviation(), "EST");
+
+    // The search is strictly before the provided instant.
+    assert_eq!(
+        rules
+            .previous_transition(fall.datetime())
+            .map(ZoneTransition::datetime),
+        Some(datetime!(2024-03-10 3:00 -4))
+    );
+    assert_eq!(
+        rules.previous_transition(datetime!(2024-03-10 7:00 UTC)),
+        None
+    );
+}
+
+#[test]
+fn transitions_skip_unchanged_local_time_type() {
+    let transitions = [
+        Transition::new(0, est()),
+        Transition::new(100, edt()),
+        Transition::new(200, edt()),
+    ];
+    let rules = ZoneRules::new(est(), &transitions);
+    assert_eq!(
+        rules
+            .next_transition(datetime!(1969-01-01 0:00 UTC))
+            .map(|transition| transition.datetime().unix_timestamp()),
+        Some(100)
+    );
+    assert_eq!(
+        rules
+            .previous_transition(datetime!(1971-01-01 0:00 UTC))
+            .map(|transition| transition.datetime().unix_timestamp()),
+        Some(100)
+    );
+}
+
+#[test]
+fn transitions_with_posix_tz() -> Result<()> {
+    let posix_tz = PosixTz::parse("AEST-10AEDT,M10.1.0,M4.1.0/3")?;
+    let sydney = ZoneRules::fixed(posix_tz.standard()).with_posix_tz(posix_tz);
+    assert_eq!(
+        sydney
+            .next_transition(datetime!(2050-01-01 0:00 UTC))
+            .map(ZoneTransition::datetime),
+        Some(datetime!(2050-04-03 2:00 +10))
+    );
+    assert_eq!(
+        sydney
+            .previous_transition(datetime!(2050-01-01 0:00 UTC))
+            .map(ZoneTransition::datetime),
+        Some(datetime!(2049-10-03 3:00 +11))
+    );
+
+    let no_dst = PosixTz::parse("IST-5:30")?;
+    let kolkata = ZoneRules::fixed(no_dst.standard()).with_posix_tz(no_dst);
+    assert_eq!(
+        kolkata.next_transition(datetime!(2050-01-01 0:00 UTC)),
+        None
+    );
+    assert_eq!(
+        kolkata.previous_transition(datetime!(2050-01-01 0:00 UTC)),
+        None
+    );
+    Ok(())
+}
+
+#[test]
+fn previous_transition_range_limits() -> Result<()> {
+    let posix_tz = PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?;
+    let rules = ZoneRules::fixed(posix_tz.standard()).with_posix_tz(posix_tz);
+    let max_year = PrimitiveDateTime::MAX.year();
+    assert_eq!(
+        rules
+            .previous_transition(PrimitiveDateTime::MAX.assume_offset(offset!(-5)))
+            .map(|transition| (transition.datetime().year(), transition.is_dst())),
+        Some((max_year, false))
+    );
+    assert_eq!(
+        rules
+            .previous_transition(PrimitiveDateTime::MAX.assume_utc())
+            .map(|transition| (transition.datetime().year(), transition.is_dst())),
+        Some((max_year, false))
+    );
+    assert_eq!(
+        rules.previous_transition(PrimitiveDateTime::MIN.assume_offset(offset!(+5))),
+        None
+    );
+    assert_eq!(
+        rules.next_transition(PrimitiveDateTime::MAX.assume_offset(offset!(-5))),
+        None
+    );
+    Ok(())
+}
+
+#[test]
+fn transitions_between() {
+    let mut transitions = Vec::new();
+    let rules = new_york_2024(&mut transitions);
+    assert_eq!(
+        rules
+            .transitions_between(
+                datetime!(2024-01-01 0:00 UTC),
+                datetime!(2025-01-01 0:00 UTC)
+            )
+            .map(ZoneTransition::datetime)
+            .collect::<Vec<_>>(),
+        [datetime!(2024-03-10 3:00 -4), datetime!(2024-11-03 1:00 -5)]
+    );
+    // The start is inclusive and the end is exclusive.
+    assert_eq!(
+        rules
+            .transitions_between(
+                datetime!(2024-03-10 7:00 UTC),
+                datetime!(2024-11-03 6:00 UTC)
+            )
+            .map(ZoneTransition::datetime)
+            .collect::<Vec<_>>(),
+        [datetime!(2024-03-10 3:00 -4)]
+    );
+    assert_eq!(
+        rules
+            .transitions_between(
+                datetime!(2025-01-01 0:00 UTC),
+                datetime!(2024-01-01 0:00 UTC)
+            )
+            .count(),
+        0
+    );
+
+    let mut iter = rules.transitions_between(
+        datetime!(2024-01-01 0:00 UTC),
+        datetime!(2024-06-01 0:00 UTC),
+    );
+    assert!(iter.next().is_some());
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn transitions_between_tzif() -> Result<()> {
+    let paris = Tzif::parse(&read_fixture("Europe/Paris"))?;
+    let transitions = paris
+        .rules()
+        .transitions_between(
+            datetime!(2035-01-01 0:00 UTC),
+            datetime!(2040-01-01 0:00 UTC),
+        )
+        .collect::<Vec<_>>();
+
+    // The table ends in 2037, after which the footer is used without repeating any transition.
+    assert_eq!(transitions.len(), 10);
+    for (idx, transition) in transitions.iter().enumerate() {
+        let (abbreviation, offset) = if idx % 2 == 0 {
+            ("CEST", offset!(+2))
+        } else {
+            ("CET", offset!(+1))
+        };
+        assert_eq!(transition.abbreviation(), abbreviation);
+        assert_eq!(transition.new_offset(), offset);
+        assert_eq!(transition.datetime().offset(), offset);
+    }
+    assert_eq!(transitions[9].datetime(), datetime!(2039-10-30 2:00 +1));
+    assert_eq!(
+        paris
+            .rules()
+            .previous_transition(datetime!(2040-01-01 0:00 UTC)),
+        Some(transitions[9])
+    );
+    assert_eq!(
+        paris
+            .rules()
+            .next_transition(datetime!(2035-01-01 0:00 UTC)),
+        Some(transitions[0])
+    );
+    Ok(())
+}
+
+#[test]
+fn time_zone_name_and_local_time_type() {
+    let paris = Tzif::from_tz("Europe/Paris", ZONEINFO).unwrap();
+    assert_eq!(TimeZone::name(&paris), Some("Europe/Paris"));
+    assert_eq!(TimeZone::name(&&paris), Some("Europe/Paris"));
+    assert_eq!(
+        paris
+            .local_time_type_at_utc(datetime!(2024-07-01 0:00 UTC))
+            .map(|local_time_type| local_time_type.offset()),
+        Some(offset!(+2))
+    );
+    assert_eq!(offset!(+2).name(), None);
+    assert_eq!(
+        offset!(+2).local_time_type_at_utc(datetime!(2024-07-01 0:00 UTC)),
+        None
+    );
+
+    let posix = PosixTz::parse("EST5EDT,M3.2.0,M11.1.0").unwrap();
+    assert_eq!(TimeZone::name(&posix), None);
+    assert_eq!(
+        posix.local_time_type_at_utc(datetime!(2024-07-01 0:00 UTC)),
+        Some(edt())
+    );
+}
+
+#[test]
+fn tzif_format_in_zone() {
+    let format = time::macros::format_description!(
+        "[year]-[month]-[day] [hour]:[minute] [zone_abbr] [zone_name]"
+    );
+    let new_york = Tzif::from_tz("America/New_York", ZONEINFO).unwrap();
+    assert_eq!(
+        datetime!(2024-07-01 12:00 UTC)
+            .format_in_zone(&format, &new_york)
+            .unwrap(),
+        "2024-07-01 08:00 EDT America/New_York"
+    );
+    assert_eq!(
+        datetime!(2024-12-01 12:00 UTC)
+            .format_in_zone(&format, &new_york)
+            .unwrap(),
+        "2024-12-01 07:00 EST America/New_York"
+    );
+}
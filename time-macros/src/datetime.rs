@@ -7,7 +7,7 @@ use crate::error::Error;
 use crate::offset::Offset;
 use crate::time::Time;
 use crate::to_tokens::ToTokenTree;
-use crate::{date, offset, time};
+use crate::{date, offset, time, zone};
 
 pub(crate) struct DateTime {
     date: Date,
@@ -18,10 +18,14 @@ pub(crate) struct DateTime {
 pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<DateTime, Error> {
     let date = date::parse(chars)?;
     let time = time::parse(chars)?;
-    let offset = match offset::parse(chars) {
-        Ok(offset) => Some(offset),
-        Err(Error::UnexpectedEndOfInput | Error::MissingComponent { name: "sign", .. }) => None,
-        Err(err) => return Err(err),
+    let offset = if zone::is_next(chars) {
+        Some(zone::parse(chars)?.offset_at(&date, &time)?)
+    } else {
+        match offset::parse(chars) {
+            Ok(offset) => Some(offset),
+            Err(Error::UnexpectedEndOfInput | Error::MissingComponent { name: "sign", .. }) => None,
+            Err(err) => return Err(err),
+        }
     };
 
     if let Some(token) = chars.peek() {
@@ -143,6 +143,7 @@ macro_rules! component_definition {
                 _component_span: Span,
             ) -> Result<Self, Error>
             {
+                #[allow(unused_mut)] // components without modifiers
                 let mut this = Self {
                     $($field: None),*
                 };
@@ -209,13 +210,29 @@ macro_rules! component_definition {
 
 component_definition! {
     pub(super) enum Component {
+        Century = "century" {
+            padding = "padding": Option<Padding> => padding,
+        },
         Day = "day" {
             padding = "padding": Option<Padding> => padding,
+            suffix = "suffix": Option<DaySuffix> => suffix,
+            case_sensitive = "case_sensitive": Option<DayCaseSensitive> => case_sensitive,
+        },
+        Days = "days" {
+            padding = "padding": Option<Padding> => padding,
+        },
+        Era = "era" {
+            repr = "repr": Option<EraRepr> => repr,
+            case_sensitive = "case_sensitive": Option<EraCaseSensitive> => case_sensitive,
         },
         Hour = "hour" {
             padding = "padding": Option<Padding> => padding,
             base = "repr": Option<HourBase> => is_12_hour_clock,
         },
+        Hours = "hours" {
+            padding = "padding": Option<Padding> => padding,
+            total = "total": Option<HoursTotal> => total,
+        },
         Ignore = "ignore" {
             #[required]
             count = "count": Option<#[from_str] NonZeroU16> => count,
@@ -223,6 +240,10 @@ component_definition! {
         Minute = "minute" {
             padding = "padding": Option<Padding> => padding,
         },
+        Minutes = "minutes" {
+            padding = "padding": Option<Padding> => padding,
+            total = "total": Option<MinutesTotal> => total,
+        },
         Month = "month" {
             padding = "padding": Option<Padding> => padding,
             repr = "repr": Option<MonthRepr> => repr,
@@ -245,9 +266,17 @@ component_definition! {
             case = "case": Option<PeriodCase> => is_uppercase,
             case_sensitive = "case_sensitive": Option<PeriodCaseSensitive> => case_sensitive,
         },
+        Quarter = "quarter" {},
         Second = "second" {
             padding = "padding": Option<Padding> => padding,
         },
+        Seconds = "seconds" {
+            padding = "padding": Option<Padding> => padding,
+            total = "total": Option<SecondsTotal> => total,
+        },
+        Sign = "sign" {
+            sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
+        },
         Subsecond = "subsecond" {
             digits = "digits": Option<SubsecondDigits> => digits,
         },
@@ -270,6 +299,8 @@ component_definition! {
             base = "base": Option<YearBase> => iso_week_based,
             sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
         },
+        ZoneAbbr = "zone_abbr" {},
+        ZoneName = "zone_name" {},
     }
 }
 
@@ -327,12 +358,48 @@ macro_rules! modifier {
 }
 
 modifier! {
+    enum DayCaseSensitive(bool) {
+        False(false) = b"false",
+        #[default]
+        True(true) = b"true",
+    }
+
+    enum DaySuffix(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
+    enum EraCaseSensitive(bool) {
+        False(false) = b"false",
+        #[default]
+        True(true) = b"true",
+    }
+
+    enum EraRepr {
+        #[default]
+        Ad = b"ad",
+        Ce = b"ce",
+    }
+
     enum HourBase(bool) {
         Twelve(true) = b"12",
         #[default]
         TwentyFour(false) = b"24",
     }
 
+    enum HoursTotal(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
+    enum MinutesTotal(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
     enum MonthCaseSensitive(bool) {
         False(false) = b"false",
         #[default]
@@ -365,6 +432,12 @@ modifier! {
         True(true) = b"true",
     }
 
+    enum SecondsTotal(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
     enum SignBehavior(bool) {
         #[default]
         Automatic(false) = b"automatic",
@@ -430,6 +503,7 @@ modifier! {
         #[default]
         Full = b"full",
         LastTwo = b"last_two",
+        Era = b"era",
     }
 }
 
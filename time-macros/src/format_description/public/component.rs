@@ -36,6 +36,9 @@ declare_component! {
     Weekday
     WeekNumber
     Year
+    Quarter
+    Century
+    Era
     Hour
     Minute
     Period
@@ -44,6 +47,13 @@ declare_component! {
     OffsetHour
     OffsetMinute
     OffsetSecond
+    ZoneName
+    ZoneAbbr
+    Sign
+    Days
+    Hours
+    Minutes
+    Seconds
     Ignore
     UnixTimestamp
 }
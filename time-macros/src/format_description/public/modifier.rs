@@ -71,6 +71,8 @@ macro_rules! to_tokens {
 to_tokens! {
     pub(crate) struct Day {
         pub(crate) padding: Padding,
+        pub(crate) suffix: bool,
+        pub(crate) case_sensitive: bool,
     }
 }
 
@@ -132,6 +134,7 @@ to_tokens! {
     pub(crate) enum YearRepr {
         Full,
         LastTwo,
+        Era,
     }
 }
 
@@ -144,6 +147,36 @@ to_tokens! {
     }
 }
 
+pub(crate) struct Quarter {}
+
+impl ToTokenTree for Quarter {
+    fn into_token_tree(self) -> TokenTree {
+        quote_group! {{
+            ::time::format_description::modifier::Quarter::default()
+        }}
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Century {
+        pub(crate) padding: Padding,
+    }
+}
+
+to_tokens! {
+    pub(crate) enum EraRepr {
+        Ad,
+        Ce,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Era {
+        pub(crate) repr: EraRepr,
+        pub(crate) case_sensitive: bool,
+    }
+}
+
 to_tokens! {
     pub(crate) struct Hour {
         pub(crate) padding: Padding,
@@ -210,6 +243,59 @@ to_tokens! {
     }
 }
 
+pub(crate) struct ZoneName {}
+
+impl ToTokenTree for ZoneName {
+    fn into_token_tree(self) -> TokenTree {
+        quote_group! {{
+            ::time::format_description::modifier::ZoneName::default()
+        }}
+    }
+}
+
+pub(crate) struct ZoneAbbr {}
+
+impl ToTokenTree for ZoneAbbr {
+    fn into_token_tree(self) -> TokenTree {
+        quote_group! {{
+            ::time::format_description::modifier::ZoneAbbr::default()
+        }}
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Sign {
+        pub(crate) sign_is_mandatory: bool,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Days {
+        pub(crate) padding: Padding,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Hours {
+        pub(crate) padding: Padding,
+        pub(crate) total: bool,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Minutes {
+        pub(crate) padding: Padding,
+        pub(crate) total: bool,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Seconds {
+        pub(crate) padding: Padding,
+        pub(crate) total: bool,
+    }
+}
+
 to_tokens! {
     pub(crate) enum Padding {
         Space,
@@ -54,6 +54,7 @@ mod offset;
 mod serde_format_description;
 mod time;
 mod to_tokens;
+mod zone;
 
 #[cfg(any(feature = "formatting", feature = "parsing"))]
 use std::iter::Peekable;
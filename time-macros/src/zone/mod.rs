@@ -0,0 +1,153 @@
+//! Time zones, resolved at compile time using the embedded time zone database.
+
+mod tzdb;
+
+use std::iter::Peekable;
+
+use proc_macro::{token_stream, Span, TokenTree};
+use time_core::convert::*;
+
+use self::tzdb::{LocalOffset, Offsets};
+use crate::date::Date;
+use crate::offset::Offset;
+use crate::time::Time;
+use crate::Error;
+
+/// Which offset to use when a local date-time occurs twice.
+#[derive(Clone, Copy)]
+enum Disambiguation {
+    /// Use the earlier of the two instants.
+    Earliest,
+    /// Use the later of the two instants.
+    Latest,
+}
+
+pub(crate) struct Zone {
+    name: String,
+    span_start: Span,
+    span_end: Span,
+    disambiguation: Option<Disambiguation>,
+}
+
+/// Whether the next token begins a time zone identifier rather than an offset.
+pub(crate) fn is_next(chars: &mut Peekable<token_stream::IntoIter>) -> bool {
+    matches!(
+        chars.peek(),
+        Some(TokenTree::Ident(ident)) if !matches!(ident.to_string().as_str(), "utc" | "UTC")
+    )
+}
+
+pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Zone, Error> {
+    let (span_start, mut name) = match chars.next() {
+        Some(TokenTree::Ident(ident)) => (ident.span(), ident.to_string()),
+        Some(tree) => return Err(Error::UnexpectedToken { tree }),
+        None => return Err(Error::UnexpectedEndOfInput),
+    };
+    let mut span_end = span_start;
+
+    // Identifiers such as `America/Port-au-Prince` and `Etc/GMT+5` are split into several tokens.
+    while let Some(TokenTree::Punct(punct)) = chars.peek() {
+        if !matches!(punct.as_char(), '/' | '-' | '+') {
+            break;
+        }
+        name.push(punct.as_char());
+        drop(chars.next());
+
+        match chars.next() {
+            Some(TokenTree::Ident(ident)) => {
+                span_end = ident.span();
+                name.push_str(&ident.to_string());
+            }
+            Some(TokenTree::Literal(literal)) => {
+                span_end = literal.span();
+                name.push_str(&literal.to_string());
+            }
+            Some(tree) => return Err(Error::UnexpectedToken { tree }),
+            None => return Err(Error::UnexpectedEndOfInput),
+        }
+    }
+
+    let disambiguation = match chars.peek() {
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "earliest" => {
+            Some(Disambiguation::Earliest)
+        }
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "latest" => {
+            Some(Disambiguation::Latest)
+        }
+        _ => None,
+    };
+    if disambiguation.is_some() {
+        drop(chars.next());
+    }
+
+    Ok(Zone {
+        name,
+        span_start,
+        span_end,
+        disambiguation,
+    })
+}
+
+impl Zone {
+    /// Obtain the offset in effect in the time zone at the provided local date-time.
+    pub(crate) fn offset_at(&self, date: &Date, time: &Time) -> Result<Offset, Error> {
+        let offsets = Offsets::new(&self.name, date.year.saturating_add(1))
+            .ok_or_else(|| self.error(format!("unknown time zone: {}", self.name)))?;
+
+        let local = (tzdb::days_from_civil(date.year, 1, 1) + i64::from(date.ordinal) - 1)
+            * Second.per(Day) as i64
+            + i64::from(time.hour) * Second.per(Hour) as i64
+            + i64::from(time.minute) * Second.per(Minute) as i64
+            + i64::from(time.second);
+
+        let seconds = match (offsets.offset_at_local(local), self.disambiguation) {
+            (LocalOffset::Single(offset), _)
+            | (LocalOffset::Ambiguous(offset, _), Some(Disambiguation::Earliest))
+            | (LocalOffset::Ambiguous(_, offset), Some(Disambiguation::Latest)) => offset,
+            (LocalOffset::Ambiguous(earlier, later), None) => {
+                return Err(self.error(format!(
+                    "local date-time is ambiguous in {}: it occurs at both {} and {}; add \
+                     `earliest` or `latest` after the time zone to choose one",
+                    self.name,
+                    display_offset(earlier),
+                    display_offset(later),
+                )));
+            }
+            (LocalOffset::Gap(before, after), _) => {
+                return Err(self.error(format!(
+                    "local date-time does not exist in {}: the offset changes from {} to {}",
+                    self.name,
+                    display_offset(before),
+                    display_offset(after),
+                )));
+            }
+        };
+
+        Ok(Offset {
+            hours: (seconds / Second.per(Hour) as i64) as _,
+            minutes: ((seconds / Second.per(Minute) as i64) % Minute.per(Hour) as i64) as _,
+            seconds: (seconds % Second.per(Minute) as i64) as _,
+        })
+    }
+
+    /// Create an error spanning the time zone identifier.
+    fn error(&self, message: String) -> Error {
+        Error::Custom {
+            message: message.into(),
+            span_start: Some(self.span_start),
+            span_end: Some(self.span_end),
+        }
+    }
+}
+
+/// Format an offset in seconds as `+hh:mm`, with seconds only if nonzero.
+fn display_offset(seconds: i64) -> String {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let seconds = seconds.unsigned_abs();
+    let (hours, minutes, seconds) = (seconds / 3_600, seconds / 60 % 60, seconds % 60);
+    if seconds == 0 {
+        format!("{sign}{hours:02}:{minutes:02}")
+    } else {
+        format!("{sign}{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
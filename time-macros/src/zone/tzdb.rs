@@ -0,0 +1,540 @@
+//! A minimal interpreter for the compact zic input format of the time zone database.
+//!
+//! The embedded `tzdata.zi` is generated by the tz project's `make tzdata.zi` and is in the public
+//! domain. Only UTC offsets are computed; abbreviations and DST flags are ignored, as the macro
+//! only needs the offset in effect at a given local date-time. The algorithm mirrors the one used
+//! by `zic` so that the results match the compiled TZif files.
+
+use std::collections::HashMap;
+
+/// The compact zic input for the time zone database.
+const TZDATA: &str = include_str!("tzdata.zi");
+
+/// The number of seconds in a day.
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Lowercase month names, used for prefix matching.
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// Lowercase weekday names, starting with Sunday, used for prefix matching.
+const WEEKDAYS: [&str; 7] = [
+    "sunday",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+];
+
+/// How a time of day is to be interpreted.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TimeKind {
+    /// Local wall clock time, including any daylight saving time.
+    Wall,
+    /// Local standard time.
+    Standard,
+    /// Universal time.
+    Universal,
+}
+
+/// The day of the month on which a rule or zone line takes effect.
+#[derive(Clone, Copy)]
+enum DaySpec {
+    /// A fixed day of the month.
+    Fixed(u8),
+    /// The last occurrence of the weekday in the month.
+    Last(u8),
+    /// The first occurrence of the weekday on or after the day.
+    OnOrAfter(u8, u8),
+    /// The last occurrence of the weekday on or before the day.
+    OnOrBefore(u8, u8),
+}
+
+/// A moment in the year, as written in a rule or in the `UNTIL` column of a zone line.
+#[derive(Clone, Copy)]
+struct YearTime {
+    /// The month, from 1 to 12.
+    month: u8,
+    /// The day of the month.
+    day: DaySpec,
+    /// Seconds after midnight, which may exceed one day.
+    seconds: i64,
+    /// How `seconds` is to be interpreted.
+    kind: TimeKind,
+}
+
+impl YearTime {
+    /// Obtain the number of seconds since the Unix epoch of the moment in the provided year, as if
+    /// the time of day were UTC.
+    fn local_seconds(self, year: i32) -> i64 {
+        let mut days = days_from_civil(year, self.month, 1);
+        match self.day {
+            DaySpec::Fixed(day) => days += i64::from(day) - 1,
+            DaySpec::Last(weekday) => {
+                days += i64::from(days_in_month(year, self.month)) - 1;
+                while weekday_of(days) != weekday {
+                    days -= 1;
+                }
+            }
+            DaySpec::OnOrAfter(weekday, day) => {
+                days += i64::from(day) - 1;
+                while weekday_of(days) != weekday {
+                    days += 1;
+                }
+            }
+            DaySpec::OnOrBefore(weekday, day) => {
+                days += i64::from(day) - 1;
+                while weekday_of(days) != weekday {
+                    days -= 1;
+                }
+            }
+        }
+        days * SECONDS_PER_DAY + self.seconds
+    }
+}
+
+/// A line of a rule set.
+struct Rule {
+    /// The first year in which the rule applies.
+    from: i32,
+    /// The last year in which the rule applies.
+    to: i32,
+    /// When in the year the rule takes effect.
+    at: YearTime,
+    /// The amount of time, in seconds, added to standard time.
+    save: i64,
+}
+
+/// The daylight saving time rules used by a zone line.
+enum LineRules<'a> {
+    /// A fixed amount of time, in seconds, added to standard time.
+    Fixed(i64),
+    /// The name of a rule set.
+    Named(&'a str),
+}
+
+/// A line of a zone, including the first.
+struct ZoneLine<'a> {
+    /// The standard offset from UTC, in seconds.
+    stdoff: i64,
+    /// The daylight saving time rules in effect.
+    rules: LineRules<'a>,
+    /// The year and moment in that year until which the line applies, if it is not the last.
+    until: Option<(i32, YearTime)>,
+}
+
+/// The UTC offsets of a time zone, as a sorted table of transitions.
+pub(super) struct Offsets {
+    /// The offset, in seconds, before the first transition.
+    initial: i64,
+    /// The instant of each transition and the offset, in seconds, that takes effect.
+    transitions: Vec<(i64, i64)>,
+}
+
+/// The result of mapping a local date-time to UTC offsets.
+pub(super) enum LocalOffset {
+    /// The local date-time has exactly one offset.
+    Single(i64),
+    /// The local date-time occurs twice. The offset of the earlier instant comes first.
+    Ambiguous(i64, i64),
+    /// The local date-time does not exist. The offsets before and after the gap are provided.
+    Gap(i64, i64),
+}
+
+impl Offsets {
+    /// Obtain the offsets of the time zone with the provided name, computing transitions through
+    /// the end of `max_year`. `None` is returned if the time zone is not known.
+    pub(super) fn new(name: &str, max_year: i32) -> Option<Self> {
+        let zone = find_zone(name)?;
+        let mut rule_sets = HashMap::new();
+        for line in &zone {
+            if let LineRules::Named(name) = line.rules {
+                rule_sets.entry(name).or_insert_with(|| find_rules(name));
+            }
+        }
+        Some(compile(&zone, &rule_sets, max_year))
+    }
+
+    /// Obtain the offset, in seconds, in effect at the provided Unix timestamp.
+    pub(super) fn offset_at(&self, timestamp: i64) -> i64 {
+        match self.transitions.partition_point(|&(at, _)| at <= timestamp) {
+            0 => self.initial,
+            idx => self.transitions[idx - 1].1,
+        }
+    }
+
+    /// Obtain the offset(s), in seconds, that the local date-time may have. The local date-time is
+    /// provided as seconds since the Unix epoch, as if it were UTC.
+    pub(super) fn offset_at_local(&self, local: i64) -> LocalOffset {
+        let before = self.offset_at(local - SECONDS_PER_DAY);
+        let after = self.offset_at(local + SECONDS_PER_DAY);
+        let is_valid = |offset| self.offset_at(local - offset) == offset;
+
+        match (is_valid(before), is_valid(after)) {
+            (true, true) if before == after => LocalOffset::Single(before),
+            // A larger offset means an earlier instant for the same local date-time.
+            (true, true) => LocalOffset::Ambiguous(before.max(after), before.min(after)),
+            (true, false) => LocalOffset::Single(before),
+            (false, true) => LocalOffset::Single(after),
+            (false, false) => LocalOffset::Gap(before, after),
+        }
+    }
+}
+
+/// Compute the transitions of a zone, following the algorithm used by `zic`.
+fn compile(zone: &[ZoneLine<'_>], rule_sets: &HashMap<&str, Vec<Rule>>, max_year: i32) -> Offsets {
+    let mut initial = None;
+    let mut transitions = Vec::new();
+    let mut start = None;
+
+    for (idx, line) in zone.iter().enumerate() {
+        let stdoff = line.stdoff;
+        let mut save = 0;
+        let mut start_offset = stdoff;
+        let mut use_start = start;
+
+        match line.rules {
+            LineRules::Fixed(fixed_save) => {
+                save = fixed_save;
+                match use_start.take() {
+                    Some(start) => transitions.push((start, stdoff + save)),
+                    None => initial = Some(stdoff + save),
+                }
+            }
+            LineRules::Named(name) => {
+                let rules = &rule_sets[name];
+                let min_year = rules.iter().map(|rule| rule.from).min().unwrap_or(max_year);
+                for year in min_year..=max_year {
+                    if matches!(line.until, Some((until_year, _)) if year > until_year) {
+                        break;
+                    }
+
+                    let mut todo = rules
+                        .iter()
+                        .map(|rule| {
+                            (rule.from..=rule.to)
+                                .contains(&year)
+                                .then(|| rule.at.local_seconds(year))
+                        })
+                        .collect::<Vec<_>>();
+
+                    loop {
+                        let until = line.until.map(|(until_year, until)| {
+                            to_universal(until.local_seconds(until_year), until.kind, stdoff, save)
+                        });
+
+                        let earliest = todo
+                            .iter()
+                            .enumerate()
+                            .filter_map(|(idx, local)| {
+                                let at = to_universal((*local)?, rules[idx].at.kind, stdoff, save);
+                                Some((at, idx))
+                            })
+                            .min();
+                        let Some((at, rule_idx)) = earliest else {
+                            break;
+                        };
+                        todo[rule_idx] = None;
+                        let rule = &rules[rule_idx];
+
+                        if matches!(until, Some(until) if at >= until) {
+                            break;
+                        }
+                        save = rule.save;
+                        if use_start == Some(at) {
+                            use_start = None;
+                        }
+                        if matches!(use_start, Some(start) if at < start) {
+                            start_offset = stdoff + save;
+                            continue;
+                        }
+                        if initial.is_none() && save == 0 {
+                            initial = Some(stdoff + save);
+                        }
+                        transitions.push((at, stdoff + save));
+                    }
+                }
+            }
+        }
+
+        if let Some(start) = use_start {
+            if initial.is_none() && start_offset == stdoff {
+                initial = Some(start_offset);
+            }
+            transitions.push((start, start_offset));
+        }
+
+        if idx + 1 < zone.len() {
+            start = line.until.map(|(until_year, until)| {
+                to_universal(until.local_seconds(until_year), until.kind, stdoff, save)
+            });
+        }
+    }
+
+    let initial = initial.unwrap_or(zone[0].stdoff);
+    transitions.sort_by_key(|&(at, _)| at);
+
+    // Like `zic`, drop a transition that is overtaken in local time by the one following it.
+    let mut optimized: Vec<(i64, i64)> = Vec::with_capacity(transitions.len());
+    for (at, offset) in transitions {
+        if let Some(&(prev_at, prev_offset)) = optimized.last() {
+            let before_prev = match optimized.len() {
+                1 => initial,
+                len => optimized[len - 2].1,
+            };
+            if at + prev_offset <= prev_at + before_prev {
+                if let Some(last) = optimized.last_mut() {
+                    last.1 = offset;
+                }
+                continue;
+            }
+        }
+        optimized.push((at, offset));
+    }
+    optimized.dedup_by(|next, prev| next.1 == prev.1);
+
+    Offsets {
+        initial,
+        transitions: optimized,
+    }
+}
+
+/// Convert a local time, expressed as seconds since the epoch as if it were UTC, to a Unix
+/// timestamp.
+const fn to_universal(seconds: i64, kind: TimeKind, stdoff: i64, save: i64) -> i64 {
+    match kind {
+        TimeKind::Wall => seconds - stdoff - save,
+        TimeKind::Standard => seconds - stdoff,
+        TimeKind::Universal => seconds,
+    }
+}
+
+/// Find the lines of the zone with the provided name, following links.
+fn find_zone(mut name: &str) -> Option<Vec<ZoneLine<'static>>> {
+    // Links may refer to other links, but never in a cycle.
+    'search: for _ in 0..4 {
+        let mut lines = TZDATA.lines();
+        while let Some(line) = lines.next() {
+            let mut fields = line.split_whitespace();
+            match (fields.next(), fields.next()) {
+                (Some("L"), Some(target)) if fields.next() == Some(name) => {
+                    name = target;
+                    continue 'search;
+                }
+                (Some("Z"), Some(zone_name)) if zone_name == name => {
+                    let mut zone = vec![parse_zone_line(fields)?];
+                    while zone.last()?.until.is_some() {
+                        zone.push(parse_zone_line(lines.next()?.split_whitespace())?);
+                    }
+                    return Some(zone);
+                }
+                _ => {}
+            }
+        }
+        return None;
+    }
+    None
+}
+
+/// Find the rules of the rule set with the provided name.
+fn find_rules(name: &str) -> Vec<Rule> {
+    TZDATA
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            if fields.next() != Some("R") || fields.next() != Some(name) {
+                return None;
+            }
+            parse_rule(fields)
+        })
+        .collect()
+}
+
+/// Parse the fields of a rule line following its name: `FROM TO - IN ON AT SAVE LETTER`.
+fn parse_rule(mut fields: impl Iterator<Item = &'static str>) -> Option<Rule> {
+    let from = fields.next()?.parse().ok()?;
+    let to = match fields.next()? {
+        to if is_prefix_of(to, "only") => from,
+        to if is_prefix_of(to, "maximum") => i32::MAX,
+        to => to.parse().ok()?,
+    };
+    let _type = fields.next()?;
+    let month = parse_month(fields.next()?)?;
+    let day = parse_day(fields.next()?)?;
+    let (seconds, kind) = parse_time_of_day(fields.next()?)?;
+    let save = parse_hms(fields.next()?.trim_end_matches(['d', 's']))?;
+
+    Some(Rule {
+        from,
+        to,
+        at: YearTime {
+            month,
+            day,
+            seconds,
+            kind,
+        },
+        save,
+    })
+}
+
+/// Parse the fields of a zone line, excluding the `Z` and name of the first line:
+/// `STDOFF RULES FORMAT [UNTIL]`.
+fn parse_zone_line<'a>(mut fields: impl Iterator<Item = &'a str>) -> Option<ZoneLine<'a>> {
+    let stdoff = parse_hms(fields.next()?)?;
+    let rules = match fields.next()? {
+        "-" => LineRules::Fixed(0),
+        rules if rules.starts_with(|c: char| c.is_ascii_digit() || c == '-') => {
+            LineRules::Fixed(parse_hms(rules)?)
+        }
+        rules => LineRules::Named(rules),
+    };
+    let _format = fields.next()?;
+
+    let until = match fields.next() {
+        None => None,
+        Some(year) => {
+            let year = year.parse().ok()?;
+            let month = fields.next().map_or(Some(1), parse_month)?;
+            let day = fields.next().map_or(Some(DaySpec::Fixed(1)), parse_day)?;
+            let (seconds, kind) = fields
+                .next()
+                .map_or(Some((0, TimeKind::Wall)), parse_time_of_day)?;
+            Some((
+                year,
+                YearTime {
+                    month,
+                    day,
+                    seconds,
+                    kind,
+                },
+            ))
+        }
+    };
+
+    Some(ZoneLine {
+        stdoff,
+        rules,
+        until,
+    })
+}
+
+/// Whether `abbreviation` is a non-empty, case-insensitive prefix of `word`.
+fn is_prefix_of(abbreviation: &str, word: &str) -> bool {
+    !abbreviation.is_empty()
+        && word
+            .get(..abbreviation.len())
+            .map_or(false, |prefix| prefix.eq_ignore_ascii_case(abbreviation))
+}
+
+/// Find the unique word that the abbreviation is a prefix of, returning its index.
+fn lookup(abbreviation: &str, words: &[&str]) -> Option<u8> {
+    let mut matches = words
+        .iter()
+        .enumerate()
+        .filter(|(_, word)| is_prefix_of(abbreviation, word));
+    match (matches.next(), matches.next()) {
+        (Some((idx, _)), None) => Some(idx as _),
+        _ => None,
+    }
+}
+
+/// Parse a month name, returning the month from 1 to 12.
+fn parse_month(month: &str) -> Option<u8> {
+    Some(lookup(month, &MONTHS)? + 1)
+}
+
+/// Parse the `ON` column of a rule or the day of an `UNTIL` column.
+fn parse_day(day: &str) -> Option<DaySpec> {
+    if let Some(weekday) = day.strip_prefix("last") {
+        return Some(DaySpec::Last(lookup(weekday, &WEEKDAYS)?));
+    }
+    if let Some((weekday, day)) = day.split_once(">=") {
+        return Some(DaySpec::OnOrAfter(
+            lookup(weekday, &WEEKDAYS)?,
+            day.parse().ok()?,
+        ));
+    }
+    if let Some((weekday, day)) = day.split_once("<=") {
+        return Some(DaySpec::OnOrBefore(
+            lookup(weekday, &WEEKDAYS)?,
+            day.parse().ok()?,
+        ));
+    }
+    Some(DaySpec::Fixed(day.parse().ok()?))
+}
+
+/// Parse a time of day with an optional suffix indicating how it is to be interpreted.
+fn parse_time_of_day(time: &str) -> Option<(i64, TimeKind)> {
+    let (time, kind) = match time.as_bytes().last()? {
+        b'w' => (&time[..time.len() - 1], TimeKind::Wall),
+        b's' => (&time[..time.len() - 1], TimeKind::Standard),
+        b'u' | b'g' | b'z' => (&time[..time.len() - 1], TimeKind::Universal),
+        _ => (time, TimeKind::Wall),
+    };
+    Some((parse_hms(time)?, kind))
+}
+
+/// Parse a duration of the form `[-]h[:m[:s]]`, returning the number of seconds. A lone `-` is
+/// zero.
+fn parse_hms(hms: &str) -> Option<i64> {
+    if hms == "-" {
+        return Some(0);
+    }
+    let (sign, hms) = match hms.strip_prefix('-') {
+        Some(hms) => (-1, hms),
+        None => (1, hms),
+    };
+    let mut seconds = 0;
+    let mut parts = hms.split(':');
+    for multiplier in [3_600, 60, 1] {
+        match parts.next() {
+            Some(part) => seconds += part.parse::<i64>().ok()? * multiplier,
+            None => break,
+        }
+    }
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(sign * seconds)
+}
+
+/// Obtain the number of days since the Unix epoch of the provided calendar date.
+pub(super) fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Obtain the number of days in the month of the provided year.
+fn days_in_month(year: i32, month: u8) -> u8 {
+    let next_month = match month {
+        12 => days_from_civil(year + 1, 1, 1),
+        _ => days_from_civil(year, month + 1, 1),
+    };
+    (next_month - days_from_civil(year, month, 1)) as _
+}
+
+/// Obtain the weekday, where Sunday is zero, of the provided number of days since the Unix epoch.
+fn weekday_of(days: i64) -> u8 {
+    // The Unix epoch was a Thursday.
+    (days + 4).rem_euclid(7) as _
+}
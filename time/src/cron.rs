@@ -0,0 +1,739 @@
+//! Cron expressions, as used by crontab and many job schedulers.
+//!
+//! A [`CronExpression`] is parsed from five or six whitespace-separated fields and yields the
+//! instants that match it. The fields are stored as bitsets and all computation is done without
+//! allocating, so cron expressions are available without the `alloc` feature.
+
+use core::iter::FusedIterator;
+use core::str::FromStr;
+
+use crate::{error, util, Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time};
+
+/// The number of years after which the Gregorian calendar repeats, including the days of the
+/// week. If no date within this many years matches an expression, no date matches at all.
+const CALENDAR_CYCLE_YEARS: i32 = 400;
+
+/// A field of a cron expression.
+#[allow(clippy::missing_docs_in_private_items)] // variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    Second,
+    Minute,
+    Hour,
+    DayOfMonth,
+    Month,
+    DayOfWeek,
+}
+
+/// The permitted values of a field of a cron expression.
+#[derive(Debug, Clone, Copy)]
+struct Field {
+    /// Which field this is.
+    kind: FieldKind,
+    /// The name of the field, used in errors.
+    name: &'static str,
+    /// The smallest permitted value.
+    min: u8,
+    /// The largest permitted value.
+    max: u8,
+    /// Names that may be used in place of numbers, beginning with `min`.
+    names: &'static [&'static [u8; 3]],
+}
+
+/// The second field, which is only present in six-field expressions.
+const SECOND: Field = Field {
+    kind: FieldKind::Second,
+    name: "second",
+    min: 0,
+    max: 59,
+    names: &[],
+};
+/// The minute field.
+const MINUTE: Field = Field {
+    kind: FieldKind::Minute,
+    name: "minute",
+    min: 0,
+    max: 59,
+    names: &[],
+};
+/// The hour field.
+const HOUR: Field = Field {
+    kind: FieldKind::Hour,
+    name: "hour",
+    min: 0,
+    max: 23,
+    names: &[],
+};
+/// The day of the month field.
+const DAY_OF_MONTH: Field = Field {
+    kind: FieldKind::DayOfMonth,
+    name: "day of month",
+    min: 1,
+    max: 31,
+    names: &[],
+};
+/// The month field.
+const MONTH: Field = Field {
+    kind: FieldKind::Month,
+    name: "month",
+    min: 1,
+    max: 12,
+    names: &[
+        b"JAN", b"FEB", b"MAR", b"APR", b"MAY", b"JUN", b"JUL", b"AUG", b"SEP", b"OCT", b"NOV",
+        b"DEC",
+    ],
+};
+/// The day of the week field. Both zero and seven are Sunday.
+const DAY_OF_WEEK: Field = Field {
+    kind: FieldKind::DayOfWeek,
+    name: "day of week",
+    min: 0,
+    max: 7,
+    names: &[b"SUN", b"MON", b"TUE", b"WED", b"THU", b"FRI", b"SAT"],
+};
+
+/// A cron expression, such as `*/15 9-17 * * MON-FRI`.
+///
+/// An expression has five fields: minute, hour, day of month, month, and day of week. A sixth
+/// field for the second may be placed before the minute; with five fields, the second is zero.
+/// Each field is a comma-separated list of values, ranges (`1-5`), and wildcards (`*`), any of
+/// which other than a single value may be followed by a step (`*/15`, `1-30/2`). Months and days
+/// of the week may be given by their three-letter English names, and Sunday is either zero or
+/// seven.
+///
+/// The following extensions are also supported:
+///
+/// - `?` is equivalent to `*` in the day of month and day of week fields.
+/// - `L` in the day of month field is the last day of the month, and `LW` is the last weekday
+///   (Monday through Friday) of the month.
+/// - `15W` in the day of month field is the weekday nearest the 15th, without leaving the month.
+/// - `5L` in the day of week field is the last Friday of the month.
+/// - `5#3` in the day of week field is the third Friday of the month.
+/// - `@yearly` (or `@annually`), `@monthly`, `@weekly`, `@daily` (or `@midnight`), and `@hourly`
+///   may be used in place of the fields.
+///
+/// As with traditional cron, when both the day of month and day of week fields are restricted,
+/// a day matches if it matches _either_ field. A field that begins with `*` or `?` is not
+/// considered restricted.
+///
+/// ```rust
+/// # use time::cron::CronExpression;
+/// # use time_macros::datetime;
+/// let cron = CronExpression::parse("30 9 * * MON-FRI")?;
+/// assert_eq!(
+///     cron.next_occurrence(datetime!(2024-01-05 10:00 UTC)),
+///     Some(datetime!(2024-01-08 9:30 UTC))
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[doc(alias = "crontab")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CronExpression {
+    /// The seconds of the minute.
+    seconds: u64,
+    /// The minutes of the hour.
+    minutes: u64,
+    /// The hours of the day.
+    hours: u64,
+    /// The days of the month.
+    days_of_month: u32,
+    /// Whether the last day of the month matches.
+    last_day_of_month: bool,
+    /// Whether the last weekday of the month matches.
+    last_weekday_of_month: bool,
+    /// The days of the month whose nearest weekday matches.
+    nearest_weekdays: u32,
+    /// The months of the year.
+    months: u16,
+    /// The days of the week, numbered from Sunday.
+    days_of_week: u8,
+    /// The days of the week, numbered from Sunday, whose last occurrence in the month matches.
+    last_days_of_week: u8,
+    /// For each day of the week numbered from Sunday, the occurrences within the month that match.
+    nth_days_of_week: [u8; 7],
+    /// Whether the day of month field begins with a wildcard.
+    day_of_month_is_wildcard: bool,
+    /// Whether the day of week field begins with a wildcard.
+    day_of_week_is_wildcard: bool,
+}
+
+impl CronExpression {
+    /// Parse a cron expression with five or six fields, or one of the `@` shorthands. Names are
+    /// case-insensitive.
+    ///
+    /// ```rust
+    /// # use time::cron::CronExpression;
+    /// assert!(CronExpression::parse("0 0 L * ?").is_ok());
+    /// assert!(CronExpression::parse("*/10 * * * * *").is_ok());
+    /// assert!(CronExpression::parse("0 24 * * *").is_err());
+    /// assert!(CronExpression::parse("0 0 * *").is_err());
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, error::InvalidCronExpression> {
+        let input = s.as_bytes();
+
+        let mut fields = [(0, 0); 6];
+        let mut count = 0;
+        let mut index = 0;
+        while index < input.len() {
+            if input[index].is_ascii_whitespace() {
+                index += 1;
+                continue;
+            }
+            let start = index;
+            while index < input.len() && !input[index].is_ascii_whitespace() {
+                index += 1;
+            }
+            if let Some(field) = fields.get_mut(count) {
+                *field = (start, index);
+            }
+            count += 1;
+        }
+
+        if count == 1 && input[fields[0].0] == b'@' {
+            let (start, end) = fields[0];
+            let name = &input[start + 1..end];
+            let expansion = [
+                (&b"yearly"[..], "0 0 1 1 *"),
+                (b"annually", "0 0 1 1 *"),
+                (b"monthly", "0 0 1 * *"),
+                (b"weekly", "0 0 * * 0"),
+                (b"daily", "0 0 * * *"),
+                (b"midnight", "0 0 * * *"),
+                (b"hourly", "0 * * * *"),
+            ]
+            .iter()
+            .find(|(expected, _)| name.eq_ignore_ascii_case(expected))
+            .map(|&(_, expansion)| expansion)
+            .ok_or(error::InvalidCronExpression::Malformed { index: start + 1 })?;
+            return Self::parse(expansion);
+        }
+
+        let fields = match count {
+            5 => [
+                None,
+                Some(fields[0]),
+                Some(fields[1]),
+                Some(fields[2]),
+                Some(fields[3]),
+                Some(fields[4]),
+            ],
+            6 => fields.map(Some),
+            _ => return Err(error::InvalidCronExpression::FieldCount { count }),
+        };
+
+        let mut expression = Self {
+            seconds: 1,
+            minutes: 0,
+            hours: 0,
+            days_of_month: 0,
+            last_day_of_month: false,
+            last_weekday_of_month: false,
+            nearest_weekdays: 0,
+            months: 0,
+            days_of_week: 0,
+            last_days_of_week: 0,
+            nth_days_of_week: [0; 7],
+            day_of_month_is_wildcard: false,
+            day_of_week_is_wildcard: false,
+        };
+
+        for (field, bounds) in [SECOND, MINUTE, HOUR, DAY_OF_MONTH, MONTH, DAY_OF_WEEK]
+            .into_iter()
+            .zip(fields)
+        {
+            let Some((start, end)) = bounds else {
+                continue;
+            };
+            let is_wildcard = matches!(input[start], b'*' | b'?');
+            let mut parser = Parser {
+                input: &input[..end],
+                index: start,
+            };
+            let values = parser.field(field, &mut expression)?;
+
+            match field.kind {
+                FieldKind::Second => expression.seconds = values,
+                FieldKind::Minute => expression.minutes = values,
+                FieldKind::Hour => expression.hours = values,
+                FieldKind::DayOfMonth => {
+                    expression.days_of_month = values as _;
+                    expression.day_of_month_is_wildcard = is_wildcard;
+                }
+                FieldKind::Month => expression.months = values as _,
+                FieldKind::DayOfWeek => {
+                    // Seven is an alias for Sunday.
+                    expression.days_of_week = (values | values >> 7) as u8 & 0x7F;
+                    expression.day_of_week_is_wildcard = is_wildcard;
+                }
+            }
+        }
+
+        Ok(expression)
+    }
+
+    /// Obtain the first instant strictly after the provided one that matches the expression, if
+    /// any. The expression is evaluated in the UTC offset of the provided value, which is also the
+    /// offset of the returned value.
+    ///
+    /// ```rust
+    /// # use time::cron::CronExpression;
+    /// # use time_macros::datetime;
+    /// let cron = CronExpression::parse("0 12 L * *")?;
+    /// assert_eq!(
+    ///     cron.next_occurrence(datetime!(2024-02-10 0:00 +1)),
+    ///     Some(datetime!(2024-02-29 12:00 +1))
+    /// );
+    /// assert_eq!(
+    ///     cron.next_occurrence(datetime!(2024-02-29 12:00 +1)),
+    ///     Some(datetime!(2024-03-31 12:00 +1))
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn next_occurrence(&self, datetime: OffsetDateTime) -> Option<OffsetDateTime> {
+        let (hour, minute, second) = datetime.time().as_hms();
+        let start = PrimitiveDateTime::new(
+            datetime.date(),
+            Time::__from_hms_nanos_unchecked(hour, minute, second, 0),
+        )
+        .checked_add(Duration::SECOND)?;
+
+        let last_year = start.year().saturating_add(CALENDAR_CYCLE_YEARS);
+        let (mut date, mut time) = if self.month_matches(start.date()) {
+            (start.date(), start.time())
+        } else {
+            (self.next_date(start.date())?, Time::MIDNIGHT)
+        };
+        while date.year() <= last_year {
+            if self.day_matches(date) {
+                if let Some(time) = self.first_time_from(time) {
+                    return Some(
+                        PrimitiveDateTime::new(date, time).assume_offset(datetime.offset()),
+                    );
+                }
+            }
+            date = self.next_date(date)?;
+            time = Time::MIDNIGHT;
+        }
+        None
+    }
+
+    /// Obtain the last instant strictly before the provided one that matches the expression, if
+    /// any. The expression is evaluated in the UTC offset of the provided value, which is also the
+    /// offset of the returned value.
+    ///
+    /// ```rust
+    /// # use time::cron::CronExpression;
+    /// # use time_macros::datetime;
+    /// let cron = CronExpression::parse("0 0 * * 1#1")?;
+    /// assert_eq!(
+    ///     cron.previous_occurrence(datetime!(2024-01-01 0:00 UTC)),
+    ///     Some(datetime!(2023-12-04 0:00 UTC))
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn previous_occurrence(&self, datetime: OffsetDateTime) -> Option<OffsetDateTime> {
+        let (hour, minute, second) = datetime.time().as_hms();
+        let truncated = PrimitiveDateTime::new(
+            datetime.date(),
+            Time::__from_hms_nanos_unchecked(hour, minute, second, 0),
+        );
+        let start = if datetime.nanosecond() == 0 {
+            truncated.checked_sub(Duration::SECOND)?
+        } else {
+            truncated
+        };
+
+        let first_year = start.year().saturating_sub(CALENDAR_CYCLE_YEARS);
+        let (mut date, mut time) = if self.month_matches(start.date()) {
+            (start.date(), start.time())
+        } else {
+            (
+                self.previous_date(start.date())?,
+                Time::__from_hms_nanos_unchecked(23, 59, 59, 0),
+            )
+        };
+        while date.year() >= first_year {
+            if self.day_matches(date) {
+                if let Some(time) = self.last_time_until(time) {
+                    return Some(
+                        PrimitiveDateTime::new(date, time).assume_offset(datetime.offset()),
+                    );
+                }
+            }
+            date = self.previous_date(date)?;
+            time = Time::__from_hms_nanos_unchecked(23, 59, 59, 0);
+        }
+        None
+    }
+
+    /// Obtain an iterator over the instants strictly after the provided one that match the
+    /// expression, in the UTC offset of the provided value.
+    ///
+    /// ```rust
+    /// # use time::cron::CronExpression;
+    /// # use time_macros::datetime;
+    /// let cron = CronExpression::parse("0 0 9 15W * *")?;
+    /// assert_eq!(
+    ///     cron.occurrences(datetime!(2024-06-01 0:00 UTC))
+    ///         .take(3)
+    ///         .collect::<Vec<_>>(),
+    ///     [
+    ///         datetime!(2024-06-14 9:00 UTC),
+    ///         datetime!(2024-07-15 9:00 UTC),
+    ///         datetime!(2024-08-15 9:00 UTC),
+    ///     ]
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn occurrences(&self, datetime: OffsetDateTime) -> Occurrences {
+        Occurrences {
+            expression: *self,
+            current: Some(datetime),
+        }
+    }
+
+    /// Whether the month of the date matches the expression.
+    const fn month_matches(&self, date: Date) -> bool {
+        self.months & (1 << date.month() as u8) != 0
+    }
+
+    /// Whether the date matches the day of month, month, and day of week fields.
+    fn day_matches(&self, date: Date) -> bool {
+        if !self.month_matches(date) {
+            return false;
+        }
+
+        let (year, month, day) = date.to_calendar_date();
+        let last_day = util::days_in_year_month(year, month);
+        let weekday = date.weekday().number_days_from_sunday();
+
+        let day_of_month_matches = self.days_of_month & (1 << day) != 0
+            || (self.last_day_of_month && day == last_day)
+            || (self.last_weekday_of_month && day == nearest_weekday(date, last_day, last_day))
+            || (1..=31).any(|target| {
+                self.nearest_weekdays & (1 << target) != 0
+                    && target <= last_day
+                    && day == nearest_weekday(date, target, last_day)
+            });
+        let day_of_week_matches = self.days_of_week & (1 << weekday) != 0
+            || (self.last_days_of_week & (1 << weekday) != 0 && day + 7 > last_day)
+            || self.nth_days_of_week[weekday as usize] & (1 << ((day - 1) / 7 + 1)) != 0;
+
+        // Traditional cron only requires both fields to match when either is a wildcard.
+        if self.day_of_month_is_wildcard || self.day_of_week_is_wildcard {
+            day_of_month_matches && day_of_week_matches
+        } else {
+            day_of_month_matches || day_of_week_matches
+        }
+    }
+
+    /// Obtain the first day after the provided one in a matching month.
+    fn next_date(&self, date: Date) -> Option<Date> {
+        let mut date = date.next_day()?;
+        while !self.month_matches(date) {
+            let (year, month) = match date.month() {
+                Month::December => (date.year() + 1, Month::January),
+                month => (date.year(), month.next()),
+            };
+            date = Date::from_calendar_date(year, month, 1).ok()?;
+        }
+        Some(date)
+    }
+
+    /// Obtain the last day before the provided one in a matching month.
+    fn previous_date(&self, date: Date) -> Option<Date> {
+        let mut date = date.previous_day()?;
+        while !self.month_matches(date) {
+            let (year, month) = match date.month() {
+                Month::January => (date.year() - 1, Month::December),
+                month => (date.year(), month.previous()),
+            };
+            date = Date::from_calendar_date(year, month, util::days_in_year_month(year, month))
+                .ok()?;
+        }
+        Some(date)
+    }
+
+    /// Obtain the first matching time of day at or after the provided one.
+    fn first_time_from(&self, time: Time) -> Option<Time> {
+        let (start_hour, start_minute, start_second) = time.as_hms();
+
+        let mut hour = first_from(self.hours, start_hour)?;
+        loop {
+            let is_start_hour = hour == start_hour;
+            let mut minute = first_from(self.minutes, if is_start_hour { start_minute } else { 0 });
+            while let Some(current) = minute {
+                let is_start_minute = is_start_hour && current == start_minute;
+                let second =
+                    first_from(self.seconds, if is_start_minute { start_second } else { 0 });
+                if let Some(second) = second {
+                    return Some(Time::__from_hms_nanos_unchecked(hour, current, second, 0));
+                }
+                minute = first_from(self.minutes, current + 1);
+            }
+            hour = first_from(self.hours, hour + 1)?;
+        }
+    }
+
+    /// Obtain the last matching time of day at or before the provided one.
+    fn last_time_until(&self, time: Time) -> Option<Time> {
+        let (end_hour, end_minute, end_second) = time.as_hms();
+
+        let mut hour = last_until(self.hours, end_hour)?;
+        loop {
+            let is_end_hour = hour == end_hour;
+            let mut minute = last_until(self.minutes, if is_end_hour { end_minute } else { 59 });
+            while let Some(current) = minute {
+                let is_end_minute = is_end_hour && current == end_minute;
+                let second = last_until(self.seconds, if is_end_minute { end_second } else { 59 });
+                if let Some(second) = second {
+                    return Some(Time::__from_hms_nanos_unchecked(hour, current, second, 0));
+                }
+                minute = current
+                    .checked_sub(1)
+                    .and_then(|end| last_until(self.minutes, end));
+            }
+            hour = hour
+                .checked_sub(1)
+                .and_then(|end| last_until(self.hours, end))?;
+        }
+    }
+}
+
+impl FromStr for CronExpression {
+    type Err = error::InvalidCronExpression;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Obtain the smallest value in the set that is at least `start`.
+const fn first_from(set: u64, start: u8) -> Option<u8> {
+    if start >= 64 {
+        return None;
+    }
+    match set & (u64::MAX << start) {
+        0 => None,
+        set => Some(set.trailing_zeros() as _),
+    }
+}
+
+/// Obtain the largest value in the set that is at most `end`.
+const fn last_until(set: u64, end: u8) -> Option<u8> {
+    let set = if end >= 63 {
+        set
+    } else {
+        set & ((1 << (end + 1)) - 1)
+    };
+    match set {
+        0 => None,
+        set => Some(63 - set.leading_zeros() as u8),
+    }
+}
+
+/// Obtain the day of the month of the weekday nearest to the target day, without leaving the
+/// month. `date` may be any date within the month.
+const fn nearest_weekday(date: Date, target: u8, last_day: u8) -> u8 {
+    let weekday = date.weekday().number_days_from_sunday() as i8;
+    // The weekday of the target day, numbered from Sunday.
+    let target_weekday = (weekday + (target as i8 - date.day() as i8) % 7 + 7) % 7;
+    match target_weekday {
+        6 if target == 1 => 3,
+        6 => target - 1,
+        0 if target == last_day => target - 2,
+        0 => target + 1,
+        _ => target,
+    }
+}
+
+/// A parser for a single field of a cron expression.
+struct Parser<'a> {
+    /// The expression, ending at the end of the field.
+    input: &'a [u8],
+    /// The index of the next byte to consume.
+    index: usize,
+}
+
+impl Parser<'_> {
+    /// Obtain the next byte without consuming it.
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.index).copied()
+    }
+
+    /// An error at the provided position.
+    const fn error(index: usize) -> error::InvalidCronExpression {
+        error::InvalidCronExpression::Malformed { index }
+    }
+
+    /// An out of range error for the field at the provided position.
+    const fn out_of_range(field: Field, index: usize) -> error::InvalidCronExpression {
+        error::InvalidCronExpression::OutOfRange {
+            field: field.name,
+            index,
+        }
+    }
+
+    /// Consume the entire field, returning the set of values it contains. Extensions are stored
+    /// directly in the expression.
+    fn field(
+        &mut self,
+        field: Field,
+        expression: &mut CronExpression,
+    ) -> Result<u64, error::InvalidCronExpression> {
+        let mut values = self.item(field, expression)?;
+        while self.peek() == Some(b',') {
+            self.index += 1;
+            values |= self.item(field, expression)?;
+        }
+        if self.index != self.input.len() {
+            return Err(Self::error(self.index));
+        }
+        Ok(values)
+    }
+
+    /// Consume a single item of a list, returning the set of values it contains.
+    fn item(
+        &mut self,
+        field: Field,
+        expression: &mut CronExpression,
+    ) -> Result<u64, error::InvalidCronExpression> {
+        let is_day = matches!(field.kind, FieldKind::DayOfMonth | FieldKind::DayOfWeek);
+
+        let (start, end, is_single) = match self.peek() {
+            Some(b'*') => {
+                self.index += 1;
+                (field.min, field.max, false)
+            }
+            Some(b'?') if is_day => {
+                self.index += 1;
+                return Ok(range(field.min, field.max, 1));
+            }
+            Some(b'L' | b'l') if field.kind == FieldKind::DayOfMonth => {
+                self.index += 1;
+                if matches!(self.peek(), Some(b'W' | b'w')) {
+                    self.index += 1;
+                    expression.last_weekday_of_month = true;
+                } else {
+                    expression.last_day_of_month = true;
+                }
+                return Ok(0);
+            }
+            _ => {
+                let start = self.value(field)?;
+                match (field.kind, self.peek()) {
+                    (FieldKind::DayOfMonth, Some(b'W' | b'w')) => {
+                        self.index += 1;
+                        expression.nearest_weekdays |= 1 << start;
+                        return Ok(0);
+                    }
+                    (FieldKind::DayOfWeek, Some(b'L' | b'l')) => {
+                        self.index += 1;
+                        expression.last_days_of_week |= 1 << (start % 7);
+                        return Ok(0);
+                    }
+                    (FieldKind::DayOfWeek, Some(b'#')) => {
+                        self.index += 1;
+                        let index = self.index;
+                        match self.number()? {
+                            nth @ 1..=5 => {
+                                expression.nth_days_of_week[(start % 7) as usize] |= 1 << nth;
+                                return Ok(0);
+                            }
+                            _ => return Err(Self::out_of_range(field, index)),
+                        }
+                    }
+                    (_, Some(b'-')) => {
+                        self.index += 1;
+                        let index = self.index;
+                        let end = self.value(field)?;
+                        if end < start {
+                            return Err(Self::out_of_range(field, index));
+                        }
+                        (start, end, false)
+                    }
+                    _ => (start, start, true),
+                }
+            }
+        };
+
+        if self.peek() != Some(b'/') {
+            return Ok(range(start, end, 1));
+        }
+        self.index += 1;
+        let index = self.index;
+        let step = self.number()?;
+        if step == 0 {
+            return Err(Self::out_of_range(field, index));
+        }
+        // A single value with a step continues to the end of the field's range.
+        let end = if is_single { field.max } else { end };
+        Ok(range(start, end, step.min(64) as _))
+    }
+
+    /// Consume a number or name that is within the range of the field.
+    fn value(&mut self, field: Field) -> Result<u8, error::InvalidCronExpression> {
+        let start = self.index;
+        if matches!(self.peek(), Some(byte) if byte.is_ascii_alphabetic()) {
+            let name = self
+                .input
+                .get(start..start + 3)
+                .ok_or_else(|| Self::error(start))?;
+            let position = field
+                .names
+                .iter()
+                .position(|expected| name.eq_ignore_ascii_case(*expected))
+                .ok_or_else(|| Self::error(start))?;
+            self.index += 3;
+            return Ok(field.min + position as u8);
+        }
+
+        match self.number()? {
+            value if (field.min as u32..=field.max as u32).contains(&value) => Ok(value as _),
+            _ => Err(Self::out_of_range(field, start)),
+        }
+    }
+
+    /// Consume a non-negative number. Values too large to represent are saturated, as they are
+    /// out of range for every field.
+    fn number(&mut self) -> Result<u32, error::InvalidCronExpression> {
+        let start = self.index;
+        let mut value: u32 = 0;
+        while let Some(digit @ b'0'..=b'9') = self.peek() {
+            value = value.saturating_mul(10).saturating_add((digit - b'0') as _);
+            self.index += 1;
+        }
+        if self.index == start {
+            return Err(Self::error(start));
+        }
+        Ok(value)
+    }
+}
+
+/// The set of values from `start` to `end`, inclusive, separated by `step`.
+fn range(start: u8, end: u8, step: u8) -> u64 {
+    (start..=end)
+        .step_by(step as _)
+        .fold(0, |set, value| set | 1 << value)
+}
+
+/// An iterator over the instants matching a [`CronExpression`], created by
+/// [`CronExpression::occurrences`].
+#[derive(Debug, Clone, Copy)]
+pub struct Occurrences {
+    /// The expression being matched.
+    expression: CronExpression,
+    /// The most recent occurrence, or the starting instant if there is none.
+    current: Option<OffsetDateTime>,
+}
+
+impl Iterator for Occurrences {
+    type Item = OffsetDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current = self.expression.next_occurrence(self.current?);
+        self.current
+    }
+}
+
+impl FusedIterator for Occurrences {}
@@ -10,9 +10,9 @@ use crate::convert::*;
 #[cfg(feature = "formatting")]
 use crate::formatting::Formattable;
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{remaining_str, Parsable};
 use crate::util::{days_in_year, days_in_year_month, is_leap_year, weeks_in_year};
-use crate::{error, Duration, Month, PrimitiveDateTime, Time, Weekday};
+use crate::{error, Duration, Era, Month, PrimitiveDateTime, Time, Weekday};
 
 /// The minimum valid year.
 pub(crate) const MIN_YEAR: i32 = if cfg!(feature = "large-dates") {
@@ -240,6 +240,43 @@ impl Date {
         self.value >> 9
     }
 
+    /// Get the era of the date in the proleptic Gregorian calendar.
+    ///
+    /// ```rust
+    /// # use time::Era;
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2019 - 01 - 01).era(), Era::Ce);
+    /// assert_eq!(date!(0001 - 01 - 01).era(), Era::Ce);
+    /// assert_eq!(date!(0000 - 12 - 31).era(), Era::Bce);
+    /// ```
+    pub const fn era(self) -> Era {
+        if self.year() > 0 {
+            Era::Ce
+        } else {
+            Era::Bce
+        }
+    }
+
+    /// Get the year of the date within its [era](Self::era).
+    ///
+    /// Years are counted from 1 in both eras, so the astronomical year 0 is 1 BCE.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2019 - 01 - 01).year_of_era(), 2019);
+    /// assert_eq!(date!(0001 - 01 - 01).year_of_era(), 1);
+    /// assert_eq!(date!(0000 - 01 - 01).year_of_era(), 1);
+    /// assert_eq!(date!(-0043 - 03 - 15).year_of_era(), 44);
+    /// ```
+    pub const fn year_of_era(self) -> u32 {
+        let year = self.year();
+        if year > 0 {
+            year as _
+        } else {
+            (1 - year) as _
+        }
+    }
+
     /// Get the month.
     ///
     /// ```rust
@@ -265,6 +302,21 @@ impl Date {
         self.month_day().1
     }
 
+    /// Get the quarter of the year.
+    ///
+    /// The returned value will always be in the range `1..=4`.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2019 - 01 - 01).quarter(), 1);
+    /// assert_eq!(date!(2019 - 06 - 30).quarter(), 2);
+    /// assert_eq!(date!(2019 - 07 - 01).quarter(), 3);
+    /// assert_eq!(date!(2019 - 12 - 31).quarter(), 4);
+    /// ```
+    pub const fn quarter(self) -> u8 {
+        (self.month() as u8 - 1) / 3 + 1
+    }
+
     /// Get the month and day. This is more efficient than fetching the components individually.
     // For whatever reason, rustc has difficulty optimizing this function. It's significantly faster
     // to write the statements out by hand.
@@ -933,7 +985,7 @@ impl Date {
         output: &mut impl io::Write,
         format: &(impl Formattable + ?Sized),
     ) -> Result<usize, error::Format> {
-        format.format_into(output, Some(self), None, None)
+        format.format_into(output, Some(self), None, None, None, None)
     }
 
     /// Format the `Date` using the provided [format description](crate::format_description).
@@ -946,7 +998,7 @@ impl Date {
     /// # Ok::<_, time::Error>(())
     /// ```
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
-        format.format(Some(self), None, None)
+        format.format(Some(self), None, None, None, None)
     }
 }
 
@@ -966,8 +1018,49 @@ impl Date {
         input: &str,
         description: &(impl Parsable + ?Sized),
     ) -> Result<Self, error::Parse> {
+        Self::parse_located(input, description).map_err(error::Located::into_inner)
+    }
+
+    /// Parse a `Date` from the input using the provided [format
+    /// description](crate::format_description). Unlike [`Date::parse`], the error records the
+    /// location in the input at which parsing failed.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::format_description;
+    /// let format = format_description!("[year]-[month]-[day]");
+    /// let err = Date::parse_located("2020-Jan-02", &format).unwrap_err();
+    /// assert_eq!(err.offset(), Some(5));
+    /// ```
+    pub fn parse_located(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<Self, error::Located<error::Parse>> {
         description.parse_date(input.as_bytes())
     }
+
+    /// Parse a `Date` from the start of the input using the provided [format
+    /// description](crate::format_description). Rather than any trailing characters being an
+    /// error, they are returned alongside the parsed value. If parsing fails, the error records the
+    /// location in the input at which it did so.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::{date, format_description};
+    /// let format = format_description!("[year]-[month]-[day]");
+    /// assert_eq!(
+    ///     Date::parse_prefix("2020-01-02,42", &format)?,
+    ///     (date!(2020 - 01 - 02), ",42")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a str), error::Located<error::Parse>> {
+        let (parsed, remaining) = description.parse_prefix(input.as_bytes())?;
+        Ok((parsed.try_into()?, remaining_str(input, remaining)?))
+    }
 }
 
 impl fmt::Display for Date {
@@ -21,8 +21,8 @@ use crate::date::{MAX_YEAR, MIN_YEAR};
 #[cfg(feature = "formatting")]
 use crate::formatting::Formattable;
 #[cfg(feature = "parsing")]
-use crate::parsing::{Parsable, Parsed};
-use crate::{error, util, Date, Duration, Month, Time, UtcOffset, Weekday};
+use crate::parsing::{remaining_str, Parsable, Parsed};
+use crate::{error, util, Date, Duration, Era, Month, Time, UtcOffset, Weekday};
 
 #[allow(missing_debug_implementations, missing_copy_implementations)]
 pub(crate) mod offset_kind {
@@ -371,6 +371,14 @@ impl<O: MaybeOffset> DateTime<O> {
         self.date.year()
     }
 
+    pub const fn era(self) -> Era {
+        self.date.era()
+    }
+
+    pub const fn year_of_era(self) -> u32 {
+        self.date.year_of_era()
+    }
+
     pub const fn month(self) -> Month {
         self.date.month()
     }
@@ -379,6 +387,10 @@ impl<O: MaybeOffset> DateTime<O> {
         self.date.day()
     }
 
+    pub const fn quarter(self) -> u8 {
+        self.date.quarter()
+    }
+
     pub const fn ordinal(self) -> u16 {
         self.date.ordinal()
     }
@@ -796,6 +808,8 @@ impl<O: MaybeOffset> DateTime<O> {
             Some(self.date),
             Some(self.time),
             maybe_offset_as_offset_opt::<O>(self.offset),
+            None,
+            None,
         )
     }
 
@@ -805,6 +819,8 @@ impl<O: MaybeOffset> DateTime<O> {
             Some(self.date),
             Some(self.time),
             maybe_offset_as_offset_opt::<O>(self.offset),
+            None,
+            None,
         )
     }
 
@@ -813,9 +829,26 @@ impl<O: MaybeOffset> DateTime<O> {
         input: &str,
         description: &(impl Parsable + ?Sized),
     ) -> Result<Self, error::Parse> {
+        Self::parse_located(input, description).map_err(error::Located::into_inner)
+    }
+
+    #[cfg(feature = "parsing")]
+    pub fn parse_located(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<Self, error::Located<error::Parse>> {
         description.parse_date_time(input.as_bytes())
     }
 
+    #[cfg(feature = "parsing")]
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a str), error::Located<error::Parse>> {
+        let (parsed, remaining) = description.parse_prefix(input.as_bytes())?;
+        Ok((parsed.try_into()?, remaining_str(input, remaining)?))
+    }
+
     /// A helper method to check if the `OffsetDateTime` is a valid representation of a leap second.
     /// Leap seconds, when parsed, are represented as the preceding nanosecond. However, leap
     /// seconds can only occur as the last second of a month UTC.
@@ -4,10 +4,17 @@ use core::cmp::Ordering;
 use core::fmt;
 use core::iter::Sum;
 use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use core::str::FromStr;
 use core::time::Duration as StdDuration;
+#[cfg(feature = "formatting")]
+use std::io;
 
 use crate::convert::*;
 use crate::error;
+#[cfg(feature = "formatting")]
+use crate::formatting::Formattable;
+#[cfg(feature = "parsing")]
+use crate::parsing::{remaining_str, Parsable};
 #[cfg(feature = "std")]
 use crate::Instant;
 
@@ -1055,6 +1062,120 @@ impl Duration {
     }
 }
 
+// region: formatting & parsing
+#[cfg(feature = "formatting")]
+impl Duration {
+    /// Format the `Duration` using the provided [format description](crate::format_description).
+    ///
+    /// Only the `[sign]`, `[days]`, `[hours]`, `[minutes]`, `[seconds]`, and `[subsecond]`
+    /// components may be used. The numerical components are formatted using the magnitude of the
+    /// duration. If `[sign]` is not present, a negative duration is preceded by `-`.
+    pub fn format_into(
+        self,
+        output: &mut impl io::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        let mut bytes = 0;
+        if self.is_negative() && !format.writes_duration_sign() {
+            bytes += crate::formatting::write(output, b"-")?;
+        }
+        Ok(bytes + format.format_duration_into(output, self)?)
+    }
+
+    /// Format the `Duration` using the provided [format description](crate::format_description).
+    ///
+    /// Only the `[sign]`, `[days]`, `[hours]`, `[minutes]`, `[seconds]`, and `[subsecond]`
+    /// components may be used. The numerical components are formatted using the magnitude of the
+    /// duration. If `[sign]` is not present, a negative duration is preceded by `-`.
+    ///
+    /// ```rust
+    /// # use time::{format_description, Duration};
+    /// let format = format_description::parse(
+    ///     "[sign][hours total:true]:[minutes]:[seconds].[subsecond digits:3]",
+    /// )?;
+    /// assert_eq!(
+    ///     Duration::milliseconds(-93_784_005).format(&format)?,
+    ///     "-26:03:04.005"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
+        let mut buf = Vec::new();
+        self.format_into(&mut buf, format)?;
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl Duration {
+    /// Parse a `Duration` from the input using the provided [format
+    /// description](crate::format_description).
+    ///
+    /// The values of the `[days]`, `[hours]`, `[minutes]`, `[seconds]`, and `[subsecond]`
+    /// components are summed, so the total of one unit may be combined with the remainder of a
+    /// smaller unit. The duration is negative if `[sign]` parses a `-` or, if `[sign]` is not
+    /// present, if the input begins with `-`.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// # use time_macros::format_description;
+    /// let format = format_description!("[days]d [hours]:[minutes]");
+    /// assert_eq!(
+    ///     Duration::parse("02d 04:30", &format)?,
+    ///     Duration::minutes(3_150)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<Self, error::Parse> {
+        Self::parse_located(input, description).map_err(error::Located::into_inner)
+    }
+
+    /// Parse a `Duration` from the input using the provided [format
+    /// description](crate::format_description). Unlike [`Duration::parse`], the error records the
+    /// location in the input at which parsing failed.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// # use time_macros::format_description;
+    /// let format = format_description!("[days]d [hours]:[minutes]");
+    /// let err = Duration::parse_located("02d 04-30", &format).unwrap_err();
+    /// assert_eq!(err.offset(), Some(6));
+    /// ```
+    pub fn parse_located(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<Self, error::Located<error::Parse>> {
+        description.parse_duration(input.as_bytes())
+    }
+
+    /// Parse a `Duration` from the start of the input using the provided [format
+    /// description](crate::format_description). Rather than any trailing characters being an
+    /// error, they are returned alongside the parsed value. If parsing fails, the error records the
+    /// location in the input at which it did so.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// # use time_macros::format_description;
+    /// let format = format_description!("[hours]:[minutes]");
+    /// assert_eq!(
+    ///     Duration::parse_prefix("04:30 elapsed", &format)?,
+    ///     (Duration::minutes(270), " elapsed")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a str), error::Located<error::Parse>> {
+        let (parsed, remaining) = description.parse_duration_prefix(input.as_bytes())?;
+        Ok((parsed.try_into()?, remaining_str(input, remaining)?))
+    }
+}
+// endregion formatting & parsing
+
 // region: trait impls
 /// The format returned by this implementation is not stable and must not be relied upon.
 ///
@@ -1147,6 +1268,169 @@ impl fmt::Display for Duration {
     }
 }
 
+/// Parses a duration written as a sequence of numbers, each followed by a unit, such as `1h30m`,
+/// `2 days 4 hours`, or `1.5s`. The output of the [`Display`](fmt::Display) implementation is
+/// accepted.
+///
+/// - The duration may begin with `-` or `+`, which applies to the duration as a whole.
+/// - Numbers may have a fractional part, such as `1.5h` or `.5h`. Any precision beyond one
+///   nanosecond is truncated.
+/// - Units are not case sensitive. The recognized units are `ns` (`nsec`, `nanosecond`), `µs`
+///   (`us`, `usec`, `microsecond`), `ms` (`msec`, `millisecond`), `s` (`sec`, `second`), `m`
+///   (`min`, `minute`), `h` (`hr`, `hour`), `d` (`day`), and `w` (`wk`, `week`). Long forms may be
+///   plural, as may `sec`, `min`, `hr`, and `wk`. A day is exactly 24 hours.
+/// - Whitespace is permitted between a number and its unit. Items may be separated by whitespace or
+///   a comma, but leading and trailing whitespace is not permitted.
+/// - Units may appear in any order and more than once. The values are summed.
+///
+/// ```rust
+/// # use time::Duration;
+/// assert_eq!("1h30m".parse(), Ok(Duration::minutes(90)));
+/// assert_eq!("2 days, 4 hours".parse(), Ok(Duration::hours(52)));
+/// assert_eq!("-1.5s".parse(), Ok(Duration::milliseconds(-1_500)));
+/// assert_eq!(
+///     Duration::new(93_784, 5).to_string().parse(),
+///     Ok(Duration::new(93_784, 5))
+/// );
+/// ```
+impl FromStr for Duration {
+    type Err = error::InvalidDuration;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        let (is_negative, mut index) = match bytes.first() {
+            Some(b'-') => (true, 1),
+            Some(b'+') => (false, 1),
+            _ => (false, 0),
+        };
+        // The magnitude of `Duration::MIN` is one second larger than that of `Duration::MAX`.
+        let max_nanoseconds = if is_negative {
+            Self::MIN.whole_nanoseconds().unsigned_abs()
+        } else {
+            Self::MAX.whole_nanoseconds().unsigned_abs()
+        };
+        let mut magnitude: u128 = 0;
+
+        loop {
+            let item_start = index;
+            let integer = take_digits(bytes, &mut index);
+            let fraction = if bytes.get(index) == Some(&b'.') {
+                index += 1;
+                take_digits(bytes, &mut index)
+            } else {
+                &[]
+            };
+            if integer.is_empty() && fraction.is_empty() {
+                return Err(error::InvalidDuration::ExpectedNumber { index: item_start });
+            }
+
+            while bytes.get(index).map_or(false, u8::is_ascii_whitespace) {
+                index += 1;
+            }
+            let unit_start = index;
+            index += s[index..]
+                .chars()
+                .take_while(|c| c.is_alphabetic())
+                .map(char::len_utf8)
+                .sum::<usize>();
+            let unit = unit_nanoseconds(&s[unit_start..index])
+                .ok_or(error::InvalidDuration::InvalidUnit { index: unit_start })?;
+
+            magnitude = item_nanoseconds(integer, fraction, unit)
+                .and_then(|value| magnitude.checked_add(value))
+                .filter(|&magnitude| magnitude <= max_nanoseconds)
+                .ok_or(error::InvalidDuration::OutOfRange { index: item_start })?;
+
+            if index == bytes.len() {
+                break;
+            }
+            let separator_start = index;
+            if bytes[index] == b',' {
+                index += 1;
+            }
+            while bytes.get(index).map_or(false, u8::is_ascii_whitespace) {
+                index += 1;
+            }
+            if index == bytes.len() && index != separator_start {
+                return Err(error::InvalidDuration::ExpectedNumber { index });
+            }
+        }
+
+        let nanoseconds = if is_negative {
+            -(magnitude as i128)
+        } else {
+            magnitude as i128
+        };
+        Ok(Self::new_unchecked(
+            (nanoseconds / Nanosecond.per(Second) as i128) as _,
+            (nanoseconds % Nanosecond.per(Second) as i128) as _,
+        ))
+    }
+}
+
+/// Consume a run of ASCII digits starting at `index`, returning the digits.
+fn take_digits<'a>(bytes: &'a [u8], index: &mut usize) -> &'a [u8] {
+    let start = *index;
+    while bytes.get(*index).map_or(false, u8::is_ascii_digit) {
+        *index += 1;
+    }
+    &bytes[start..*index]
+}
+
+/// The length of the named unit in nanoseconds.
+fn unit_nanoseconds(name: &str) -> Option<u128> {
+    /// The names of each unit, along with its length.
+    const UNITS: [(&[&str], u64); 8] = [
+        (&["ns", "nsec", "nanosecond", "nanoseconds"], 1),
+        (
+            &["µs", "μs", "us", "usec", "microsecond", "microseconds"],
+            Nanosecond.per(Microsecond) as _,
+        ),
+        (
+            &["ms", "msec", "millisecond", "milliseconds"],
+            Nanosecond.per(Millisecond) as _,
+        ),
+        (
+            &["s", "sec", "secs", "second", "seconds"],
+            Nanosecond.per(Second) as _,
+        ),
+        (
+            &["m", "min", "mins", "minute", "minutes"],
+            Nanosecond.per(Minute),
+        ),
+        (&["h", "hr", "hrs", "hour", "hours"], Nanosecond.per(Hour)),
+        (&["d", "day", "days"], Nanosecond.per(Day)),
+        (&["w", "wk", "wks", "week", "weeks"], Nanosecond.per(Week)),
+    ];
+
+    UNITS
+        .iter()
+        .find(|(names, _)| names.iter().any(|unit| unit.eq_ignore_ascii_case(name)))
+        .map(|&(_, nanoseconds)| nanoseconds as u128)
+}
+
+/// The number of nanoseconds represented by a number with the given integer and fractional digits
+/// of a unit. Returns `None` on overflow.
+fn item_nanoseconds(integer: &[u8], fraction: &[u8], unit: u128) -> Option<u128> {
+    /// Digits of the fraction beyond this are less than one nanosecond, even for the largest unit.
+    const MAX_FRACTION_DIGITS: usize = 18;
+
+    let mut value: u128 = 0;
+    for &digit in integer {
+        value = value.checked_mul(10)?.checked_add((digit - b'0') as u128)?;
+    }
+    let mut value = value.checked_mul(unit)?;
+
+    let fraction = &fraction[..fraction.len().min(MAX_FRACTION_DIGITS)];
+    let mut numerator: u128 = 0;
+    for &digit in fraction {
+        numerator = numerator * 10 + (digit - b'0') as u128;
+    }
+    value = value.checked_add(numerator * unit / 10_u128.pow(fraction.len() as u32))?;
+
+    Some(value)
+}
+
 impl TryFrom<StdDuration> for Duration {
     type Error = error::ConversionRange;
 
@@ -0,0 +1,26 @@
+//! Eras of the proleptic Gregorian calendar.
+
+use core::fmt::{self, Display};
+
+use Era::*;
+
+/// An era of the proleptic Gregorian calendar.
+///
+/// Years within an era are counted from 1, so there is no year zero: the year before 1 CE is
+/// 1 BCE. The astronomical year 0 corresponds to 1 BCE, -1 to 2 BCE, and so on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Era {
+    /// Before the common era, also known as BC.
+    Bce,
+    /// The common era, also known as AD.
+    Ce,
+}
+
+impl Display for Era {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Bce => "BCE",
+            Ce => "CE",
+        })
+    }
+}
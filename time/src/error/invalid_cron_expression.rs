@@ -0,0 +1,70 @@
+//! Invalid cron expression error
+
+use core::fmt;
+
+use crate::error;
+
+/// A string could not be parsed as a [cron expression](crate::cron::CronExpression).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidCronExpression {
+    /// The expression does not have five or six fields.
+    #[non_exhaustive]
+    FieldCount {
+        /// The number of fields that were present.
+        count: usize,
+    },
+    /// The expression is syntactically invalid.
+    #[non_exhaustive]
+    Malformed {
+        /// The zero-based byte index at which the expression became invalid.
+        index: usize,
+    },
+    /// A value is outside the range permitted by its field.
+    #[non_exhaustive]
+    OutOfRange {
+        /// The name of the field.
+        field: &'static str,
+        /// The zero-based byte index of the value.
+        index: usize,
+    },
+}
+
+impl fmt::Display for InvalidCronExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FieldCount { count } => write!(
+                f,
+                "cron expression has {count} fields, but five or six are required"
+            ),
+            Self::Malformed { index } => {
+                write!(f, "malformed cron expression at byte index {index}")
+            }
+            Self::OutOfRange { field, index } => write!(
+                f,
+                "value out of range for the {field} field of the cron expression at byte index \
+                 {index}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidCronExpression {}
+
+impl From<InvalidCronExpression> for crate::Error {
+    fn from(err: InvalidCronExpression) -> Self {
+        Self::InvalidCronExpression(err)
+    }
+}
+
+impl TryFrom<crate::Error> for InvalidCronExpression {
+    type Error = error::DifferentVariant;
+
+    fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
+        match err {
+            crate::Error::InvalidCronExpression(err) => Ok(err),
+            _ => Err(error::DifferentVariant),
+        }
+    }
+}
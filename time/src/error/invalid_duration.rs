@@ -0,0 +1,61 @@
+//! Invalid duration error
+
+use core::fmt;
+
+use crate::error;
+
+/// A string could not be parsed as a [`Duration`](crate::Duration).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidDuration {
+    /// A number was expected, but not found.
+    #[non_exhaustive]
+    ExpectedNumber {
+        /// The zero-based byte index at which the number was expected.
+        index: usize,
+    },
+    /// The unit following a number is missing or not recognized.
+    #[non_exhaustive]
+    InvalidUnit {
+        /// The zero-based byte index at which the unit starts.
+        index: usize,
+    },
+    /// The duration is outside the range of [`Duration`](crate::Duration).
+    #[non_exhaustive]
+    OutOfRange {
+        /// The zero-based byte index of the number that caused the duration to be out of range.
+        index: usize,
+    },
+}
+
+impl fmt::Display for InvalidDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExpectedNumber { index } => write!(f, "expected a number at byte index {index}"),
+            Self::InvalidUnit { index } => write!(f, "invalid unit at byte index {index}"),
+            Self::OutOfRange { index } => {
+                write!(f, "duration out of range at byte index {index}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidDuration {}
+
+impl From<InvalidDuration> for crate::Error {
+    fn from(err: InvalidDuration) -> Self {
+        Self::InvalidDuration(err)
+    }
+}
+
+impl TryFrom<crate::Error> for InvalidDuration {
+    type Error = error::DifferentVariant;
+
+    fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
+        match err {
+            crate::Error::InvalidDuration(err) => Ok(err),
+            _ => Err(error::DifferentVariant),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Invalid recurrence rule error
+
+use core::fmt;
+
+use crate::error;
+
+/// A string could not be parsed as a [recurrence rule](crate::recurrence::RecurrenceRule).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidRecurrenceRule {
+    /// The rule is syntactically invalid, or a value is out of range.
+    #[non_exhaustive]
+    Malformed {
+        /// The zero-based byte index at which the rule became invalid.
+        index: usize,
+    },
+    /// The rule parts are well-formed, but their combination is not permitted.
+    #[non_exhaustive]
+    Conflict {
+        /// Why the combination is not permitted.
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for InvalidRecurrenceRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed { index } => {
+                write!(f, "malformed recurrence rule at byte index {index}")
+            }
+            Self::Conflict { reason } => write!(f, "invalid recurrence rule: {reason}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidRecurrenceRule {}
+
+impl From<InvalidRecurrenceRule> for crate::Error {
+    fn from(err: InvalidRecurrenceRule) -> Self {
+        Self::InvalidRecurrenceRule(err)
+    }
+}
+
+impl TryFrom<crate::Error> for InvalidRecurrenceRule {
+    type Error = error::DifferentVariant;
+
+    fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
+        match err {
+            crate::Error::InvalidRecurrenceRule(err) => Ok(err),
+            _ => Err(error::DifferentVariant),
+        }
+    }
+}
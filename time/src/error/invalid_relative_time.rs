@@ -0,0 +1,67 @@
+//! Invalid relative time error
+
+use core::fmt;
+
+use crate::error;
+
+/// A string could not be parsed as a [relative time](crate::relative::parse).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidRelativeTime {
+    /// The input did not match any of the expected items.
+    #[non_exhaustive]
+    Unexpected {
+        /// The zero-based byte index at which the input did not match.
+        index: usize,
+        /// A description of each item that would have been accepted, such as `"a weekday"`.
+        expected: &'static [&'static str],
+    },
+    /// The resulting value is outside the range of the type.
+    #[non_exhaustive]
+    OutOfRange {
+        /// The zero-based byte index of the item that caused the value to be out of range.
+        index: usize,
+    },
+}
+
+impl fmt::Display for InvalidRelativeTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unexpected { index, expected } => {
+                f.write_str("expected ")?;
+                for (i, item) in expected.iter().enumerate() {
+                    match i {
+                        0 => {}
+                        _ if i + 1 == expected.len() => f.write_str(" or ")?,
+                        _ => f.write_str(", ")?,
+                    }
+                    f.write_str(item)?;
+                }
+                write!(f, " at byte index {index}")
+            }
+            Self::OutOfRange { index } => {
+                write!(f, "relative time out of range at byte index {index}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidRelativeTime {}
+
+impl From<InvalidRelativeTime> for crate::Error {
+    fn from(err: InvalidRelativeTime) -> Self {
+        Self::InvalidRelativeTime(err)
+    }
+}
+
+impl TryFrom<crate::Error> for InvalidRelativeTime {
+    type Error = error::DifferentVariant;
+
+    fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
+        match err {
+            crate::Error::InvalidRelativeTime(err) => Ok(err),
+            _ => Err(error::DifferentVariant),
+        }
+    }
+}
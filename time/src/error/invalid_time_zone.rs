@@ -0,0 +1,52 @@
+//! Invalid time zone error
+
+use core::fmt;
+
+use crate::error;
+
+/// Time zone data could not be parsed.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidTimeZone {
+    /// A TZif file is malformed.
+    #[non_exhaustive]
+    Tzif {
+        /// Why the file could not be parsed.
+        reason: &'static str,
+    },
+    /// A POSIX TZ string is malformed.
+    #[non_exhaustive]
+    PosixTz {
+        /// The zero-based byte index at which the string became invalid.
+        index: usize,
+    },
+}
+
+impl fmt::Display for InvalidTimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tzif { reason } => write!(f, "invalid TZif data: {reason}"),
+            Self::PosixTz { index } => write!(f, "invalid POSIX TZ string at byte index {index}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidTimeZone {}
+
+impl From<InvalidTimeZone> for crate::Error {
+    fn from(err: InvalidTimeZone) -> Self {
+        Self::InvalidTimeZone(err)
+    }
+}
+
+impl TryFrom<crate::Error> for InvalidTimeZone {
+    type Error = error::DifferentVariant;
+
+    fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
+        match err {
+            crate::Error::InvalidTimeZone(err) => Ok(err),
+            _ => Err(error::DifferentVariant),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Error along with the location in the input at which it occurred
+
+use core::fmt;
+
+use crate::error::{DifferentVariant, Parse, ParseFromDescription, TryFromParsed};
+use crate::parsing::Location;
+
+/// An error that occurred while parsing, along with the location in the input at which it
+/// occurred.
+///
+/// This is returned when parsing with [`Date::parse_located`](crate::Date::parse_located) or
+/// [`Date::parse_prefix`](crate::Date::parse_prefix) (and their equivalents on other types). The
+/// location is kept when converting to [`time::Error`](crate::Error).
+///
+/// ```rust
+/// # use time::macros::format_description;
+/// # use time::Date;
+/// let format = format_description!("[year]-[month]-[day]");
+/// let err = Date::parse_located("2024-Foo-01", format).unwrap_err();
+/// assert_eq!(err.offset(), Some(5));
+/// assert_eq!(err.snippet(), Some("Foo-01"));
+/// assert_eq!(
+///     err.to_string(),
+///     "the 'month' component could not be parsed at byte index 5 (found \"Foo-01\")"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Located<E> {
+    /// The error that occurred.
+    pub(crate) error: E,
+    /// The location in the input at which the error occurred. This is `None` if the input was
+    /// parsed successfully, but the parsed values could not be converted to the requested type.
+    pub(crate) location: Option<Location>,
+}
+
+impl<E> Located<E> {
+    /// Make the location of the error, if any, relative to the start of the provided input. The
+    /// input must end with the input that remained when parsing failed.
+    pub(crate) fn relative_to(mut self, input: &[u8]) -> Self {
+        self.location = self.location.map(|location| location.relative_to(input));
+        self
+    }
+
+    /// Obtain the error, discarding its location.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+
+    /// Obtain the offset in bytes from the start of the input at which parsing failed. This is
+    /// `None` if the input was parsed successfully, but the parsed values could not be converted
+    /// to the requested type.
+    pub const fn offset(&self) -> Option<usize> {
+        match self.location {
+            Some(location) => Some(location.offset()),
+            None => None,
+        }
+    }
+
+    /// Obtain a short snippet of the input beginning at the location parsing failed. This is
+    /// `None` if the input was parsed successfully, but the parsed values could not be converted
+    /// to the requested type. If parsing failed at the end of the input, the snippet is empty.
+    ///
+    /// The snippet is truncated to at most 16 bytes, ending on a character boundary.
+    pub fn snippet(&self) -> Option<&str> {
+        self.location.as_ref().map(Location::snippet)
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Located<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.location {
+            Some(location) => write!(f, "{} {location}", self.error),
+            None => self.error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for Located<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<Located<ParseFromDescription>> for Located<Parse> {
+    fn from(err: Located<ParseFromDescription>) -> Self {
+        Self {
+            error: Parse::ParseFromDescription(err.error),
+            location: err.location,
+        }
+    }
+}
+
+impl From<TryFromParsed> for Located<Parse> {
+    fn from(err: TryFromParsed) -> Self {
+        Self {
+            error: Parse::TryFromParsed(err),
+            location: None,
+        }
+    }
+}
+
+impl From<Located<Self>> for Parse {
+    fn from(err: Located<Self>) -> Self {
+        err.error
+    }
+}
+
+impl From<Located<Parse>> for crate::Error {
+    fn from(err: Located<Parse>) -> Self {
+        Self::Located(err)
+    }
+}
+
+impl TryFrom<crate::Error> for Located<Parse> {
+    type Error = DifferentVariant;
+
+    fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
+        match err {
+            crate::Error::Located(err) => Ok(err),
+            _ => Err(DifferentVariant),
+        }
+    }
+}
@@ -7,15 +7,25 @@ mod different_variant;
 mod format;
 #[cfg(feature = "local-offset")]
 mod indeterminate_offset;
+mod invalid_cron_expression;
+mod invalid_duration;
 #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
 mod invalid_format_description;
+#[cfg(feature = "alloc")]
+mod invalid_recurrence_rule;
+#[cfg(feature = "parsing")]
+mod invalid_relative_time;
+mod invalid_time_zone;
 mod invalid_variant;
 #[cfg(feature = "parsing")]
+mod located;
+#[cfg(feature = "parsing")]
 mod parse;
 #[cfg(feature = "parsing")]
 mod parse_from_description;
 #[cfg(feature = "parsing")]
 mod try_from_parsed;
+mod unresolved_local_time;
 
 use core::fmt;
 
@@ -26,15 +36,25 @@ pub use different_variant::DifferentVariant;
 pub use format::Format;
 #[cfg(feature = "local-offset")]
 pub use indeterminate_offset::IndeterminateOffset;
+pub use invalid_cron_expression::InvalidCronExpression;
+pub use invalid_duration::InvalidDuration;
 #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
 pub use invalid_format_description::InvalidFormatDescription;
+#[cfg(feature = "alloc")]
+pub use invalid_recurrence_rule::InvalidRecurrenceRule;
+#[cfg(feature = "parsing")]
+pub use invalid_relative_time::InvalidRelativeTime;
+pub use invalid_time_zone::InvalidTimeZone;
 pub use invalid_variant::InvalidVariant;
 #[cfg(feature = "parsing")]
+pub use located::Located;
+#[cfg(feature = "parsing")]
 pub use parse::Parse;
 #[cfg(feature = "parsing")]
 pub use parse_from_description::ParseFromDescription;
 #[cfg(feature = "parsing")]
 pub use try_from_parsed::TryFromParsed;
+pub use unresolved_local_time::UnresolvedLocalTime;
 
 /// A unified error type for anything returned by a method in the time crate.
 ///
@@ -58,10 +78,20 @@ pub enum Error {
     UnexpectedTrailingCharacters,
     #[cfg(feature = "parsing")]
     TryFromParsed(TryFromParsed),
+    #[cfg(feature = "parsing")]
+    Located(Located<Parse>),
     #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
     InvalidFormatDescription(InvalidFormatDescription),
     DifferentVariant(DifferentVariant),
     InvalidVariant(InvalidVariant),
+    UnresolvedLocalTime(UnresolvedLocalTime),
+    InvalidTimeZone(InvalidTimeZone),
+    InvalidDuration(InvalidDuration),
+    #[cfg(feature = "parsing")]
+    InvalidRelativeTime(InvalidRelativeTime),
+    #[cfg(feature = "alloc")]
+    InvalidRecurrenceRule(InvalidRecurrenceRule),
+    InvalidCronExpression(InvalidCronExpression),
 }
 
 impl fmt::Display for Error {
@@ -79,10 +109,20 @@ impl fmt::Display for Error {
             Self::UnexpectedTrailingCharacters => f.write_str("unexpected trailing characters"),
             #[cfg(feature = "parsing")]
             Self::TryFromParsed(e) => e.fmt(f),
+            #[cfg(feature = "parsing")]
+            Self::Located(e) => e.fmt(f),
             #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
             Self::InvalidFormatDescription(e) => e.fmt(f),
             Self::DifferentVariant(e) => e.fmt(f),
             Self::InvalidVariant(e) => e.fmt(f),
+            Self::UnresolvedLocalTime(e) => e.fmt(f),
+            Self::InvalidTimeZone(e) => e.fmt(f),
+            Self::InvalidDuration(e) => e.fmt(f),
+            #[cfg(feature = "parsing")]
+            Self::InvalidRelativeTime(e) => e.fmt(f),
+            #[cfg(feature = "alloc")]
+            Self::InvalidRecurrenceRule(e) => e.fmt(f),
+            Self::InvalidCronExpression(e) => e.fmt(f),
         }
     }
 }
@@ -103,10 +143,20 @@ impl std::error::Error for Error {
             Self::UnexpectedTrailingCharacters => None,
             #[cfg(feature = "parsing")]
             Self::TryFromParsed(err) => Some(err),
+            #[cfg(feature = "parsing")]
+            Self::Located(err) => Some(err),
             #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
             Self::InvalidFormatDescription(err) => Some(err),
             Self::DifferentVariant(err) => Some(err),
             Self::InvalidVariant(err) => Some(err),
+            Self::UnresolvedLocalTime(err) => Some(err),
+            Self::InvalidTimeZone(err) => Some(err),
+            Self::InvalidDuration(err) => Some(err),
+            #[cfg(feature = "parsing")]
+            Self::InvalidRelativeTime(err) => Some(err),
+            #[cfg(feature = "alloc")]
+            Self::InvalidRecurrenceRule(err) => Some(err),
+            Self::InvalidCronExpression(err) => Some(err),
         }
     }
 }
@@ -91,6 +91,7 @@ impl TryFrom<crate::Error> for Parse {
             crate::Error::ParseFromDescription(err) => Ok(Self::ParseFromDescription(err)),
             crate::Error::UnexpectedTrailingCharacters => Ok(Self::UnexpectedTrailingCharacters),
             crate::Error::TryFromParsed(err) => Ok(Self::TryFromParsed(err)),
+            crate::Error::Located(err) => Ok(err.into_inner()),
             _ => Err(error::DifferentVariant),
         }
     }
@@ -40,7 +40,11 @@ impl TryFrom<crate::Error> for ParseFromDescription {
 
     fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
         match err {
-            crate::Error::ParseFromDescription(err) => Ok(err),
+            crate::Error::ParseFromDescription(err)
+            | crate::Error::Located(error::Located {
+                error: error::Parse::ParseFromDescription(err),
+                ..
+            }) => Ok(err),
             _ => Err(error::DifferentVariant),
         }
     }
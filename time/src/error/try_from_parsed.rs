@@ -64,7 +64,11 @@ impl TryFrom<crate::Error> for TryFromParsed {
 
     fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
         match err {
-            crate::Error::TryFromParsed(err) => Ok(err),
+            crate::Error::TryFromParsed(err)
+            | crate::Error::Located(error::Located {
+                error: error::Parse::TryFromParsed(err),
+                ..
+            }) => Ok(err),
             _ => Err(error::DifferentVariant),
         }
     }
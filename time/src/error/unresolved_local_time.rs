@@ -0,0 +1,44 @@
+//! Unresolved local time error
+
+use core::fmt;
+
+use crate::error;
+
+/// A local date-time could not be mapped to a single instant in a time zone.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnresolvedLocalTime {
+    /// The local date-time occurs more than once, typically because clocks were turned back.
+    Ambiguous,
+    /// The local date-time does not exist, typically because clocks were turned forward.
+    Nonexistent,
+}
+
+impl fmt::Display for UnresolvedLocalTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ambiguous => f.write_str("the local date-time is ambiguous in the time zone"),
+            Self::Nonexistent => f.write_str("the local date-time does not exist in the time zone"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnresolvedLocalTime {}
+
+impl From<UnresolvedLocalTime> for crate::Error {
+    fn from(err: UnresolvedLocalTime) -> Self {
+        Self::UnresolvedLocalTime(err)
+    }
+}
+
+impl TryFrom<crate::Error> for UnresolvedLocalTime {
+    type Error = error::DifferentVariant;
+
+    fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
+        match err {
+            crate::Error::UnresolvedLocalTime(err) => Ok(err),
+            _ => Err(error::DifferentVariant),
+        }
+    }
+}
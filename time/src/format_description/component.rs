@@ -18,6 +18,12 @@ pub enum Component {
     WeekNumber(modifier::WeekNumber),
     /// Year of the date.
     Year(modifier::Year),
+    /// Quarter of the year.
+    Quarter(modifier::Quarter),
+    /// Century of the year.
+    Century(modifier::Century),
+    /// Era of the year.
+    Era(modifier::Era),
     /// Hour of the day.
     Hour(modifier::Hour),
     /// Minute within the hour.
@@ -34,6 +40,20 @@ pub enum Component {
     OffsetMinute(modifier::OffsetMinute),
     /// Second within the minute of the UTC offset.
     OffsetSecond(modifier::OffsetSecond),
+    /// Identifier of the time zone, such as `Europe/Paris`.
+    ZoneName(modifier::ZoneName),
+    /// Abbreviation of the time zone, such as `CEST`.
+    ZoneAbbr(modifier::ZoneAbbr),
+    /// Sign of a duration.
+    Sign(modifier::Sign),
+    /// Whole days of a duration.
+    Days(modifier::Days),
+    /// Hours of a duration, either in total or past the last whole day.
+    Hours(modifier::Hours),
+    /// Minutes of a duration, either in total or past the last whole hour.
+    Minutes(modifier::Minutes),
+    /// Seconds of a duration, either in total or past the last whole minute.
+    Seconds(modifier::Seconds),
     /// A number of bytes to ignore when parsing. This has no effect on formatting.
     Ignore(modifier::Ignore),
     /// A Unix timestamp.
@@ -0,0 +1,270 @@
+//! Names of months and weekdays in languages other than English.
+//!
+//! By default, the `[month repr:long]`, `[month repr:short]`, `[weekday repr:long]`, and
+//! `[weekday repr:short]` components are formatted and parsed in English, as is the suffix of
+//! `[day suffix:true]`. Wrapping a format description in [`Localized`] makes these components use
+//! the names and suffixes provided by a [`Locale`] instead. All other components are unaffected.
+
+use core::fmt;
+
+/// A table of the names of months and weekdays, and of the suffixes following days of the month.
+///
+/// This trait is implemented by the built-in locales in this module. It may also be implemented
+/// for any other type to supply custom names.
+///
+/// No name may be empty. Names that are a prefix of another name in the same table are permitted;
+/// the longest match is used when parsing.
+pub trait Locale {
+    /// The full names of the months, starting with January.
+    fn month_names(&self) -> &[&str; 12];
+    /// The abbreviated names of the months, starting with January.
+    fn month_abbreviations(&self) -> &[&str; 12];
+    /// The full names of the weekdays, starting with Monday.
+    fn weekday_names(&self) -> &[&str; 7];
+    /// The abbreviated names of the weekdays, starting with Monday.
+    fn weekday_abbreviations(&self) -> &[&str; 7];
+
+    /// The suffix following the provided day of the month (`1..=31`) when the `suffix` modifier
+    /// is set. The suffix may be empty.
+    ///
+    /// The default implementation returns the English ordinal suffixes: "st", "nd", "rd", and
+    /// "th".
+    fn day_suffix(&self, day: u8) -> &str {
+        english_day_suffix(day)
+    }
+}
+
+/// The English ordinal suffix of a day of the month.
+const fn english_day_suffix(day: u8) -> &'static str {
+    match (day % 10, day % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+/// Declare a built-in locale, implementing [`Locale`] using the provided tables.
+macro_rules! declare_locale {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident {
+            month_names: $month_names:expr,
+            month_abbreviations: $month_abbreviations:expr,
+            weekday_names: $weekday_names:expr,
+            weekday_abbreviations: $weekday_abbreviations:expr,
+            $(day_suffix: $day_suffix:expr,)?
+        }
+    )*) => {$(
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name;
+
+        impl Locale for $name {
+            fn month_names(&self) -> &[&str; 12] {
+                &$month_names
+            }
+
+            fn month_abbreviations(&self) -> &[&str; 12] {
+                &$month_abbreviations
+            }
+
+            fn weekday_names(&self) -> &[&str; 7] {
+                &$weekday_names
+            }
+
+            fn weekday_abbreviations(&self) -> &[&str; 7] {
+                &$weekday_abbreviations
+            }
+
+            $(fn day_suffix(&self, day: u8) -> &str {
+                $day_suffix(day)
+            })?
+        }
+    )*};
+}
+
+declare_locale! {
+    /// English, using the same names as when no locale is provided.
+    English {
+        month_names: [
+            "January", "February", "March", "April", "May", "June", "July", "August", "September",
+            "October", "November", "December",
+        ],
+        month_abbreviations: [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+        weekday_names: [
+            "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday",
+        ],
+        weekday_abbreviations: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    }
+
+    /// German.
+    German {
+        month_names: [
+            "Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September",
+            "Oktober", "November", "Dezember",
+        ],
+        month_abbreviations: [
+            "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+            "Dez.",
+        ],
+        weekday_names: [
+            "Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag", "Sonntag",
+        ],
+        weekday_abbreviations: ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+        day_suffix: |_| ".",
+    }
+
+    /// French.
+    French {
+        month_names: [
+            "janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre",
+            "octobre", "novembre", "décembre",
+        ],
+        month_abbreviations: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        weekday_names: ["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche"],
+        weekday_abbreviations: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+        day_suffix: |day| if day == 1 { "er" } else { "" },
+    }
+
+    /// Spanish.
+    Spanish {
+        month_names: [
+            "enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre",
+            "octubre", "noviembre", "diciembre",
+        ],
+        month_abbreviations: [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+        ],
+        weekday_names: ["lunes", "martes", "miércoles", "jueves", "viernes", "sábado", "domingo"],
+        weekday_abbreviations: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+        day_suffix: |day| if day == 1 { ".º" } else { "" },
+    }
+
+    /// Italian.
+    Italian {
+        month_names: [
+            "gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto",
+            "settembre", "ottobre", "novembre", "dicembre",
+        ],
+        month_abbreviations: [
+            "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+        ],
+        weekday_names: [
+            "lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato", "domenica",
+        ],
+        weekday_abbreviations: ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+        day_suffix: |day| if day == 1 { "º" } else { "" },
+    }
+
+    /// Portuguese.
+    Portuguese {
+        month_names: [
+            "janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto",
+            "setembro", "outubro", "novembro", "dezembro",
+        ],
+        month_abbreviations: [
+            "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.",
+            "dez.",
+        ],
+        weekday_names: [
+            "segunda-feira", "terça-feira", "quarta-feira", "quinta-feira", "sexta-feira",
+            "sábado", "domingo",
+        ],
+        weekday_abbreviations: ["seg.", "ter.", "qua.", "qui.", "sex.", "sáb.", "dom."],
+        day_suffix: |day| if day == 1 { "º" } else { "" },
+    }
+
+    /// Dutch.
+    Dutch {
+        month_names: [
+            "januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus",
+            "september", "oktober", "november", "december",
+        ],
+        month_abbreviations: [
+            "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+        ],
+        weekday_names: [
+            "maandag", "dinsdag", "woensdag", "donderdag", "vrijdag", "zaterdag", "zondag",
+        ],
+        weekday_abbreviations: ["ma", "di", "wo", "do", "vr", "za", "zo"],
+        day_suffix: |_| "e",
+    }
+
+    /// Japanese.
+    Japanese {
+        month_names: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        month_abbreviations: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        weekday_names: ["月曜日", "火曜日", "水曜日", "木曜日", "金曜日", "土曜日", "日曜日"],
+        weekday_abbreviations: ["月", "火", "水", "木", "金", "土", "日"],
+        day_suffix: |_| "日",
+    }
+}
+
+/// A format description whose month and weekday names are taken from a [`Locale`].
+///
+/// This type can be used anywhere a format description is accepted, for both formatting and
+/// parsing.
+#[cfg_attr(all(feature = "formatting", feature = "parsing"), doc = "```rust")]
+#[cfg_attr(
+    not(all(feature = "formatting", feature = "parsing")),
+    doc = "```rust,ignore"
+)]
+/// # use time::format_description::locale::{French, German, Localized};
+/// # use time::Date;
+/// # use time_macros::{date, format_description};
+/// let format = format_description!("[weekday], [day]. [month repr:long] [year]");
+/// assert_eq!(
+///     date!(2023-03-01).format(&Localized::new(format, &German))?,
+///     "Mittwoch, 01. März 2023"
+/// );
+///
+/// let format = format_description!(
+///     "[weekday repr:short case_sensitive:false] [day] [month repr:short case_sensitive:false] \
+///      [year]"
+/// );
+/// assert_eq!(
+///     Date::parse("MER. 01 FÉVR. 2023", &Localized::new(format, &French))?,
+///     date!(2023-02-01)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+pub struct Localized<'a, T: ?Sized> {
+    /// The format description.
+    pub(crate) format: &'a T,
+    /// The locale providing the names of months and weekdays.
+    pub(crate) locale: &'a (dyn Locale + Sync),
+}
+
+impl<'a, T: ?Sized> Localized<'a, T> {
+    /// Use the names of months and weekdays from the provided locale with the format description.
+    pub const fn new(format: &'a T, locale: &'a (dyn Locale + Sync)) -> Self {
+        Self { format, locale }
+    }
+}
+
+impl<T: ?Sized> Clone for Localized<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for Localized<'_, T> {}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for Localized<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Localized")
+            .field("format", &self.format)
+            .finish_non_exhaustive()
+    }
+}
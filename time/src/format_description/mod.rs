@@ -5,10 +5,11 @@
 //! [`format_description!`](crate::macros::format_description) macro or a function listed below.
 //!
 //! For examples, see the implementors of [Formattable](crate::formatting::Formattable),
-//! e.g. [`well_known::Rfc3339`].
+//! e.g. [`well_known::Rfc3339`](struct@well_known::Rfc3339).
 
 mod borrowed_format_item;
 mod component;
+pub mod locale;
 pub mod modifier;
 #[cfg(feature = "alloc")]
 mod owned_format_item;
@@ -21,16 +22,33 @@ pub use owned_format_item::OwnedFormatItem;
 
 pub use self::component::Component;
 #[cfg(feature = "alloc")]
-pub use self::parse::{parse, parse_borrowed, parse_owned};
+pub use self::parse::{
+    parse, parse_borrowed, parse_owned, parse_strftime_borrowed, parse_strftime_owned,
+};
 
 /// Well-known formats, typically standards.
 pub mod well_known {
+    mod generalized_time;
+    mod http_date;
     pub mod iso8601;
     mod rfc2822;
-    mod rfc3339;
+    mod rfc3164;
+    pub mod rfc3339;
+    mod rfc5424;
+    mod rfc5545;
+    mod sql;
+    mod utc_time;
 
+    pub use generalized_time::GeneralizedTime;
+    pub use http_date::HttpDate;
     #[doc(inline)]
     pub use iso8601::Iso8601;
     pub use rfc2822::Rfc2822;
+    pub use rfc3164::Rfc3164;
+    #[doc(inline)]
     pub use rfc3339::Rfc3339;
+    pub use rfc5424::Rfc5424;
+    pub use rfc5545::Rfc5545;
+    pub use sql::Sql;
+    pub use utc_time::UtcTime;
 }
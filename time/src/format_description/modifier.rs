@@ -8,7 +8,17 @@ use core::num::NonZeroU16;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Day {
     /// The padding to obtain the minimum width.
+    ///
+    /// This is ignored if [`suffix`](Self::suffix) is set, as a suffixed day is never padded.
     pub padding: Padding,
+    /// Is the value followed by its ordinal suffix, such as the "rd" in "23rd"?
+    ///
+    /// The suffix is English unless it is provided by a [`Locale`](super::locale::Locale). The
+    /// value is never padded when the suffix is present, so `[day suffix:true]` formats the third
+    /// day of the month as "3rd" rather than "03rd".
+    pub suffix: bool,
+    /// Is the suffix case sensitive when parsing?
+    pub case_sensitive: bool,
 }
 
 /// The representation of a month.
@@ -103,6 +113,9 @@ pub enum YearRepr {
     Full,
     /// Only the last two digits of the year.
     LastTwo,
+    /// The year within its era, counting from 1 and without a sign. This is typically used in
+    /// conjunction with the [`Era`](super::Component::Era) component.
+    Era,
 }
 
 /// Year of the date.
@@ -118,6 +131,42 @@ pub struct Year {
     /// Whether the `+` sign is present when a positive year contains fewer than five digits.
     pub sign_is_mandatory: bool,
 }
+
+/// Quarter of the year.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quarter {}
+
+/// Century of the year, obtained by removing the last two digits of the year.
+///
+/// The year -44 is in century -00, while the year 44 is in century 00.
+/// When parsing, the century must be at most two digits.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Century {
+    /// The padding to obtain the minimum width.
+    pub padding: Padding,
+}
+
+/// The representation used for the era.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EraRepr {
+    /// `AD` and `BC`.
+    Ad,
+    /// `CE` and `BCE`.
+    Ce,
+}
+
+/// Era of the proleptic Gregorian calendar.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Era {
+    /// What form of representation should be used?
+    pub repr: EraRepr,
+    /// Is the value case sensitive when parsing?
+    pub case_sensitive: bool,
+}
 // endregion date modifiers
 
 // region: time modifiers
@@ -223,6 +272,72 @@ pub struct OffsetSecond {
 }
 // endregion offset modifiers
 
+// region: zone modifiers
+/// Identifier of the time zone, such as `Europe/Paris`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZoneName {}
+
+/// Abbreviation of the time zone, such as `CEST`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZoneAbbr {}
+// endregion zone modifiers
+
+// region: duration modifiers
+/// Sign of a [`Duration`](crate::Duration).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sign {
+    /// Whether the `+` sign is present on non-negative values.
+    pub sign_is_mandatory: bool,
+}
+
+/// Whole days of a [`Duration`](crate::Duration).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Days {
+    /// The padding to obtain the minimum width.
+    pub padding: Padding,
+}
+
+/// Hours of a [`Duration`](crate::Duration).
+///
+/// By default, `[hours]` is the hours past the last whole day, so whole days are not shown unless
+/// `[days]` is also present. Use `[hours total:true]` to include them in the hours instead.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hours {
+    /// The padding to obtain the minimum width.
+    pub padding: Padding,
+    /// Is the value the total number of whole hours, rather than the hours past the last whole
+    /// day?
+    pub total: bool,
+}
+
+/// Minutes of a [`Duration`](crate::Duration).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Minutes {
+    /// The padding to obtain the minimum width.
+    pub padding: Padding,
+    /// Is the value the total number of whole minutes, rather than the minutes past the last
+    /// whole hour?
+    pub total: bool,
+}
+
+/// Seconds of a [`Duration`](crate::Duration).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Seconds {
+    /// The padding to obtain the minimum width.
+    pub padding: Padding,
+    /// Is the value the total number of whole seconds, rather than the seconds past the last
+    /// whole minute?
+    pub total: bool,
+}
+// endregion duration modifiers
+
 /// Type of padding to ensure a minimum width.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -319,8 +434,13 @@ macro_rules! impl_const_default {
 }
 
 impl_const_default! {
-    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
-    @pub Day => Self { padding: Padding::Zero };
+    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero), has no
+    /// suffix, and is case-sensitive when parsing.
+    @pub Day => Self {
+        padding: Padding::Zero,
+        suffix: false,
+        case_sensitive: true,
+    };
     /// Creates a modifier that indicates the value uses the
     /// [`Numerical`](Self::Numerical) representation.
     MonthRepr => Self::Numerical;
@@ -363,6 +483,18 @@ impl_const_default! {
         iso_week_based: false,
         sign_is_mandatory: false,
     };
+    /// Creates a modifier for the quarter of the year.
+    @pub Quarter => Self {};
+    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
+    @pub Century => Self { padding: Padding::Zero };
+    /// Creates a modifier that indicates the value uses the [`Ad`](Self::Ad) representation.
+    EraRepr => Self::Ad;
+    /// Creates a modifier that indicates the value uses the [`Ad`](EraRepr::Ad) representation
+    /// and is case-sensitive when parsing.
+    @pub Era => Self {
+        repr: EraRepr::Ad,
+        case_sensitive: true,
+    };
     /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero) and
     /// has the 24-hour representation.
     @pub Hour => Self {
@@ -395,6 +527,32 @@ impl_const_default! {
     @pub OffsetMinute => Self { padding: Padding::Zero };
     /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
     @pub OffsetSecond => Self { padding: Padding::Zero };
+    /// Creates a modifier for the time zone identifier.
+    @pub ZoneName => Self {};
+    /// Creates a modifier for the time zone abbreviation.
+    @pub ZoneAbbr => Self {};
+    /// Creates a modifier that indicates the `+` sign is not written for non-negative values.
+    @pub Sign => Self { sign_is_mandatory: false };
+    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
+    @pub Days => Self { padding: Padding::Zero };
+    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero) and is
+    /// the number of hours past the last whole day.
+    @pub Hours => Self {
+        padding: Padding::Zero,
+        total: false,
+    };
+    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero) and is
+    /// the number of minutes past the last whole hour.
+    @pub Minutes => Self {
+        padding: Padding::Zero,
+        total: false,
+    };
+    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero) and is
+    /// the number of seconds past the last whole minute.
+    @pub Seconds => Self {
+        padding: Padding::Zero,
+        total: false,
+    };
     /// Creates a modifier that indicates the value is [padded with zeroes](Self::Zero).
     Padding => Self::Zero;
     /// Creates a modifier that indicates the value represents the [number of seconds](Self::Second)
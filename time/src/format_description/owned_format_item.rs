@@ -45,6 +45,44 @@ impl fmt::Debug for OwnedFormatItem {
     }
 }
 
+impl OwnedFormatItem {
+    /// Render the item as a format description of the given version, such that parsing the
+    /// result produces equivalent items. Modifiers that have their default value are omitted.
+    /// Adjacent literals may be combined or split when the result is parsed.
+    ///
+    /// An error is returned if the item cannot be represented. This is the case for literals that
+    /// are not valid UTF-8 and, in version 1, for nested descriptions that begin with a bracket or
+    /// that contain a literal closing bracket.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// let item = format_description::parse_owned::<2>("[year]-[month repr:short] \\[UTC\\]")?;
+    /// assert_eq!(
+    ///     item.to_description_string::<2>()?,
+    ///     "[year]-[month repr:short] \\[UTC\\]"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn to_description_string<const VERSION: usize>(
+        &self,
+    ) -> Result<String, error::InvalidFormatDescription> {
+        crate::format_description::parse::to_description_string::<VERSION>(self)
+    }
+
+    /// Whether the `[sign]` component is used when formatting or parsing a duration. Only the
+    /// first item of [`First`](Self::First) is considered, as it is the one used when formatting.
+    #[cfg(any(feature = "formatting", feature = "parsing"))]
+    pub(crate) fn has_duration_sign(&self) -> bool {
+        match self {
+            Self::Literal(_) => false,
+            Self::Component(component) => matches!(component, Component::Sign(_)),
+            Self::Compound(items) => items.iter().any(Self::has_duration_sign),
+            Self::Optional(item) => item.has_duration_sign(),
+            Self::First(items) => items.first().map_or(false, Self::has_duration_sign),
+        }
+    }
+}
+
 // region: conversions from FormatItem
 impl From<FormatItem<'_>> for OwnedFormatItem {
     fn from(item: FormatItem<'_>) -> Self {
@@ -1,7 +1,7 @@
 //! Typed, validated representation of a parsed format description.
 
 use alloc::boxed::Box;
-use alloc::string::String;
+use alloc::string::{String, ToString};
 use core::num::NonZeroU16;
 use core::str::{self, FromStr};
 
@@ -190,6 +190,7 @@ macro_rules! component_definition {
                 _component_span: Span,
             ) -> Result<Self, Error>
             {
+                #[allow(unused_mut)] // components without modifiers
                 let mut this = Self {
                     $($field: None),*
                 };
@@ -254,6 +255,36 @@ macro_rules! component_definition {
             }
         }
 
+        /// Write the component in the format description syntax. Modifiers with their default
+        /// value are omitted.
+        pub(super) fn write_component(
+            output: &mut String,
+            component: crate::format_description::Component,
+        ) {
+            match component {$(
+                #[allow(unused_variables)] // components without modifiers
+                crate::format_description::Component::$variant(modifier) => {
+                    output.push('[');
+                    output.push_str($parse_variant);
+                    $(component_definition!(@if_from_str $($from_str)?
+                        then {
+                            write_modifier(
+                                output,
+                                $parse_field,
+                                modifier.$target_field.to_string().as_bytes(),
+                            );
+                        } else {
+                            if let Some(value) =
+                                <$field_type>::to_modifier_value(modifier.$target_field)
+                            {
+                                write_modifier(output, $parse_field, value);
+                            }
+                        });)*
+                    output.push(']');
+                }
+            )*}
+        }
+
         /// Parse a component from the AST, given its name and modifiers.
         fn component_from_ast(
             name: &Spanned<&[u8]>,
@@ -277,13 +308,29 @@ macro_rules! component_definition {
 // Keep in alphabetical order.
 component_definition! {
     pub(super) enum Component {
+        Century = "century" {
+            padding = "padding": Option<Padding> => padding,
+        },
         Day = "day" {
             padding = "padding": Option<Padding> => padding,
+            suffix = "suffix": Option<DaySuffix> => suffix,
+            case_sensitive = "case_sensitive": Option<DayCaseSensitive> => case_sensitive,
+        },
+        Days = "days" {
+            padding = "padding": Option<Padding> => padding,
+        },
+        Era = "era" {
+            repr = "repr": Option<EraRepr> => repr,
+            case_sensitive = "case_sensitive": Option<EraCaseSensitive> => case_sensitive,
         },
         Hour = "hour" {
             padding = "padding": Option<Padding> => padding,
             base = "repr": Option<HourBase> => is_12_hour_clock,
         },
+        Hours = "hours" {
+            padding = "padding": Option<Padding> => padding,
+            total = "total": Option<HoursTotal> => total,
+        },
         Ignore = "ignore" {
             #[required]
             count = "count": Option<#[from_str] NonZeroU16> => count,
@@ -291,6 +338,10 @@ component_definition! {
         Minute = "minute" {
             padding = "padding": Option<Padding> => padding,
         },
+        Minutes = "minutes" {
+            padding = "padding": Option<Padding> => padding,
+            total = "total": Option<MinutesTotal> => total,
+        },
         Month = "month" {
             padding = "padding": Option<Padding> => padding,
             repr = "repr": Option<MonthRepr> => repr,
@@ -313,9 +364,17 @@ component_definition! {
             case = "case": Option<PeriodCase> => is_uppercase,
             case_sensitive = "case_sensitive": Option<PeriodCaseSensitive> => case_sensitive,
         },
+        Quarter = "quarter" {},
         Second = "second" {
             padding = "padding": Option<Padding> => padding,
         },
+        Seconds = "seconds" {
+            padding = "padding": Option<Padding> => padding,
+            total = "total": Option<SecondsTotal> => total,
+        },
+        Sign = "sign" {
+            sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
+        },
         Subsecond = "subsecond" {
             digits = "digits": Option<SubsecondDigits> => digits,
         },
@@ -338,6 +397,8 @@ component_definition! {
             base = "base": Option<YearBase> => iso_week_based,
             sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
         },
+        ZoneAbbr = "zone_abbr" {},
+        ZoneName = "zone_name" {},
     }
 }
 
@@ -408,6 +469,21 @@ macro_rules! modifier {
             }
         }
 
+        impl $name {
+            /// Obtain the string representation of the modifier value, or `None` if the value is
+            /// the default.
+            fn to_modifier_value(value: target_ty!($name $($target_ty)?)) -> Option<&'static [u8]> {
+                let default: target_ty!($name $($target_ty)?) = Self::default().into();
+                if value == default {
+                    return None;
+                }
+                $(if value == target_value!($name $variant $($target_value)?) {
+                    return Some(&$parse_variant[..]);
+                })*
+                bug!("every modifier value has a string representation")
+            }
+        }
+
         impl From<$name> for target_ty!($name $($target_ty)?) {
             fn from(modifier: $name) -> Self {
                 match modifier {
@@ -420,12 +496,48 @@ macro_rules! modifier {
 
 // Keep in alphabetical order.
 modifier! {
+    enum DayCaseSensitive(bool) {
+        False(false) = b"false",
+        #[default]
+        True(true) = b"true",
+    }
+
+    enum DaySuffix(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
+    enum EraCaseSensitive(bool) {
+        False(false) = b"false",
+        #[default]
+        True(true) = b"true",
+    }
+
+    enum EraRepr {
+        #[default]
+        Ad = b"ad",
+        Ce = b"ce",
+    }
+
     enum HourBase(bool) {
         Twelve(true) = b"12",
         #[default]
         TwentyFour(false) = b"24",
     }
 
+    enum HoursTotal(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
+    enum MinutesTotal(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
     enum MonthCaseSensitive(bool) {
         False(false) = b"false",
         #[default]
@@ -458,6 +570,12 @@ modifier! {
         True(true) = b"true",
     }
 
+    enum SecondsTotal(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
     enum SignBehavior(bool) {
         #[default]
         Automatic(false) = b"automatic",
@@ -523,9 +641,18 @@ modifier! {
         #[default]
         Full = b"full",
         LastTwo = b"last_two",
+        Era = b"era",
     }
 }
 
+/// Write a modifier as ` key:value`. Modifier values are always ASCII.
+fn write_modifier(output: &mut String, key: &str, value: &[u8]) {
+    output.push(' ');
+    output.push_str(key);
+    output.push(':');
+    output.extend(value.iter().copied().map(char::from));
+}
+
 /// Parse a modifier value using `FromStr`. Requires the modifier value to be valid UTF-8.
 fn parse_from_modifier_value<T: FromStr>(value: &Spanned<&[u8]>) -> Result<Option<T>, Error> {
     str::from_utf8(value)
@@ -21,6 +21,8 @@ macro_rules! validate_version {
 mod ast;
 mod format_item;
 mod lexer;
+mod render;
+mod strftime;
 
 /// A struct that is used to ensure that the version is valid.
 struct Version<const N: usize>;
@@ -82,10 +84,80 @@ pub fn parse_owned<const VERSION: usize>(
     let format_items = format_item::parse(ast);
     let items = format_items
         .map(|res| res.map(Into::into))
-        .collect::<Result<Box<_>, _>>()?;
+        .collect::<Result<Box<[_]>, _>>()?;
     Ok(items.into())
 }
 
+/// Parse a sequence of items from a `strftime`-style format string.
+///
+/// This permits reusing the patterns understood by C's `strftime` and `strptime`, such as
+/// `%Y-%m-%dT%H:%M:%S%z`. The following directives are supported:
+///
+/// | Directive | Meaning |
+/// |-----------|---------|
+/// | `%a`, `%A` | abbreviated and full weekday name |
+/// | `%b`, `%h`, `%B` | abbreviated and full month name |
+/// | `%c` | date and time, equivalent to `%a %b %e %H:%M:%S %Y` |
+/// | `%C` | century, zero-padded to two digits |
+/// | `%d`, `%e` | day of the month, padded with zeroes and spaces respectively |
+/// | `%D`, `%x` | equivalent to `%m/%d/%y` |
+/// | `%F` | equivalent to `%Y-%m-%d` |
+/// | `%g`, `%G` | ISO week-based year, last two digits and full |
+/// | `%H`, `%k` | hour on a 24-hour clock, padded with zeroes and spaces respectively |
+/// | `%I`, `%l` | hour on a 12-hour clock, padded with zeroes and spaces respectively |
+/// | `%j` | day of the year |
+/// | `%m` | month number |
+/// | `%M` | minute |
+/// | `%n`, `%t` | a newline and a tab respectively |
+/// | `%p`, `%P` | `AM`/`PM` and `am`/`pm` respectively |
+/// | `%r` | equivalent to `%I:%M:%S %p` |
+/// | `%R` | equivalent to `%H:%M` |
+/// | `%s` | Unix timestamp in seconds |
+/// | `%S` | second |
+/// | `%T`, `%X` | equivalent to `%H:%M:%S` |
+/// | `%u`, `%w` | weekday number, Monday = 1 and Sunday = 0 respectively |
+/// | `%U`, `%W`, `%V` | week number, Sunday-based, Monday-based and ISO respectively |
+/// | `%y`, `%Y` | year, last two digits and full |
+/// | `%z` | UTC offset as `+hhmm` |
+/// | `%Z` | time zone abbreviation |
+/// | `%%` | a literal `%` |
+///
+/// Numeric directives accept a padding flag between the `%` and the directive: `-` disables
+/// padding, `_` pads with spaces, and `0` pads with zeroes. As with glibc, the flag is ignored on
+/// all other directives. Names are matched case-insensitively when parsing, as with `strptime`.
+///
+/// `%Z` is equivalent to `[zone_abbr]`. The abbreviation is determined by the time zone, which an
+/// [`OffsetDateTime`](crate::OffsetDateTime) does not have. Formatting `%Z` therefore requires
+/// [`OffsetDateTime::format_in_zone`](crate::OffsetDateTime::format_in_zone). Formatting it with
+/// [`OffsetDateTime::format`](crate::OffsetDateTime::format) fails with
+/// [`Format::InsufficientTypeInformation`](crate::error::Format::InsufficientTypeInformation).
+///
+/// Unsupported directives result in [`InvalidFormatDescription::InvalidComponentName`], with the
+/// directive as the name.
+///
+/// [`InvalidFormatDescription::InvalidComponentName`]:
+///     crate::error::InvalidFormatDescription::InvalidComponentName
+pub fn parse_strftime_borrowed(
+    s: &str,
+) -> Result<Vec<crate::format_description::FormatItem<'_>>, crate::error::InvalidFormatDescription>
+{
+    strftime::parse(s)
+}
+
+/// Parse a sequence of items from a `strftime`-style format string.
+///
+/// The supported directives are listed on [`parse_strftime_borrowed`]. Unlike that function, this
+/// function returns [`OwnedFormatItem`], which owns its contents.
+///
+/// [`OwnedFormatItem`]: crate::format_description::OwnedFormatItem
+pub fn parse_strftime_owned(
+    s: &str,
+) -> Result<crate::format_description::OwnedFormatItem, crate::error::InvalidFormatDescription> {
+    strftime::parse(s).map(Into::into)
+}
+
+pub(crate) use render::to_description_string;
+
 /// A location within a string.
 #[derive(Clone, Copy)]
 struct Location {
@@ -0,0 +1,157 @@
+//! Rendering of format items back into a format description.
+
+use alloc::string::String;
+use core::str;
+
+use super::format_item::write_component;
+use crate::error::InvalidFormatDescription;
+use crate::format_description::{FormatItem, OwnedFormatItem};
+
+/// A format item that can be rendered as a format description. This permits sharing the logic
+/// between [`FormatItem`] and [`OwnedFormatItem`].
+pub(crate) trait Render {
+    /// Write the item to the output in the syntax of the given version. `nested` indicates whether
+    /// the item is inside an `optional` or `first` item.
+    fn render<const VERSION: usize>(
+        &self,
+        output: &mut String,
+        nested: bool,
+    ) -> Result<(), InvalidFormatDescription>;
+}
+
+/// Render the item as a format description of the given version.
+pub(crate) fn to_description_string<const VERSION: usize>(
+    item: &impl Render,
+) -> Result<String, InvalidFormatDescription> {
+    validate_version!(VERSION);
+    let mut output = String::new();
+    item.render::<VERSION>(&mut output, false)?;
+    Ok(output)
+}
+
+impl Render for FormatItem<'_> {
+    fn render<const VERSION: usize>(
+        &self,
+        output: &mut String,
+        nested: bool,
+    ) -> Result<(), InvalidFormatDescription> {
+        match *self {
+            Self::Literal(literal) => write_literal::<VERSION>(output, literal, nested),
+            Self::Component(component) => {
+                write_component(output, component);
+                Ok(())
+            }
+            Self::Compound(items) => items
+                .iter()
+                .try_for_each(|item| item.render::<VERSION>(output, nested)),
+            Self::Optional(item) => write_optional::<VERSION>(output, item),
+            Self::First(items) => write_first::<VERSION, _>(output, items),
+        }
+    }
+}
+
+impl Render for OwnedFormatItem {
+    fn render<const VERSION: usize>(
+        &self,
+        output: &mut String,
+        nested: bool,
+    ) -> Result<(), InvalidFormatDescription> {
+        match self {
+            Self::Literal(literal) => write_literal::<VERSION>(output, literal, nested),
+            Self::Component(component) => {
+                write_component(output, *component);
+                Ok(())
+            }
+            Self::Compound(items) => items
+                .iter()
+                .try_for_each(|item| item.render::<VERSION>(output, nested)),
+            Self::Optional(item) => write_optional::<VERSION>(output, &**item),
+            Self::First(items) => write_first::<VERSION, _>(output, items),
+        }
+    }
+}
+
+/// Write a literal, escaping any characters that would otherwise have a special meaning.
+fn write_literal<const VERSION: usize>(
+    output: &mut String,
+    literal: &[u8],
+    nested: bool,
+) -> Result<(), InvalidFormatDescription> {
+    let literal = str::from_utf8(literal).map_err(|_| InvalidFormatDescription::NotSupported {
+        what: "non-UTF-8 literal",
+        context: "format descriptions",
+        index: output.len(),
+    })?;
+
+    for char in literal.chars() {
+        match char {
+            '[' if version!(..=1) => output.push_str("[["),
+            // Version 1 has no way to escape a closing bracket, which would end the nested
+            // description.
+            ']' if version!(..=1) && nested => {
+                return Err(InvalidFormatDescription::NotSupported {
+                    what: "closing bracket in nested literal",
+                    context: "version 1 format descriptions",
+                    index: output.len(),
+                });
+            }
+            '\\' | '[' | ']' if version!(2..) => {
+                output.push('\\');
+                output.push(char);
+            }
+            _ => output.push(char),
+        }
+    }
+
+    Ok(())
+}
+
+/// Write an `optional` item containing the provided item.
+fn write_optional<const VERSION: usize>(
+    output: &mut String,
+    item: &impl Render,
+) -> Result<(), InvalidFormatDescription> {
+    output.push_str("[optional ");
+    write_nested::<VERSION>(output, item)?;
+    output.push(']');
+    Ok(())
+}
+
+/// Write a `first` item containing the provided items.
+fn write_first<const VERSION: usize, T: Render>(
+    output: &mut String,
+    items: &[T],
+) -> Result<(), InvalidFormatDescription> {
+    output.push_str("[first");
+    for item in items {
+        output.push(' ');
+        write_nested::<VERSION>(output, item)?;
+    }
+    // A `first` item with no descriptions still requires the whitespace after the keyword.
+    if items.is_empty() {
+        output.push(' ');
+    }
+    output.push(']');
+    Ok(())
+}
+
+/// Write a nested format description, including its surrounding brackets.
+fn write_nested<const VERSION: usize>(
+    output: &mut String,
+    item: &impl Render,
+) -> Result<(), InvalidFormatDescription> {
+    output.push('[');
+    let start = output.len();
+    item.render::<VERSION>(output, true)?;
+    // In version 1, the opening bracket of the nested description would be combined with the
+    // following bracket into an escaped bracket.
+    if version!(..=1) && output[start..].starts_with('[') {
+        return Err(InvalidFormatDescription::NotSupported {
+            what: "nested description starting with a bracket",
+            context: "version 1 format descriptions",
+            index: start,
+        });
+    }
+    output.push(']');
+    Ok(())
+}
@@ -0,0 +1,305 @@
+//! Conversion of `strftime`-style format strings into format items.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::error::InvalidFormatDescription;
+use crate::format_description::modifier::*;
+use crate::format_description::{Component, FormatItem};
+
+/// Shorthand for a component item, usable in `const` contexts.
+macro_rules! item {
+    ($name:ident { $($field:ident : $value:expr),* $(,)? }) => {
+        FormatItem::Component(Component::$name($name {
+            $($field: $value,)*
+            ..$name::default()
+        }))
+    };
+}
+
+/// `%Y`
+const YEAR: FormatItem<'static> = item!(Year {});
+/// `%y`
+const YEAR_LAST_TWO: FormatItem<'static> = item!(Year {
+    repr: YearRepr::LastTwo
+});
+/// `%m`
+const MONTH: FormatItem<'static> = item!(Month {});
+/// `%b`
+const MONTH_SHORT: FormatItem<'static> = item!(Month {
+    repr: MonthRepr::Short,
+    case_sensitive: false,
+});
+/// `%d`
+const DAY: FormatItem<'static> = item!(Day {});
+/// `%e`
+const DAY_SPACE: FormatItem<'static> = item!(Day {
+    padding: Padding::Space
+});
+/// `%a`
+const WEEKDAY_SHORT: FormatItem<'static> = item!(Weekday {
+    repr: WeekdayRepr::Short,
+    case_sensitive: false,
+});
+/// `%H`
+const HOUR: FormatItem<'static> = item!(Hour {});
+/// `%I`
+const HOUR_12: FormatItem<'static> = item!(Hour {
+    is_12_hour_clock: true
+});
+/// `%M`
+const MINUTE: FormatItem<'static> = item!(Minute {});
+/// `%S`
+const SECOND: FormatItem<'static> = item!(Second {});
+/// `%p`
+const PERIOD: FormatItem<'static> = item!(Period {
+    case_sensitive: false
+});
+
+/// `%c`, the date and time representation of the POSIX locale.
+const DATE_TIME: &[FormatItem<'static>] = &[
+    WEEKDAY_SHORT,
+    FormatItem::Literal(b" "),
+    MONTH_SHORT,
+    FormatItem::Literal(b" "),
+    DAY_SPACE,
+    FormatItem::Literal(b" "),
+    FormatItem::Compound(TIME),
+    FormatItem::Literal(b" "),
+    YEAR,
+];
+/// `%D` and `%x`
+const DATE_US: &[FormatItem<'static>] = &[
+    MONTH,
+    FormatItem::Literal(b"/"),
+    DAY,
+    FormatItem::Literal(b"/"),
+    YEAR_LAST_TWO,
+];
+/// `%F`
+const DATE_ISO: &[FormatItem<'static>] = &[
+    YEAR,
+    FormatItem::Literal(b"-"),
+    MONTH,
+    FormatItem::Literal(b"-"),
+    DAY,
+];
+/// `%T` and `%X`
+const TIME: &[FormatItem<'static>] = &[
+    HOUR,
+    FormatItem::Literal(b":"),
+    MINUTE,
+    FormatItem::Literal(b":"),
+    SECOND,
+];
+/// `%r`
+const TIME_12: &[FormatItem<'static>] = &[
+    HOUR_12,
+    FormatItem::Literal(b":"),
+    MINUTE,
+    FormatItem::Literal(b":"),
+    SECOND,
+    FormatItem::Literal(b" "),
+    PERIOD,
+];
+/// `%R`
+const HOUR_MINUTE: &[FormatItem<'static>] = &[HOUR, FormatItem::Literal(b":"), MINUTE];
+/// `%z`
+const OFFSET: &[FormatItem<'static>] = &[item!(OffsetHour {}), item!(OffsetMinute {})];
+
+/// Parse a `strftime`-style format string into a sequence of format items.
+///
+/// Literal text is borrowed from the input. See [`parse_strftime_borrowed`] for the supported
+/// directives.
+///
+/// [`parse_strftime_borrowed`]: crate::format_description::parse_strftime_borrowed
+pub(super) fn parse(s: &str) -> Result<Vec<FormatItem<'_>>, InvalidFormatDescription> {
+    let bytes = s.as_bytes();
+    let mut items = Vec::new();
+    let mut literal_start = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index] != b'%' {
+            index += 1;
+            continue;
+        }
+
+        if literal_start < index {
+            items.push(FormatItem::Literal(&bytes[literal_start..index]));
+        }
+        let percent_index = index;
+        index += 1;
+
+        let padding = match bytes.get(index) {
+            Some(b'-') => Some(Padding::None),
+            Some(b'_') => Some(Padding::Space),
+            Some(b'0') => Some(Padding::Zero),
+            _ => None,
+        };
+        if padding.is_some() {
+            index += 1;
+        }
+
+        // Directives are ASCII, but the input may contain any character after a `%`. Take the full
+        // character so that the error does not split it.
+        let directive = match s[index..].chars().next() {
+            Some(directive) => directive,
+            None => {
+                return Err(InvalidFormatDescription::Expected {
+                    what: "strftime directive",
+                    index: percent_index,
+                });
+            }
+        };
+
+        // As with glibc, a padding flag on a non-numeric directive is ignored.
+        let item = match padded_directive(directive, padding) {
+            Some(component) => FormatItem::Component(component),
+            None => match unpadded_directive(directive) {
+                Some(item) => item,
+                None => {
+                    return Err(InvalidFormatDescription::InvalidComponentName {
+                        name: String::from(directive),
+                        index,
+                    });
+                }
+            },
+        };
+        items.push(item);
+
+        index += directive.len_utf8();
+        literal_start = index;
+    }
+
+    if literal_start < bytes.len() {
+        items.push(FormatItem::Literal(&bytes[literal_start..]));
+    }
+
+    Ok(items)
+}
+
+/// Obtain the component for a numeric directive, which accepts a padding flag. If no flag is
+/// present, the padding specified by `strftime` is used.
+const fn padded_directive(directive: char, padding: Option<Padding>) -> Option<Component> {
+    /// Use the provided padding if present, falling back to the default otherwise.
+    const fn pad(padding: Option<Padding>, default: Padding) -> Padding {
+        match padding {
+            Some(padding) => padding,
+            None => default,
+        }
+    }
+
+    let zero = pad(padding, Padding::Zero);
+    let space = pad(padding, Padding::Space);
+
+    Some(match directive {
+        'C' => Component::Century(Century { padding: zero }),
+        'd' => Component::Day(Day {
+            padding: zero,
+            suffix: false,
+            case_sensitive: true,
+        }),
+        'e' => Component::Day(Day {
+            padding: space,
+            suffix: false,
+            case_sensitive: true,
+        }),
+        'G' => Component::Year(Year {
+            padding: zero,
+            iso_week_based: true,
+            ..Year::default()
+        }),
+        'g' => Component::Year(Year {
+            padding: zero,
+            repr: YearRepr::LastTwo,
+            iso_week_based: true,
+            sign_is_mandatory: false,
+        }),
+        'H' => Component::Hour(Hour {
+            padding: zero,
+            is_12_hour_clock: false,
+        }),
+        'I' => Component::Hour(Hour {
+            padding: zero,
+            is_12_hour_clock: true,
+        }),
+        'j' => Component::Ordinal(Ordinal { padding: zero }),
+        'k' => Component::Hour(Hour {
+            padding: space,
+            is_12_hour_clock: false,
+        }),
+        'l' => Component::Hour(Hour {
+            padding: space,
+            is_12_hour_clock: true,
+        }),
+        'M' => Component::Minute(Minute { padding: zero }),
+        'm' => Component::Month(Month {
+            padding: zero,
+            ..Month::default()
+        }),
+        'S' => Component::Second(Second { padding: zero }),
+        'U' => Component::WeekNumber(WeekNumber {
+            padding: zero,
+            repr: WeekNumberRepr::Sunday,
+        }),
+        'V' => Component::WeekNumber(WeekNumber {
+            padding: zero,
+            repr: WeekNumberRepr::Iso,
+        }),
+        'W' => Component::WeekNumber(WeekNumber {
+            padding: zero,
+            repr: WeekNumberRepr::Monday,
+        }),
+        'Y' => Component::Year(Year {
+            padding: zero,
+            ..Year::default()
+        }),
+        'y' => Component::Year(Year {
+            padding: zero,
+            repr: YearRepr::LastTwo,
+            ..Year::default()
+        }),
+        _ => return None,
+    })
+}
+
+/// Obtain the format item for a directive that is unaffected by padding flags.
+const fn unpadded_directive(directive: char) -> Option<FormatItem<'static>> {
+    Some(match directive {
+        'A' => item!(Weekday {
+            case_sensitive: false
+        }),
+        'a' => WEEKDAY_SHORT,
+        'B' => item!(Month {
+            repr: MonthRepr::Long,
+            case_sensitive: false,
+        }),
+        'b' | 'h' => MONTH_SHORT,
+        'c' => FormatItem::Compound(DATE_TIME),
+        'D' | 'x' => FormatItem::Compound(DATE_US),
+        'F' => FormatItem::Compound(DATE_ISO),
+        'n' => FormatItem::Literal(b"\n"),
+        'P' => item!(Period {
+            is_uppercase: false,
+            case_sensitive: false,
+        }),
+        'p' => PERIOD,
+        'R' => FormatItem::Compound(HOUR_MINUTE),
+        'r' => FormatItem::Compound(TIME_12),
+        's' => item!(UnixTimestamp {}),
+        'T' | 'X' => FormatItem::Compound(TIME),
+        't' => FormatItem::Literal(b"\t"),
+        'u' => item!(Weekday {
+            repr: WeekdayRepr::Monday
+        }),
+        'w' => item!(Weekday {
+            repr: WeekdayRepr::Sunday,
+            one_indexed: false,
+        }),
+        'Z' => item!(ZoneAbbr {}),
+        'z' => FormatItem::Compound(OFFSET),
+        '%' => FormatItem::Literal(b"%"),
+        _ => return None,
+    })
+}
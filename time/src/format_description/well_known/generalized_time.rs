@@ -0,0 +1,44 @@
+//! The ASN.1 `GeneralizedTime` format, as restricted by DER.
+
+/// The ASN.1 `GeneralizedTime` format, as restricted by the Distinguished Encoding Rules and used
+/// by X.509 certificates ([RFC 5280](https://www.rfc-editor.org/rfc/rfc5280#section-4.1.2.5.2)).
+///
+/// Format example: 20240102030405.123Z
+///
+/// The value is always in UTC and must include the seconds. A fractional second is permitted, but
+/// must not have trailing zeros; a whole second has no fractional part at all. When formatting,
+/// the value is converted to UTC and the fractional second uses the fewest digits necessary. When
+/// parsing, any offset other than `Z`, a comma as the decimal sign, and leap seconds are rejected.
+///
+/// Note that RFC 5280 itself does not permit fractional seconds in certificates. Values with a
+/// whole number of seconds are formatted without one.
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::{format_description::well_known::GeneralizedTime, OffsetDateTime};
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     OffsetDateTime::parse("20240102030405Z", &GeneralizedTime)?,
+///     datetime!(2024-01-02 03:04:05 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("20240102030405.12Z", &GeneralizedTime)?,
+///     datetime!(2024-01-02 03:04:05.12 UTC)
+/// );
+/// assert!(OffsetDateTime::parse("20240102030405.120Z", &GeneralizedTime).is_err());
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::GeneralizedTime;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     datetime!(2024-01-02 05:04:05.5 +2).format(&GeneralizedTime)?,
+///     "20240102030405.5Z"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeneralizedTime;
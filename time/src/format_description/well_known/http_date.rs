@@ -0,0 +1,47 @@
+//! The HTTP-date format described in RFC 9110.
+
+/// The HTTP-date format described in
+/// [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#section-5.6.7).
+///
+/// Values are always formatted as IMF-fixdate, the preferred format, after being converted to
+/// UTC. When parsing, the obsolete RFC 850 and asctime formats are also accepted. A two-digit year
+/// in the RFC 850 format is interpreted as 2000–2049 if it is less than 50 and 1950–1999
+/// otherwise.
+///
+/// Examples:
+/// - IMF-fixdate: Sun, 06 Nov 1994 08:49:37 GMT
+/// - RFC 850: Sunday, 06-Nov-94 08:49:37 GMT
+/// - asctime: Sun Nov  6 08:49:37 1994
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::{format_description::well_known::HttpDate, OffsetDateTime};
+/// use time_macros::datetime;
+/// assert_eq!(
+///     OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT", &HttpDate)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("Sunday, 06-Nov-94 08:49:37 GMT", &HttpDate)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("Sun Nov  6 08:49:37 1994", &HttpDate)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::HttpDate;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     datetime!(1994-11-06 02:49:37 -06:00).format(&HttpDate)?,
+///     "Sun, 06 Nov 1994 08:49:37 GMT"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HttpDate;
@@ -0,0 +1,70 @@
+//! The timestamp format described in RFC 3164.
+
+use crate::Date;
+
+/// The timestamp format of BSD syslog messages, described in
+/// [RFC 3164](https://www.rfc-editor.org/rfc/rfc3164#section-4.1.2).
+///
+/// Format example: Mar  3 14:05:09
+///
+/// The format contains neither the year nor the UTC offset. When formatting, the date and time are
+/// used as-is. When parsing, the day may be padded with either a space or a zero. The year is only
+/// set if a reference date was provided with [`Rfc3164::with_year_reference`], in which case the
+/// year that places the parsed date closest to the reference date is used. This handles messages
+/// logged shortly before or after the turn of the year. As no UTC offset is present, parsing an
+/// [`OffsetDateTime`](crate::OffsetDateTime) is not possible.
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::{format_description::well_known::Rfc3164, PrimitiveDateTime};
+/// # use time_macros::{date, datetime};
+/// assert_eq!(
+///     PrimitiveDateTime::parse(
+///         "Mar  3 14:05:09",
+///         &Rfc3164::with_year_reference(date!(2024 - 03 - 10))
+///     )?,
+///     datetime!(2024-03-03 14:05:09)
+/// );
+/// assert_eq!(
+///     PrimitiveDateTime::parse(
+///         "Dec 31 23:59:59",
+///         &Rfc3164::with_year_reference(date!(2024 - 01 - 01))
+///     )?,
+///     datetime!(2023-12-31 23:59:59)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Rfc3164;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     datetime!(2024-03-03 14:05:09).format(&Rfc3164::DEFAULT)?,
+///     "Mar  3 14:05:09"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rfc3164 {
+    /// The date used to infer the year when parsing.
+    pub(crate) year_reference: Option<Date>,
+}
+
+impl Rfc3164 {
+    /// An [`Rfc3164`] without a reference date. Parsing does not set the year.
+    pub const DEFAULT: Self = Self {
+        year_reference: None,
+    };
+
+    /// An [`Rfc3164`] that infers the year from the provided date when parsing.
+    ///
+    /// The year is chosen from the year of the reference date and the years immediately before and
+    /// after it, such that the parsed date is as close as possible to the reference date.
+    pub const fn with_year_reference(reference: Date) -> Self {
+        Self {
+            year_reference: Some(reference),
+        }
+    }
+}
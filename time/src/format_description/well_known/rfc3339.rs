@@ -1,9 +1,28 @@
 //! The format described in RFC 3339.
 
+// This provides a way to include `EncodedConfig` in documentation without displaying the type it is
+// aliased to.
+#[doc(hidden)]
+pub type DoNotRelyOnWhatThisIs = u32;
+
+/// An encoded [`Config`] that can be used as a const parameter to [`Rfc3339`](struct@Rfc3339).
+///
+/// The type this is aliased to must not be relied upon. It can change in any release without
+/// notice.
+pub type EncodedConfig = DoNotRelyOnWhatThisIs;
+
+/// The default configuration for [`Rfc3339`](struct@Rfc3339).
+const DEFAULT_CONFIG: EncodedConfig = Config::DEFAULT.encode();
+
 /// The format described in [RFC 3339](https://tools.ietf.org/html/rfc3339#section-5.6).
 ///
 /// Format example: 1985-04-12T23:20:50.52Z
 ///
+/// The const parameter `CONFIG` **must** be a value that was returned by [`Config::encode`].
+/// Passing any other value is **unspecified behavior**. The configuration only affects formatting,
+/// with the exception of the separator between the date and time. Parsing otherwise accepts exactly
+/// what the RFC permits, regardless of configuration.
+///
 /// # Examples
 #[cfg_attr(feature = "parsing", doc = "```rust")]
 #[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
@@ -26,5 +45,197 @@
 /// );
 /// # Ok::<_, time::Error>(())
 /// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Rfc3339<const CONFIG: EncodedConfig = DEFAULT_CONFIG> {
+    /// Prevent construction outside of this module, as the value namespace is occupied by the
+    /// constant of the same name.
+    _private: (),
+}
+
+/// The format described in [RFC 3339](https://tools.ietf.org/html/rfc3339#section-5.6), using the
+/// default configuration.
+///
+/// This constant permits the type to continue to be used as if it were a unit struct.
+#[allow(non_upper_case_globals)]
+pub const Rfc3339: Rfc3339 = Rfc3339 { _private: () };
+
+impl<const CONFIG: EncodedConfig> core::fmt::Debug for Rfc3339<CONFIG> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if CONFIG == DEFAULT_CONFIG {
+            return f.write_str("Rfc3339");
+        }
+
+        f.debug_struct("Rfc3339")
+            .field("config", &Config::decode(CONFIG))
+            .finish()
+    }
+}
+
+impl<const CONFIG: EncodedConfig> Rfc3339<CONFIG> {
+    /// An [`Rfc3339`](struct@Rfc3339) with the configuration provided by the const parameter.
+    ///
+    /// # Examples
+    #[cfg_attr(feature = "formatting", doc = "```rust")]
+    #[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+    /// # use time::format_description::well_known::{rfc3339, Rfc3339};
+    /// # use time_macros::datetime;
+    /// const CONFIG: rfc3339::EncodedConfig = rfc3339::Config::DEFAULT
+    ///     .set_subsecond_digits(rfc3339::SubsecondDigits::Fixed(3))
+    ///     .set_use_z_for_utc(false)
+    ///     .encode();
+    ///
+    /// assert_eq!(
+    ///     datetime!(1985-04-12 23:20:50.52 +00:00).format(&Rfc3339::<CONFIG>::FORMAT)?,
+    ///     "1985-04-12T23:20:50.520+00:00"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const FORMAT: Self = Self { _private: () };
+
+    /// The user-provided configuration for the RFC 3339 format.
+    #[cfg(any(feature = "formatting", feature = "parsing"))]
+    const CONFIG: Config = Config::decode(CONFIG);
+    /// The number of digits to use for the subsecond.
+    #[cfg(feature = "formatting")]
+    pub(crate) const SUBSECOND_DIGITS: SubsecondDigits = Self::CONFIG.subsecond_digits;
+    /// Whether a UTC offset of zero is formatted as `Z`.
+    #[cfg(feature = "formatting")]
+    pub(crate) const USE_Z_FOR_UTC: bool = Self::CONFIG.use_z_for_utc;
+    /// The separator between the date and time.
+    #[cfg(any(feature = "formatting", feature = "parsing"))]
+    pub(crate) const SEPARATOR: Separator = Self::CONFIG.separator;
+}
+
+/// The number of digits to use for the subsecond.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Rfc3339;
+pub enum SubsecondDigits {
+    /// Use the fewest digits necessary to represent the value exactly. The subsecond is omitted
+    /// entirely when it is zero.
+    Minimal,
+    /// Always use the provided number of digits, truncating any further precision. The value must
+    /// be at most nine. When the value is zero, the subsecond is always omitted.
+    Fixed(u8),
+}
+
+/// The separator between the date and time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Separator {
+    /// Use `T`, as required by the grammar of the RFC. Parsing also accepts `t`.
+    T,
+    /// Use a space, as permitted by the RFC for the sake of readability. Parsing only accepts a
+    /// space.
+    Space,
+}
+
+/// Configuration for [`Rfc3339`](struct@Rfc3339).
+// This is only used as a const generic, so there's no need to have a number of implementations on
+// it.
+#[allow(missing_copy_implementations)]
+#[doc(alias = "EncodedConfig")] // People will likely search for `EncodedConfig`, so show them this.
+#[derive(Debug)]
+pub struct Config {
+    /// The number of digits to use for the subsecond.
+    pub(crate) subsecond_digits: SubsecondDigits,
+    /// Whether a UTC offset of zero is formatted as `Z` rather than `+00:00`.
+    pub(crate) use_z_for_utc: bool,
+    /// The separator between the date and time.
+    pub(crate) separator: Separator,
+}
+
+impl Config {
+    /// A configuration for the [`Rfc3339`](struct@Rfc3339) format.
+    ///
+    /// The following is the default behavior:
+    ///
+    /// - The subsecond uses the minimal number of digits, and is omitted when zero.
+    /// - A UTC offset of zero is formatted as `Z`.
+    /// - The date and time are separated by `T`.
+    ///
+    /// If you need different behavior, use the setter methods on this struct.
+    pub const DEFAULT: Self = Self {
+        subsecond_digits: SubsecondDigits::Minimal,
+        use_z_for_utc: true,
+        separator: Separator::T,
+    };
+
+    /// Set the number of digits to use for the subsecond.
+    pub const fn set_subsecond_digits(self, subsecond_digits: SubsecondDigits) -> Self {
+        Self {
+            subsecond_digits,
+            ..self
+        }
+    }
+
+    /// Set whether a UTC offset of zero is formatted as `Z` rather than `+00:00`.
+    pub const fn set_use_z_for_utc(self, use_z_for_utc: bool) -> Self {
+        Self {
+            use_z_for_utc,
+            ..self
+        }
+    }
+
+    /// Set the separator between the date and time.
+    pub const fn set_separator(self, separator: Separator) -> Self {
+        Self { separator, ..self }
+    }
+
+    /// Encode the configuration, permitting it to be used as a const parameter of
+    /// [`Rfc3339`](struct@Rfc3339).
+    ///
+    /// The value returned by this method must only be used as a const parameter to
+    /// [`Rfc3339`](struct@Rfc3339). Any other usage is unspecified behavior.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if a fixed number of subsecond digits greater than nine is provided.
+    pub const fn encode(&self) -> EncodedConfig {
+        let mut bytes = [0; EncodedConfig::BITS as usize / 8];
+
+        bytes[0] = match self.subsecond_digits {
+            SubsecondDigits::Minimal => 0,
+            SubsecondDigits::Fixed(_) => 1,
+        };
+        bytes[1] = match self.subsecond_digits {
+            SubsecondDigits::Minimal => 0,
+            SubsecondDigits::Fixed(digits) => {
+                assert!(digits <= 9, "at most nine subsecond digits are permitted");
+                digits
+            }
+        };
+        bytes[2] = self.use_z_for_utc as _;
+        bytes[3] = match self.separator {
+            Separator::T => 0,
+            Separator::Space => 1,
+        };
+
+        EncodedConfig::from_be_bytes(bytes)
+    }
+
+    /// Decode the configuration. The configuration must have been generated from
+    /// [`Config::encode`].
+    const fn decode(encoded: EncodedConfig) -> Self {
+        let bytes = encoded.to_be_bytes();
+
+        let subsecond_digits = match (bytes[0], bytes[1]) {
+            (0, 0) => SubsecondDigits::Minimal,
+            (1, digits @ 0..=9) => SubsecondDigits::Fixed(digits),
+            _ => panic!("invalid configuration"),
+        };
+        let use_z_for_utc = match bytes[2] {
+            0 => false,
+            1 => true,
+            _ => panic!("invalid configuration"),
+        };
+        let separator = match bytes[3] {
+            0 => Separator::T,
+            1 => Separator::Space,
+            _ => panic!("invalid configuration"),
+        };
+
+        Self {
+            subsecond_digits,
+            use_z_for_utc,
+            separator,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! The timestamp format described in RFC 5424.
+
+/// The timestamp format of syslog messages, described in
+/// [RFC 5424](https://www.rfc-editor.org/rfc/rfc5424#section-6.2.3).
+///
+/// Format example: 2024-03-03T14:05:09.123456+01:00
+///
+/// This is a restricted form of RFC 3339. The separator and `Z` must be uppercase, leap seconds are
+/// not permitted, and at most six fractional digits may be present. When formatting, the subsecond
+/// uses the minimal number of digits and is truncated to microsecond precision.
+///
+/// The RFC permits the NILVALUE `-` in place of a timestamp. Parsing it succeeds but sets no
+/// components, such that converting to any type fails with
+/// [`TryFromParsed::InsufficientInformation`](crate::error::TryFromParsed::InsufficientInformation).
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::{format_description::well_known::Rfc5424, OffsetDateTime};
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     OffsetDateTime::parse("2024-03-03T14:05:09.123456+01:00", &Rfc5424)?,
+///     datetime!(2024-03-03 14:05:09.123_456 +01:00)
+/// );
+/// assert!(OffsetDateTime::parse("2024-03-03T14:05:09.1234567+01:00", &Rfc5424).is_err());
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Rfc5424;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     datetime!(2024-03-03 14:05:09.123_456_789 +01:00).format(&Rfc5424)?,
+///     "2024-03-03T14:05:09.123456+01:00"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rfc5424;
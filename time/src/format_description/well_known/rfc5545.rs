@@ -0,0 +1,80 @@
+//! The iCalendar value formats described in RFC 5545.
+
+/// The `DATE`, `DATE-TIME` and `DURATION` value formats of iCalendar, described in
+/// [RFC 5545](https://www.rfc-editor.org/rfc/rfc5545#section-3.3).
+///
+/// Examples:
+/// - `DATE`: 20240101
+/// - floating `DATE-TIME`: 20240101T090000
+/// - UTC `DATE-TIME`: 20240101T090000Z
+/// - `DATE-TIME` with a time zone: TZID=Europe/Paris:20240101T090000
+/// - `DURATION`: P15DT5H0M20S, -PT15M, P7W
+///
+/// Which form is used when formatting depends on the value:
+///
+/// - A [`Date`](crate::Date) is formatted as a `DATE`.
+/// - A [`PrimitiveDateTime`](crate::PrimitiveDateTime) is formatted as a floating `DATE-TIME`.
+/// - An [`OffsetDateTime`](crate::OffsetDateTime) is converted to UTC and formatted as a UTC
+///   `DATE-TIME`. When formatted with [`format_in_zone`](crate::OffsetDateTime::format_in_zone) and
+///   a time zone that has a name, the local time is formatted with the `TZID` parameter instead.
+/// - A [`Duration`](crate::Duration) is formatted as a `DURATION`.
+///
+/// As the formats have no fractional seconds, any subsecond precision is truncated.
+///
+/// When parsing, a floating or `TZID` value can only be parsed into a `PrimitiveDateTime`, as it
+/// does not contain a UTC offset. The time zone identifier can be obtained with
+/// [`Parsed::parse_using`](crate::parsing::Parsed::parse_using) and resolved with
+/// [`PrimitiveDateTime::assume_zone`](crate::PrimitiveDateTime::assume_zone). A UTC value can be
+/// parsed into either type. A week-based duration is treated as seven days per week.
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Rfc5545;
+/// # use time::parsing::Parsed;
+/// # use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime};
+/// # use time_macros::{date, datetime};
+/// assert_eq!(Date::parse("20240101", &Rfc5545)?, date!(2024 - 01 - 01));
+/// assert_eq!(
+///     PrimitiveDateTime::parse("20240101T090000", &Rfc5545)?,
+///     datetime!(2024-01-01 9:00)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("20240101T090000Z", &Rfc5545)?,
+///     datetime!(2024-01-01 9:00 UTC)
+/// );
+/// assert_eq!(
+///     Duration::parse("P15DT5H0M20S", &Rfc5545)?,
+///     Duration::days(15) + Duration::hours(5) + Duration::seconds(20)
+/// );
+///
+/// let mut parsed = Parsed::new();
+/// parsed.parse_using(b"TZID=Europe/Paris:20240101T090000", &Rfc5545)?;
+/// assert_eq!(parsed.zone_name(), Some("Europe/Paris"));
+/// assert_eq!(
+///     PrimitiveDateTime::try_from(parsed)?,
+///     datetime!(2024-01-01 9:00)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Rfc5545;
+/// # use time::Duration;
+/// # use time_macros::{date, datetime};
+/// assert_eq!(date!(2024 - 01 - 01).format(&Rfc5545)?, "20240101");
+/// assert_eq!(datetime!(2024-01-01 9:00).format(&Rfc5545)?, "20240101T090000");
+/// assert_eq!(
+///     datetime!(2024-01-01 9:00 +1).format(&Rfc5545)?,
+///     "20240101T080000Z"
+/// );
+/// assert_eq!(
+///     (-Duration::minutes(90)).format(&Rfc5545)?,
+///     "-PT1H30M"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[doc(alias = "iCalendar")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rfc5545;
@@ -0,0 +1,65 @@
+//! The SQL timestamp literal format.
+
+/// The format of date, time, and timestamp literals in SQL (ISO 9075), as written by PostgreSQL.
+///
+/// Format examples:
+/// - date: 2024-01-02
+/// - time: 03:04:05.123456
+/// - timestamp: 2024-01-02 03:04:05.123456
+/// - timestamp with time zone: 2024-01-02 03:04:05.123456+00
+///
+/// Which components are formatted depends on the value: a [`Date`](crate::Date) is formatted as
+/// a date, a [`Time`](crate::Time) as a time, a [`PrimitiveDateTime`](crate::PrimitiveDateTime)
+/// as a timestamp, and an [`OffsetDateTime`](crate::OffsetDateTime) as a timestamp with time zone.
+/// As with PostgreSQL, the fractional second has at most six digits, with any further precision
+/// truncated, and trailing zeros are omitted. The minutes and seconds of the offset are only
+/// included when they are not zero. Years before 1 are formatted with the ` BC` suffix, as SQL has
+/// no year zero: the year 0 is 1 BC.
+///
+/// When parsing, the variations PostgreSQL may produce or accept are permitted:
+///
+/// - The date and time may be separated by `T` rather than a space.
+/// - The seconds and fractional second are optional, and the fractional second may have up to nine
+///   digits.
+/// - The offset may be `+hh`, `+hh:mm`, `+hh:mm:ss`, or `+hhmm`.
+/// - The year may have more than four digits, and the value may end with ` BC`.
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Sql;
+/// # use time::{OffsetDateTime, PrimitiveDateTime};
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     OffsetDateTime::parse("2024-01-02 03:04:05.123456+00", &Sql)?,
+///     datetime!(2024-01-02 03:04:05.123456 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("2024-01-02T03:04:05-05:30", &Sql)?,
+///     datetime!(2024-01-02 03:04:05 -5:30)
+/// );
+/// assert_eq!(
+///     PrimitiveDateTime::parse("0044-03-15 12:00:00 BC", &Sql)?,
+///     datetime!(-0043-03-15 12:00)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Sql;
+/// # use time_macros::{date, datetime};
+/// assert_eq!(
+///     datetime!(2024-01-02 03:04:05.123456 UTC).format(&Sql)?,
+///     "2024-01-02 03:04:05.123456+00"
+/// );
+/// assert_eq!(
+///     datetime!(2024-01-02 03:04:05.5).format(&Sql)?,
+///     "2024-01-02 03:04:05.5"
+/// );
+/// assert_eq!(date!(-0043 - 03 - 15).format(&Sql)?, "0044-03-15 BC");
+/// # Ok::<_, time::Error>(())
+/// ```
+#[doc(alias = "ISO 9075")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sql;
@@ -0,0 +1,44 @@
+//! The ASN.1 `UTCTime` format, as restricted by DER and RFC 5280.
+
+/// The ASN.1 `UTCTime` format, as restricted by the Distinguished Encoding Rules and used by
+/// X.509 certificates ([RFC 5280](https://www.rfc-editor.org/rfc/rfc5280#section-4.1.2.5.1)).
+///
+/// Format example: 240102030405Z
+///
+/// The two-digit year is interpreted using the RFC 5280 pivot: values from 50 through 99 are in
+/// the 1900s, while values from 00 through 49 are in the 2000s. As a result, only years 1950
+/// through 2049 can be represented.
+///
+/// The value is always in UTC and must include the seconds. When formatting, the value is
+/// converted to UTC and any subsecond precision is truncated. When parsing, any offset other than
+/// `Z` is rejected, as are leap seconds.
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::{format_description::well_known::UtcTime, OffsetDateTime};
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     OffsetDateTime::parse("240102030405Z", &UtcTime)?,
+///     datetime!(2024-01-02 03:04:05 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("991231235959Z", &UtcTime)?,
+///     datetime!(1999-12-31 23:59:59 UTC)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::UtcTime;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     datetime!(2024-01-02 05:04:05 +2).format(&UtcTime)?,
+///     "240102030405Z"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[doc(alias = "UTCTime")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UtcTime;
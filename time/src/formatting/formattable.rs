@@ -3,13 +3,23 @@
 use core::ops::Deref;
 use std::io;
 
+use crate::date_time::offset_kind;
+use crate::format_description::locale::{Locale, Localized};
 use crate::format_description::well_known::iso8601::EncodedConfig;
-use crate::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+use crate::format_description::well_known::rfc3339::{
+    EncodedConfig as Rfc3339EncodedConfig, Separator, SubsecondDigits,
+};
+use crate::format_description::well_known::{
+    GeneralizedTime, HttpDate, Iso8601, Rfc2822, Rfc3164, Rfc3339, Rfc5424, Rfc5545, Sql,
+    UtcTime,
+};
 use crate::format_description::{FormatItem, OwnedFormatItem};
 use crate::formatting::{
-    format_component, format_number_pad_zero, iso8601, write, MONTH_NAMES, WEEKDAY_NAMES,
+    format_component, format_duration_component, format_number_pad_none, format_number_pad_space,
+    format_number_pad_zero, iso8601, write, MONTH_NAMES, WEEKDAY_NAMES,
 };
-use crate::{error, Date, Time, UtcOffset};
+use crate::zone::TimeZone;
+use crate::{error, Date, DateTime, Duration, Month, Time, UtcOffset};
 
 /// A type that describes a format.
 ///
@@ -23,9 +33,17 @@ impl Formattable for FormatItem<'_> {}
 impl Formattable for [FormatItem<'_>] {}
 impl Formattable for OwnedFormatItem {}
 impl Formattable for [OwnedFormatItem] {}
-impl Formattable for Rfc3339 {}
+impl<const CONFIG: Rfc3339EncodedConfig> Formattable for Rfc3339<CONFIG> {}
 impl Formattable for Rfc2822 {}
+impl Formattable for HttpDate {}
+impl Formattable for Rfc3164 {}
+impl Formattable for Rfc5424 {}
+impl Formattable for Rfc5545 {}
+impl Formattable for UtcTime {}
+impl Formattable for GeneralizedTime {}
+impl Formattable for Sql {}
 impl<const CONFIG: EncodedConfig> Formattable for Iso8601<CONFIG> {}
+impl<T: Formattable + ?Sized> Formattable for Localized<'_, T> {}
 impl<T: Deref> Formattable for T where T::Target: Formattable {}
 
 /// Seal the trait to prevent downstream users from implementing it.
@@ -42,6 +60,8 @@ mod sealed {
             date: Option<Date>,
             time: Option<Time>,
             offset: Option<UtcOffset>,
+            zone: Option<&dyn TimeZone>,
+            locale: Option<&dyn Locale>,
         ) -> Result<usize, error::Format>;
 
         /// Format the item directly to a `String`.
@@ -50,11 +70,30 @@ mod sealed {
             date: Option<Date>,
             time: Option<Time>,
             offset: Option<UtcOffset>,
+            zone: Option<&dyn TimeZone>,
+            locale: Option<&dyn Locale>,
         ) -> Result<String, error::Format> {
             let mut buf = Vec::new();
-            self.format_into(&mut buf, date, time, offset)?;
+            self.format_into(&mut buf, date, time, offset, zone, locale)?;
             Ok(String::from_utf8_lossy(&buf).into_owned())
         }
+
+        /// Format a [`Duration`] into the provided output, returning the number of bytes written.
+        ///
+        /// Only format descriptions consisting of components applicable to a duration can be used.
+        fn format_duration_into(
+            &self,
+            _output: &mut impl io::Write,
+            _duration: Duration,
+        ) -> Result<usize, error::Format> {
+            Err(error::Format::InsufficientTypeInformation)
+        }
+
+        /// Whether formatting a [`Duration`] writes its sign. If it does not, a negative duration
+        /// is preceded by `-` so that it is not mistaken for its magnitude.
+        fn writes_duration_sign(&self) -> bool {
+            true
+        }
     }
 }
 
@@ -66,18 +105,43 @@ impl<'a> sealed::Sealed for FormatItem<'a> {
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        zone: Option<&dyn TimeZone>,
+        locale: Option<&dyn Locale>,
     ) -> Result<usize, error::Format> {
         Ok(match *self {
             Self::Literal(literal) => write(output, literal)?,
-            Self::Component(component) => format_component(output, component, date, time, offset)?,
-            Self::Compound(items) => items.format_into(output, date, time, offset)?,
-            Self::Optional(item) => item.format_into(output, date, time, offset)?,
+            Self::Component(component) => {
+                format_component(output, component, date, time, offset, zone, locale)?
+            }
+            Self::Compound(items) => items.format_into(output, date, time, offset, zone, locale)?,
+            Self::Optional(item) => item.format_into(output, date, time, offset, zone, locale)?,
             Self::First(items) => match items {
                 [] => 0,
-                [item, ..] => item.format_into(output, date, time, offset)?,
+                [item, ..] => item.format_into(output, date, time, offset, zone, locale)?,
             },
         })
     }
+
+    fn format_duration_into(
+        &self,
+        output: &mut impl io::Write,
+        duration: Duration,
+    ) -> Result<usize, error::Format> {
+        Ok(match *self {
+            Self::Literal(literal) => write(output, literal)?,
+            Self::Component(component) => format_duration_component(output, component, duration)?,
+            Self::Compound(items) => items.format_duration_into(output, duration)?,
+            Self::Optional(item) => item.format_duration_into(output, duration)?,
+            Self::First(items) => match items {
+                [] => 0,
+                [item, ..] => item.format_duration_into(output, duration)?,
+            },
+        })
+    }
+
+    fn writes_duration_sign(&self) -> bool {
+        self.has_duration_sign()
+    }
 }
 
 impl<'a> sealed::Sealed for [FormatItem<'a>] {
@@ -87,13 +151,31 @@ impl<'a> sealed::Sealed for [FormatItem<'a>] {
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        zone: Option<&dyn TimeZone>,
+        locale: Option<&dyn Locale>,
     ) -> Result<usize, error::Format> {
         let mut bytes = 0;
         for item in self.iter() {
-            bytes += item.format_into(output, date, time, offset)?;
+            bytes += item.format_into(output, date, time, offset, zone, locale)?;
         }
         Ok(bytes)
     }
+
+    fn format_duration_into(
+        &self,
+        output: &mut impl io::Write,
+        duration: Duration,
+    ) -> Result<usize, error::Format> {
+        let mut bytes = 0;
+        for item in self.iter() {
+            bytes += item.format_duration_into(output, duration)?;
+        }
+        Ok(bytes)
+    }
+
+    fn writes_duration_sign(&self) -> bool {
+        self.iter().any(|item| item.has_duration_sign())
+    }
 }
 
 impl sealed::Sealed for OwnedFormatItem {
@@ -103,18 +185,43 @@ impl sealed::Sealed for OwnedFormatItem {
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        zone: Option<&dyn TimeZone>,
+        locale: Option<&dyn Locale>,
     ) -> Result<usize, error::Format> {
         match self {
             Self::Literal(literal) => Ok(write(output, literal)?),
-            Self::Component(component) => format_component(output, *component, date, time, offset),
-            Self::Compound(items) => items.format_into(output, date, time, offset),
-            Self::Optional(item) => item.format_into(output, date, time, offset),
+            Self::Component(component) => {
+                format_component(output, *component, date, time, offset, zone, locale)
+            }
+            Self::Compound(items) => items.format_into(output, date, time, offset, zone, locale),
+            Self::Optional(item) => item.format_into(output, date, time, offset, zone, locale),
             Self::First(items) => match &**items {
                 [] => Ok(0),
-                [item, ..] => item.format_into(output, date, time, offset),
+                [item, ..] => item.format_into(output, date, time, offset, zone, locale),
             },
         }
     }
+
+    fn format_duration_into(
+        &self,
+        output: &mut impl io::Write,
+        duration: Duration,
+    ) -> Result<usize, error::Format> {
+        match self {
+            Self::Literal(literal) => Ok(write(output, literal)?),
+            Self::Component(component) => format_duration_component(output, *component, duration),
+            Self::Compound(items) => items.format_duration_into(output, duration),
+            Self::Optional(item) => item.format_duration_into(output, duration),
+            Self::First(items) => match &**items {
+                [] => Ok(0),
+                [item, ..] => item.format_duration_into(output, duration),
+            },
+        }
+    }
+
+    fn writes_duration_sign(&self) -> bool {
+        self.has_duration_sign()
+    }
 }
 
 impl sealed::Sealed for [OwnedFormatItem] {
@@ -124,13 +231,31 @@ impl sealed::Sealed for [OwnedFormatItem] {
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        zone: Option<&dyn TimeZone>,
+        locale: Option<&dyn Locale>,
+    ) -> Result<usize, error::Format> {
+        let mut bytes = 0;
+        for item in self.iter() {
+            bytes += item.format_into(output, date, time, offset, zone, locale)?;
+        }
+        Ok(bytes)
+    }
+
+    fn format_duration_into(
+        &self,
+        output: &mut impl io::Write,
+        duration: Duration,
     ) -> Result<usize, error::Format> {
         let mut bytes = 0;
         for item in self.iter() {
-            bytes += item.format_into(output, date, time, offset)?;
+            bytes += item.format_duration_into(output, duration)?;
         }
         Ok(bytes)
     }
+
+    fn writes_duration_sign(&self) -> bool {
+        self.iter().any(|item| item.has_duration_sign())
+    }
 }
 
 impl<T: Deref> sealed::Sealed for T
@@ -143,8 +268,50 @@ where
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        zone: Option<&dyn TimeZone>,
+        locale: Option<&dyn Locale>,
+    ) -> Result<usize, error::Format> {
+        self.deref()
+            .format_into(output, date, time, offset, zone, locale)
+    }
+
+    fn format_duration_into(
+        &self,
+        output: &mut impl io::Write,
+        duration: Duration,
+    ) -> Result<usize, error::Format> {
+        self.deref().format_duration_into(output, duration)
+    }
+
+    fn writes_duration_sign(&self) -> bool {
+        self.deref().writes_duration_sign()
+    }
+}
+
+impl<T: sealed::Sealed + ?Sized> sealed::Sealed for Localized<'_, T> {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        zone: Option<&dyn TimeZone>,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<usize, error::Format> {
+        self.format
+            .format_into(output, date, time, offset, zone, Some(self.locale))
+    }
+
+    fn format_duration_into(
+        &self,
+        output: &mut impl io::Write,
+        duration: Duration,
     ) -> Result<usize, error::Format> {
-        self.deref().format_into(output, date, time, offset)
+        self.format.format_duration_into(output, duration)
+    }
+
+    fn writes_duration_sign(&self) -> bool {
+        self.format.writes_duration_sign()
     }
 }
 // endregion custom formats
@@ -157,6 +324,8 @@ impl sealed::Sealed for Rfc2822 {
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        _zone: Option<&dyn TimeZone>,
+        _locale: Option<&dyn Locale>,
     ) -> Result<usize, error::Format> {
         let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
         let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
@@ -198,13 +367,91 @@ impl sealed::Sealed for Rfc2822 {
     }
 }
 
-impl sealed::Sealed for Rfc3339 {
+impl sealed::Sealed for HttpDate {
     fn format_into(
         &self,
         output: &mut impl io::Write,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        _zone: Option<&dyn TimeZone>,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<usize, error::Format> {
+        let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+        let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+        let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+
+        // HTTP dates are always in UTC.
+        let (year, ordinal, time) =
+            DateTime::<offset_kind::Fixed> { date, time, offset }.to_offset_raw(UtcOffset::UTC);
+        if !(0..10_000).contains(&year) {
+            return Err(error::Format::InvalidComponent("year"));
+        }
+        let date = Date::__from_ordinal_date_unchecked(year, ordinal);
+        let (_, month, day) = date.to_calendar_date();
+
+        let mut bytes = 0;
+
+        bytes += write(
+            output,
+            &WEEKDAY_NAMES[date.weekday().number_days_from_monday() as usize][..3],
+        )?;
+        bytes += write(output, b", ")?;
+        bytes += format_number_pad_zero::<2>(output, day)?;
+        bytes += write(output, b" ")?;
+        bytes += write(output, &MONTH_NAMES[month as usize - 1][..3])?;
+        bytes += write(output, b" ")?;
+        bytes += format_number_pad_zero::<4>(output, year as u32)?;
+        bytes += write(output, b" ")?;
+        bytes += format_number_pad_zero::<2>(output, time.hour())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<2>(output, time.minute())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<2>(output, time.second())?;
+        bytes += write(output, b" GMT")?;
+
+        Ok(bytes)
+    }
+}
+
+impl sealed::Sealed for Rfc3164 {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        _offset: Option<UtcOffset>,
+        _zone: Option<&dyn TimeZone>,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<usize, error::Format> {
+        let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+        let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+
+        let mut bytes = 0;
+
+        bytes += write(output, &MONTH_NAMES[date.month() as usize - 1][..3])?;
+        bytes += write(output, b" ")?;
+        bytes += format_number_pad_space::<2>(output, date.day())?;
+        bytes += write(output, b" ")?;
+        bytes += format_number_pad_zero::<2>(output, time.hour())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<2>(output, time.minute())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<2>(output, time.second())?;
+
+        Ok(bytes)
+    }
+}
+
+impl sealed::Sealed for Rfc5424 {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        _zone: Option<&dyn TimeZone>,
+        _locale: Option<&dyn Locale>,
     ) -> Result<usize, error::Format> {
         let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
         let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
@@ -233,8 +480,388 @@ impl sealed::Sealed for Rfc3339 {
         bytes += write(output, b":")?;
         bytes += format_number_pad_zero::<2>(output, time.second())?;
 
+        // The RFC permits at most six fractional digits.
+        let micros = time.microsecond();
+        if micros != 0 {
+            bytes += write(output, b".")?;
+            bytes += if micros % 10 != 0 {
+                format_number_pad_zero::<6>(output, micros)
+            } else if (micros / 10) % 10 != 0 {
+                format_number_pad_zero::<5>(output, micros / 10)
+            } else if (micros / 100) % 10 != 0 {
+                format_number_pad_zero::<4>(output, micros / 100)
+            } else if (micros / 1_000) % 10 != 0 {
+                format_number_pad_zero::<3>(output, micros / 1_000)
+            } else if (micros / 10_000) % 10 != 0 {
+                format_number_pad_zero::<2>(output, micros / 10_000)
+            } else {
+                format_number_pad_zero::<1>(output, micros / 100_000)
+            }?;
+        }
+
+        if offset == UtcOffset::UTC {
+            bytes += write(output, b"Z")?;
+            return Ok(bytes);
+        }
+
+        bytes += write(output, if offset.is_negative() { b"-" } else { b"+" })?;
+        bytes += format_number_pad_zero::<2>(output, offset.whole_hours().unsigned_abs())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<2>(output, offset.minutes_past_hour().unsigned_abs())?;
+
+        Ok(bytes)
+    }
+}
+
+impl sealed::Sealed for Rfc5545 {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        zone: Option<&dyn TimeZone>,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<usize, error::Format> {
+        let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+
+        let mut bytes = 0;
+
+        // A UTC value is used unless the time zone can be identified.
+        let zone_name = zone.and_then(TimeZone::name);
+        let (date, time) = match (time, offset) {
+            (Some(time), Some(offset)) if zone_name.is_none() => {
+                let (year, ordinal, time) = DateTime::<offset_kind::Fixed> { date, time, offset }
+                    .to_offset_raw(UtcOffset::UTC);
+                if !(0..10_000).contains(&year) {
+                    return Err(error::Format::InvalidComponent("year"));
+                }
+                let date = Date::__from_ordinal_date_unchecked(year, ordinal);
+                (date, Some(time))
+            }
+            _ => (date, time),
+        };
+
+        if !(0..10_000).contains(&date.year()) {
+            return Err(error::Format::InvalidComponent("year"));
+        }
+
+        if let (Some(zone_name), Some(_), Some(_)) = (zone_name, time, offset) {
+            bytes += write(output, b"TZID=")?;
+            bytes += write(output, zone_name.as_bytes())?;
+            bytes += write(output, b":")?;
+        }
+
+        bytes += format_number_pad_zero::<4>(output, date.year() as u32)?;
+        bytes += format_number_pad_zero::<2>(output, date.month() as u8)?;
+        bytes += format_number_pad_zero::<2>(output, date.day())?;
+
+        if let Some(time) = time {
+            bytes += write(output, b"T")?;
+            bytes += format_number_pad_zero::<2>(output, time.hour())?;
+            bytes += format_number_pad_zero::<2>(output, time.minute())?;
+            bytes += format_number_pad_zero::<2>(output, time.second())?;
+
+            if offset.is_some() && zone_name.is_none() {
+                bytes += write(output, b"Z")?;
+            }
+        }
+
+        Ok(bytes)
+    }
+
+    fn format_duration_into(
+        &self,
+        output: &mut impl io::Write,
+        duration: Duration,
+    ) -> Result<usize, error::Format> {
+        let mut bytes = 0;
+
+        if duration.is_negative() {
+            bytes += write(output, b"-")?;
+        }
+        bytes += write(output, b"P")?;
+
+        let seconds = duration.whole_seconds().unsigned_abs();
+        let (weeks, days) = (seconds / 604_800, seconds / 86_400);
+        let (hours, minutes, seconds) = (seconds / 3_600 % 24, seconds / 60 % 60, seconds % 60);
+
+        if days != 0 && days % 7 == 0 && hours == 0 && minutes == 0 && seconds == 0 {
+            bytes += format_number_pad_none(output, weeks)?;
+            bytes += write(output, b"W")?;
+            return Ok(bytes);
+        }
+
+        if days != 0 {
+            bytes += format_number_pad_none(output, days)?;
+            bytes += write(output, b"D")?;
+        }
+        if hours == 0 && minutes == 0 && seconds == 0 && days != 0 {
+            return Ok(bytes);
+        }
+
+        // The grammar requires that no unit be skipped between the first and last that are
+        // present, so zero values are written where necessary.
+        bytes += write(output, b"T")?;
+        if hours != 0 {
+            bytes += format_number_pad_none(output, hours)?;
+            bytes += write(output, b"H")?;
+        }
+        if minutes != 0 || (hours != 0 && seconds != 0) {
+            bytes += format_number_pad_none(output, minutes)?;
+            bytes += write(output, b"M")?;
+        }
+        if seconds != 0 || (hours == 0 && minutes == 0) {
+            bytes += format_number_pad_none(output, seconds)?;
+            bytes += write(output, b"S")?;
+        }
+
+        Ok(bytes)
+    }
+}
+
+impl sealed::Sealed for UtcTime {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        _zone: Option<&dyn TimeZone>,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<usize, error::Format> {
+        let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+        let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+        let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+
+        let (year, ordinal, time) =
+            DateTime::<offset_kind::Fixed> { date, time, offset }.to_offset_raw(UtcOffset::UTC);
+        // Two-digit years use the pivot from RFC 5280.
+        if !(1950..2050).contains(&year) {
+            return Err(error::Format::InvalidComponent("year"));
+        }
+        let (_, month, day) = Date::__from_ordinal_date_unchecked(year, ordinal).to_calendar_date();
+
+        let mut bytes = 0;
+        bytes += format_number_pad_zero::<2>(output, (year % 100) as u32)?;
+        bytes += format_date_time_digits(output, month, day, time)?;
+        bytes += write(output, b"Z")?;
+
+        Ok(bytes)
+    }
+}
+
+impl sealed::Sealed for GeneralizedTime {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        _zone: Option<&dyn TimeZone>,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<usize, error::Format> {
+        let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+        let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+        let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+
+        let (year, ordinal, time) =
+            DateTime::<offset_kind::Fixed> { date, time, offset }.to_offset_raw(UtcOffset::UTC);
+        if !(0..10_000).contains(&year) {
+            return Err(error::Format::InvalidComponent("year"));
+        }
+        let (_, month, day) = Date::__from_ordinal_date_unchecked(year, ordinal).to_calendar_date();
+
+        let mut bytes = 0;
+        bytes += format_number_pad_zero::<4>(output, year as u32)?;
+        bytes += format_date_time_digits(output, month, day, time)?;
+
+        // DER forbids trailing zeros, so the fewest digits necessary are used.
+        let nanos = time.nanosecond();
+        if nanos != 0 {
+            bytes += write(output, b".")?;
+            let mut digits = 9;
+            let mut value = nanos;
+            while value % 10 == 0 {
+                value /= 10;
+                digits -= 1;
+            }
+            bytes += match digits {
+                1 => format_number_pad_zero::<1>(output, value),
+                2 => format_number_pad_zero::<2>(output, value),
+                3 => format_number_pad_zero::<3>(output, value),
+                4 => format_number_pad_zero::<4>(output, value),
+                5 => format_number_pad_zero::<5>(output, value),
+                6 => format_number_pad_zero::<6>(output, value),
+                7 => format_number_pad_zero::<7>(output, value),
+                8 => format_number_pad_zero::<8>(output, value),
+                _ => format_number_pad_zero::<9>(output, value),
+            }?;
+        }
+        bytes += write(output, b"Z")?;
+
+        Ok(bytes)
+    }
+}
+
+/// Format the month, day, hour, minute, and second as consecutive two-digit numbers, as used by
+/// the ASN.1 time formats.
+fn format_date_time_digits(
+    output: &mut impl io::Write,
+    month: Month,
+    day: u8,
+    time: Time,
+) -> Result<usize, io::Error> {
+    let mut bytes = 0;
+    bytes += format_number_pad_zero::<2>(output, month as u8)?;
+    bytes += format_number_pad_zero::<2>(output, day)?;
+    bytes += format_number_pad_zero::<2>(output, time.hour())?;
+    bytes += format_number_pad_zero::<2>(output, time.minute())?;
+    bytes += format_number_pad_zero::<2>(output, time.second())?;
+    Ok(bytes)
+}
+
+impl sealed::Sealed for Sql {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        _zone: Option<&dyn TimeZone>,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<usize, error::Format> {
+        if date.is_none() && time.is_none() && offset.is_none() {
+            return Err(error::Format::InsufficientTypeInformation);
+        }
+
+        let mut bytes = 0;
+
+        if let Some(date) = date {
+            // There is no year zero, so the year before 1 AD is 1 BC.
+            let year = date.year();
+            let era_year = if year > 0 { year } else { 1 - year };
+            bytes += format_number_pad_zero::<4>(output, era_year as u32)?;
+            bytes += write(output, b"-")?;
+            bytes += format_number_pad_zero::<2>(output, date.month() as u8)?;
+            bytes += write(output, b"-")?;
+            bytes += format_number_pad_zero::<2>(output, date.day())?;
+        }
+
+        if let Some(time) = time {
+            if date.is_some() {
+                bytes += write(output, b" ")?;
+            }
+            bytes += format_number_pad_zero::<2>(output, time.hour())?;
+            bytes += write(output, b":")?;
+            bytes += format_number_pad_zero::<2>(output, time.minute())?;
+            bytes += write(output, b":")?;
+            bytes += format_number_pad_zero::<2>(output, time.second())?;
+
+            // At most six fractional digits are used, without trailing zeros.
+            let micros = time.microsecond();
+            if micros != 0 {
+                bytes += write(output, b".")?;
+                bytes += if micros % 10 != 0 {
+                    format_number_pad_zero::<6>(output, micros)
+                } else if (micros / 10) % 10 != 0 {
+                    format_number_pad_zero::<5>(output, micros / 10)
+                } else if (micros / 100) % 10 != 0 {
+                    format_number_pad_zero::<4>(output, micros / 100)
+                } else if (micros / 1_000) % 10 != 0 {
+                    format_number_pad_zero::<3>(output, micros / 1_000)
+                } else if (micros / 10_000) % 10 != 0 {
+                    format_number_pad_zero::<2>(output, micros / 10_000)
+                } else {
+                    format_number_pad_zero::<1>(output, micros / 100_000)
+                }?;
+            }
+        }
+
+        if let Some(offset) = offset {
+            let (hours, minutes, seconds) = offset.as_hms();
+            bytes += write(output, if offset.is_negative() { b"-" } else { b"+" })?;
+            bytes += format_number_pad_zero::<2>(output, hours.unsigned_abs())?;
+            if minutes != 0 || seconds != 0 {
+                bytes += write(output, b":")?;
+                bytes += format_number_pad_zero::<2>(output, minutes.unsigned_abs())?;
+            }
+            if seconds != 0 {
+                bytes += write(output, b":")?;
+                bytes += format_number_pad_zero::<2>(output, seconds.unsigned_abs())?;
+            }
+        }
+
+        if matches!(date, Some(date) if date.year() <= 0) {
+            bytes += write(output, b" BC")?;
+        }
+
+        Ok(bytes)
+    }
+}
+
+impl<const CONFIG: Rfc3339EncodedConfig> sealed::Sealed for Rfc3339<CONFIG> {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        _zone: Option<&dyn TimeZone>,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<usize, error::Format> {
+        let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+        let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+        let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+
+        let mut bytes = 0;
+
+        let year = date.year();
+
+        if !(0..10_000).contains(&year) {
+            return Err(error::Format::InvalidComponent("year"));
+        }
+        if offset.seconds_past_minute() != 0 {
+            return Err(error::Format::InvalidComponent("offset_second"));
+        }
+
+        bytes += format_number_pad_zero::<4>(output, year as u32)?;
+        bytes += write(output, b"-")?;
+        bytes += format_number_pad_zero::<2>(output, date.month() as u8)?;
+        bytes += write(output, b"-")?;
+        bytes += format_number_pad_zero::<2>(output, date.day())?;
+        bytes += write(
+            output,
+            match Self::SEPARATOR {
+                Separator::T => b"T",
+                Separator::Space => b" ",
+            },
+        )?;
+        bytes += format_number_pad_zero::<2>(output, time.hour())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<2>(output, time.minute())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<2>(output, time.second())?;
+
         #[allow(clippy::if_not_else)]
-        if time.nanosecond() != 0 {
+        if let SubsecondDigits::Fixed(digits) = Self::SUBSECOND_DIGITS {
+            let nanos = time.nanosecond();
+            if digits != 0 {
+                bytes += write(output, b".")?;
+            }
+            bytes += match digits {
+                0 => Ok(0),
+                1 => format_number_pad_zero::<1>(output, nanos / 100_000_000),
+                2 => format_number_pad_zero::<2>(output, nanos / 10_000_000),
+                3 => format_number_pad_zero::<3>(output, nanos / 1_000_000),
+                4 => format_number_pad_zero::<4>(output, nanos / 100_000),
+                5 => format_number_pad_zero::<5>(output, nanos / 10_000),
+                6 => format_number_pad_zero::<6>(output, nanos / 1_000),
+                7 => format_number_pad_zero::<7>(output, nanos / 100),
+                8 => format_number_pad_zero::<8>(output, nanos / 10),
+                _ => format_number_pad_zero::<9>(output, nanos),
+            }?;
+        } else if time.nanosecond() != 0 {
             let nanos = time.nanosecond();
             bytes += write(output, b".")?;
             bytes += if nanos % 10 != 0 {
@@ -258,7 +885,7 @@ impl sealed::Sealed for Rfc3339 {
             }?;
         }
 
-        if offset == UtcOffset::UTC {
+        if Self::USE_Z_FOR_UTC && offset == UtcOffset::UTC {
             bytes += write(output, b"Z")?;
             return Ok(bytes);
         }
@@ -279,6 +906,8 @@ impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        _zone: Option<&dyn TimeZone>,
+        _locale: Option<&dyn Locale>,
     ) -> Result<usize, error::Format> {
         let mut bytes = 0;
 
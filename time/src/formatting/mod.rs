@@ -8,8 +8,10 @@ use std::io;
 
 pub use self::formattable::Formattable;
 use crate::convert::*;
+use crate::format_description::locale::{English, Locale};
 use crate::format_description::{modifier, Component};
-use crate::{error, Date, OffsetDateTime, Time, UtcOffset};
+use crate::zone::{LocalTimeType, TimeZone};
+use crate::{error, Date, Duration, Era, OffsetDateTime, Time, UtcOffset};
 
 #[allow(clippy::missing_docs_in_private_items)]
 const MONTH_NAMES: [&[u8]; 12] = [
@@ -116,6 +118,14 @@ impl DigitCount for u32 {
         ((self as u64 + TABLE[31_u32.saturating_sub(self.leading_zeros()) as usize]) >> 32) as _
     }
 }
+impl DigitCount for u64 {
+    fn num_digits(self) -> u8 {
+        match u32::try_from(self) {
+            Ok(value) => value.num_digits(),
+            Err(_) => 1 + (self / 10).num_digits(),
+        }
+    }
+}
 // endregion extension trait
 
 /// Write all bytes to the output, returning the number of bytes written.
@@ -229,23 +239,41 @@ pub(crate) fn format_component(
     date: Option<Date>,
     time: Option<Time>,
     offset: Option<UtcOffset>,
+    zone: Option<&dyn TimeZone>,
+    locale: Option<&dyn Locale>,
 ) -> Result<usize, error::Format> {
     use Component::*;
     Ok(match (component, date, time, offset) {
-        (Day(modifier), Some(date), ..) => fmt_day(output, date, modifier)?,
-        (Month(modifier), Some(date), ..) => fmt_month(output, date, modifier)?,
+        (Day(modifier), Some(date), ..) => fmt_day(output, date, modifier, locale)?,
+        (Month(modifier), Some(date), ..) => fmt_month(output, date, modifier, locale)?,
         (Ordinal(modifier), Some(date), ..) => fmt_ordinal(output, date, modifier)?,
-        (Weekday(modifier), Some(date), ..) => fmt_weekday(output, date, modifier)?,
+        (Weekday(modifier), Some(date), ..) => fmt_weekday(output, date, modifier, locale)?,
         (WeekNumber(modifier), Some(date), ..) => fmt_week_number(output, date, modifier)?,
         (Year(modifier), Some(date), ..) => fmt_year(output, date, modifier)?,
+        (Quarter(modifier), Some(date), ..) => fmt_quarter(output, date, modifier)?,
+        (Century(modifier), Some(date), ..) => fmt_century(output, date, modifier)?,
+        (Era(modifier), Some(date), ..) => fmt_era(output, date, modifier)?,
         (Hour(modifier), _, Some(time), _) => fmt_hour(output, time, modifier)?,
         (Minute(modifier), _, Some(time), _) => fmt_minute(output, time, modifier)?,
         (Period(modifier), _, Some(time), _) => fmt_period(output, time, modifier)?,
         (Second(modifier), _, Some(time), _) => fmt_second(output, time, modifier)?,
-        (Subsecond(modifier), _, Some(time), _) => fmt_subsecond(output, time, modifier)?,
+        (Subsecond(modifier), _, Some(time), _) => {
+            fmt_subsecond(output, time.nanosecond(), modifier)?
+        }
         (OffsetHour(modifier), .., Some(offset)) => fmt_offset_hour(output, offset, modifier)?,
         (OffsetMinute(modifier), .., Some(offset)) => fmt_offset_minute(output, offset, modifier)?,
         (OffsetSecond(modifier), .., Some(offset)) => fmt_offset_second(output, offset, modifier)?,
+        (ZoneName(modifier), ..) => match zone.and_then(TimeZone::name) {
+            Some(name) => fmt_zone_name(output, name, modifier)?,
+            None => return Err(error::Format::InsufficientTypeInformation),
+        },
+        (ZoneAbbr(modifier), Some(date), Some(time), Some(offset)) => {
+            let datetime = date.with_time(time).assume_offset(offset);
+            match zone.and_then(|zone| zone.local_time_type_at_utc(datetime)) {
+                Some(local_time_type) => fmt_zone_abbr(output, local_time_type, modifier)?,
+                None => return Err(error::Format::InsufficientTypeInformation),
+            }
+        }
         (Ignore(_), ..) => 0,
         (UnixTimestamp(modifier), Some(date), Some(time), Some(offset)) => {
             fmt_unix_timestamp(output, date, time, offset, modifier)?
@@ -254,14 +282,51 @@ pub(crate) fn format_component(
     })
 }
 
+/// Format the provided component of a [`Duration`] into the designated output. An `Err` will be
+/// returned if the component is not applicable to a duration or if the value cannot be output to
+/// the stream.
+pub(crate) fn format_duration_component(
+    output: &mut impl io::Write,
+    component: Component,
+    duration: Duration,
+) -> Result<usize, error::Format> {
+    use Component::*;
+    Ok(match component {
+        Sign(modifier) => fmt_sign(output, duration, modifier)?,
+        Days(modifier) => fmt_days(output, duration, modifier)?,
+        Hours(modifier) => fmt_hours(output, duration, modifier)?,
+        Minutes(modifier) => fmt_minutes(output, duration, modifier)?,
+        Seconds(modifier) => fmt_seconds(output, duration, modifier)?,
+        Subsecond(modifier) => fmt_subsecond(
+            output,
+            duration.subsec_nanoseconds().unsigned_abs(),
+            modifier,
+        )?,
+        Ignore(_) => 0,
+        _ => return Err(error::Format::InsufficientTypeInformation),
+    })
+}
+
 // region: date formatters
 /// Format the day into the designated output.
 fn fmt_day(
     output: &mut impl io::Write,
     date: Date,
-    modifier::Day { padding }: modifier::Day,
+    modifier::Day {
+        padding,
+        suffix,
+        case_sensitive: _, // no effect on formatting
+    }: modifier::Day,
+    locale: Option<&dyn Locale>,
 ) -> Result<usize, io::Error> {
-    format_number::<2>(output, date.day(), padding)
+    if !suffix {
+        return format_number::<2>(output, date.day(), padding);
+    }
+
+    // A suffixed day is never padded.
+    let suffix = locale.unwrap_or(&English).day_suffix(date.day());
+    let bytes = format_number::<2>(output, date.day(), modifier::Padding::None)?;
+    Ok(bytes + write(output, suffix.as_bytes())?)
 }
 
 /// Format the month into the designated output.
@@ -273,11 +338,19 @@ fn fmt_month(
         repr,
         case_sensitive: _, // no effect on formatting
     }: modifier::Month,
+    locale: Option<&dyn Locale>,
 ) -> Result<usize, io::Error> {
+    let index = date.month() as usize - 1;
     match repr {
         modifier::MonthRepr::Numerical => format_number::<2>(output, date.month() as u8, padding),
-        modifier::MonthRepr::Long => write(output, MONTH_NAMES[date.month() as usize - 1]),
-        modifier::MonthRepr::Short => write(output, &MONTH_NAMES[date.month() as usize - 1][..3]),
+        modifier::MonthRepr::Long => match locale {
+            Some(locale) => write(output, locale.month_names()[index].as_bytes()),
+            None => write(output, MONTH_NAMES[index]),
+        },
+        modifier::MonthRepr::Short => match locale {
+            Some(locale) => write(output, locale.month_abbreviations()[index].as_bytes()),
+            None => write(output, &MONTH_NAMES[index][..3]),
+        },
     }
 }
 
@@ -299,16 +372,18 @@ fn fmt_weekday(
         one_indexed,
         case_sensitive: _, // no effect on formatting
     }: modifier::Weekday,
+    locale: Option<&dyn Locale>,
 ) -> Result<usize, io::Error> {
+    let index = date.weekday().number_days_from_monday() as usize;
     match repr {
-        modifier::WeekdayRepr::Short => write(
-            output,
-            &WEEKDAY_NAMES[date.weekday().number_days_from_monday() as usize][..3],
-        ),
-        modifier::WeekdayRepr::Long => write(
-            output,
-            WEEKDAY_NAMES[date.weekday().number_days_from_monday() as usize],
-        ),
+        modifier::WeekdayRepr::Short => match locale {
+            Some(locale) => write(output, locale.weekday_abbreviations()[index].as_bytes()),
+            None => write(output, &WEEKDAY_NAMES[index][..3]),
+        },
+        modifier::WeekdayRepr::Long => match locale {
+            Some(locale) => write(output, locale.weekday_names()[index].as_bytes()),
+            None => write(output, WEEKDAY_NAMES[index]),
+        },
         modifier::WeekdayRepr::Sunday => format_number::<1>(
             output,
             date.weekday().number_days_from_sunday() + one_indexed as u8,
@@ -358,17 +433,19 @@ fn fmt_year(
     let value = match repr {
         modifier::YearRepr::Full => full_year,
         modifier::YearRepr::LastTwo => (full_year % 100).abs(),
+        modifier::YearRepr::Era if full_year > 0 => full_year,
+        modifier::YearRepr::Era => 1 - full_year,
     };
     let format_number = match repr {
         #[cfg(feature = "large-dates")]
         modifier::YearRepr::Full if value.abs() >= 100_000 => format_number::<6>,
         #[cfg(feature = "large-dates")]
         modifier::YearRepr::Full if value.abs() >= 10_000 => format_number::<5>,
-        modifier::YearRepr::Full => format_number::<4>,
+        modifier::YearRepr::Full | modifier::YearRepr::Era => format_number::<4>,
         modifier::YearRepr::LastTwo => format_number::<2>,
     };
     let mut bytes = 0;
-    if repr != modifier::YearRepr::LastTwo {
+    if repr == modifier::YearRepr::Full {
         if full_year < 0 {
             bytes += write(output, b"-")?;
         } else if sign_is_mandatory || cfg!(feature = "large-dates") && full_year >= 10_000 {
@@ -378,6 +455,49 @@ fn fmt_year(
     bytes += format_number(output, value.unsigned_abs(), padding)?;
     Ok(bytes)
 }
+
+/// Format the quarter into the designated output.
+fn fmt_quarter(
+    output: &mut impl io::Write,
+    date: Date,
+    _: modifier::Quarter,
+) -> Result<usize, io::Error> {
+    format_number::<1>(output, date.quarter(), modifier::Padding::None)
+}
+
+/// Format the century into the designated output.
+fn fmt_century(
+    output: &mut impl io::Write,
+    date: Date,
+    modifier::Century { padding }: modifier::Century,
+) -> Result<usize, io::Error> {
+    let year = date.year();
+    let mut bytes = 0;
+    // The sign is needed to distinguish years -99 through -1 from years 0 through 99.
+    if year < 0 {
+        bytes += write(output, b"-")?;
+    }
+    bytes += format_number::<2>(output, (year / 100).unsigned_abs(), padding)?;
+    Ok(bytes)
+}
+
+/// Format the era into the designated output.
+fn fmt_era(
+    output: &mut impl io::Write,
+    date: Date,
+    modifier::Era {
+        repr,
+        case_sensitive: _, // no effect on formatting
+    }: modifier::Era,
+) -> Result<usize, io::Error> {
+    let value: &[u8] = match (repr, date.era()) {
+        (modifier::EraRepr::Ad, Era::Ce) => b"AD",
+        (modifier::EraRepr::Ad, Era::Bce) => b"BC",
+        (modifier::EraRepr::Ce, Era::Ce) => b"CE",
+        (modifier::EraRepr::Ce, Era::Bce) => b"BCE",
+    };
+    write(output, value)
+}
 // endregion date formatters
 
 // region: time formatters
@@ -437,11 +557,10 @@ fn fmt_second(
 /// Format the subsecond into the designated output.
 fn fmt_subsecond<W: io::Write>(
     output: &mut W,
-    time: Time,
+    nanos: u32,
     modifier::Subsecond { digits }: modifier::Subsecond,
 ) -> Result<usize, io::Error> {
     use modifier::SubsecondDigits::*;
-    let nanos = time.nanosecond();
 
     if digits == Nine || (digits == OneOrMore && nanos % 10 != 0) {
         format_number_pad_zero::<9>(output, nanos)
@@ -504,6 +623,93 @@ fn fmt_offset_second(
 }
 // endregion offset formatters
 
+// region: duration formatters
+/// Format the sign of the duration into the designated output.
+fn fmt_sign(
+    output: &mut impl io::Write,
+    duration: Duration,
+    modifier::Sign { sign_is_mandatory }: modifier::Sign,
+) -> Result<usize, io::Error> {
+    if duration.is_negative() {
+        write(output, b"-")
+    } else {
+        write_if(output, sign_is_mandatory, b"+")
+    }
+}
+
+/// Format the whole days of the duration into the designated output.
+fn fmt_days(
+    output: &mut impl io::Write,
+    duration: Duration,
+    modifier::Days { padding }: modifier::Days,
+) -> Result<usize, io::Error> {
+    let days = duration.unsigned_abs().as_secs() / Second.per(Day) as u64;
+    format_number::<2>(output, days, padding)
+}
+
+/// Format the hours of the duration into the designated output.
+fn fmt_hours(
+    output: &mut impl io::Write,
+    duration: Duration,
+    modifier::Hours { padding, total }: modifier::Hours,
+) -> Result<usize, io::Error> {
+    let hours = duration.unsigned_abs().as_secs() / Second.per(Hour) as u64;
+    if total {
+        format_number::<2>(output, hours, padding)
+    } else {
+        format_number::<2>(output, (hours % Hour.per(Day) as u64) as u8, padding)
+    }
+}
+
+/// Format the minutes of the duration into the designated output.
+fn fmt_minutes(
+    output: &mut impl io::Write,
+    duration: Duration,
+    modifier::Minutes { padding, total }: modifier::Minutes,
+) -> Result<usize, io::Error> {
+    let minutes = duration.unsigned_abs().as_secs() / Second.per(Minute) as u64;
+    if total {
+        format_number::<2>(output, minutes, padding)
+    } else {
+        format_number::<2>(output, (minutes % Minute.per(Hour) as u64) as u8, padding)
+    }
+}
+
+/// Format the seconds of the duration into the designated output.
+fn fmt_seconds(
+    output: &mut impl io::Write,
+    duration: Duration,
+    modifier::Seconds { padding, total }: modifier::Seconds,
+) -> Result<usize, io::Error> {
+    let seconds = duration.unsigned_abs().as_secs();
+    if total {
+        format_number::<2>(output, seconds, padding)
+    } else {
+        format_number::<2>(output, (seconds % Second.per(Minute) as u64) as u8, padding)
+    }
+}
+// endregion duration formatters
+
+// region: zone formatters
+/// Format the time zone identifier into the designated output.
+fn fmt_zone_name(
+    output: &mut impl io::Write,
+    name: &str,
+    _: modifier::ZoneName,
+) -> Result<usize, io::Error> {
+    write(output, name.as_bytes())
+}
+
+/// Format the time zone abbreviation into the designated output.
+fn fmt_zone_abbr(
+    output: &mut impl io::Write,
+    local_time_type: LocalTimeType,
+    _: modifier::ZoneAbbr,
+) -> Result<usize, io::Error> {
+    write(output, local_time_type.abbreviation().as_bytes())
+}
+// endregion zone formatters
+
 /// Format the Unix timestamp into the designated output.
 fn fmt_unix_timestamp(
     output: &mut impl io::Write,
@@ -312,9 +312,11 @@ macro_rules! bug {
 }
 // endregion macros
 
+pub mod cron;
 mod date;
 mod date_time;
 mod duration;
+mod era;
 pub mod error;
 pub mod ext;
 #[cfg(any(feature = "formatting", feature = "parsing"))]
@@ -329,11 +331,16 @@ mod month;
 mod offset_date_time;
 #[cfg(feature = "parsing")]
 pub mod parsing;
+pub mod postgres;
 mod primitive_date_time;
 #[cfg(feature = "quickcheck")]
 mod quickcheck;
 #[cfg(feature = "rand")]
 mod rand;
+#[cfg(feature = "alloc")]
+pub mod recurrence;
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+pub mod relative;
 #[cfg(feature = "serde")]
 #[allow(missing_copy_implementations, missing_debug_implementations)]
 pub mod serde;
@@ -344,6 +351,7 @@ mod time;
 mod utc_offset;
 pub mod util;
 mod weekday;
+pub mod zone;
 
 // Not public yet.
 use time_core::convert;
@@ -351,6 +359,7 @@ use time_core::convert;
 pub use crate::date::Date;
 use crate::date_time::DateTime;
 pub use crate::duration::Duration;
+pub use crate::era::Era;
 pub use crate::error::Error;
 #[cfg(feature = "std")]
 pub use crate::instant::Instant;
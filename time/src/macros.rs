@@ -28,6 +28,12 @@ pub use time_macros::date;
 /// [`offset!`], all space-separated. If an [`offset!`] is provided, the resulting value will
 /// be an [`OffsetDateTime`]; otherwise it will be a [`PrimitiveDateTime`].
 ///
+/// In place of the offset, an IANA time zone identifier may be provided. The offset in effect at
+/// the given local date-time is looked up in a copy of the time zone database embedded in the
+/// macro, and the resulting value is an [`OffsetDateTime`]. A local date-time that does not exist
+/// in the time zone is a compile error, as is one that occurs twice unless it is followed by
+/// `earliest` or `latest` to choose between the two.
+///
 /// [`OffsetDateTime`]: crate::OffsetDateTime
 /// [`PrimitiveDateTime`]: crate::PrimitiveDateTime
 ///
@@ -46,6 +52,14 @@ pub use time_macros::date;
 ///     Date::from_calendar_date(2020, Month::January, 1)?.midnight()
 ///         .assume_offset(UtcOffset::from_hms(-1, 0, 0)?)
 /// );
+/// assert_eq!(
+///     datetime!(2024-07-01 9:00 America/New_York),
+///     datetime!(2024-07-01 9:00 -4)
+/// );
+/// assert_eq!(
+///     datetime!(2024-11-03 1:30 America/New_York latest),
+///     datetime!(2024-11-03 1:30 -5)
+/// );
 /// # Ok::<_, time::Error>(())
 /// ```
 pub use time_macros::datetime;
@@ -13,7 +13,9 @@ use std::io;
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
+#[cfg(feature = "icp")]
 use candid::types::{Serializer, Type, TypeInner};
+#[cfg(feature = "icp")]
 use candid::CandidType;
 
 use crate::date_time::offset_kind;
@@ -21,7 +23,10 @@ use crate::date_time::offset_kind;
 use crate::formatting::Formattable;
 #[cfg(feature = "parsing")]
 use crate::parsing::Parsable;
-use crate::{error, Date, DateTime, Duration, Month, PrimitiveDateTime, Time, UtcOffset, Weekday};
+use crate::zone::TimeZone;
+use crate::{
+    error, Date, DateTime, Duration, Era, Month, PrimitiveDateTime, Time, UtcOffset, Weekday,
+};
 
 /// The actual type doing all the work.
 type Inner = DateTime<offset_kind::Fixed>;
@@ -124,6 +129,24 @@ impl OffsetDateTime {
         Some(Self(const_try_opt!(self.0.checked_to_offset(offset))))
     }
 
+    /// Convert the `OffsetDateTime` to the [`UtcOffset`] in effect at the same instant in the
+    /// provided [time zone](crate::zone::TimeZone).
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(
+    ///     datetime!(2000-01-01 0:00 UTC).to_zone(&offset!(-1)),
+    ///     datetime!(1999-12-31 23:00 -1),
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the local date-time in the new offset is outside the supported range.
+    pub fn to_zone(self, zone: &(impl TimeZone + ?Sized)) -> Self {
+        self.to_offset(zone.offset_at_utc(self))
+    }
+
     // region: constructors
     /// Create an `OffsetDateTime` from the provided Unix timestamp. Calling `.offset()` on the
     /// resulting value is guaranteed to return UTC.
@@ -266,6 +289,39 @@ impl OffsetDateTime {
         self.0.year()
     }
 
+    /// Get the era of the date in the stored offset, in the proleptic Gregorian calendar.
+    ///
+    /// ```rust
+    /// # use time::Era;
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(datetime!(2019-01-01 0:00 UTC).era(), Era::Ce);
+    /// assert_eq!(
+    ///     datetime!(0001-01-01 0:00 UTC)
+    ///         .to_offset(offset!(-1))
+    ///         .era(),
+    ///     Era::Bce,
+    /// );
+    /// ```
+    pub const fn era(self) -> Era {
+        self.0.era()
+    }
+
+    /// Get the year of the date in the stored offset within its [era](Self::era).
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(datetime!(2019-01-01 0:00 UTC).year_of_era(), 2019);
+    /// assert_eq!(
+    ///     datetime!(0001-01-01 0:00 UTC)
+    ///         .to_offset(offset!(-1))
+    ///         .year_of_era(),
+    ///     1,
+    /// );
+    /// ```
+    pub const fn year_of_era(self) -> u32 {
+        self.0.year_of_era()
+    }
+
     /// Get the month of the date in the stored offset.
     ///
     /// ```rust
@@ -301,6 +357,24 @@ impl OffsetDateTime {
         self.0.day()
     }
 
+    /// Get the quarter of the year of the date in the stored offset.
+    ///
+    /// The returned value will always be in the range `1..=4`.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(datetime!(2019-01-01 0:00 UTC).quarter(), 1);
+    /// assert_eq!(
+    ///     datetime!(2019-12-31 23:00 UTC)
+    ///         .to_offset(offset!(+1))
+    ///         .quarter(),
+    ///     1,
+    /// );
+    /// ```
+    pub const fn quarter(self) -> u8 {
+        self.0.quarter()
+    }
+
     /// Get the day of the year of the date in the stored offset.
     ///
     /// The returned value will always be in the range `1..=366`.
@@ -1008,6 +1082,74 @@ impl OffsetDateTime {
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
         self.0.format(format)
     }
+
+    /// Format the `OffsetDateTime` in the provided [time zone](crate::zone::TimeZone) using the
+    /// provided [format description](crate::format_description).
+    ///
+    /// The value is first converted to the offset in effect in the time zone, as with
+    /// [`to_zone`](Self::to_zone). The time zone is then available to the `[zone_name]` and
+    /// `[zone_abbr]` components.
+    pub fn format_into_in_zone(
+        self,
+        output: &mut impl io::Write,
+        format: &(impl Formattable + ?Sized),
+        zone: &(impl TimeZone + ?Sized),
+    ) -> Result<usize, error::Format> {
+        let datetime = self.to_zone(zone);
+        format.format_into(
+            output,
+            Some(datetime.date()),
+            Some(datetime.time()),
+            Some(datetime.offset()),
+            Some(&zone),
+            None,
+        )
+    }
+
+    /// Format the `OffsetDateTime` in the provided [time zone](crate::zone::TimeZone) using the
+    /// provided [format description](crate::format_description).
+    ///
+    /// The value is first converted to the offset in effect in the time zone, as with
+    /// [`to_zone`](Self::to_zone). The time zone is then available to the `[zone_name]` and
+    /// `[zone_abbr]` components.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time::zone::{LocalTimeType, Transition, ZoneRules};
+    /// # use time_macros::{datetime, offset};
+    /// let cet = LocalTimeType::new(offset!(+1), false, "CET")?;
+    /// let cest = LocalTimeType::new(offset!(+2), true, "CEST")?;
+    /// let transitions = [Transition::new(
+    ///     datetime!(2023-03-26 1:00 UTC).unix_timestamp(),
+    ///     cest,
+    /// )];
+    /// let rules = ZoneRules::new(cet, &transitions);
+    ///
+    /// let format = format_description::parse("[hour]:[minute] [zone_abbr]")?;
+    /// assert_eq!(
+    ///     datetime!(2023-03-26 0:30 UTC).format_in_zone(&format, &rules)?,
+    ///     "01:30 CET"
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2023-03-26 1:30 UTC).format_in_zone(&format, &rules)?,
+    ///     "03:30 CEST"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_in_zone(
+        self,
+        format: &(impl Formattable + ?Sized),
+        zone: &(impl TimeZone + ?Sized),
+    ) -> Result<String, error::Format> {
+        let datetime = self.to_zone(zone);
+        format.format(
+            Some(datetime.date()),
+            Some(datetime.time()),
+            Some(datetime.offset()),
+            Some(&zone),
+            None,
+        )
+    }
 }
 
 #[cfg(feature = "parsing")]
@@ -1034,6 +1176,49 @@ impl OffsetDateTime {
     ) -> Result<Self, error::Parse> {
         Inner::parse(input, description).map(Self)
     }
+
+    /// Parse an `OffsetDateTime` from the input using the provided [format
+    /// description](crate::format_description). Unlike [`OffsetDateTime::parse`], the error records
+    /// the location in the input at which parsing failed.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::format_description;
+    /// let format = format_description!(
+    ///     "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour \
+    ///      sign:mandatory]:[offset_minute]:[offset_second]"
+    /// );
+    /// let err = OffsetDateTime::parse_located("2020-01-02 03:04:05 06:07:08", &format).unwrap_err();
+    /// assert_eq!(err.offset(), Some(20));
+    /// ```
+    pub fn parse_located(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<Self, error::Located<error::Parse>> {
+        Inner::parse_located(input, description).map(Self)
+    }
+
+    /// Parse an `OffsetDateTime` from the start of the input using the provided [format
+    /// description](crate::format_description). Rather than any trailing characters being an
+    /// error, they are returned alongside the parsed value. If parsing fails, the error records the
+    /// location in the input at which it did so.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// let line = "2020-01-02T03:04:05Z [INFO] server started";
+    /// let (timestamp, message) = OffsetDateTime::parse_prefix(line, &Rfc3339)?;
+    /// assert_eq!(timestamp, datetime!(2020-01-02 03:04:05 UTC));
+    /// assert_eq!(message, " [INFO] server started");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a str), error::Located<error::Parse>> {
+        Inner::parse_prefix(input, description).map(|(value, remaining)| (Self(value), remaining))
+    }
 }
 
 #[cfg(feature = "icp")]
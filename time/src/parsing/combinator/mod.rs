@@ -38,6 +38,60 @@ pub(crate) fn first_match<'a, T>(
     }
 }
 
+/// Consume the longest matching item, returning its associated value.
+///
+/// Unlike [`first_match`], the options are not limited to ASCII. When case-insensitive, characters
+/// are compared by their lowercase mapping.
+pub(crate) fn longest_match<'a, 'b, T>(
+    options: impl IntoIterator<Item = (&'b str, T)>,
+    case_sensitive: bool,
+) -> impl FnMut(&'a [u8]) -> Option<ParsedItem<'a, T>> {
+    let mut options = options.into_iter();
+    move |input| {
+        let mut longest: Option<ParsedItem<'a, T>> = None;
+        for (expected, t) in options.by_ref() {
+            if expected.is_empty() {
+                continue;
+            }
+            let remaining = if case_sensitive {
+                input.strip_prefix(expected.as_bytes())
+            } else {
+                strip_prefix_ignore_case(input, expected)
+            };
+            match (remaining, &longest) {
+                (Some(remaining), Some(ParsedItem(current, _)))
+                    if remaining.len() >= current.len() => {}
+                (Some(remaining), _) => longest = Some(ParsedItem(remaining, t)),
+                (None, _) => {}
+            }
+        }
+        longest
+    }
+}
+
+/// Strip the provided prefix from the input, comparing characters by their lowercase mapping.
+pub(crate) fn strip_prefix_ignore_case<'a>(input: &'a [u8], prefix: &str) -> Option<&'a [u8]> {
+    // Each character of the prefix corresponds to exactly one character of the input, which is at
+    // most four bytes long.
+    let input_prefix = &input[..input.len().min(4 * prefix.len())];
+    let input_prefix = match core::str::from_utf8(input_prefix) {
+        Ok(input_prefix) => input_prefix,
+        Err(err) => core::str::from_utf8(&input_prefix[..err.valid_up_to()]).ok()?,
+    };
+
+    let mut input_chars = input_prefix.char_indices();
+    for expected in prefix.chars() {
+        let (_, actual) = input_chars.next()?;
+        if !actual.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+    }
+    let len = input_chars
+        .next()
+        .map_or(input_prefix.len(), |(index, _)| index);
+    Some(&input[len..])
+}
+
 /// Consume zero or more instances of the provided parser. The parser must return the unit value.
 pub(crate) fn zero_or_more<'a, P: Fn(&'a [u8]) -> Option<ParsedItem<'a, ()>>>(
     parser: P,
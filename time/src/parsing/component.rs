@@ -3,14 +3,14 @@
 use core::num::{NonZeroU16, NonZeroU8};
 
 use crate::convert::*;
+use crate::format_description::locale::{English, Locale};
 use crate::format_description::modifier;
-#[cfg(feature = "large-dates")]
-use crate::parsing::combinator::n_to_m_digits_padded;
 use crate::parsing::combinator::{
-    any_digit, exactly_n_digits, exactly_n_digits_padded, first_match, n_to_m_digits, opt, sign,
+    any_digit, exactly_n_digits, exactly_n_digits_padded, first_match, longest_match,
+    n_to_m_digits, n_to_m_digits_padded, opt, sign, strip_prefix_ignore_case,
 };
 use crate::parsing::ParsedItem;
-use crate::{Month, Weekday};
+use crate::{Era, Month, Weekday};
 
 // region: date components
 /// Parse the "year" component of a `Date`.
@@ -33,15 +33,74 @@ pub(crate) fn parse_year(input: &[u8], modifiers: modifier::Year) -> Option<Pars
         modifier::YearRepr::LastTwo => {
             Some(exactly_n_digits_padded::<2, u32>(modifiers.padding)(input)?.map(|v| v as i32))
         }
+        modifier::YearRepr::Era => {
+            #[cfg(not(feature = "large-dates"))]
+            let ParsedItem(input, year) =
+                exactly_n_digits_padded::<4, u32>(modifiers.padding)(input)?;
+            #[cfg(feature = "large-dates")]
+            let ParsedItem(input, year) =
+                n_to_m_digits_padded::<4, 6, u32>(modifiers.padding)(input)?;
+            Some(ParsedItem(input, year as i32))
+        }
     }
 }
 
-/// Parse the "month" component of a `Date`.
-pub(crate) fn parse_month(
+/// Parse the "quarter" component of a `Date`.
+pub(crate) fn parse_quarter(
+    input: &[u8],
+    _: modifier::Quarter,
+) -> Option<ParsedItem<'_, NonZeroU8>> {
+    exactly_n_digits::<1, NonZeroU8>(input).filter(|parsed| parsed.1.get() <= 4)
+}
+
+/// Parse the "century" component of a `Date`.
+///
+/// Returns the value and whether the value is negative. This is used for when "-00" is parsed.
+pub(crate) fn parse_century(
     input: &[u8],
+    modifiers: modifier::Century,
+) -> Option<ParsedItem<'_, (i16, bool)>> {
+    let ParsedItem(input, sign) = opt(sign)(input);
+    // More digits are not accepted, as the century is typically followed immediately by the last
+    // two digits of the year.
+    let ParsedItem(input, century) = exactly_n_digits_padded::<2, u8>(modifiers.padding)(input)?;
+    match sign {
+        Some(b'-') => Some(ParsedItem(input, (-(century as i16), true))),
+        _ => Some(ParsedItem(input, (century as i16, false))),
+    }
+}
+
+/// Parse the "era" component of a `Date`.
+pub(crate) fn parse_era(input: &[u8], modifiers: modifier::Era) -> Option<ParsedItem<'_, Era>> {
+    first_match(
+        match modifiers.repr {
+            modifier::EraRepr::Ad => [(b"AD".as_slice(), Era::Ce), (b"BC".as_slice(), Era::Bce)],
+            modifier::EraRepr::Ce => [(b"CE".as_slice(), Era::Ce), (b"BCE".as_slice(), Era::Bce)],
+        },
+        modifiers.case_sensitive,
+    )(input)
+}
+
+/// Parse the "month" component of a `Date`.
+pub(crate) fn parse_month<'a>(
+    input: &'a [u8],
     modifiers: modifier::Month,
-) -> Option<ParsedItem<'_, Month>> {
+    locale: Option<&dyn Locale>,
+) -> Option<ParsedItem<'a, Month>> {
     use Month::*;
+    let names = match (modifiers.repr, locale) {
+        (modifier::MonthRepr::Long, Some(locale)) => Some(locale.month_names()),
+        (modifier::MonthRepr::Short, Some(locale)) => Some(locale.month_abbreviations()),
+        _ => None,
+    };
+    if let Some(names) = names {
+        let months = [
+            January, February, March, April, May, June, July, August, September, October, November,
+            December,
+        ];
+        return longest_match(names.iter().copied().zip(months), modifiers.case_sensitive)(input);
+    }
+
     let ParsedItem(remaining, value) = first_match(
         match modifiers.repr {
             modifier::MonthRepr::Numerical => {
@@ -91,10 +150,21 @@ pub(crate) fn parse_week_number(
 }
 
 /// Parse the "weekday" component of a `Date`.
-pub(crate) fn parse_weekday(
-    input: &[u8],
+pub(crate) fn parse_weekday<'a>(
+    input: &'a [u8],
     modifiers: modifier::Weekday,
-) -> Option<ParsedItem<'_, Weekday>> {
+    locale: Option<&dyn Locale>,
+) -> Option<ParsedItem<'a, Weekday>> {
+    let names = match (modifiers.repr, locale) {
+        (modifier::WeekdayRepr::Long, Some(locale)) => Some(locale.weekday_names()),
+        (modifier::WeekdayRepr::Short, Some(locale)) => Some(locale.weekday_abbreviations()),
+        _ => None,
+    };
+    if let Some(names) = names {
+        return longest_match(names.iter().copied().zip(0..), modifiers.case_sensitive)(input)
+            .map(|parsed| parsed.map(|n| Weekday::Monday.nth_next(n)));
+    }
+
     first_match(
         match (modifiers.repr, modifiers.one_indexed) {
             (modifier::WeekdayRepr::Short, _) => [
@@ -165,11 +235,28 @@ pub(crate) fn parse_ordinal(
 }
 
 /// Parse the "day" component of a `Date`.
-pub(crate) fn parse_day(
-    input: &[u8],
+pub(crate) fn parse_day<'a>(
+    input: &'a [u8],
     modifiers: modifier::Day,
-) -> Option<ParsedItem<'_, NonZeroU8>> {
-    exactly_n_digits_padded::<2, _>(modifiers.padding)(input)
+    locale: Option<&dyn Locale>,
+) -> Option<ParsedItem<'a, NonZeroU8>> {
+    if !modifiers.suffix {
+        return exactly_n_digits_padded::<2, _>(modifiers.padding)(input);
+    }
+
+    // A suffixed day is never padded.
+    let ParsedItem(input, day) =
+        exactly_n_digits_padded::<2, NonZeroU8>(modifier::Padding::None)(input)?;
+    if day.get() > 31 {
+        return None;
+    }
+    let suffix = locale.unwrap_or(&English).day_suffix(day.get());
+    let input = if modifiers.case_sensitive {
+        input.strip_prefix(suffix.as_bytes())?
+    } else {
+        strip_prefix_ignore_case(input, suffix)?
+    };
+    Some(ParsedItem(input, day))
 }
 // endregion date components
 
@@ -298,6 +385,112 @@ pub(crate) fn parse_offset_second(
 }
 // endregion offset components
 
+// region: zone components
+/// Parse the "zone name" component, an identifier such as `Europe/Paris`.
+///
+/// The identifier must begin with an ASCII letter and may contain ASCII letters, digits, and any of
+/// `_`, `+`, `-`, and `/`.
+pub(crate) fn parse_zone_name(input: &[u8], _: modifier::ZoneName) -> Option<ParsedItem<'_, &str>> {
+    if !input.first()?.is_ascii_alphabetic() {
+        return None;
+    }
+    let len = input
+        .iter()
+        .position(|&byte| {
+            !(byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'+' | b'-' | b'/'))
+        })
+        .unwrap_or(input.len());
+    let (name, input) = input.split_at(len);
+    Some(ParsedItem(input, core::str::from_utf8(name).ok()?))
+}
+
+/// Parse the "zone abbreviation" component, such as `CEST` or `+0530`.
+///
+/// The abbreviation is either one to eight ASCII letters or a sign followed by two or four digits.
+pub(crate) fn parse_zone_abbr(input: &[u8], _: modifier::ZoneAbbr) -> Option<ParsedItem<'_, &str>> {
+    let len = match input.first()? {
+        b'+' | b'-' => {
+            let digits = input[1..]
+                .iter()
+                .take_while(|byte| byte.is_ascii_digit())
+                .count();
+            match digits {
+                2 | 4 => digits + 1,
+                _ => return None,
+            }
+        }
+        _ => match input
+            .iter()
+            .take_while(|byte| byte.is_ascii_alphabetic())
+            .count()
+        {
+            len @ 1..=8 => len,
+            _ => return None,
+        },
+    };
+    let (abbr, input) = input.split_at(len);
+    Some(ParsedItem(input, core::str::from_utf8(abbr).ok()?))
+}
+// endregion zone components
+
+// region: duration components
+/// Parse the "sign" component of a `Duration`, returning whether the duration is negative.
+pub(crate) fn parse_sign(input: &[u8], modifiers: modifier::Sign) -> Option<ParsedItem<'_, bool>> {
+    match opt(sign)(input) {
+        ParsedItem(input, Some(sign)) => Some(ParsedItem(input, sign == b'-')),
+        ParsedItem(_, None) if modifiers.sign_is_mandatory => None,
+        ParsedItem(input, None) => Some(ParsedItem(input, false)),
+    }
+}
+
+/// Parse the "days" component of a `Duration`.
+pub(crate) fn parse_days(input: &[u8], modifiers: modifier::Days) -> Option<ParsedItem<'_, u64>> {
+    n_to_m_digits_padded::<2, 20, _>(modifiers.padding)(input)
+}
+
+/// Parse the "hours" component of a `Duration`.
+pub(crate) fn parse_hours(input: &[u8], modifiers: modifier::Hours) -> Option<ParsedItem<'_, u64>> {
+    parse_duration_unit(input, modifiers.padding, modifiers.total, Hour.per(Day))
+}
+
+/// Parse the "minutes" component of a `Duration`.
+pub(crate) fn parse_minutes(
+    input: &[u8],
+    modifiers: modifier::Minutes,
+) -> Option<ParsedItem<'_, u64>> {
+    parse_duration_unit(input, modifiers.padding, modifiers.total, Minute.per(Hour))
+}
+
+/// Parse the "seconds" component of a `Duration`.
+pub(crate) fn parse_seconds(
+    input: &[u8],
+    modifiers: modifier::Seconds,
+) -> Option<ParsedItem<'_, u64>> {
+    parse_duration_unit(
+        input,
+        modifiers.padding,
+        modifiers.total,
+        Second.per(Minute),
+    )
+}
+
+/// Parse a unit of a `Duration`. If the value is not the total, it must be less than the number of
+/// units in the next largest unit.
+fn parse_duration_unit(
+    input: &[u8],
+    padding: modifier::Padding,
+    total: bool,
+    per_next_unit: u8,
+) -> Option<ParsedItem<'_, u64>> {
+    if total {
+        n_to_m_digits_padded::<2, 20, _>(padding)(input)
+    } else {
+        exactly_n_digits_padded::<2, u8>(padding)(input)?
+            .flat_map(|value| (value < per_next_unit).then_some(value as _))
+    }
+}
+// endregion duration components
+
 /// Ignore the given number of bytes.
 pub(crate) fn parse_ignore(
     input: &[u8],
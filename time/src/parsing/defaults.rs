@@ -0,0 +1,167 @@
+//! Values used in place of components that are absent from the input.
+
+use core::fmt;
+
+use crate::{Date, OffsetDateTime, Time, UtcOffset};
+
+/// The values used in place of components that are absent from the input.
+///
+/// A default is only used if the input contains no information about that part of the value. A
+/// date without any year component uses the default year, a value without any time component uses
+/// the default time, and a value without any offset or time zone component uses the default
+/// offset. Components that are only partially present, such as an hour without a minute, are not
+/// completed from the defaults.
+///
+/// Defaults are applied by wrapping a format description in [`WithDefaults`], or by calling
+/// [`Parsed::apply_defaults`](crate::parsing::Parsed::apply_defaults) directly.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Defaults {
+    /// The year used if none is present.
+    pub(crate) year: Option<YearDefault>,
+    /// The time used if none is present.
+    pub(crate) time: Option<Time>,
+    /// The offset used if none is present.
+    pub(crate) offset: Option<UtcOffset>,
+}
+
+/// How the year is determined if none is present.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum YearDefault {
+    /// The provided year is used as-is.
+    Exact(i32),
+    /// The year that places the date closest to the provided date is used.
+    NearestTo(Date),
+}
+
+impl Defaults {
+    /// Create a new `Defaults` with no values. Parsing is unaffected.
+    pub const fn new() -> Self {
+        Self {
+            year: None,
+            time: None,
+            offset: None,
+        }
+    }
+
+    /// Create a new `Defaults` from a reference moment, such as the current time.
+    ///
+    /// The time and offset of the reference are used as-is. The year is the one that places the
+    /// parsed date closest to the date of the reference, as with [`Self::with_year_nearest_to`].
+    ///
+    /// ```rust
+    /// # use time::parsing::{Defaults, WithDefaults};
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::{datetime, format_description};
+    /// let format = format_description!("[month repr:short] [day padding:space] [hour]:[minute]");
+    /// let defaults = Defaults::from_reference(datetime!(2024-01-01 0:00 UTC));
+    /// assert_eq!(
+    ///     PrimitiveDateTime::parse("Dec 31 23:59", &WithDefaults::new(&format, defaults))?,
+    ///     datetime!(2023-12-31 23:59)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn from_reference(reference: OffsetDateTime) -> Self {
+        Self {
+            year: Some(YearDefault::NearestTo(reference.date())),
+            time: Some(reference.time()),
+            offset: Some(reference.offset()),
+        }
+    }
+
+    /// Use the provided year if none is present.
+    pub const fn with_year(self, year: i32) -> Self {
+        Self {
+            year: Some(YearDefault::Exact(year)),
+            ..self
+        }
+    }
+
+    /// Use the year that places the parsed date closest to the provided date if no year is
+    /// present.
+    ///
+    /// Only the year of the reference date and the years immediately before and after it are
+    /// considered. This handles dates without a year, such as those in syslog messages, that were
+    /// recorded shortly before or after the turn of the year.
+    ///
+    /// ```rust
+    /// # use time::parsing::{Defaults, WithDefaults};
+    /// # use time::Date;
+    /// # use time_macros::{date, format_description};
+    /// let format = format_description!("[month]-[day]");
+    /// let defaults = Defaults::new().with_year_nearest_to(date!(2024 - 01 - 01));
+    /// let format = WithDefaults::new(&format, defaults);
+    /// assert_eq!(Date::parse("12-31", &format)?, date!(2023 - 12 - 31));
+    /// assert_eq!(Date::parse("01-02", &format)?, date!(2024 - 01 - 02));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn with_year_nearest_to(self, reference: Date) -> Self {
+        Self {
+            year: Some(YearDefault::NearestTo(reference)),
+            ..self
+        }
+    }
+
+    /// Use the provided time if none is present.
+    pub const fn with_time(self, time: Time) -> Self {
+        Self {
+            time: Some(time),
+            ..self
+        }
+    }
+
+    /// Use the provided offset if none is present.
+    pub const fn with_offset(self, offset: UtcOffset) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+}
+
+/// A format description that fills in any components absent from the input using [`Defaults`].
+///
+/// ```rust
+/// # use time::parsing::{Defaults, WithDefaults};
+/// # use time::{OffsetDateTime, Time};
+/// # use time_macros::{datetime, format_description, offset};
+/// let format = format_description!("[year]-[month]-[day]");
+/// let defaults = Defaults::new()
+///     .with_time(Time::MIDNIGHT)
+///     .with_offset(offset!(+1));
+/// assert_eq!(
+///     OffsetDateTime::parse("2024-03-03", &WithDefaults::new(&format, defaults))?,
+///     datetime!(2024-03-03 0:00 +1)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+pub struct WithDefaults<'a, T: ?Sized> {
+    /// The format description.
+    pub(crate) format: &'a T,
+    /// The values used in place of absent components.
+    pub(crate) defaults: Defaults,
+}
+
+impl<'a, T: ?Sized> WithDefaults<'a, T> {
+    /// Use the provided defaults for any components absent when parsing with the format
+    /// description.
+    pub const fn new(format: &'a T, defaults: Defaults) -> Self {
+        Self { format, defaults }
+    }
+}
+
+impl<T: ?Sized> Clone for WithDefaults<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for WithDefaults<'_, T> {}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for WithDefaults<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WithDefaults")
+            .field("format", &self.format)
+            .field("defaults", &self.defaults)
+            .finish()
+    }
+}
@@ -2,14 +2,13 @@
 
 use crate::convert::*;
 use crate::error;
-use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
 use crate::format_description::well_known::iso8601::EncodedConfig;
 use crate::format_description::well_known::Iso8601;
 use crate::parsing::combinator::rfc::iso8601::{
     day, dayk, dayo, float, hour, min, month, week, year, ExtendedKind,
 };
 use crate::parsing::combinator::{ascii_char, sign};
-use crate::parsing::{Parsed, ParsedItem};
+use crate::parsing::{invalid_component, invalid_literal, Parsed, ParsedItem};
 
 impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
     // Basic: [year][month][day]
@@ -22,10 +21,11 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
     pub(crate) fn parse_date<'a>(
         parsed: &'a mut Parsed,
         extended_kind: &'a mut ExtendedKind,
-    ) -> impl FnMut(&[u8]) -> Result<&[u8], error::Parse> + 'a {
+    ) -> impl FnMut(&[u8]) -> Result<&[u8], error::Located<error::Parse>> + 'a {
         move |input| {
             // Same for any acceptable format.
-            let ParsedItem(mut input, year) = year(input).ok_or(InvalidComponent("year"))?;
+            let ParsedItem(mut input, year) =
+                year(input).ok_or_else(|| invalid_component("year", input))?;
             *extended_kind = match ascii_char::<b'-'>(input) {
                 Some(ParsedItem(new_input, ())) => {
                     input = new_input;
@@ -35,23 +35,25 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
             };
 
             let mut ret_error = match (|| {
-                let ParsedItem(mut input, month) = month(input).ok_or(InvalidComponent("month"))?;
+                let ParsedItem(mut input, month) =
+                    month(input).ok_or_else(|| invalid_component("month", input))?;
                 if extended_kind.is_extended() {
                     input = ascii_char::<b'-'>(input)
-                        .ok_or(InvalidLiteral)?
+                        .ok_or_else(|| invalid_literal(input))?
                         .into_inner();
                 }
-                let ParsedItem(input, day) = day(input).ok_or(InvalidComponent("day"))?;
+                let ParsedItem(input, day) =
+                    day(input).ok_or_else(|| invalid_component("day", input))?;
                 Ok(ParsedItem(input, (month, day)))
             })() {
                 Ok(ParsedItem(input, (month, day))) => {
                     *parsed = parsed
                         .with_year(year)
-                        .ok_or(InvalidComponent("year"))?
+                        .ok_or_else(|| invalid_component("year", input))?
                         .with_month(month)
-                        .ok_or(InvalidComponent("month"))?
+                        .ok_or_else(|| invalid_component("month", input))?
                         .with_day(day)
-                        .ok_or(InvalidComponent("day"))?;
+                        .ok_or_else(|| invalid_component("day", input))?;
                     return Ok(input);
                 }
                 Err(err) => err,
@@ -61,35 +63,35 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
             if let Some(ParsedItem(input, ordinal)) = dayo(input) {
                 *parsed = parsed
                     .with_year(year)
-                    .ok_or(InvalidComponent("year"))?
+                    .ok_or_else(|| invalid_component("year", input))?
                     .with_ordinal(ordinal)
-                    .ok_or(InvalidComponent("ordinal"))?;
+                    .ok_or_else(|| invalid_component("ordinal", input))?;
                 return Ok(input);
             }
 
             match (|| {
                 let input = ascii_char::<b'W'>(input)
-                    .ok_or((false, InvalidLiteral))?
+                    .ok_or_else(|| (false, invalid_literal(input)))?
                     .into_inner();
                 let ParsedItem(mut input, week) =
-                    week(input).ok_or((true, InvalidComponent("week")))?;
+                    week(input).ok_or_else(|| (true, invalid_component("week", input)))?;
                 if extended_kind.is_extended() {
                     input = ascii_char::<b'-'>(input)
-                        .ok_or((true, InvalidLiteral))?
+                        .ok_or_else(|| (true, invalid_literal(input)))?
                         .into_inner();
                 }
                 let ParsedItem(input, weekday) =
-                    dayk(input).ok_or((true, InvalidComponent("weekday")))?;
+                    dayk(input).ok_or_else(|| (true, invalid_component("weekday", input)))?;
                 Ok(ParsedItem(input, (week, weekday)))
             })() {
                 Ok(ParsedItem(input, (week, weekday))) => {
                     *parsed = parsed
                         .with_iso_year(year)
-                        .ok_or(InvalidComponent("year"))?
+                        .ok_or_else(|| invalid_component("year", input))?
                         .with_iso_week_number(week)
-                        .ok_or(InvalidComponent("week"))?
+                        .ok_or_else(|| invalid_component("week", input))?
                         .with_weekday(weekday)
-                        .ok_or(InvalidComponent("weekday"))?;
+                        .ok_or_else(|| invalid_component("weekday", input))?;
                     return Ok(input);
                 }
                 Err((false, _err)) => {}
@@ -110,33 +112,36 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
         parsed: &'a mut Parsed,
         extended_kind: &'a mut ExtendedKind,
         date_is_present: bool,
-    ) -> impl FnMut(&[u8]) -> Result<&[u8], error::Parse> + 'a {
+    ) -> impl FnMut(&[u8]) -> Result<&[u8], error::Located<error::Parse>> + 'a {
         move |mut input| {
             if date_is_present {
                 input = ascii_char::<b'T'>(input)
-                    .ok_or(InvalidLiteral)?
+                    .ok_or_else(|| invalid_literal(input))?
                     .into_inner();
             }
 
-            let ParsedItem(mut input, hour) = float(input).ok_or(InvalidComponent("hour"))?;
+            let ParsedItem(mut input, hour) =
+                float(input).ok_or_else(|| invalid_component("hour", input))?;
             match hour {
-                (hour, None) => parsed.set_hour_24(hour).ok_or(InvalidComponent("hour"))?,
+                (hour, None) => parsed
+                    .set_hour_24(hour)
+                    .ok_or_else(|| invalid_component("hour", input))?,
                 (hour, Some(fractional_part)) => {
                     *parsed = parsed
                         .with_hour_24(hour)
-                        .ok_or(InvalidComponent("hour"))?
+                        .ok_or_else(|| invalid_component("hour", input))?
                         .with_minute((fractional_part * Second.per(Minute) as f64) as _)
-                        .ok_or(InvalidComponent("minute"))?
+                        .ok_or_else(|| invalid_component("minute", input))?
                         .with_second(
                             (fractional_part * Second.per(Hour) as f64 % Minute.per(Hour) as f64)
                                 as _,
                         )
-                        .ok_or(InvalidComponent("second"))?
+                        .ok_or_else(|| invalid_component("second", input))?
                         .with_subsecond(
                             (fractional_part * Nanosecond.per(Hour) as f64
                                 % Nanosecond.per(Second) as f64) as _,
                         )
-                        .ok_or(InvalidComponent("subsecond"))?;
+                        .ok_or_else(|| invalid_component("subsecond", input))?;
                     return Ok(input);
                 }
             };
@@ -144,7 +149,7 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
             if let Some(ParsedItem(new_input, ())) = ascii_char::<b':'>(input) {
                 extended_kind
                     .coerce_extended()
-                    .ok_or(InvalidComponent("minute"))?;
+                    .ok_or_else(|| invalid_component("minute", input))?;
                 input = new_input;
             };
 
@@ -153,7 +158,7 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                     extended_kind.coerce_basic();
                     parsed
                         .set_minute(minute)
-                        .ok_or(InvalidComponent("minute"))?;
+                        .ok_or_else(|| invalid_component("minute", input))?;
                     input
                 }
                 Some(ParsedItem(input, (minute, Some(fractional_part)))) => {
@@ -161,31 +166,29 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                     extended_kind.coerce_basic();
                     *parsed = parsed
                         .with_minute(minute)
-                        .ok_or(InvalidComponent("minute"))?
+                        .ok_or_else(|| invalid_component("minute", input))?
                         .with_second((fractional_part * Second.per(Minute) as f64) as _)
-                        .ok_or(InvalidComponent("second"))?
+                        .ok_or_else(|| invalid_component("second", input))?
                         .with_subsecond(
                             (fractional_part * Nanosecond.per(Minute) as f64
                                 % Nanosecond.per(Second) as f64) as _,
                         )
-                        .ok_or(InvalidComponent("subsecond"))?;
+                        .ok_or_else(|| invalid_component("subsecond", input))?;
                     return Ok(input);
                 }
                 // colon was present, so minutes are required
                 None if extended_kind.is_extended() => {
-                    return Err(error::Parse::ParseFromDescription(InvalidComponent(
-                        "minute",
-                    )));
+                    return Err(invalid_component("minute", input).into());
                 }
                 None => {
                     // Missing components are assumed to be zero.
                     *parsed = parsed
                         .with_minute(0)
-                        .ok_or(InvalidComponent("minute"))?
+                        .ok_or_else(|| invalid_component("minute", input))?
                         .with_second(0)
-                        .ok_or(InvalidComponent("second"))?
+                        .ok_or_else(|| invalid_component("second", input))?
                         .with_subsecond(0)
-                        .ok_or(InvalidComponent("subsecond"))?;
+                        .ok_or_else(|| invalid_component("subsecond", input))?;
                     return Ok(input);
                 }
             };
@@ -196,9 +199,9 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                     None => {
                         *parsed = parsed
                             .with_second(0)
-                            .ok_or(InvalidComponent("second"))?
+                            .ok_or_else(|| invalid_component("second", input))?
                             .with_subsecond(0)
-                            .ok_or(InvalidComponent("subsecond"))?;
+                            .ok_or_else(|| invalid_component("subsecond", input))?;
                         return Ok(input);
                     }
                 }
@@ -212,18 +215,16 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                     round(fractional_part * Nanosecond.per(Second) as f64) as _,
                 ),
                 None if extended_kind.is_extended() => {
-                    return Err(error::Parse::ParseFromDescription(InvalidComponent(
-                        "second",
-                    )));
+                    return Err(invalid_component("second", input).into());
                 }
                 // Missing components are assumed to be zero.
                 None => (input, 0, 0),
             };
             *parsed = parsed
                 .with_second(second)
-                .ok_or(InvalidComponent("second"))?
+                .ok_or_else(|| invalid_component("second", input))?
                 .with_subsecond(subsecond)
-                .ok_or(InvalidComponent("subsecond"))?;
+                .ok_or_else(|| invalid_component("subsecond", input))?;
 
             Ok(input)
         }
@@ -236,20 +237,21 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
     pub(crate) fn parse_offset<'a>(
         parsed: &'a mut Parsed,
         extended_kind: &'a mut ExtendedKind,
-    ) -> impl FnMut(&[u8]) -> Result<&[u8], error::Parse> + 'a {
+    ) -> impl FnMut(&[u8]) -> Result<&[u8], error::Located<error::Parse>> + 'a {
         move |input| {
             if let Some(ParsedItem(input, ())) = ascii_char::<b'Z'>(input) {
                 *parsed = parsed
                     .with_offset_hour(0)
-                    .ok_or(InvalidComponent("offset hour"))?
+                    .ok_or_else(|| invalid_component("offset hour", input))?
                     .with_offset_minute_signed(0)
-                    .ok_or(InvalidComponent("offset minute"))?
+                    .ok_or_else(|| invalid_component("offset minute", input))?
                     .with_offset_second_signed(0)
-                    .ok_or(InvalidComponent("offset second"))?;
+                    .ok_or_else(|| invalid_component("offset second", input))?;
                 return Ok(input);
             }
 
-            let ParsedItem(input, sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+            let ParsedItem(input, sign) =
+                sign(input).ok_or_else(|| invalid_component("offset hour", input))?;
             let mut input = hour(input)
                 .and_then(|parsed_item| {
                     parsed_item.consume_value(|hour| {
@@ -260,13 +262,13 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                         })
                     })
                 })
-                .ok_or(InvalidComponent("offset hour"))?;
+                .ok_or_else(|| invalid_component("offset hour", input))?;
 
             if extended_kind.maybe_extended() {
                 if let Some(ParsedItem(new_input, ())) = ascii_char::<b':'>(input) {
                     extended_kind
                         .coerce_extended()
-                        .ok_or(InvalidComponent("offset minute"))?;
+                        .ok_or_else(|| invalid_component("offset minute", input))?;
                     input = new_input;
                 };
             }
@@ -281,7 +283,7 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                         })
                     })
                 })
-                .ok_or(InvalidComponent("offset minute"))?;
+                .ok_or_else(|| invalid_component("offset minute", input))?;
             // If `:` was present, the format has already been set to extended. As such, this call
             // will do nothing in that case. If there wasn't `:` but minutes were
             // present, we know it's the basic format. Do not use `?` on the call, as
@@ -312,5 +314,9 @@ fn round_impl(value: f64) -> f64 {
     debug_assert!(value.is_sign_positive() && !value.is_nan());
 
     let f = value % 1.;
-    if f < 0.5 { value - f } else { value - f + 1. }
+    if f < 0.5 {
+        value - f
+    } else {
+        value - f + 1.
+    }
 }
@@ -0,0 +1,84 @@
+//! The location within the input at which parsing failed.
+
+use core::fmt;
+
+/// The maximum length of the snippet of input retained by a [`Location`], in bytes.
+const SNIPPET_LEN: usize = 16;
+
+/// The location within the input at which parsing failed.
+///
+/// Along with the offset, a short snippet of the input beginning at that offset is retained. This
+/// allows an error message to show what was found in place of what was expected.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Location {
+    /// The length of the input the location is relative to.
+    input_len: usize,
+    /// The length of the input that remained when parsing failed.
+    remaining_len: usize,
+    /// The number of bytes in the snippet.
+    snippet_len: u8,
+    /// The snippet of input. Only the first `snippet_len` bytes are meaningful.
+    snippet: [u8; SNIPPET_LEN],
+}
+
+impl Location {
+    /// Record that parsing failed at the start of the remaining input. The snippet is only filled
+    /// in once the location is made [relative to](Self::relative_to) the full input.
+    pub(crate) const fn new(remaining: &[u8]) -> Self {
+        Self {
+            input_len: remaining.len(),
+            remaining_len: remaining.len(),
+            snippet_len: 0,
+            snippet: [0; SNIPPET_LEN],
+        }
+    }
+
+    /// Make the location relative to the start of the provided input. The input must end with the
+    /// input that remained when parsing failed.
+    pub(crate) const fn relative_to(mut self, input: &[u8]) -> Self {
+        self.input_len = input.len();
+        let offset = self.offset();
+        let mut snippet_len = 0;
+        while snippet_len < SNIPPET_LEN && offset + snippet_len < input.len() {
+            self.snippet[snippet_len] = input[offset + snippet_len];
+            snippet_len += 1;
+        }
+        self.snippet_len = snippet_len as _;
+        self
+    }
+
+    /// Obtain the offset in bytes from the start of the input.
+    pub(crate) const fn offset(self) -> usize {
+        self.input_len.saturating_sub(self.remaining_len)
+    }
+
+    /// Obtain a short snippet of the input beginning at the offset. If parsing failed at the end of
+    /// the input, the snippet is empty.
+    ///
+    /// The snippet is truncated to at most 16 bytes, ending on a character boundary.
+    pub(crate) fn snippet(&self) -> &str {
+        let snippet = &self.snippet[..self.snippet_len as usize];
+        match core::str::from_utf8(snippet) {
+            Ok(snippet) => snippet,
+            Err(err) => core::str::from_utf8(&snippet[..err.valid_up_to()]).unwrap_or_default(),
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.snippet() {
+            "" => write!(f, "at byte index {} (found end of input)", self.offset()),
+            snippet => write!(f, "at byte index {} (found \"{snippet}\")", self.offset()),
+        }
+    }
+}
+
+impl fmt::Debug for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Location")
+            .field("offset", &self.offset())
+            .field("snippet", &self.snippet())
+            .finish()
+    }
+}
@@ -2,13 +2,18 @@
 
 pub(crate) mod combinator;
 pub(crate) mod component;
+mod defaults;
 mod iso8601;
+mod location;
 pub(crate) mod parsable;
 mod parsed;
 pub(crate) mod shim;
 
+pub use self::defaults::{Defaults, WithDefaults};
+pub(crate) use self::location::Location;
 pub use self::parsable::Parsable;
 pub use self::parsed::Parsed;
+use crate::error;
 
 /// An item that has been parsed. Represented as a `(remaining, value)` pair.
 #[derive(Debug)]
@@ -48,3 +53,48 @@ impl<'a> ParsedItem<'a, Option<()>> {
         self.0
     }
 }
+
+/// Create an error indicating that a literal was expected at the start of the remaining input.
+pub(crate) const fn invalid_literal(
+    remaining: &[u8],
+) -> error::Located<error::ParseFromDescription> {
+    error::Located {
+        error: error::ParseFromDescription::InvalidLiteral,
+        location: Some(Location::new(remaining)),
+    }
+}
+
+/// Create an error indicating that the named component was expected at the start of the remaining
+/// input.
+pub(crate) const fn invalid_component(
+    name: &'static str,
+    remaining: &[u8],
+) -> error::Located<error::ParseFromDescription> {
+    error::Located {
+        error: error::ParseFromDescription::InvalidComponent(name),
+        location: Some(Location::new(remaining)),
+    }
+}
+
+/// Create an error indicating that the remaining input should have been empty.
+pub(crate) const fn unexpected_trailing_characters(
+    remaining: &[u8],
+) -> error::Located<error::Parse> {
+    error::Located {
+        error: error::Parse::UnexpectedTrailingCharacters,
+        location: Some(Location::new(remaining)),
+    }
+}
+
+/// Obtain the portion of the input that remains after parsing.
+///
+/// As the remaining input is obtained from the bytes of a `str`, parsing may have stopped partway
+/// through a character. This is treated as though a literal was not matched.
+pub(crate) fn remaining_str<'a>(
+    input: &'a str,
+    remaining: &[u8],
+) -> Result<&'a str, error::Located<error::ParseFromDescription>> {
+    input
+        .get(input.len() - remaining.len()..)
+        .ok_or_else(|| invalid_literal(remaining).relative_to(input.as_bytes()))
+}
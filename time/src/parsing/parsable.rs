@@ -4,13 +4,20 @@ use core::ops::Deref;
 
 use crate::date_time::{maybe_offset_from_offset, MaybeOffset};
 use crate::error::TryFromParsed;
+use crate::format_description::locale::{Locale, Localized};
 use crate::format_description::well_known::iso8601::EncodedConfig;
-use crate::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+use crate::format_description::well_known::rfc3339::{
+    EncodedConfig as Rfc3339EncodedConfig, Separator,
+};
+use crate::format_description::well_known::{
+    GeneralizedTime, HttpDate, Iso8601, Rfc2822, Rfc3164, Rfc3339, Rfc5424, Rfc5545, Sql, UtcTime,
+};
 use crate::format_description::FormatItem;
 #[cfg(feature = "alloc")]
 use crate::format_description::OwnedFormatItem;
-use crate::parsing::{Parsed, ParsedItem};
-use crate::{error, Date, DateTime, Month, Time, UtcOffset, Weekday};
+use crate::parsing::parsed::{Accumulator, ParsedDuration};
+use crate::parsing::{unexpected_trailing_characters, Parsed, ParsedItem, WithDefaults};
+use crate::{error, Date, DateTime, Duration, Month, Time, UtcOffset, Weekday};
 
 /// A type that can be parsed.
 #[cfg_attr(__time_03_docs, doc(notable_trait))]
@@ -22,8 +29,17 @@ impl Parsable for OwnedFormatItem {}
 #[cfg(feature = "alloc")]
 impl Parsable for [OwnedFormatItem] {}
 impl Parsable for Rfc2822 {}
-impl Parsable for Rfc3339 {}
+impl<const CONFIG: Rfc3339EncodedConfig> Parsable for Rfc3339<CONFIG> {}
+impl Parsable for HttpDate {}
+impl Parsable for Rfc3164 {}
+impl Parsable for Rfc5424 {}
+impl Parsable for Rfc5545 {}
+impl Parsable for UtcTime {}
+impl Parsable for GeneralizedTime {}
+impl Parsable for Sql {}
 impl<const CONFIG: EncodedConfig> Parsable for Iso8601<CONFIG> {}
+impl<T: Parsable + ?Sized> Parsable for Localized<'_, T> {}
+impl<T: Parsable + ?Sized> Parsable for WithDefaults<'_, T> {}
 impl<T: Deref> Parsable for T where T::Target: Parsable {}
 
 /// Seal the trait to prevent downstream users from implementing it, while still allowing it to
@@ -41,41 +57,101 @@ mod sealed {
             &self,
             input: &'a [u8],
             parsed: &mut Parsed,
-        ) -> Result<&'a [u8], error::Parse>;
+            locale: Option<&dyn Locale>,
+        ) -> Result<&'a [u8], error::Located<error::Parse>>;
 
         /// Parse the item into a new [`Parsed`] struct.
         ///
         /// This method can only be used to parse a complete value of a type. If any characters
         /// remain after parsing, an error will be returned.
-        fn parse(&self, input: &[u8]) -> Result<Parsed, error::Parse> {
+        fn parse(&self, input: &[u8]) -> Result<Parsed, error::Located<error::Parse>> {
+            match self.parse_prefix(input)? {
+                (parsed, []) => Ok(parsed),
+                (_, remaining) => Err(unexpected_trailing_characters(remaining).relative_to(input)),
+            }
+        }
+
+        /// Parse the item into a new [`Parsed`] struct, returning it along with the input that
+        /// remains after parsing.
+        fn parse_prefix<'a>(
+            &self,
+            input: &'a [u8],
+        ) -> Result<(Parsed, &'a [u8]), error::Located<error::Parse>> {
             let mut parsed = Parsed::new();
-            if self.parse_into(input, &mut parsed)?.is_empty() {
-                Ok(parsed)
-            } else {
-                Err(error::Parse::UnexpectedTrailingCharacters)
+            match self.parse_into(input, &mut parsed, None) {
+                Ok(remaining) => Ok((parsed, remaining)),
+                Err(err) => Err(err.relative_to(input)),
             }
         }
 
         /// Parse a [`Date`] from the format description.
-        fn parse_date(&self, input: &[u8]) -> Result<Date, error::Parse> {
+        fn parse_date(&self, input: &[u8]) -> Result<Date, error::Located<error::Parse>> {
             Ok(self.parse(input)?.try_into()?)
         }
 
         /// Parse a [`Time`] from the format description.
-        fn parse_time(&self, input: &[u8]) -> Result<Time, error::Parse> {
+        fn parse_time(&self, input: &[u8]) -> Result<Time, error::Located<error::Parse>> {
             Ok(self.parse(input)?.try_into()?)
         }
 
         /// Parse a [`UtcOffset`] from the format description.
-        fn parse_offset(&self, input: &[u8]) -> Result<UtcOffset, error::Parse> {
+        fn parse_offset(&self, input: &[u8]) -> Result<UtcOffset, error::Located<error::Parse>> {
             Ok(self.parse(input)?.try_into()?)
         }
 
+        /// Parse the item into the provided [`ParsedDuration`] struct.
+        ///
+        /// Only format descriptions that are able to contain the components of a duration need to
+        /// override this method. Any other format description is parsed as usual, which results in
+        /// no components of the duration being known.
+        fn parse_duration_into<'a>(
+            &self,
+            input: &'a [u8],
+            parsed: &mut ParsedDuration,
+            locale: Option<&dyn Locale>,
+        ) -> Result<&'a [u8], error::Located<error::Parse>> {
+            self.parse_into(input, &mut parsed.parsed, locale)
+        }
+
+        /// Whether parsing a [`Duration`] reads its sign. If it does not, a leading `-` indicates a
+        /// negative duration, matching the output when formatting.
+        fn reads_duration_sign(&self) -> bool {
+            true
+        }
+
+        /// Parse the item into a new [`ParsedDuration`] struct, returning it along with the input
+        /// that remains after parsing.
+        fn parse_duration_prefix<'a>(
+            &self,
+            input: &'a [u8],
+        ) -> Result<(ParsedDuration, &'a [u8]), error::Located<error::Parse>> {
+            let mut parsed = ParsedDuration::new();
+            let mut remaining = input;
+            if !self.reads_duration_sign() {
+                if let Some(rest) = input.strip_prefix(b"-") {
+                    parsed.set_is_negative(true);
+                    remaining = rest;
+                }
+            }
+            match self.parse_duration_into(remaining, &mut parsed, None) {
+                Ok(remaining) => Ok((parsed, remaining)),
+                Err(err) => Err(err.relative_to(input)),
+            }
+        }
+
+        /// Parse a [`Duration`] from the format description.
+        fn parse_duration(&self, input: &[u8]) -> Result<Duration, error::Located<error::Parse>> {
+            match self.parse_duration_prefix(input)? {
+                (parsed, []) => Ok(parsed.try_into()?),
+                (_, remaining) => Err(unexpected_trailing_characters(remaining).relative_to(input)),
+            }
+        }
+
         /// Parse a [`DateTime`] from the format description.
         fn parse_date_time<O: MaybeOffset>(
             &self,
             input: &[u8],
-        ) -> Result<DateTime<O>, error::Parse> {
+        ) -> Result<DateTime<O>, error::Located<error::Parse>> {
             Ok(self.parse(input)?.try_into()?)
         }
     }
@@ -87,8 +163,22 @@ impl sealed::Sealed for FormatItem<'_> {
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
-    ) -> Result<&'a [u8], error::Parse> {
-        Ok(parsed.parse_item(input, self)?)
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        Ok(parsed.parse_item_with_locale(input, self, locale)?)
+    }
+
+    fn parse_duration_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut ParsedDuration,
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        Ok(parsed.parse_item_with_locale(input, self, locale)?)
+    }
+
+    fn reads_duration_sign(&self) -> bool {
+        self.has_duration_sign()
     }
 }
 
@@ -97,8 +187,22 @@ impl sealed::Sealed for [FormatItem<'_>] {
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
-    ) -> Result<&'a [u8], error::Parse> {
-        Ok(parsed.parse_items(input, self)?)
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        Ok(parsed.parse_items_with_locale(input, self, locale)?)
+    }
+
+    fn parse_duration_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut ParsedDuration,
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        Ok(parsed.parse_items_with_locale(input, self, locale)?)
+    }
+
+    fn reads_duration_sign(&self) -> bool {
+        self.iter().any(|item| item.has_duration_sign())
     }
 }
 
@@ -108,8 +212,22 @@ impl sealed::Sealed for OwnedFormatItem {
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
-    ) -> Result<&'a [u8], error::Parse> {
-        Ok(parsed.parse_item(input, self)?)
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        Ok(parsed.parse_item_with_locale(input, self, locale)?)
+    }
+
+    fn parse_duration_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut ParsedDuration,
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        Ok(parsed.parse_item_with_locale(input, self, locale)?)
+    }
+
+    fn reads_duration_sign(&self) -> bool {
+        self.has_duration_sign()
     }
 }
 
@@ -119,8 +237,22 @@ impl sealed::Sealed for [OwnedFormatItem] {
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
-    ) -> Result<&'a [u8], error::Parse> {
-        Ok(parsed.parse_items(input, self)?)
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        Ok(parsed.parse_items_with_locale(input, self, locale)?)
+    }
+
+    fn parse_duration_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut ParsedDuration,
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        Ok(parsed.parse_items_with_locale(input, self, locale)?)
+    }
+
+    fn reads_duration_sign(&self) -> bool {
+        self.iter().any(|item| item.has_duration_sign())
     }
 }
 
@@ -132,8 +264,74 @@ where
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
-    ) -> Result<&'a [u8], error::Parse> {
-        self.deref().parse_into(input, parsed)
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        self.deref().parse_into(input, parsed, locale)
+    }
+
+    fn parse_duration_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut ParsedDuration,
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        self.deref().parse_duration_into(input, parsed, locale)
+    }
+
+    fn reads_duration_sign(&self) -> bool {
+        self.deref().reads_duration_sign()
+    }
+}
+
+impl<T: sealed::Sealed + ?Sized> sealed::Sealed for Localized<'_, T> {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        self.format.parse_into(input, parsed, Some(self.locale))
+    }
+
+    fn parse_duration_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut ParsedDuration,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        self.format
+            .parse_duration_into(input, parsed, Some(self.locale))
+    }
+
+    fn reads_duration_sign(&self) -> bool {
+        self.format.reads_duration_sign()
+    }
+}
+
+impl<T: sealed::Sealed + ?Sized> sealed::Sealed for WithDefaults<'_, T> {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        let remaining = self.format.parse_into(input, parsed, locale)?;
+        parsed.apply_defaults(self.defaults);
+        Ok(remaining)
+    }
+
+    // The defaults do not include any component of a duration.
+    fn parse_duration_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut ParsedDuration,
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        self.format.parse_duration_into(input, parsed, locale)
+    }
+
+    fn reads_duration_sign(&self) -> bool {
+        self.format.reads_duration_sign()
     }
 }
 // endregion custom formats
@@ -144,12 +342,13 @@ impl sealed::Sealed for Rfc2822 {
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
-    ) -> Result<&'a [u8], error::Parse> {
-        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+        _locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
         use crate::parsing::combinator::rfc::rfc2822::{cfws, fws};
         use crate::parsing::combinator::{
             ascii_char, exactly_n_digits, first_match, n_to_m_digits, opt, sign,
         };
+        use crate::parsing::{invalid_component, invalid_literal};
 
         let colon = ascii_char::<b':'>;
         let comma = ascii_char::<b','>;
@@ -168,13 +367,19 @@ impl sealed::Sealed for Rfc2822 {
             false,
         )(input)
         .and_then(|item| item.consume_value(|value| parsed.set_weekday(value)))
-        .ok_or(InvalidComponent("weekday"))?;
-        let input = comma(input).ok_or(InvalidLiteral)?.into_inner();
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+        .ok_or_else(|| invalid_component("weekday", input))?;
+        let input = comma(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
+        let input = cfws(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
         let input = n_to_m_digits::<1, 2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
-            .ok_or(InvalidComponent("day"))?;
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+            .ok_or_else(|| invalid_component("day", input))?;
+        let input = cfws(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
         let input = first_match(
             [
                 (b"Jan".as_slice(), Month::January),
@@ -193,15 +398,19 @@ impl sealed::Sealed for Rfc2822 {
             false,
         )(input)
         .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
-        .ok_or(InvalidComponent("month"))?;
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+        .ok_or_else(|| invalid_component("month", input))?;
+        let input = cfws(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
         let input = match exactly_n_digits::<4, u32>(input) {
             Some(item) => {
                 let input = item
                     .flat_map(|year| if year >= 1900 { Some(year) } else { None })
                     .and_then(|item| item.consume_value(|value| parsed.set_year(value as _)))
-                    .ok_or(InvalidComponent("year"))?;
-                fws(input).ok_or(InvalidLiteral)?.into_inner()
+                    .ok_or_else(|| invalid_component("year", input))?;
+                fws(input)
+                    .ok_or_else(|| invalid_literal(input))?
+                    .into_inner()
             }
             None => {
                 let input = exactly_n_digits::<2, u32>(input)
@@ -210,30 +419,38 @@ impl sealed::Sealed for Rfc2822 {
                             .map(|year| year as _)
                             .consume_value(|value| parsed.set_year(value))
                     })
-                    .ok_or(InvalidComponent("year"))?;
-                cfws(input).ok_or(InvalidLiteral)?.into_inner()
+                    .ok_or_else(|| invalid_component("year", input))?;
+                cfws(input)
+                    .ok_or_else(|| invalid_literal(input))?
+                    .into_inner()
             }
         };
 
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
-            .ok_or(InvalidComponent("hour"))?;
+            .ok_or_else(|| invalid_component("hour", input))?;
         let input = opt(cfws)(input).into_inner();
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
         let input = opt(cfws)(input).into_inner();
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
-            .ok_or(InvalidComponent("minute"))?;
+            .ok_or_else(|| invalid_component("minute", input))?;
 
         let input = if let Some(input) = colon(opt(cfws)(input).into_inner()) {
             let input = input.into_inner(); // discard the colon
             let input = opt(cfws)(input).into_inner();
             let input = exactly_n_digits::<2, _>(input)
                 .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
-                .ok_or(InvalidComponent("second"))?;
-            cfws(input).ok_or(InvalidLiteral)?.into_inner()
+                .ok_or_else(|| invalid_component("second", input))?;
+            cfws(input)
+                .ok_or_else(|| invalid_literal(input))?
+                .into_inner()
         } else {
-            cfws(input).ok_or(InvalidLiteral)?.into_inner()
+            cfws(input)
+                .ok_or_else(|| invalid_literal(input))?
+                .into_inner()
         };
 
         // The RFC explicitly allows leap seconds.
@@ -265,17 +482,18 @@ impl sealed::Sealed for Rfc2822 {
         if let Some(zone_literal) = zone_literal {
             let input = zone_literal
                 .consume_value(|value| parsed.set_offset_hour(value))
-                .ok_or(InvalidComponent("offset hour"))?;
+                .ok_or_else(|| invalid_component("offset hour", input))?;
             parsed
                 .set_offset_minute_signed(0)
-                .ok_or(InvalidComponent("offset minute"))?;
+                .ok_or_else(|| invalid_component("offset minute", input))?;
             parsed
                 .set_offset_second_signed(0)
-                .ok_or(InvalidComponent("offset second"))?;
+                .ok_or_else(|| invalid_component("offset second", input))?;
             return Ok(input);
         }
 
-        let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+        let ParsedItem(input, offset_sign) =
+            sign(input).ok_or_else(|| invalid_component("offset hour", input))?;
         let input = exactly_n_digits::<2, u8>(input)
             .and_then(|item| {
                 item.map(|offset_hour| {
@@ -287,22 +505,25 @@ impl sealed::Sealed for Rfc2822 {
                 })
                 .consume_value(|value| parsed.set_offset_hour(value))
             })
-            .ok_or(InvalidComponent("offset hour"))?;
+            .ok_or_else(|| invalid_component("offset hour", input))?;
         let input = exactly_n_digits::<2, u8>(input)
             .and_then(|item| {
                 item.consume_value(|value| parsed.set_offset_minute_signed(value as _))
             })
-            .ok_or(InvalidComponent("offset minute"))?;
+            .ok_or_else(|| invalid_component("offset minute", input))?;
 
         Ok(input)
     }
 
-    fn parse_date_time<O: MaybeOffset>(&self, input: &[u8]) -> Result<DateTime<O>, error::Parse> {
-        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+    fn parse_date_time<O: MaybeOffset>(
+        &self,
+        input: &[u8],
+    ) -> Result<DateTime<O>, error::Located<error::Parse>> {
         use crate::parsing::combinator::rfc::rfc2822::{cfws, fws};
         use crate::parsing::combinator::{
             ascii_char, exactly_n_digits, first_match, n_to_m_digits, opt, sign,
         };
+        use crate::parsing::{invalid_component, invalid_literal};
 
         let colon = ascii_char::<b':'>;
         let comma = ascii_char::<b','>;
@@ -322,12 +543,18 @@ impl sealed::Sealed for Rfc2822 {
             ],
             false,
         )(input)
-        .ok_or(InvalidComponent("weekday"))?;
-        let input = comma(input).ok_or(InvalidLiteral)?.into_inner();
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+        .ok_or_else(|| invalid_component("weekday", input))?;
+        let input = comma(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
+        let input = cfws(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
         let ParsedItem(input, day) =
-            n_to_m_digits::<1, 2, _>(input).ok_or(InvalidComponent("day"))?;
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+            n_to_m_digits::<1, 2, _>(input).ok_or_else(|| invalid_component("day", input))?;
+        let input = cfws(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
         let ParsedItem(input, month) = first_match(
             [
                 (b"Jan".as_slice(), Month::January),
@@ -345,42 +572,57 @@ impl sealed::Sealed for Rfc2822 {
             ],
             false,
         )(input)
-        .ok_or(InvalidComponent("month"))?;
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+        .ok_or_else(|| invalid_component("month", input))?;
+        let input = cfws(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
         let (input, year) = match exactly_n_digits::<4, u32>(input) {
             Some(item) => {
                 let ParsedItem(input, year) = item
                     .flat_map(|year| if year >= 1900 { Some(year) } else { None })
-                    .ok_or(InvalidComponent("year"))?;
-                let input = fws(input).ok_or(InvalidLiteral)?.into_inner();
+                    .ok_or_else(|| invalid_component("year", input))?;
+                let input = fws(input)
+                    .ok_or_else(|| invalid_literal(input))?
+                    .into_inner();
                 (input, year)
             }
             None => {
                 let ParsedItem(input, year) = exactly_n_digits::<2, u32>(input)
                     .map(|item| item.map(|year| if year < 50 { year + 2000 } else { year + 1900 }))
-                    .ok_or(InvalidComponent("year"))?;
-                let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+                    .ok_or_else(|| invalid_component("year", input))?;
+                let input = cfws(input)
+                    .ok_or_else(|| invalid_literal(input))?
+                    .into_inner();
                 (input, year)
             }
         };
 
         let ParsedItem(input, hour) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("hour"))?;
+            exactly_n_digits::<2, _>(input).ok_or_else(|| invalid_component("hour", input))?;
         let input = opt(cfws)(input).into_inner();
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
         let input = opt(cfws)(input).into_inner();
         let ParsedItem(input, minute) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("minute"))?;
+            exactly_n_digits::<2, _>(input).ok_or_else(|| invalid_component("minute", input))?;
 
         let (input, mut second) = if let Some(input) = colon(opt(cfws)(input).into_inner()) {
             let input = input.into_inner(); // discard the colon
             let input = opt(cfws)(input).into_inner();
-            let ParsedItem(input, second) =
-                exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("second"))?;
-            let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+            let ParsedItem(input, second) = exactly_n_digits::<2, _>(input)
+                .ok_or_else(|| invalid_component("second", input))?;
+            let input = cfws(input)
+                .ok_or_else(|| invalid_literal(input))?
+                .into_inner();
             (input, second)
         } else {
-            (cfws(input).ok_or(InvalidLiteral)?.into_inner(), 0)
+            (
+                cfws(input)
+                    .ok_or_else(|| invalid_literal(input))?
+                    .into_inner(),
+                0,
+            )
         };
 
         #[allow(clippy::unnecessary_lazy_evaluations)] // rust-lang/rust-clippy#8522
@@ -412,7 +654,7 @@ impl sealed::Sealed for Rfc2822 {
             (input, offset_hour, 0)
         } else {
             let ParsedItem(input, offset_sign) =
-                sign(input).ok_or(InvalidComponent("offset hour"))?;
+                sign(input).ok_or_else(|| invalid_component("offset hour", input))?;
             let ParsedItem(input, offset_hour) = exactly_n_digits::<2, u8>(input)
                 .map(|item| {
                     item.map(|offset_hour| {
@@ -423,14 +665,14 @@ impl sealed::Sealed for Rfc2822 {
                         }
                     })
                 })
-                .ok_or(InvalidComponent("offset hour"))?;
-            let ParsedItem(input, offset_minute) =
-                exactly_n_digits::<2, u8>(input).ok_or(InvalidComponent("offset minute"))?;
+                .ok_or_else(|| invalid_component("offset hour", input))?;
+            let ParsedItem(input, offset_minute) = exactly_n_digits::<2, u8>(input)
+                .ok_or_else(|| invalid_component("offset minute", input))?;
             (input, offset_hour, offset_minute as i8)
         };
 
         if !input.is_empty() {
-            return Err(error::Parse::UnexpectedTrailingCharacters);
+            return Err(unexpected_trailing_characters(input));
         }
 
         let mut nanosecond = 0;
@@ -457,64 +699,885 @@ impl sealed::Sealed for Rfc2822 {
         .map_err(TryFromParsed::ComponentRange)?;
 
         if leap_second_input && !dt.is_valid_leap_second_stand_in() {
-            return Err(error::Parse::TryFromParsed(TryFromParsed::ComponentRange(
-                error::ComponentRange {
-                    name: "second",
-                    minimum: 0,
-                    maximum: 59,
-                    value: 60,
-                    conditional_range: true,
-                },
-            )));
+            return Err(TryFromParsed::ComponentRange(error::ComponentRange {
+                name: "second",
+                minimum: 0,
+                maximum: 59,
+                value: 60,
+                conditional_range: true,
+            })
+            .into());
         }
 
         Ok(dt)
     }
 }
 
-impl sealed::Sealed for Rfc3339 {
+/// Parse an abbreviated month name, as used by RFC 3164 and RFC 9110.
+fn abbreviated_month<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<&'a [u8], error::Located<error::Parse>> {
+    use crate::parsing::combinator::first_match;
+    use crate::parsing::invalid_component;
+
+    Ok(first_match(
+        [
+            (b"Jan".as_slice(), Month::January),
+            (b"Feb".as_slice(), Month::February),
+            (b"Mar".as_slice(), Month::March),
+            (b"Apr".as_slice(), Month::April),
+            (b"May".as_slice(), Month::May),
+            (b"Jun".as_slice(), Month::June),
+            (b"Jul".as_slice(), Month::July),
+            (b"Aug".as_slice(), Month::August),
+            (b"Sep".as_slice(), Month::September),
+            (b"Oct".as_slice(), Month::October),
+            (b"Nov".as_slice(), Month::November),
+            (b"Dec".as_slice(), Month::December),
+        ],
+        false,
+    )(input)
+    .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
+    .ok_or_else(|| invalid_component("month", input))?)
+}
+
+/// Parse a time of day in the form `hh:mm:ss`, as used by RFC 3164 and RFC 9110.
+fn time_of_day<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<&'a [u8], error::Located<error::Parse>> {
+    use crate::parsing::combinator::{ascii_char, exactly_n_digits};
+    use crate::parsing::{invalid_component, invalid_literal};
+
+    let colon = ascii_char::<b':'>;
+
+    let input = exactly_n_digits::<2, _>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
+        .ok_or_else(|| invalid_component("hour", input))?;
+    let input = colon(input)
+        .ok_or_else(|| invalid_literal(input))?
+        .into_inner();
+    let input = exactly_n_digits::<2, _>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
+        .ok_or_else(|| invalid_component("minute", input))?;
+    let input = colon(input)
+        .ok_or_else(|| invalid_literal(input))?
+        .into_inner();
+    let input = exactly_n_digits::<2, _>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
+        .ok_or_else(|| invalid_component("second", input))?;
+    Ok(input)
+}
+
+impl sealed::Sealed for HttpDate {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        use crate::parsing::combinator::{ascii_char, exactly_n_digits, first_match};
+        use crate::parsing::{invalid_component, invalid_literal};
+
+        let space = ascii_char::<b' '>;
+        let dash = ascii_char::<b'-'>;
+
+        // The full names are used by the RFC 850 format and must be checked first.
+        let input = first_match(
+            [
+                (b"Monday".as_slice(), Weekday::Monday),
+                (b"Tuesday".as_slice(), Weekday::Tuesday),
+                (b"Wednesday".as_slice(), Weekday::Wednesday),
+                (b"Thursday".as_slice(), Weekday::Thursday),
+                (b"Friday".as_slice(), Weekday::Friday),
+                (b"Saturday".as_slice(), Weekday::Saturday),
+                (b"Sunday".as_slice(), Weekday::Sunday),
+                (b"Mon".as_slice(), Weekday::Monday),
+                (b"Tue".as_slice(), Weekday::Tuesday),
+                (b"Wed".as_slice(), Weekday::Wednesday),
+                (b"Thu".as_slice(), Weekday::Thursday),
+                (b"Fri".as_slice(), Weekday::Friday),
+                (b"Sat".as_slice(), Weekday::Saturday),
+                (b"Sun".as_slice(), Weekday::Sunday),
+            ],
+            false,
+        )(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_weekday(value)))
+        .ok_or_else(|| invalid_component("weekday", input))?;
+
+        // The RFC explicitly allows leap seconds.
+        parsed.set_flag(Parsed::LEAP_SECOND_ALLOWED_FLAG, true);
+
+        let input = match ascii_char::<b','>(input) {
+            Some(ParsedItem(input, ())) => {
+                let input = space(input)
+                    .ok_or_else(|| invalid_literal(input))?
+                    .into_inner();
+                let input = exactly_n_digits::<2, _>(input)
+                    .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+                    .ok_or_else(|| invalid_component("day", input))?;
+                let input = if let Some(input) = space(input) {
+                    // IMF-fixdate: `Sun, 06 Nov 1994 08:49:37 GMT`
+                    let input = abbreviated_month(input.into_inner(), parsed)?;
+                    let input = space(input)
+                        .ok_or_else(|| invalid_literal(input))?
+                        .into_inner();
+                    exactly_n_digits::<4, u32>(input)
+                        .and_then(|item| item.consume_value(|value| parsed.set_year(value as _)))
+                        .ok_or_else(|| invalid_component("year", input))?
+                } else {
+                    // RFC 850: `Sunday, 06-Nov-94 08:49:37 GMT`
+                    let input = dash(input)
+                        .ok_or_else(|| invalid_literal(input))?
+                        .into_inner();
+                    let input = abbreviated_month(input, parsed)?;
+                    let input = dash(input)
+                        .ok_or_else(|| invalid_literal(input))?
+                        .into_inner();
+                    exactly_n_digits::<2, u32>(input)
+                        .and_then(|item| {
+                            item.map(|year| if year < 50 { year + 2000 } else { year + 1900 })
+                                .map(|year| year as _)
+                                .consume_value(|value| parsed.set_year(value))
+                        })
+                        .ok_or_else(|| invalid_component("year", input))?
+                };
+                let input = space(input)
+                    .ok_or_else(|| invalid_literal(input))?
+                    .into_inner();
+                let input = time_of_day(input, parsed)?;
+                let input = space(input)
+                    .ok_or_else(|| invalid_literal(input))?
+                    .into_inner();
+                first_match([(b"GMT".as_slice(), ())], false)(input)
+                    .ok_or_else(|| invalid_literal(input))?
+                    .into_inner()
+            }
+            None => {
+                // asctime: `Sun Nov  6 08:49:37 1994`
+                let input = space(input)
+                    .ok_or_else(|| invalid_literal(input))?
+                    .into_inner();
+                let input = abbreviated_month(input, parsed)?;
+                let input = space(input)
+                    .ok_or_else(|| invalid_literal(input))?
+                    .into_inner();
+                let input = match space(input) {
+                    Some(input) => exactly_n_digits::<1, _>(input.into_inner()),
+                    None => exactly_n_digits::<2, _>(input),
+                }
+                .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+                .ok_or_else(|| invalid_component("day", input))?;
+                let input = space(input)
+                    .ok_or_else(|| invalid_literal(input))?
+                    .into_inner();
+                let input = time_of_day(input, parsed)?;
+                let input = space(input)
+                    .ok_or_else(|| invalid_literal(input))?
+                    .into_inner();
+                exactly_n_digits::<4, u32>(input)
+                    .and_then(|item| item.consume_value(|value| parsed.set_year(value as _)))
+                    .ok_or_else(|| invalid_component("year", input))?
+            }
+        };
+
+        // HTTP dates are always in UTC.
+        parsed
+            .set_offset_hour(0)
+            .ok_or_else(|| invalid_component("offset hour", input))?;
+        parsed
+            .set_offset_minute_signed(0)
+            .ok_or_else(|| invalid_component("offset minute", input))?;
+        parsed
+            .set_offset_second_signed(0)
+            .ok_or_else(|| invalid_component("offset second", input))?;
+
+        Ok(input)
+    }
+}
+
+impl sealed::Sealed for Rfc3164 {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        use crate::parsing::combinator::{ascii_char, exactly_n_digits};
+        use crate::parsing::{invalid_component, invalid_literal};
+
+        let space = ascii_char::<b' '>;
+
+        let input = abbreviated_month(input, parsed)?;
+        let input = space(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
+        let ParsedItem(input, day) = match space(input) {
+            Some(input) => exactly_n_digits::<1, _>(input.into_inner()),
+            None => exactly_n_digits::<2, _>(input),
+        }
+        .ok_or_else(|| invalid_component("day", input))?;
+        parsed
+            .set_day(day)
+            .ok_or_else(|| invalid_component("day", input))?;
+        let input = space(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
+        let input = time_of_day(input, parsed)?;
+
+        if let Some(reference) = self.year_reference {
+            let year = parsed
+                .year_nearest_to(reference)
+                .ok_or_else(|| invalid_component("day", input))?;
+            parsed
+                .set_year(year)
+                .ok_or_else(|| invalid_component("year", input))?;
+        }
+
+        Ok(input)
+    }
+}
+
+impl sealed::Sealed for Rfc5424 {
     fn parse_into<'a>(
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
-    ) -> Result<&'a [u8], error::Parse> {
-        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+        _locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        use crate::parsing::combinator::{any_digit, ascii_char, exactly_n_digits, sign};
+        use crate::parsing::{invalid_component, invalid_literal};
+
+        let dash = ascii_char::<b'-'>;
+        let colon = ascii_char::<b':'>;
+
+        // The NILVALUE indicates that no timestamp is present.
+        if let Some(ParsedItem(input, ())) = dash(input) {
+            return Ok(input);
+        }
+
+        let input = exactly_n_digits::<4, u32>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_year(value as _)))
+            .ok_or_else(|| invalid_component("year", input))?;
+        let input = dash(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.flat_map(|value| Month::from_number(value).ok()))
+            .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
+            .ok_or_else(|| invalid_component("month", input))?;
+        let input = dash(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+            .ok_or_else(|| invalid_component("day", input))?;
+        let input = ascii_char::<b'T'>(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
+            .ok_or_else(|| invalid_component("hour", input))?;
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
+            .ok_or_else(|| invalid_component("minute", input))?;
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
+            .ok_or_else(|| invalid_component("second", input))?;
+        let input = if let Some(ParsedItem(input, ())) = ascii_char::<b'.'>(input) {
+            let ParsedItem(mut input, mut value) = any_digit(input)
+                .ok_or_else(|| invalid_component("subsecond", input))?
+                .map(|v| (v - b'0') as u32 * 100_000_000);
+
+            // The RFC permits at most six fractional digits.
+            let mut multiplier = 10_000_000;
+            while let Some(ParsedItem(new_input, digit)) = any_digit(input) {
+                if multiplier < 1_000 {
+                    return Err(invalid_component("subsecond", input).into());
+                }
+                value += (digit - b'0') as u32 * multiplier;
+                input = new_input;
+                multiplier /= 10;
+            }
+
+            parsed
+                .set_subsecond(value)
+                .ok_or_else(|| invalid_component("subsecond", input))?;
+            input
+        } else {
+            input
+        };
+
+        if let Some(ParsedItem(input, ())) = ascii_char::<b'Z'>(input) {
+            parsed
+                .set_offset_hour(0)
+                .ok_or_else(|| invalid_component("offset hour", input))?;
+            parsed
+                .set_offset_minute_signed(0)
+                .ok_or_else(|| invalid_component("offset minute", input))?;
+            parsed
+                .set_offset_second_signed(0)
+                .ok_or_else(|| invalid_component("offset second", input))?;
+            return Ok(input);
+        }
+
+        let ParsedItem(input, offset_sign) =
+            sign(input).ok_or_else(|| invalid_component("offset hour", input))?;
+        let input = exactly_n_digits::<2, u8>(input)
+            .and_then(|item| {
+                item.map(|offset_hour| {
+                    if offset_sign == b'-' {
+                        -(offset_hour as i8)
+                    } else {
+                        offset_hour as _
+                    }
+                })
+                .consume_value(|value| parsed.set_offset_hour(value))
+            })
+            .ok_or_else(|| invalid_component("offset hour", input))?;
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
+        let input = exactly_n_digits::<2, u8>(input)
+            .and_then(|item| {
+                item.map(|offset_minute| {
+                    if offset_sign == b'-' {
+                        -(offset_minute as i8)
+                    } else {
+                        offset_minute as _
+                    }
+                })
+                .consume_value(|value| parsed.set_offset_minute_signed(value))
+            })
+            .ok_or_else(|| invalid_component("offset minute", input))?;
+
+        Ok(input)
+    }
+}
+
+impl sealed::Sealed for Rfc5545 {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        use crate::parsing::combinator::{ascii_char, exactly_n_digits};
+        use crate::parsing::{invalid_component, invalid_literal};
+
+        let (input, has_zone) = if let Some(value) = input.strip_prefix(b"TZID=") {
+            let (zone_name, input) = if let Some(value) = value.strip_prefix(b"\"") {
+                let len = value
+                    .iter()
+                    .position(|&c| c == b'"')
+                    .ok_or_else(|| invalid_component("zone name", input))?;
+                (&value[..len], &value[len + 1..])
+            } else {
+                let len = value
+                    .iter()
+                    .position(|&c| matches!(c, b':' | b';' | b','))
+                    .unwrap_or(value.len());
+                value.split_at(len)
+            };
+            core::str::from_utf8(zone_name)
+                .ok()
+                .and_then(|name| parsed.set_zone_name(name))
+                .ok_or_else(|| invalid_component("zone name", input))?;
+            let input = ascii_char::<b':'>(input)
+                .ok_or_else(|| invalid_literal(input))?
+                .into_inner();
+            (input, true)
+        } else {
+            (input, false)
+        };
+
+        let input = exactly_n_digits::<4, u32>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_year(value as _)))
+            .ok_or_else(|| invalid_component("year", input))?;
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.flat_map(|value| Month::from_number(value).ok()))
+            .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
+            .ok_or_else(|| invalid_component("month", input))?;
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+            .ok_or_else(|| invalid_component("day", input))?;
+
+        // A time zone only applies to a `DATE-TIME`.
+        let input = match ascii_char::<b'T'>(input) {
+            Some(ParsedItem(input, ())) => input,
+            None if has_zone => return Err(invalid_literal(input).into()),
+            None => return Ok(input),
+        };
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
+            .ok_or_else(|| invalid_component("hour", input))?;
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
+            .ok_or_else(|| invalid_component("minute", input))?;
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
+            .ok_or_else(|| invalid_component("second", input))?;
+
+        parsed.set_flag(Parsed::LEAP_SECOND_ALLOWED_FLAG, true);
+
+        // The offset is only present for a UTC value.
+        match ascii_char::<b'Z'>(input) {
+            Some(ParsedItem(input, ())) if !has_zone => {
+                parsed
+                    .set_offset_hour(0)
+                    .ok_or_else(|| invalid_component("offset hour", input))?;
+                parsed
+                    .set_offset_minute_signed(0)
+                    .ok_or_else(|| invalid_component("offset minute", input))?;
+                parsed
+                    .set_offset_second_signed(0)
+                    .ok_or_else(|| invalid_component("offset second", input))?;
+                Ok(input)
+            }
+            _ => Ok(input),
+        }
+    }
+
+    fn parse_duration_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut ParsedDuration,
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        use crate::parsing::combinator::sign;
+        use crate::parsing::invalid_component;
+
+        // A duration always begins with a sign or `P`, which a date never does.
+        if let Some(ParsedItem(input, sign)) = sign(input) {
+            parsed
+                .set_is_negative(sign == b'-')
+                .ok_or_else(|| invalid_component("sign", input))?;
+            return Ok(rfc5545_duration(input, parsed)?);
+        }
+        if input.first() == Some(&b'P') {
+            parsed
+                .set_is_negative(false)
+                .ok_or_else(|| invalid_component("sign", input))?;
+            return Ok(rfc5545_duration(input, parsed)?);
+        }
+
+        self.parse_into(input, &mut parsed.parsed, locale)
+    }
+}
+
+/// Parse the portion of an RFC 5545 `DURATION` following the sign.
+fn rfc5545_duration<'a>(
+    input: &'a [u8],
+    parsed: &mut ParsedDuration,
+) -> Result<&'a [u8], error::Located<error::ParseFromDescription>> {
+    use crate::parsing::combinator::{ascii_char, n_to_m_digits};
+    use crate::parsing::{invalid_component, invalid_literal};
+
+    let input = ascii_char::<b'P'>(input)
+        .ok_or_else(|| invalid_literal(input))?
+        .into_inner();
+
+    let (input, value) = match n_to_m_digits::<1, 20, u64>(input) {
+        Some(ParsedItem(input, value)) => (input, Some(value)),
+        None => (input, None),
+    };
+    if let Some(value) = value {
+        if let Some(ParsedItem(input, ())) = ascii_char::<b'W'>(input) {
+            value
+                .checked_mul(7)
+                .and_then(|days| parsed.set_days(days))
+                .ok_or_else(|| invalid_component("days", input))?;
+            return Ok(input);
+        }
+        let input = ascii_char::<b'D'>(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
+        parsed
+            .set_days(value)
+            .ok_or_else(|| invalid_component("days", input))?;
+        match ascii_char::<b'T'>(input) {
+            Some(ParsedItem(input, ())) => rfc5545_duration_time(input, parsed),
+            None => Ok(input),
+        }
+    } else {
+        let input = ascii_char::<b'T'>(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
+        rfc5545_duration_time(input, parsed)
+    }
+}
+
+/// Parse the time portion of an RFC 5545 `DURATION`, following the `T`.
+///
+/// The hours, minutes, and seconds are each optional, but at least one must be present. If more
+/// than one is present, no unit may be skipped between them.
+fn rfc5545_duration_time<'a>(
+    mut input: &'a [u8],
+    parsed: &mut ParsedDuration,
+) -> Result<&'a [u8], error::Located<error::ParseFromDescription>> {
+    use crate::parsing::combinator::n_to_m_digits;
+    use crate::parsing::{invalid_component, invalid_literal};
+
+    let mut found = false;
+    for (unit, name) in [(b'H', "hours"), (b'M', "minutes"), (b'S', "seconds")] {
+        let ParsedItem(remaining, value) = match n_to_m_digits::<1, 20, u64>(input) {
+            Some(item) => item,
+            None => break,
+        };
+        match remaining.split_first() {
+            Some((&c, remaining)) if c == unit => {
+                match unit {
+                    b'H' => parsed.set_hours(value),
+                    b'M' => parsed.set_minutes(value),
+                    _ => parsed.set_seconds(value),
+                }
+                .ok_or_else(|| invalid_component(name, input))?;
+                input = remaining;
+                found = true;
+            }
+            // A unit was skipped. This is only permitted before the first unit present.
+            _ if found => return Err(invalid_literal(input)),
+            _ => continue,
+        }
+    }
+
+    if found {
+        Ok(input)
+    } else {
+        Err(invalid_literal(input))
+    }
+}
+
+impl sealed::Sealed for UtcTime {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        use crate::parsing::combinator::exactly_n_digits;
+        use crate::parsing::invalid_component;
+
+        // Two-digit years use the pivot from RFC 5280.
+        let input = exactly_n_digits::<2, u32>(input)
+            .and_then(|item| {
+                item.map(|year| if year < 50 { year + 2000 } else { year + 1900 })
+                    .map(|year| year as _)
+                    .consume_value(|value| parsed.set_year(value))
+            })
+            .ok_or_else(|| invalid_component("year", input))?;
+        let input = asn1_date_time(input, parsed)?;
+        Ok(asn1_utc(input, parsed)?)
+    }
+}
+
+impl sealed::Sealed for GeneralizedTime {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        use crate::parsing::combinator::{any_digit, ascii_char, exactly_n_digits};
+        use crate::parsing::invalid_component;
+
+        let input = exactly_n_digits::<4, u32>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_year(value as _)))
+            .ok_or_else(|| invalid_component("year", input))?;
+        let input = asn1_date_time(input, parsed)?;
+
+        let input = if let Some(ParsedItem(mut input, ())) = ascii_char::<b'.'>(input) {
+            let mut value = 0;
+            let mut last_digit = None;
+            let mut multiplier = 100_000_000;
+            while let Some(ParsedItem(new_input, digit)) = any_digit(input) {
+                if multiplier == 0 {
+                    return Err(invalid_component("subsecond", input).into());
+                }
+                value += (digit - b'0') as u32 * multiplier;
+                last_digit = Some(digit);
+                input = new_input;
+                multiplier /= 10;
+            }
+
+            // DER requires at least one digit and forbids trailing zeros.
+            if matches!(last_digit, None | Some(b'0')) {
+                return Err(invalid_component("subsecond", input).into());
+            }
+            parsed
+                .set_subsecond(value)
+                .ok_or_else(|| invalid_component("subsecond", input))?;
+            input
+        } else {
+            input
+        };
+
+        Ok(asn1_utc(input, parsed)?)
+    }
+}
+
+/// Parse the month, day, hour, minute, and second of an ASN.1 time, each of which is two digits.
+fn asn1_date_time<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<&'a [u8], error::Located<error::ParseFromDescription>> {
+    use crate::parsing::combinator::exactly_n_digits;
+    use crate::parsing::invalid_component;
+
+    let input = exactly_n_digits::<2, _>(input)
+        .and_then(|item| item.flat_map(|value| Month::from_number(value).ok()))
+        .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
+        .ok_or_else(|| invalid_component("month", input))?;
+    let input = exactly_n_digits::<2, _>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+        .ok_or_else(|| invalid_component("day", input))?;
+    let input = exactly_n_digits::<2, _>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
+        .ok_or_else(|| invalid_component("hour", input))?;
+    let input = exactly_n_digits::<2, _>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
+        .ok_or_else(|| invalid_component("minute", input))?;
+    let input = exactly_n_digits::<2, _>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
+        .ok_or_else(|| invalid_component("second", input))?;
+    Ok(input)
+}
+
+/// Parse the `Z` that ends an ASN.1 time. DER does not permit any other offset.
+fn asn1_utc<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<&'a [u8], error::Located<error::ParseFromDescription>> {
+    use crate::parsing::combinator::ascii_char;
+    use crate::parsing::{invalid_component, invalid_literal};
+
+    let input = ascii_char::<b'Z'>(input)
+        .ok_or_else(|| invalid_literal(input))?
+        .into_inner();
+    parsed
+        .set_offset_hour(0)
+        .ok_or_else(|| invalid_component("offset hour", input))?;
+    parsed
+        .set_offset_minute_signed(0)
+        .ok_or_else(|| invalid_component("offset minute", input))?;
+    parsed
+        .set_offset_second_signed(0)
+        .ok_or_else(|| invalid_component("offset second", input))?;
+    Ok(input)
+}
+
+impl sealed::Sealed for Sql {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
+        use crate::parsing::combinator::{ascii_char, exactly_n_digits, n_to_m_digits};
+        use crate::parsing::{invalid_component, invalid_literal};
+
+        let dash = ascii_char::<b'-'>;
+
+        // A time has a colon after two digits, which a date never does.
+        if let [_, _, b':', ..] = input {
+            return Ok(sql_time(input, parsed)?);
+        }
+
+        let ParsedItem(input, year) =
+            n_to_m_digits::<4, 6, u32>(input).ok_or_else(|| invalid_component("year", input))?;
+        if year == 0 {
+            return Err(invalid_component("year", input).into());
+        }
+        let input = dash(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.flat_map(|value| Month::from_number(value).ok()))
+            .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
+            .ok_or_else(|| invalid_component("month", input))?;
+        let input = dash(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
+        let mut input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+            .ok_or_else(|| invalid_component("day", input))?;
+
+        // The time is optional, so the separator is only consumed if a time follows it.
+        if let [b' ' | b'T', b'0'..=b'9', ..] = input {
+            input = sql_time(&input[1..], parsed)?;
+        }
+
+        // There is no year zero, so the year before 1 AD is 1 BC.
+        let year = match input.strip_prefix(b" BC") {
+            Some(remaining) => {
+                input = remaining;
+                1 - year as i32
+            }
+            None => year as _,
+        };
+        parsed
+            .set_year(year)
+            .ok_or_else(|| invalid_component("year", input))?;
+
+        Ok(input)
+    }
+}
+
+/// Parse a SQL time with an optional offset.
+fn sql_time<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<&'a [u8], error::Located<error::ParseFromDescription>> {
+    use crate::parsing::combinator::{any_digit, ascii_char, exactly_n_digits, sign};
+    use crate::parsing::{invalid_component, invalid_literal};
+
+    let colon = ascii_char::<b':'>;
+
+    let input = exactly_n_digits::<2, _>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
+        .ok_or_else(|| invalid_component("hour", input))?;
+    let input = colon(input)
+        .ok_or_else(|| invalid_literal(input))?
+        .into_inner();
+    let mut input = exactly_n_digits::<2, _>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
+        .ok_or_else(|| invalid_component("minute", input))?;
+
+    if let Some(ParsedItem(remaining, ())) = colon(input) {
+        input = exactly_n_digits::<2, _>(remaining)
+            .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
+            .ok_or_else(|| invalid_component("second", input))?;
+
+        if let Some(ParsedItem(remaining, ())) = ascii_char::<b'.'>(input) {
+            let ParsedItem(mut remaining, mut value) = any_digit(remaining)
+                .ok_or_else(|| invalid_component("subsecond", input))?
+                .map(|v| (v - b'0') as u32 * 100_000_000);
+
+            let mut multiplier = 10_000_000;
+            while let Some(ParsedItem(new_input, digit)) = any_digit(remaining) {
+                if multiplier == 0 {
+                    return Err(invalid_component("subsecond", input));
+                }
+                value += (digit - b'0') as u32 * multiplier;
+                remaining = new_input;
+                multiplier /= 10;
+            }
+
+            parsed
+                .set_subsecond(value)
+                .ok_or_else(|| invalid_component("subsecond", input))?;
+            input = remaining;
+        }
+    }
+
+    let Some(ParsedItem(remaining, offset_sign)) = sign(input) else {
+        return Ok(input);
+    };
+    let signed = |value: u8| {
+        if offset_sign == b'-' {
+            -(value as i8)
+        } else {
+            value as _
+        }
+    };
+
+    input = exactly_n_digits::<2, u8>(remaining)
+        .and_then(|item| {
+            item.map(signed)
+                .consume_value(|value| parsed.set_offset_hour(value))
+        })
+        .ok_or_else(|| invalid_component("offset hour", input))?;
+
+    // The minutes may follow the hour directly, while the seconds require a colon.
+    let (minute_input, has_colon) = match colon(input) {
+        Some(ParsedItem(remaining, ())) => (remaining, true),
+        None => (input, false),
+    };
+    match exactly_n_digits::<2, u8>(minute_input) {
+        Some(ParsedItem(remaining, minute)) => {
+            parsed
+                .set_offset_minute_signed(signed(minute))
+                .ok_or_else(|| invalid_component("offset minute", input))?;
+            input = remaining;
+        }
+        None if has_colon => return Err(invalid_component("offset minute", input)),
+        None => return Ok(input),
+    }
+
+    if let Some(ParsedItem(remaining, ())) = colon(input).filter(|_| has_colon) {
+        input = exactly_n_digits::<2, u8>(remaining)
+            .and_then(|item| {
+                item.map(signed)
+                    .consume_value(|value| parsed.set_offset_second_signed(value))
+            })
+            .ok_or_else(|| invalid_component("offset second", input))?;
+    }
+
+    Ok(input)
+}
+
+impl<const CONFIG: Rfc3339EncodedConfig> sealed::Sealed for Rfc3339<CONFIG> {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        _locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
         use crate::parsing::combinator::{
             any_digit, ascii_char, ascii_char_ignore_case, exactly_n_digits, sign,
         };
+        use crate::parsing::{invalid_component, invalid_literal};
 
         let dash = ascii_char::<b'-'>;
         let colon = ascii_char::<b':'>;
 
         let input = exactly_n_digits::<4, u32>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_year(value as _)))
-            .ok_or(InvalidComponent("year"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+            .ok_or_else(|| invalid_component("year", input))?;
+        let input = dash(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.flat_map(|value| Month::from_number(value).ok()))
             .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
-            .ok_or(InvalidComponent("month"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+            .ok_or_else(|| invalid_component("month", input))?;
+        let input = dash(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
-            .ok_or(InvalidComponent("day"))?;
-        let input = ascii_char_ignore_case::<b'T'>(input)
-            .ok_or(InvalidLiteral)?
-            .into_inner();
+            .ok_or_else(|| invalid_component("day", input))?;
+        let input = match Self::SEPARATOR {
+            Separator::T => ascii_char_ignore_case::<b'T'>(input),
+            Separator::Space => ascii_char::<b' '>(input),
+        }
+        .ok_or_else(|| invalid_literal(input))?
+        .into_inner();
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
-            .ok_or(InvalidComponent("hour"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+            .ok_or_else(|| invalid_component("hour", input))?;
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
-            .ok_or(InvalidComponent("minute"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+            .ok_or_else(|| invalid_component("minute", input))?;
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
-            .ok_or(InvalidComponent("second"))?;
+            .ok_or_else(|| invalid_component("second", input))?;
         let input = if let Some(ParsedItem(input, ())) = ascii_char::<b'.'>(input) {
             let ParsedItem(mut input, mut value) = any_digit(input)
-                .ok_or(InvalidComponent("subsecond"))?
+                .ok_or_else(|| invalid_component("subsecond", input))?
                 .map(|v| (v - b'0') as u32 * 100_000_000);
 
             let mut multiplier = 10_000_000;
@@ -526,7 +1589,7 @@ impl sealed::Sealed for Rfc3339 {
 
             parsed
                 .set_subsecond(value)
-                .ok_or(InvalidComponent("subsecond"))?;
+                .ok_or_else(|| invalid_component("subsecond", input))?;
             input
         } else {
             input
@@ -538,17 +1601,18 @@ impl sealed::Sealed for Rfc3339 {
         if let Some(ParsedItem(input, ())) = ascii_char_ignore_case::<b'Z'>(input) {
             parsed
                 .set_offset_hour(0)
-                .ok_or(InvalidComponent("offset hour"))?;
+                .ok_or_else(|| invalid_component("offset hour", input))?;
             parsed
                 .set_offset_minute_signed(0)
-                .ok_or(InvalidComponent("offset minute"))?;
+                .ok_or_else(|| invalid_component("offset minute", input))?;
             parsed
                 .set_offset_second_signed(0)
-                .ok_or(InvalidComponent("offset second"))?;
+                .ok_or_else(|| invalid_component("offset second", input))?;
             return Ok(input);
         }
 
-        let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+        let ParsedItem(input, offset_sign) =
+            sign(input).ok_or_else(|| invalid_component("offset hour", input))?;
         let input = exactly_n_digits::<2, u8>(input)
             .and_then(|item| {
                 item.map(|offset_hour| {
@@ -560,8 +1624,10 @@ impl sealed::Sealed for Rfc3339 {
                 })
                 .consume_value(|value| parsed.set_offset_hour(value))
             })
-            .ok_or(InvalidComponent("offset hour"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+            .ok_or_else(|| invalid_component("offset hour", input))?;
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
         let input = exactly_n_digits::<2, u8>(input)
             .and_then(|item| {
                 item.map(|offset_minute| {
@@ -573,43 +1639,57 @@ impl sealed::Sealed for Rfc3339 {
                 })
                 .consume_value(|value| parsed.set_offset_minute_signed(value))
             })
-            .ok_or(InvalidComponent("offset minute"))?;
+            .ok_or_else(|| invalid_component("offset minute", input))?;
 
         Ok(input)
     }
 
-    fn parse_date_time<O: MaybeOffset>(&self, input: &[u8]) -> Result<DateTime<O>, error::Parse> {
-        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+    fn parse_date_time<O: MaybeOffset>(
+        &self,
+        input: &[u8],
+    ) -> Result<DateTime<O>, error::Located<error::Parse>> {
         use crate::parsing::combinator::{
             any_digit, ascii_char, ascii_char_ignore_case, exactly_n_digits, sign,
         };
+        use crate::parsing::{invalid_component, invalid_literal};
 
         let dash = ascii_char::<b'-'>;
         let colon = ascii_char::<b':'>;
 
         let ParsedItem(input, year) =
-            exactly_n_digits::<4, u32>(input).ok_or(InvalidComponent("year"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+            exactly_n_digits::<4, u32>(input).ok_or_else(|| invalid_component("year", input))?;
+        let input = dash(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
         let ParsedItem(input, month) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("month"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
-        let ParsedItem(input, day) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("day"))?;
-        let input = ascii_char_ignore_case::<b'T'>(input)
-            .ok_or(InvalidLiteral)?
+            exactly_n_digits::<2, _>(input).ok_or_else(|| invalid_component("month", input))?;
+        let input = dash(input)
+            .ok_or_else(|| invalid_literal(input))?
             .into_inner();
+        let ParsedItem(input, day) =
+            exactly_n_digits::<2, _>(input).ok_or_else(|| invalid_component("day", input))?;
+        let input = match Self::SEPARATOR {
+            Separator::T => ascii_char_ignore_case::<b'T'>(input),
+            Separator::Space => ascii_char::<b' '>(input),
+        }
+        .ok_or_else(|| invalid_literal(input))?
+        .into_inner();
         let ParsedItem(input, hour) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("hour"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+            exactly_n_digits::<2, _>(input).ok_or_else(|| invalid_component("hour", input))?;
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
         let ParsedItem(input, minute) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("minute"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+            exactly_n_digits::<2, _>(input).ok_or_else(|| invalid_component("minute", input))?;
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(input))?
+            .into_inner();
         let ParsedItem(input, mut second) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("second"))?;
+            exactly_n_digits::<2, _>(input).ok_or_else(|| invalid_component("second", input))?;
         let ParsedItem(input, mut nanosecond) =
             if let Some(ParsedItem(input, ())) = ascii_char::<b'.'>(input) {
                 let ParsedItem(mut input, mut value) = any_digit(input)
-                    .ok_or(InvalidComponent("subsecond"))?
+                    .ok_or_else(|| invalid_component("subsecond", input))?
                     .map(|v| (v - b'0') as u32 * 100_000_000);
 
                 let mut multiplier = 10_000_000;
@@ -628,12 +1708,14 @@ impl sealed::Sealed for Rfc3339 {
                 ParsedItem(input, UtcOffset::UTC)
             } else {
                 let ParsedItem(input, offset_sign) =
-                    sign(input).ok_or(InvalidComponent("offset hour"))?;
-                let ParsedItem(input, offset_hour) =
-                    exactly_n_digits::<2, u8>(input).ok_or(InvalidComponent("offset hour"))?;
-                let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
-                let ParsedItem(input, offset_minute) =
-                    exactly_n_digits::<2, u8>(input).ok_or(InvalidComponent("offset minute"))?;
+                    sign(input).ok_or_else(|| invalid_component("offset hour", input))?;
+                let ParsedItem(input, offset_hour) = exactly_n_digits::<2, u8>(input)
+                    .ok_or_else(|| invalid_component("offset hour", input))?;
+                let input = colon(input)
+                    .ok_or_else(|| invalid_literal(input))?
+                    .into_inner();
+                let ParsedItem(input, offset_minute) = exactly_n_digits::<2, u8>(input)
+                    .ok_or_else(|| invalid_component("offset minute", input))?;
                 UtcOffset::from_hms(
                     if offset_sign == b'-' {
                         -(offset_hour as i8)
@@ -662,7 +1744,7 @@ impl sealed::Sealed for Rfc3339 {
         };
 
         if !input.is_empty() {
-            return Err(error::Parse::UnexpectedTrailingCharacters);
+            return Err(unexpected_trailing_characters(input));
         }
 
         // The RFC explicitly permits leap seconds. We don't currently support them, so treat it as
@@ -685,15 +1767,14 @@ impl sealed::Sealed for Rfc3339 {
         let dt = DateTime { date, time, offset };
 
         if leap_second_input && !dt.is_valid_leap_second_stand_in() {
-            return Err(error::Parse::TryFromParsed(TryFromParsed::ComponentRange(
-                error::ComponentRange {
-                    name: "second",
-                    minimum: 0,
-                    maximum: 59,
-                    value: 60,
-                    conditional_range: true,
-                },
-            )));
+            return Err(TryFromParsed::ComponentRange(error::ComponentRange {
+                name: "second",
+                minimum: 0,
+                maximum: 59,
+                value: 60,
+                conditional_range: true,
+            })
+            .into());
         }
 
         Ok(dt)
@@ -705,7 +1786,8 @@ impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
         &self,
         mut input: &'a [u8],
         parsed: &mut Parsed,
-    ) -> Result<&'a [u8], error::Parse> {
+        _locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::Parse>> {
         use crate::parsing::combinator::rfc::iso8601::ExtendedKind;
 
         let mut extended_kind = ExtendedKind::Unknown;
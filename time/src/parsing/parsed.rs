@@ -1,21 +1,30 @@
 //! Information parsed from an input and format description.
 
+use core::fmt;
 use core::mem::MaybeUninit;
-use core::num::{NonZeroU16, NonZeroU8};
+use core::num::{NonZeroU16, NonZeroU32, NonZeroU8};
 
+use crate::convert::*;
 use crate::date_time::{maybe_offset_from_offset, offset_kind, DateTime, MaybeOffset};
 use crate::error::TryFromParsed::InsufficientInformation;
+use crate::format_description::locale::Locale;
 use crate::format_description::modifier::{WeekNumberRepr, YearRepr};
 #[cfg(feature = "alloc")]
 use crate::format_description::OwnedFormatItem;
 use crate::format_description::{Component, FormatItem};
 use crate::parsing::component::{
-    parse_day, parse_hour, parse_ignore, parse_minute, parse_month, parse_offset_hour,
-    parse_offset_minute, parse_offset_second, parse_ordinal, parse_period, parse_second,
-    parse_subsecond, parse_unix_timestamp, parse_week_number, parse_weekday, parse_year, Period,
+    parse_century, parse_day, parse_days, parse_era, parse_hour, parse_hours, parse_ignore,
+    parse_minute, parse_minutes, parse_month, parse_offset_hour, parse_offset_minute,
+    parse_offset_second, parse_ordinal, parse_period, parse_quarter, parse_second, parse_seconds,
+    parse_sign, parse_subsecond, parse_unix_timestamp, parse_week_number, parse_weekday,
+    parse_year, parse_zone_abbr, parse_zone_name, Period,
+};
+use crate::parsing::defaults::YearDefault;
+use crate::parsing::{invalid_literal, Defaults, Parsable, ParsedItem};
+use crate::zone::LocalTimeType;
+use crate::{
+    error, Date, Duration, Era, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday,
 };
-use crate::parsing::ParsedItem;
-use crate::{error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
 /// Sealed to prevent downstream implementations.
 mod sealed {
@@ -26,28 +35,79 @@ mod sealed {
         /// Parse a single item, returning the remaining input on success.
         fn parse_item<'a>(
             &self,
-            parsed: &mut Parsed,
+            parsed: &mut impl Accumulator,
             input: &'a [u8],
-        ) -> Result<&'a [u8], error::ParseFromDescription>;
+            locale: Option<&dyn Locale>,
+        ) -> Result<&'a [u8], error::Located<error::ParseFromDescription>>;
+    }
+
+    /// A struct that components are parsed into, allowing the parsing of items to be generic.
+    pub trait Accumulator: Copy {
+        /// Parse a single component using the names of months and weekdays from the provided
+        /// locale, falling back to English if none is provided.
+        fn parse_component_with_locale<'a>(
+            &mut self,
+            input: &'a [u8],
+            component: Component,
+            locale: Option<&dyn Locale>,
+        ) -> Result<&'a [u8], error::Located<error::ParseFromDescription>>;
+
+        /// Parse a single item using the names of months and weekdays from the provided locale,
+        /// falling back to English if none is provided.
+        fn parse_item_with_locale<'a>(
+            &mut self,
+            input: &'a [u8],
+            item: &impl AnyFormatItem,
+            locale: Option<&dyn Locale>,
+        ) -> Result<&'a [u8], error::Located<error::ParseFromDescription>> {
+            item.parse_item(self, input, locale)
+        }
+
+        /// Parse a sequence of items using the names of months and weekdays from the provided
+        /// locale, falling back to English if none is provided.
+        fn parse_items_with_locale<'a>(
+            &mut self,
+            mut input: &'a [u8],
+            items: &[impl AnyFormatItem],
+            locale: Option<&dyn Locale>,
+        ) -> Result<&'a [u8], error::Located<error::ParseFromDescription>> {
+            // Make a copy that we can mutate. It will only be set to the user's copy if everything
+            // succeeds.
+            let mut this = *self;
+            for item in items {
+                input = this.parse_item_with_locale(input, item, locale)?;
+            }
+            *self = this;
+            Ok(input)
+        }
     }
 }
 
+pub(crate) use sealed::Accumulator;
+
 impl sealed::AnyFormatItem for FormatItem<'_> {
     fn parse_item<'a>(
         &self,
-        parsed: &mut Parsed,
+        parsed: &mut impl Accumulator,
         input: &'a [u8],
-    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::ParseFromDescription>> {
         match self {
-            Self::Literal(literal) => Parsed::parse_literal(input, literal),
-            Self::Component(component) => parsed.parse_component(input, *component),
-            Self::Compound(compound) => parsed.parse_items(input, compound),
-            Self::Optional(item) => parsed.parse_item(input, *item).or(Ok(input)),
+            Self::Literal(literal) => input
+                .strip_prefix(*literal)
+                .ok_or_else(|| invalid_literal(input)),
+            Self::Component(component) => {
+                parsed.parse_component_with_locale(input, *component, locale)
+            }
+            Self::Compound(compound) => parsed.parse_items_with_locale(input, compound, locale),
+            Self::Optional(item) => parsed
+                .parse_item_with_locale(input, *item, locale)
+                .or(Ok(input)),
             Self::First(items) => {
                 let mut first_err = None;
 
                 for item in items.iter() {
-                    match parsed.parse_item(input, item) {
+                    match parsed.parse_item_with_locale(input, item, locale) {
                         Ok(remaining_input) => return Ok(remaining_input),
                         Err(err) if first_err.is_none() => first_err = Some(err),
                         Err(_) => {}
@@ -69,19 +129,26 @@ impl sealed::AnyFormatItem for FormatItem<'_> {
 impl sealed::AnyFormatItem for OwnedFormatItem {
     fn parse_item<'a>(
         &self,
-        parsed: &mut Parsed,
+        parsed: &mut impl Accumulator,
         input: &'a [u8],
-    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::ParseFromDescription>> {
         match self {
-            Self::Literal(literal) => Parsed::parse_literal(input, literal),
-            Self::Component(component) => parsed.parse_component(input, *component),
-            Self::Compound(compound) => parsed.parse_items(input, compound),
-            Self::Optional(item) => parsed.parse_item(input, item.as_ref()).or(Ok(input)),
+            Self::Literal(literal) => input
+                .strip_prefix(&**literal)
+                .ok_or_else(|| invalid_literal(input)),
+            Self::Component(component) => {
+                parsed.parse_component_with_locale(input, *component, locale)
+            }
+            Self::Compound(compound) => parsed.parse_items_with_locale(input, compound, locale),
+            Self::Optional(item) => parsed
+                .parse_item_with_locale(input, item.as_ref(), locale)
+                .or(Ok(input)),
             Self::First(items) => {
                 let mut first_err = None;
 
                 for item in items.iter() {
-                    match parsed.parse_item(input, item) {
+                    match parsed.parse_item_with_locale(input, item, locale) {
                         Ok(remaining_input) => return Ok(remaining_input),
                         Err(err) if first_err.is_none() => first_err = Some(err),
                         Err(_) => {}
@@ -99,6 +166,48 @@ impl sealed::AnyFormatItem for OwnedFormatItem {
     }
 }
 
+/// The maximum length of a parsed time zone identifier, in bytes.
+const MAX_ZONE_NAME_LEN: usize = 32;
+/// The maximum length of a parsed time zone abbreviation, in bytes.
+const MAX_ZONE_ABBR_LEN: usize = 8;
+
+/// A string stored inline and padded with zeros, allowing [`Parsed`] to remain `Copy`. An empty
+/// string indicates the absence of a value.
+#[derive(Clone, Copy)]
+struct InlineStr<const N: usize>([u8; N]);
+
+impl<const N: usize> InlineStr<N> {
+    /// An absent value.
+    const EMPTY: Self = Self([0; N]);
+
+    /// Copy the provided string, returning `None` if it is empty, is longer than `N` bytes, or
+    /// contains a NUL byte.
+    fn new(value: &str) -> Option<Self> {
+        if value.is_empty() || value.len() > N || value.contains('\0') {
+            return None;
+        }
+        let mut bytes = [0; N];
+        bytes[..value.len()].copy_from_slice(value.as_bytes());
+        Some(Self(bytes))
+    }
+
+    /// Obtain the stored string, if any.
+    fn as_str(&self) -> Option<&str> {
+        let len = self.0.iter().position(|&byte| byte == 0).unwrap_or(N);
+        match core::str::from_utf8(&self.0[..len]) {
+            Ok("") => None,
+            Ok(value) => Some(value),
+            Err(_) => bug!("value is copied from a `str`"),
+        }
+    }
+}
+
+impl<const N: usize> fmt::Debug for InlineStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// The type of the `flags` field in [`Parsed`]. Allows for changing a single location and having it
 /// effect all uses.
 type Flag = u32;
@@ -121,6 +230,14 @@ pub struct Parsed {
     iso_year: MaybeUninit<i32>,
     /// The last two digits of the ISO week year.
     iso_year_last_two: MaybeUninit<u8>,
+    /// Century of the calendar year, obtained by removing its last two digits.
+    century: MaybeUninit<i16>,
+    /// Era of the calendar year.
+    era: Option<Era>,
+    /// Calendar year within its era, counting from 1.
+    year_of_era: Option<NonZeroU32>,
+    /// Quarter of the year.
+    quarter: Option<NonZeroU8>,
     /// Month of the year.
     month: Option<Month>,
     /// Week of the year, where week one begins on the first Sunday of the calendar year.
@@ -156,6 +273,10 @@ pub struct Parsed {
     offset_second: MaybeUninit<i8>,
     /// The Unix timestamp in nanoseconds.
     unix_timestamp_nanos: MaybeUninit<i128>,
+    /// Identifier of the time zone, such as `Europe/Paris`.
+    zone_name: InlineStr<MAX_ZONE_NAME_LEN>,
+    /// Abbreviation of the time zone, such as `CEST`.
+    zone_abbr: InlineStr<MAX_ZONE_ABBR_LEN>,
 }
 
 #[allow(clippy::missing_docs_in_private_items)]
@@ -183,6 +304,10 @@ impl Parsed {
     /// default value? If the latter, the value should be considered to have no meaning.
     const OFFSET_IS_NEGATIVE_FLAG_IS_INITIALIZED: Flag = 1 << 15;
     const UNIX_TIMESTAMP_NANOS_FLAG: Flag = 1 << 16;
+    const CENTURY_FLAG: Flag = 1 << 17;
+    /// Indicates whether the century is negative. This information is obtained when parsing the
+    /// century, but may not otherwise be stored due to "-00" being distinct from "00".
+    const CENTURY_IS_NEGATIVE_FLAG: Flag = 1 << 18;
 }
 
 impl Parsed {
@@ -194,6 +319,10 @@ impl Parsed {
             year_last_two: MaybeUninit::uninit(),
             iso_year: MaybeUninit::uninit(),
             iso_year_last_two: MaybeUninit::uninit(),
+            century: MaybeUninit::uninit(),
+            era: None,
+            year_of_era: None,
+            quarter: None,
             month: None,
             sunday_week_number: MaybeUninit::uninit(),
             monday_week_number: MaybeUninit::uninit(),
@@ -211,6 +340,8 @@ impl Parsed {
             offset_minute: MaybeUninit::uninit(),
             offset_second: MaybeUninit::uninit(),
             unix_timestamp_nanos: MaybeUninit::uninit(),
+            zone_name: InlineStr::EMPTY,
+            zone_abbr: InlineStr::EMPTY,
         }
     }
 
@@ -224,7 +355,8 @@ impl Parsed {
         input: &'a [u8],
         item: &impl sealed::AnyFormatItem,
     ) -> Result<&'a [u8], error::ParseFromDescription> {
-        item.parse_item(self, input)
+        self.parse_item_with_locale(input, item, None)
+            .map_err(error::Located::into_inner)
     }
 
     /// Parse a sequence of [`FormatItem`]s or [`OwnedFormatItem`]s, mutating the struct. The
@@ -234,17 +366,26 @@ impl Parsed {
     /// to parse. `self` will not be mutated in this instance.
     pub fn parse_items<'a>(
         &mut self,
-        mut input: &'a [u8],
+        input: &'a [u8],
         items: &[impl sealed::AnyFormatItem],
     ) -> Result<&'a [u8], error::ParseFromDescription> {
-        // Make a copy that we can mutate. It will only be set to the user's copy if everything
-        // succeeds.
-        let mut this = *self;
-        for item in items {
-            input = this.parse_item(input, item)?;
-        }
-        *self = this;
-        Ok(input)
+        self.parse_items_with_locale(input, items, None)
+            .map_err(error::Located::into_inner)
+    }
+
+    /// Parse the input using any [format description](crate::format_description), including the
+    /// well-known formats, mutating the struct. The remaining input is returned as the `Ok` value.
+    ///
+    /// This provides access to information that is not retained by the parsed type, such as the
+    /// `TZID` parameter of an [iCalendar](crate::format_description::well_known::Rfc5545) value.
+    pub fn parse_using<'a>(
+        &mut self,
+        input: &'a [u8],
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<&'a [u8], error::Parse> {
+        description
+            .parse_into(input, self, None)
+            .map_err(error::Located::into_inner)
     }
 
     /// Parse a literal byte sequence. The remaining input is returned as the `Ok` value.
@@ -264,24 +405,50 @@ impl Parsed {
         input: &'a [u8],
         component: Component,
     ) -> Result<&'a [u8], error::ParseFromDescription> {
-        use error::ParseFromDescription::InvalidComponent;
+        self.parse_component_with_locale(input, component, None)
+            .map_err(error::Located::into_inner)
+    }
+
+    /// Get the value of the provided flag.
+    const fn get_flag(&self, flag: Flag) -> bool {
+        self.flags & flag == flag
+    }
+
+    /// Set the value of the provided flag.
+    pub(super) fn set_flag(&mut self, flag: Flag, value: bool) {
+        if value {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+    }
+}
+
+impl Accumulator for Parsed {
+    fn parse_component_with_locale<'a>(
+        &mut self,
+        input: &'a [u8],
+        component: Component,
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::ParseFromDescription>> {
+        use crate::parsing::invalid_component;
 
         match component {
-            Component::Day(modifiers) => parse_day(input, modifiers)
+            Component::Day(modifiers) => parse_day(input, modifiers, locale)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_day(value)))
-                .ok_or(InvalidComponent("day")),
-            Component::Month(modifiers) => parse_month(input, modifiers)
+                .ok_or_else(|| invalid_component("day", input)),
+            Component::Month(modifiers) => parse_month(input, modifiers, locale)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_month(value)))
-                .ok_or(InvalidComponent("month")),
+                .ok_or_else(|| invalid_component("month", input)),
             Component::Ordinal(modifiers) => parse_ordinal(input, modifiers)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_ordinal(value)))
-                .ok_or(InvalidComponent("ordinal")),
-            Component::Weekday(modifiers) => parse_weekday(input, modifiers)
+                .ok_or_else(|| invalid_component("ordinal", input)),
+            Component::Weekday(modifiers) => parse_weekday(input, modifiers, locale)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_weekday(value)))
-                .ok_or(InvalidComponent("weekday")),
+                .ok_or_else(|| invalid_component("weekday", input)),
             Component::WeekNumber(modifiers) => {
-                let ParsedItem(remaining, value) =
-                    parse_week_number(input, modifiers).ok_or(InvalidComponent("week number"))?;
+                let ParsedItem(remaining, value) = parse_week_number(input, modifiers)
+                    .ok_or_else(|| invalid_component("week number", input))?;
                 match modifiers.repr {
                     WeekNumberRepr::Iso => {
                         NonZeroU8::new(value).and_then(|value| self.set_iso_week_number(value))
@@ -289,46 +456,65 @@ impl Parsed {
                     WeekNumberRepr::Sunday => self.set_sunday_week_number(value),
                     WeekNumberRepr::Monday => self.set_monday_week_number(value),
                 }
-                .ok_or(InvalidComponent("week number"))?;
+                .ok_or_else(|| invalid_component("week number", input))?;
                 Ok(remaining)
             }
             Component::Year(modifiers) => {
                 let ParsedItem(remaining, value) =
-                    parse_year(input, modifiers).ok_or(InvalidComponent("year"))?;
+                    parse_year(input, modifiers).ok_or_else(|| invalid_component("year", input))?;
                 match (modifiers.iso_week_based, modifiers.repr) {
                     (false, YearRepr::Full) => self.set_year(value),
                     (false, YearRepr::LastTwo) => self.set_year_last_two(value as _),
                     (true, YearRepr::Full) => self.set_iso_year(value),
                     (true, YearRepr::LastTwo) => self.set_iso_year_last_two(value as _),
+                    (false, YearRepr::Era) => {
+                        NonZeroU32::new(value as _).and_then(|value| self.set_year_of_era(value))
+                    }
+                    // There is no field for the ISO week-based year of the era.
+                    (true, YearRepr::Era) => None,
                 }
-                .ok_or(InvalidComponent("year"))?;
+                .ok_or_else(|| invalid_component("year", input))?;
                 Ok(remaining)
             }
+            Component::Quarter(modifiers) => parse_quarter(input, modifiers)
+                .and_then(|parsed| parsed.consume_value(|value| self.set_quarter(value)))
+                .ok_or_else(|| invalid_component("quarter", input)),
+            Component::Century(modifiers) => parse_century(input, modifiers)
+                .and_then(|parsed| {
+                    parsed.consume_value(|(value, is_negative)| {
+                        self.set_flag(Self::CENTURY_IS_NEGATIVE_FLAG, is_negative);
+                        self.set_century(value)
+                    })
+                })
+                .ok_or_else(|| invalid_component("century", input)),
+            Component::Era(modifiers) => parse_era(input, modifiers)
+                .and_then(|parsed| parsed.consume_value(|value| self.set_era(value)))
+                .ok_or_else(|| invalid_component("era", input)),
             Component::Hour(modifiers) => {
                 let ParsedItem(remaining, value) =
-                    parse_hour(input, modifiers).ok_or(InvalidComponent("hour"))?;
+                    parse_hour(input, modifiers).ok_or_else(|| invalid_component("hour", input))?;
                 if modifiers.is_12_hour_clock {
                     NonZeroU8::new(value).and_then(|value| self.set_hour_12(value))
                 } else {
                     self.set_hour_24(value)
                 }
-                .ok_or(InvalidComponent("hour"))?;
+                .ok_or_else(|| invalid_component("hour", input))?;
                 Ok(remaining)
             }
             Component::Minute(modifiers) => parse_minute(input, modifiers)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_minute(value)))
-                .ok_or(InvalidComponent("minute")),
+                .ok_or_else(|| invalid_component("minute", input)),
             Component::Period(modifiers) => parse_period(input, modifiers)
                 .and_then(|parsed| {
                     parsed.consume_value(|value| self.set_hour_12_is_pm(value == Period::Pm))
                 })
-                .ok_or(InvalidComponent("period")),
+                .ok_or_else(|| invalid_component("period", input)),
             Component::Second(modifiers) => parse_second(input, modifiers)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_second(value)))
-                .ok_or(InvalidComponent("second")),
+                .ok_or_else(|| invalid_component("second", input)),
             Component::Subsecond(modifiers) => parse_subsecond(input, modifiers)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_subsecond(value)))
-                .ok_or(InvalidComponent("subsecond")),
+                .ok_or_else(|| invalid_component("subsecond", input)),
             Component::OffsetHour(modifiers) => parse_offset_hour(input, modifiers)
                 .and_then(|parsed| {
                     parsed.consume_value(|(value, is_negative)| {
@@ -337,39 +523,47 @@ impl Parsed {
                         self.set_offset_hour(value)
                     })
                 })
-                .ok_or(InvalidComponent("offset hour")),
+                .ok_or_else(|| invalid_component("offset hour", input)),
             Component::OffsetMinute(modifiers) => parse_offset_minute(input, modifiers)
                 .and_then(|parsed| {
                     parsed.consume_value(|value| self.set_offset_minute_signed(value))
                 })
-                .ok_or(InvalidComponent("offset minute")),
+                .ok_or_else(|| invalid_component("offset minute", input)),
             Component::OffsetSecond(modifiers) => parse_offset_second(input, modifiers)
                 .and_then(|parsed| {
                     parsed.consume_value(|value| self.set_offset_second_signed(value))
                 })
-                .ok_or(InvalidComponent("offset second")),
+                .ok_or_else(|| invalid_component("offset second", input)),
+            Component::ZoneName(modifiers) => parse_zone_name(input, modifiers)
+                .and_then(|parsed| parsed.consume_value(|value| self.set_zone_name(value)))
+                .ok_or_else(|| invalid_component("zone name", input)),
+            Component::ZoneAbbr(modifiers) => parse_zone_abbr(input, modifiers)
+                .and_then(|parsed| parsed.consume_value(|value| self.set_zone_abbr(value)))
+                .ok_or_else(|| invalid_component("zone abbreviation", input)),
+            // The components of a duration are only retained when parsing a `Duration`.
+            Component::Sign(modifiers) => parse_sign(input, modifiers)
+                .map(|ParsedItem(remaining, _)| remaining)
+                .ok_or_else(|| invalid_component("sign", input)),
+            Component::Days(modifiers) => parse_days(input, modifiers)
+                .map(|ParsedItem(remaining, _)| remaining)
+                .ok_or_else(|| invalid_component("days", input)),
+            Component::Hours(modifiers) => parse_hours(input, modifiers)
+                .map(|ParsedItem(remaining, _)| remaining)
+                .ok_or_else(|| invalid_component("hours", input)),
+            Component::Minutes(modifiers) => parse_minutes(input, modifiers)
+                .map(|ParsedItem(remaining, _)| remaining)
+                .ok_or_else(|| invalid_component("minutes", input)),
+            Component::Seconds(modifiers) => parse_seconds(input, modifiers)
+                .map(|ParsedItem(remaining, _)| remaining)
+                .ok_or_else(|| invalid_component("seconds", input)),
             Component::Ignore(modifiers) => parse_ignore(input, modifiers)
                 .map(ParsedItem::<()>::into_inner)
-                .ok_or(InvalidComponent("ignore")),
+                .ok_or_else(|| invalid_component("ignore", input)),
             Component::UnixTimestamp(modifiers) => parse_unix_timestamp(input, modifiers)
                 .and_then(|parsed| {
                     parsed.consume_value(|value| self.set_unix_timestamp_nanos(value))
                 })
-                .ok_or(InvalidComponent("unix_timestamp")),
-        }
-    }
-
-    /// Get the value of the provided flag.
-    const fn get_flag(&self, flag: Flag) -> bool {
-        self.flags & flag == flag
-    }
-
-    /// Set the value of the provided flag.
-    pub(super) fn set_flag(&mut self, flag: Flag, value: bool) {
-        if value {
-            self.flags |= flag;
-        } else {
-            self.flags &= !flag;
+                .ok_or_else(|| invalid_component("unix_timestamp", input)),
         }
     }
 }
@@ -405,6 +599,10 @@ impl Parsed {
         @YEAR_LAST_TWO_FLAG year_last_two: u8,
         @ISO_YEAR_FLAG iso_year: i32,
         @ISO_YEAR_LAST_TWO_FLAG iso_year_last_two: u8,
+        @CENTURY_FLAG century: i16,
+        era: Era,
+        year_of_era: NonZeroU32,
+        quarter: NonZeroU8,
         month: Month,
         @SUNDAY_WEEK_NUMBER_FLAG sunday_week_number: u8,
         @MONDAY_WEEK_NUMBER_FLAG monday_week_number: u8,
@@ -471,6 +669,16 @@ impl Parsed {
             }
         }
     }
+
+    /// Obtain the identifier of the time zone, such as `Europe/Paris`.
+    pub fn zone_name(&self) -> Option<&str> {
+        self.zone_name.as_str()
+    }
+
+    /// Obtain the abbreviation of the time zone, such as `CEST`.
+    pub fn zone_abbr(&self) -> Option<&str> {
+        self.zone_abbr.as_str()
+    }
 }
 
 /// Generate setters for each of the fields.
@@ -507,6 +715,10 @@ impl Parsed {
         @YEAR_LAST_TWO_FLAG set_year_last_two year_last_two: u8,
         @ISO_YEAR_FLAG set_iso_year iso_year: i32,
         @ISO_YEAR_LAST_TWO_FLAG set_iso_year_last_two iso_year_last_two: u8,
+        @CENTURY_FLAG set_century century: i16,
+        set_era era: Era,
+        set_year_of_era year_of_era: NonZeroU32,
+        set_quarter quarter: NonZeroU8,
         set_month month: Month,
         @SUNDAY_WEEK_NUMBER_FLAG set_sunday_week_number sunday_week_number: u8,
         @MONDAY_WEEK_NUMBER_FLAG set_monday_week_number monday_week_number: u8,
@@ -565,6 +777,162 @@ impl Parsed {
         self.set_flag(Self::OFFSET_SECOND_FLAG, true);
         Some(())
     }
+
+    /// Set the `zone_name` component. The value must be at most 32 bytes long and must not contain
+    /// a NUL byte.
+    pub fn set_zone_name(&mut self, value: &str) -> Option<()> {
+        self.zone_name = InlineStr::new(value)?;
+        Some(())
+    }
+
+    /// Set the `zone_abbr` component. The value must be at most eight bytes long and must not
+    /// contain a NUL byte.
+    pub fn set_zone_abbr(&mut self, value: &str) -> Option<()> {
+        self.zone_abbr = InlineStr::new(value)?;
+        Some(())
+    }
+
+    /// Set the offset components from the `zone_abbr` component, using the provided table of
+    /// [`LocalTimeType`]s.
+    ///
+    /// Abbreviations are frequently ambiguous: `IST` is used for India, Ireland, and Israel, among
+    /// others. The caller is therefore required to provide the abbreviations it wishes to accept.
+    /// `None` is returned and no offset is set if the abbreviation was not parsed, is not present
+    /// in the table, or is present with more than one offset.
+    ///
+    /// ```rust
+    /// # use time::format_description::{modifier, Component, FormatItem};
+    /// # use time::parsing::Parsed;
+    /// # use time::zone::LocalTimeType;
+    /// # use time::UtcOffset;
+    /// # use time_macros::offset;
+    /// let table = [
+    ///     LocalTimeType::new(offset!(-5), false, "EST")?,
+    ///     LocalTimeType::new(offset!(-4), true, "EDT")?,
+    /// ];
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.parse_item(
+    ///     b"EDT",
+    ///     &FormatItem::Component(Component::ZoneAbbr(modifier::ZoneAbbr::default())),
+    /// )?;
+    /// assert_eq!(parsed.set_offset_from_zone_abbr(&table), Some(()));
+    /// assert_eq!(UtcOffset::try_from(parsed)?, offset!(-4));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn set_offset_from_zone_abbr(&mut self, table: &[LocalTimeType]) -> Option<()> {
+        let abbr = self.zone_abbr()?;
+        let mut matches = table
+            .iter()
+            .filter(|local_time_type| local_time_type.abbreviation() == abbr)
+            .map(|local_time_type| local_time_type.offset());
+        let offset = matches.next()?;
+        if matches.any(|other| other != offset) {
+            return None;
+        }
+        self.set_offset(offset)
+    }
+
+    /// Set any components that are absent using the provided [`Defaults`].
+    ///
+    /// The year is only set if no component that determines the year is present, the time only if
+    /// no time component is present, and the offset only if no offset or time zone component is
+    /// present. Nothing is set if a Unix timestamp is present, as it determines the full value.
+    ///
+    /// ```rust
+    /// # use time::format_description::{modifier, Component, FormatItem};
+    /// # use time::parsing::{Defaults, Parsed};
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::{datetime, time};
+    /// let mut parsed = Parsed::new();
+    /// parsed.parse_items(
+    ///     b"03-03",
+    ///     &[
+    ///         FormatItem::Component(Component::Month(modifier::Month::default())),
+    ///         FormatItem::Literal(b"-"),
+    ///         FormatItem::Component(Component::Day(modifier::Day::default())),
+    ///     ],
+    /// )?;
+    /// parsed.apply_defaults(Defaults::new().with_year(2024).with_time(time!(12:00)));
+    /// assert_eq!(
+    ///     PrimitiveDateTime::try_from(parsed)?,
+    ///     datetime!(2024-03-03 12:00)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn apply_defaults(&mut self, defaults: Defaults) {
+        if self.unix_timestamp_nanos().is_some() {
+            return;
+        }
+
+        if self.year().is_none()
+            && self.year_last_two().is_none()
+            && self.iso_year().is_none()
+            && self.iso_year_last_two().is_none()
+            && self.century().is_none()
+            && self.year_of_era().is_none()
+        {
+            match defaults.year {
+                Some(YearDefault::Exact(year)) => {
+                    self.set_year(year);
+                }
+                Some(YearDefault::NearestTo(reference)) => {
+                    // If the date is not valid in any nearby year, the year of the reference is
+                    // used so that the error is reported when converting.
+                    let year = self
+                        .year_nearest_to(reference)
+                        .unwrap_or_else(|| reference.year());
+                    self.set_year(year);
+                }
+                None => {}
+            }
+        }
+
+        if let Some(time) = defaults.time {
+            if self.hour_24().is_none()
+                && self.hour_12().is_none()
+                && self.minute().is_none()
+                && self.second().is_none()
+                && self.subsecond().is_none()
+            {
+                self.set_hour_24(time.hour());
+                self.set_minute(time.minute());
+                self.set_second(time.second());
+                self.set_subsecond(time.nanosecond());
+            }
+        }
+
+        if let Some(offset) = defaults.offset {
+            if self.offset_hour().is_none()
+                && self.offset_minute_signed().is_none()
+                && self.offset_second_signed().is_none()
+                && self.zone_name().is_none()
+                && self.zone_abbr().is_none()
+            {
+                self.set_offset(offset);
+            }
+        }
+    }
+
+    /// Obtain the year that places the parsed date closest to the reference date. Only the year of
+    /// the reference and those adjacent to it need to be considered, as any other year is
+    /// necessarily further away. `None` is returned if the date is not valid in any of them.
+    pub(crate) fn year_nearest_to(&self, reference: Date) -> Option<i32> {
+        [reference.year(), reference.year() - 1, reference.year() + 1]
+            .into_iter()
+            .filter_map(|year| Date::try_from(self.with_year(year)?).ok())
+            .min_by_key(|date| (date.to_julian_day() - reference.to_julian_day()).abs())
+            .map(Date::year)
+    }
+
+    /// Set all offset components from the provided offset.
+    fn set_offset(&mut self, offset: UtcOffset) -> Option<()> {
+        self.set_flag(Self::OFFSET_IS_NEGATIVE_FLAG_IS_INITIALIZED, true);
+        self.set_flag(Self::OFFSET_IS_NEGATIVE_FLAG, offset.is_negative());
+        self.set_offset_hour(offset.whole_hours())?;
+        self.set_offset_minute_signed(offset.minutes_past_hour())?;
+        self.set_offset_second_signed(offset.seconds_past_minute())
+    }
 }
 
 /// Generate build methods for each of the fields.
@@ -601,6 +969,10 @@ impl Parsed {
         @YEAR_LAST_TWO_FLAG with_year_last_two year_last_two: u8,
         @ISO_YEAR_FLAG with_iso_year iso_year: i32,
         @ISO_YEAR_LAST_TWO_FLAG with_iso_year_last_two iso_year_last_two: u8,
+        @CENTURY_FLAG with_century century: i16,
+        with_era era: Era,
+        with_year_of_era year_of_era: NonZeroU32,
+        with_quarter quarter: NonZeroU8,
         with_month month: Month,
         @SUNDAY_WEEK_NUMBER_FLAG with_sunday_week_number sunday_week_number: u8,
         @MONDAY_WEEK_NUMBER_FLAG with_monday_week_number monday_week_number: u8,
@@ -659,12 +1031,50 @@ impl Parsed {
         self.flags |= Self::OFFSET_SECOND_FLAG;
         Some(self)
     }
+
+    /// Set the `zone_name` component and return `self`. The value must be at most 32 bytes long
+    /// and must not contain a NUL byte.
+    pub fn with_zone_name(mut self, value: &str) -> Option<Self> {
+        self.set_zone_name(value)?;
+        Some(self)
+    }
+
+    /// Set the `zone_abbr` component and return `self`. The value must be at most eight bytes
+    /// long and must not contain a NUL byte.
+    pub fn with_zone_abbr(mut self, value: &str) -> Option<Self> {
+        self.set_zone_abbr(value)?;
+        Some(self)
+    }
+}
+
+impl Parsed {
+    /// Obtain the calendar year from the era and year of the era, or from the century and the last
+    /// two digits of the year.
+    ///
+    /// If the year of the era is present without an era, the common era is assumed.
+    fn resolve_year(&self) -> Option<i32> {
+        if let Some(year_of_era) = self.year_of_era() {
+            let year_of_era = year_of_era.get() as i32;
+            return Some(match self.era() {
+                Some(Era::Bce) => 1 - year_of_era,
+                Some(Era::Ce) | None => year_of_era,
+            });
+        }
+
+        let century = self.century()?;
+        let last_two = self.year_last_two()? as i32;
+        if century < 0 || self.get_flag(Self::CENTURY_IS_NEGATIVE_FLAG) {
+            Some(century as i32 * 100 - last_two)
+        } else {
+            Some(century as i32 * 100 + last_two)
+        }
+    }
 }
 
 impl TryFrom<Parsed> for Date {
     type Error = error::TryFromParsed;
 
-    fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
+    fn try_from(mut parsed: Parsed) -> Result<Self, Self::Error> {
         /// Match on the components that need to be present.
         macro_rules! match_ {
             (_ => $catch_all:expr $(,)?) => {
@@ -696,6 +1106,12 @@ impl TryFrom<Parsed> for Date {
         // TODO Only the basics have been covered. There are many other valid values that are not
         // currently constructed from the information known.
 
+        if parsed.year().is_none() {
+            if let Some(year) = parsed.resolve_year() {
+                parsed.set_year(year);
+            }
+        }
+
         match_! {
             (year, ordinal) => Ok(Self::from_ordinal_date(year, ordinal.get())?),
             (year, month, day) => Ok(Self::from_calendar_date(year, month, day.get())?),
@@ -753,7 +1169,15 @@ impl TryFrom<Parsed> for UtcOffset {
     type Error = error::TryFromParsed;
 
     fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
-        let hour = parsed.offset_hour().ok_or(InsufficientInformation)?;
+        let hour = match parsed.offset_hour() {
+            Some(hour) => hour,
+            None => {
+                return parsed
+                    .zone_abbr()
+                    .and_then(offset_from_zone_abbr)
+                    .ok_or(InsufficientInformation)
+            }
+        };
         let minute = parsed.offset_minute_signed().unwrap_or(0);
         let second = parsed.offset_second_signed().unwrap_or(0);
 
@@ -771,6 +1195,173 @@ impl TryFrom<Parsed> for UtcOffset {
     }
 }
 
+/// Obtain the offset indicated by a time zone abbreviation that has only one meaning: `UTC`, `GMT`,
+/// `UT`, `Z`, or a numeric offset such as `+0530` or `-03`.
+fn offset_from_zone_abbr(abbr: &str) -> Option<UtcOffset> {
+    /// Parse two ASCII digits.
+    fn two_digits(digits: &[u8]) -> Option<i8> {
+        match digits {
+            [tens @ b'0'..=b'9', ones @ b'0'..=b'9'] => {
+                Some(((tens - b'0') * 10 + ones - b'0') as _)
+            }
+            _ => None,
+        }
+    }
+
+    match abbr.as_bytes() {
+        b"UTC" | b"GMT" | b"UT" | b"Z" => Some(UtcOffset::UTC),
+        [sign @ (b'+' | b'-'), digits @ ..] => {
+            let (hours, minutes) = match digits.len() {
+                2 => (two_digits(digits)?, 0),
+                4 => (two_digits(&digits[..2])?, two_digits(&digits[2..])?),
+                _ => return None,
+            };
+            let offset = UtcOffset::from_hms(hours, minutes, 0).ok()?;
+            Some(if *sign == b'-' { -offset } else { offset })
+        }
+        _ => None,
+    }
+}
+
+/// All information parsed for a [`Duration`].
+///
+/// The components of a duration are kept apart from [`Parsed`] so that parsing any other type does
+/// not pay for them. All other components, including the subsecond, are parsed into the contained
+/// [`Parsed`].
+#[derive(Debug, Clone, Copy)]
+pub struct ParsedDuration {
+    /// The components that are not specific to a duration.
+    pub(crate) parsed: Parsed,
+    /// Whether the duration is negative.
+    is_negative: Option<bool>,
+    /// Whole days of the duration.
+    days: Option<u64>,
+    /// Hours of the duration, either in total or past the last whole day.
+    hours: Option<u64>,
+    /// Minutes of the duration, either in total or past the last whole hour.
+    minutes: Option<u64>,
+    /// Seconds of the duration, either in total or past the last whole minute.
+    seconds: Option<u64>,
+}
+
+impl ParsedDuration {
+    /// Create a new instance of `ParsedDuration` with no information known.
+    pub(crate) const fn new() -> Self {
+        Self {
+            parsed: Parsed::new(),
+            is_negative: None,
+            days: None,
+            hours: None,
+            minutes: None,
+            seconds: None,
+        }
+    }
+
+    /// Set whether the duration is negative.
+    pub(crate) fn set_is_negative(&mut self, value: bool) -> Option<()> {
+        self.is_negative = Some(value);
+        Some(())
+    }
+
+    /// Set the whole days of the duration.
+    pub(crate) fn set_days(&mut self, value: u64) -> Option<()> {
+        self.days = Some(value);
+        Some(())
+    }
+
+    /// Set the hours of the duration.
+    pub(crate) fn set_hours(&mut self, value: u64) -> Option<()> {
+        self.hours = Some(value);
+        Some(())
+    }
+
+    /// Set the minutes of the duration.
+    pub(crate) fn set_minutes(&mut self, value: u64) -> Option<()> {
+        self.minutes = Some(value);
+        Some(())
+    }
+
+    /// Set the seconds of the duration.
+    pub(crate) fn set_seconds(&mut self, value: u64) -> Option<()> {
+        self.seconds = Some(value);
+        Some(())
+    }
+}
+
+impl Accumulator for ParsedDuration {
+    fn parse_component_with_locale<'a>(
+        &mut self,
+        input: &'a [u8],
+        component: Component,
+        locale: Option<&dyn Locale>,
+    ) -> Result<&'a [u8], error::Located<error::ParseFromDescription>> {
+        use crate::parsing::invalid_component;
+
+        match component {
+            Component::Sign(modifiers) => parse_sign(input, modifiers)
+                .and_then(|parsed| parsed.consume_value(|value| self.set_is_negative(value)))
+                .ok_or_else(|| invalid_component("sign", input)),
+            Component::Days(modifiers) => parse_days(input, modifiers)
+                .and_then(|parsed| parsed.consume_value(|value| self.set_days(value)))
+                .ok_or_else(|| invalid_component("days", input)),
+            Component::Hours(modifiers) => parse_hours(input, modifiers)
+                .and_then(|parsed| parsed.consume_value(|value| self.set_hours(value)))
+                .ok_or_else(|| invalid_component("hours", input)),
+            Component::Minutes(modifiers) => parse_minutes(input, modifiers)
+                .and_then(|parsed| parsed.consume_value(|value| self.set_minutes(value)))
+                .ok_or_else(|| invalid_component("minutes", input)),
+            Component::Seconds(modifiers) => parse_seconds(input, modifiers)
+                .and_then(|parsed| parsed.consume_value(|value| self.set_seconds(value)))
+                .ok_or_else(|| invalid_component("seconds", input)),
+            _ => self
+                .parsed
+                .parse_component_with_locale(input, component, locale),
+        }
+    }
+}
+
+impl TryFrom<ParsedDuration> for Duration {
+    type Error = error::TryFromParsed;
+
+    fn try_from(parsed: ParsedDuration) -> Result<Self, Self::Error> {
+        if parsed.days.is_none()
+            && parsed.hours.is_none()
+            && parsed.minutes.is_none()
+            && parsed.seconds.is_none()
+            && parsed.parsed.subsecond().is_none()
+        {
+            return Err(InsufficientInformation);
+        }
+
+        // The values are summed, so that the total of one unit may be combined with the remainder
+        // of a smaller unit. None of the products can overflow, as each value is at most
+        // `u64::MAX`.
+        let seconds = parsed.days.unwrap_or(0) as i128 * Second.per(Day) as i128
+            + parsed.hours.unwrap_or(0) as i128 * Second.per(Hour) as i128
+            + parsed.minutes.unwrap_or(0) as i128 * Second.per(Minute) as i128
+            + parsed.seconds.unwrap_or(0) as i128;
+        let nanoseconds = parsed.parsed.subsecond().unwrap_or(0) as i32;
+
+        let (seconds, nanoseconds) = if parsed.is_negative == Some(true) {
+            (-seconds, -nanoseconds)
+        } else {
+            (seconds, nanoseconds)
+        };
+        if seconds < i64::MIN as i128 || seconds > i64::MAX as i128 {
+            return Err(error::TryFromParsed::ComponentRange(
+                error::ComponentRange {
+                    name: "seconds",
+                    minimum: i64::MIN,
+                    maximum: i64::MAX,
+                    value: seconds as _,
+                    conditional_range: true,
+                },
+            ));
+        }
+        Ok(Self::new_unchecked(seconds as _, nanoseconds))
+    }
+}
+
 impl TryFrom<Parsed> for PrimitiveDateTime {
     type Error = <DateTime<offset_kind::None> as TryFrom<Parsed>>::Error;
 
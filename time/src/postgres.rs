@@ -0,0 +1,149 @@
+//! The binary wire format of PostgreSQL's `date`, `timestamp`, and `timestamptz` types.
+//!
+//! A `date` is transmitted as the number of days since 2000-01-01, and a `timestamp` or
+//! `timestamptz` as the number of microseconds since 2000-01-01 00:00. Both are big-endian signed
+//! integers. A `timestamptz` is always transmitted in UTC. As PostgreSQL has microsecond
+//! precision, any further precision is truncated when encoding.
+//!
+//! The special values `infinity` and `-infinity` cannot be represented by this crate, so they are
+//! rejected when decoding.
+
+use crate::{error, Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
+
+/// The Julian day of the PostgreSQL epoch, 2000-01-01.
+const EPOCH_JULIAN_DAY: i32 = 2_451_545;
+
+/// The PostgreSQL epoch, 2000-01-01 00:00.
+const EPOCH: PrimitiveDateTime =
+    PrimitiveDateTime::new(Date::__from_ordinal_date_unchecked(2000, 1), Time::MIDNIGHT);
+
+/// Encode a `date`.
+///
+/// ```rust
+/// # use time::postgres;
+/// # use time_macros::date;
+/// assert_eq!(
+///     postgres::encode_date(date!(2024 - 01 - 02)),
+///     [0x00, 0x00, 0x22, 0x3F]
+/// );
+/// assert_eq!(postgres::encode_date(date!(1999 - 12 - 31)), [0xFF; 4]);
+/// ```
+pub const fn encode_date(date: Date) -> [u8; 4] {
+    (date.to_julian_day() - EPOCH_JULIAN_DAY).to_be_bytes()
+}
+
+/// Decode a `date`.
+///
+/// ```rust
+/// # use time::postgres;
+/// # use time_macros::date;
+/// assert_eq!(
+///     postgres::decode_date([0x00, 0x00, 0x22, 0x3F])?,
+///     date!(2024 - 01 - 02)
+/// );
+/// // `infinity`
+/// assert!(postgres::decode_date([0x7F, 0xFF, 0xFF, 0xFF]).is_err());
+/// # Ok::<_, time::Error>(())
+/// ```
+pub const fn decode_date(bytes: [u8; 4]) -> Result<Date, error::ConversionRange> {
+    let days = i32::from_be_bytes(bytes);
+    if days == i32::MAX || days == i32::MIN {
+        return Err(error::ConversionRange);
+    }
+    match days.checked_add(EPOCH_JULIAN_DAY) {
+        Some(julian_day) => match Date::from_julian_day(julian_day) {
+            Ok(date) => Ok(date),
+            Err(_) => Err(error::ConversionRange),
+        },
+        None => Err(error::ConversionRange),
+    }
+}
+
+/// Encode a `timestamp`.
+///
+/// An error is only returned if the value is too far from the epoch to be represented, which is
+/// only possible with the `large-dates` feature enabled.
+///
+/// ```rust
+/// # use time::postgres;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     postgres::encode_timestamp(datetime!(2024-01-02 03:04:05.123456))?,
+///     [0x00, 0x02, 0xB0, 0xEC, 0x85, 0x17, 0xD5, 0x80]
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+pub fn encode_timestamp(datetime: PrimitiveDateTime) -> Result<[u8; 8], error::ConversionRange> {
+    encode_microseconds(datetime - EPOCH)
+}
+
+/// Decode a `timestamp`.
+///
+/// ```rust
+/// # use time::postgres;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     postgres::decode_timestamp([0x00, 0x02, 0xB0, 0xEC, 0x85, 0x17, 0xD5, 0x80])?,
+///     datetime!(2024-01-02 03:04:05.123456)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+pub fn decode_timestamp(bytes: [u8; 8]) -> Result<PrimitiveDateTime, error::ConversionRange> {
+    EPOCH
+        .checked_add(decode_microseconds(bytes)?)
+        .ok_or(error::ConversionRange)
+}
+
+/// Encode a `timestamptz`. The value is converted to UTC.
+///
+/// An error is only returned if the value is too far from the epoch to be represented, which is
+/// only possible with the `large-dates` feature enabled.
+///
+/// ```rust
+/// # use time::postgres;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     postgres::encode_timestamptz(datetime!(2024-01-02 05:04:05.123456 +2))?,
+///     [0x00, 0x02, 0xB0, 0xEC, 0x85, 0x17, 0xD5, 0x80]
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+pub fn encode_timestamptz(datetime: OffsetDateTime) -> Result<[u8; 8], error::ConversionRange> {
+    encode_microseconds(datetime - EPOCH.assume_utc())
+}
+
+/// Decode a `timestamptz`. The returned value is in UTC.
+///
+/// ```rust
+/// # use time::postgres;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     postgres::decode_timestamptz([0x00, 0x02, 0xB0, 0xEC, 0x85, 0x17, 0xD5, 0x80])?,
+///     datetime!(2024-01-02 03:04:05.123456 UTC)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+pub fn decode_timestamptz(bytes: [u8; 8]) -> Result<OffsetDateTime, error::ConversionRange> {
+    EPOCH
+        .assume_utc()
+        .checked_add(decode_microseconds(bytes)?)
+        .ok_or(error::ConversionRange)
+}
+
+/// Encode the number of whole microseconds in the duration, rounding towards negative infinity.
+fn encode_microseconds(duration: Duration) -> Result<[u8; 8], error::ConversionRange> {
+    let microseconds = duration.whole_nanoseconds().div_euclid(1_000);
+    match i64::try_from(microseconds) {
+        // These values are `infinity` and `-infinity`.
+        Ok(i64::MAX | i64::MIN) | Err(_) => Err(error::ConversionRange),
+        Ok(microseconds) => Ok(microseconds.to_be_bytes()),
+    }
+}
+
+/// Decode a number of microseconds, rejecting `infinity` and `-infinity`.
+const fn decode_microseconds(bytes: [u8; 8]) -> Result<Duration, error::ConversionRange> {
+    match i64::from_be_bytes(bytes) {
+        i64::MAX | i64::MIN => Err(error::ConversionRange),
+        microseconds => Ok(Duration::microseconds(microseconds)),
+    }
+}
@@ -6,7 +6,9 @@ use core::time::Duration as StdDuration;
 #[cfg(feature = "formatting")]
 use std::io;
 
+#[cfg(feature = "icp")]
 use candid::types::{Serializer, Type, TypeInner};
+#[cfg(feature = "icp")]
 use candid::CandidType;
 
 use crate::date_time::offset_kind;
@@ -14,7 +16,10 @@ use crate::date_time::offset_kind;
 use crate::formatting::Formattable;
 #[cfg(feature = "parsing")]
 use crate::parsing::Parsable;
-use crate::{error, Date, DateTime, Duration, Month, OffsetDateTime, Time, UtcOffset, Weekday};
+use crate::zone::{LocalResult, TimeZone};
+use crate::{
+    error, Date, DateTime, Duration, Era, Month, OffsetDateTime, Time, UtcOffset, Weekday,
+};
 
 /// The actual type doing all the work.
 type Inner = DateTime<offset_kind::None>;
@@ -130,6 +135,29 @@ impl PrimitiveDateTime {
         self.0.year()
     }
 
+    /// Get the era of the date in the proleptic Gregorian calendar.
+    ///
+    /// ```rust
+    /// # use time::Era;
+    /// # use time_macros::datetime;
+    /// assert_eq!(datetime!(2019-01-01 0:00).era(), Era::Ce);
+    /// assert_eq!(datetime!(0000-12-31 0:00).era(), Era::Bce);
+    /// ```
+    pub const fn era(self) -> Era {
+        self.0.era()
+    }
+
+    /// Get the year of the date within its [era](Self::era).
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(datetime!(2019-01-01 0:00).year_of_era(), 2019);
+    /// assert_eq!(datetime!(-0043-03-15 0:00).year_of_era(), 44);
+    /// ```
+    pub const fn year_of_era(self) -> u32 {
+        self.0.year_of_era()
+    }
+
     /// Get the month of the date.
     ///
     /// ```rust
@@ -155,6 +183,19 @@ impl PrimitiveDateTime {
         self.0.day()
     }
 
+    /// Get the quarter of the year of the date.
+    ///
+    /// The returned value will always be in the range `1..=4`.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(datetime!(2019-01-01 0:00).quarter(), 1);
+    /// assert_eq!(datetime!(2019-12-31 0:00).quarter(), 4);
+    /// ```
+    pub const fn quarter(self) -> u8 {
+        self.0.quarter()
+    }
+
     /// Get the day of the year.
     ///
     /// The returned value will always be in the range `1..=366` (`1..=365` for common years).
@@ -482,6 +523,28 @@ impl PrimitiveDateTime {
     pub const fn assume_utc(self) -> OffsetDateTime {
         OffsetDateTime(self.0.assume_utc())
     }
+
+    /// Assuming that the existing `PrimitiveDateTime` represents a local date-time in the provided
+    /// [time zone](crate::zone::TimeZone), return the [`OffsetDateTime`]s it may correspond to.
+    ///
+    /// ```rust
+    /// # use time::zone::{Disambiguation, LocalResult};
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 0:00).assume_zone(&offset!(+1)),
+    ///     LocalResult::Single(datetime!(2019-01-01 0:00 +1)),
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 0:00)
+    ///         .assume_zone(&offset!(+1))
+    ///         .resolve(Disambiguation::Reject)?,
+    ///     datetime!(2019-01-01 0:00 +1),
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn assume_zone(self, zone: &(impl TimeZone + ?Sized)) -> LocalResult<OffsetDateTime> {
+        zone.offset_at_local(self).map(|offset| self.assume_offset(offset))
+    }
     // endregion attach offset
 
     // region: checked arithmetic
@@ -806,6 +869,46 @@ impl PrimitiveDateTime {
     ) -> Result<Self, error::Parse> {
         Inner::parse(input, description).map(Self)
     }
+
+    /// Parse a `PrimitiveDateTime` from the input using the provided [format
+    /// description](crate::format_description). Unlike [`PrimitiveDateTime::parse`], the error
+    /// records the location in the input at which parsing failed.
+    ///
+    /// ```rust
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::format_description;
+    /// let format = format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+    /// let err = PrimitiveDateTime::parse_located("2020-01-02T03:04:05", &format).unwrap_err();
+    /// assert_eq!(err.offset(), Some(10));
+    /// ```
+    pub fn parse_located(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<Self, error::Located<error::Parse>> {
+        Inner::parse_located(input, description).map(Self)
+    }
+
+    /// Parse a `PrimitiveDateTime` from the start of the input using the provided [format
+    /// description](crate::format_description). Rather than any trailing characters being an
+    /// error, they are returned alongside the parsed value. If parsing fails, the error records the
+    /// location in the input at which it did so.
+    ///
+    /// ```rust
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::{datetime, format_description};
+    /// let format = format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+    /// assert_eq!(
+    ///     PrimitiveDateTime::parse_prefix("2020-01-02 03:04:05 GET /index.html", &format)?,
+    ///     (datetime!(2020-01-02 03:04:05), " GET /index.html")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a str), error::Located<error::Parse>> {
+        Inner::parse_prefix(input, description).map(|(value, remaining)| (Self(value), remaining))
+    }
 }
 #[cfg(feature = "icp")]
 impl CandidType for PrimitiveDateTime {
@@ -0,0 +1,1006 @@
+//! Recurrence rules, as described by the `RRULE` property of iCalendar.
+//!
+//! A [`RecurrenceRule`] is parsed from the value of an `RRULE` property and expanded from a start
+//! into its [`Occurrences`]. Occurrences are computed lazily, one period of the rule at a time, so
+//! that rules without a `COUNT` or `UNTIL` can be used.
+
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::str::FromStr;
+
+use crate::{
+    error, util, Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday,
+};
+
+/// The unit of time by which a rule repeats.
+#[allow(clippy::missing_docs_in_private_items)] // variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Frequency {
+    Secondly,
+    Minutely,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A `BYDAY` value: a day of the week, optionally restricted to its nth occurrence within the
+/// month or year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ByDay {
+    /// The occurrence within the month or year, counting from the end if negative. Zero matches
+    /// every occurrence.
+    nth: i8,
+    /// The day of the week.
+    weekday: Weekday,
+}
+
+/// The `UNTIL` value of a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Until {
+    /// A date, which includes any time on that day.
+    Date(Date),
+    /// A date and time without a UTC offset, which is compared with the local time.
+    Local(PrimitiveDateTime),
+    /// A date and time in UTC.
+    Utc(OffsetDateTime),
+}
+
+/// A recurrence rule, as described in
+/// [RFC 5545](https://www.rfc-editor.org/rfc/rfc5545#section-3.3.10).
+///
+/// All frequencies and `BYxxx` rule parts are supported, along with `INTERVAL`, `COUNT`, `UNTIL`,
+/// and `WKST`. Leap seconds are not supported, so a `BYSECOND` value of 60 is rejected.
+///
+/// ```rust
+/// # use time::recurrence::RecurrenceRule;
+/// # use time_macros::datetime;
+/// let rule = RecurrenceRule::parse("FREQ=MONTHLY;BYDAY=2TU;COUNT=3")?;
+/// assert_eq!(
+///     rule.occurrences(datetime!(2024-01-09 9:00)).collect::<Vec<_>>(),
+///     [
+///         datetime!(2024-01-09 9:00),
+///         datetime!(2024-02-13 9:00),
+///         datetime!(2024-03-12 9:00),
+///     ]
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[doc(alias = "RRULE")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecurrenceRule {
+    /// The unit of time by which the rule repeats.
+    frequency: Frequency,
+    /// The number of units between each period.
+    interval: u32,
+    /// The maximum number of occurrences, including the start.
+    count: Option<u32>,
+    /// The last moment that may be an occurrence.
+    until: Option<Until>,
+    /// The seconds of the minute, sorted and deduplicated.
+    by_second: Vec<u8>,
+    /// The minutes of the hour, sorted and deduplicated.
+    by_minute: Vec<u8>,
+    /// The hours of the day, sorted and deduplicated.
+    by_hour: Vec<u8>,
+    /// The days of the week.
+    by_day: Vec<ByDay>,
+    /// The days of the month, counting from the end if negative.
+    by_month_day: Vec<i8>,
+    /// The days of the year, counting from the end if negative.
+    by_year_day: Vec<i16>,
+    /// The weeks of the year, counting from the end if negative.
+    by_week_no: Vec<i8>,
+    /// The months of the year.
+    by_month: Vec<Month>,
+    /// The positions within each period's set of occurrences, counting from the end if negative.
+    by_set_pos: Vec<i16>,
+    /// The day on which a week starts.
+    week_start: Weekday,
+}
+
+impl RecurrenceRule {
+    /// Parse the value of an `RRULE` property, such as `FREQ=WEEKLY;BYDAY=MO,WE`. The property
+    /// name may be included as a prefix. Names and values are case-insensitive.
+    ///
+    /// In addition to the syntax, the restrictions the RFC places on combining rule parts are
+    /// checked. For example, `COUNT` and `UNTIL` cannot both be present.
+    ///
+    /// ```rust
+    /// # use time::recurrence::RecurrenceRule;
+    /// assert!(RecurrenceRule::parse("RRULE:FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO").is_ok());
+    /// assert!(RecurrenceRule::parse("FREQ=MONTHLY;BYWEEKNO=20").is_err());
+    /// assert!(RecurrenceRule::parse("FREQ=DAILY;BYMONTHDAY=32").is_err());
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, error::InvalidRecurrenceRule> {
+        /// Store the value of a rule part, which must not already be present.
+        fn set_once<T>(
+            part: &mut Option<T>,
+            value: T,
+            index: usize,
+        ) -> Result<(), error::InvalidRecurrenceRule> {
+            if part.is_some() {
+                return Err(error::InvalidRecurrenceRule::Malformed { index });
+            }
+            *part = Some(value);
+            Ok(())
+        }
+
+        let mut parser = Parser {
+            input: s.as_bytes(),
+            index: 0,
+        };
+        if s.len() >= 6 && s.as_bytes()[..6].eq_ignore_ascii_case(b"RRULE:") {
+            parser.index = 6;
+        }
+
+        let mut frequency = None;
+        let mut interval = None;
+        let mut count = None;
+        let mut until = None;
+        let mut by_second = None;
+        let mut by_minute = None;
+        let mut by_hour = None;
+        let mut by_day = None;
+        let mut by_month_day = None;
+        let mut by_year_day = None;
+        let mut by_week_no = None;
+        let mut by_month = None;
+        let mut by_set_pos = None;
+        let mut week_start = None;
+
+        loop {
+            let index = parser.index;
+            let name = parser.name()?;
+            parser.expect(b'=')?;
+            let is = |expected: &str| name.eq_ignore_ascii_case(expected.as_bytes());
+
+            if is("FREQ") {
+                set_once(&mut frequency, parser.frequency()?, index)?;
+            } else if is("INTERVAL") {
+                let value_index = parser.index;
+                let value = parser.number()?;
+                if value == 0 {
+                    return Err(error::InvalidRecurrenceRule::Malformed { index: value_index });
+                }
+                set_once(&mut interval, value, index)?;
+            } else if is("COUNT") {
+                set_once(&mut count, parser.number()?, index)?;
+            } else if is("UNTIL") {
+                set_once(&mut until, parser.until()?, index)?;
+            } else if is("BYSECOND") {
+                set_once(&mut by_second, parser.list(|p| p.unsigned(0, 59))?, index)?;
+            } else if is("BYMINUTE") {
+                set_once(&mut by_minute, parser.list(|p| p.unsigned(0, 59))?, index)?;
+            } else if is("BYHOUR") {
+                set_once(&mut by_hour, parser.list(|p| p.unsigned(0, 23))?, index)?;
+            } else if is("BYDAY") {
+                set_once(&mut by_day, parser.list(Parser::by_day)?, index)?;
+            } else if is("BYMONTHDAY") {
+                let values = parser.list(|p| p.signed(31).map(|value| value as i8))?;
+                set_once(&mut by_month_day, values, index)?;
+            } else if is("BYYEARDAY") {
+                set_once(&mut by_year_day, parser.list(|p| p.signed(366))?, index)?;
+            } else if is("BYWEEKNO") {
+                let values = parser.list(|p| p.signed(53).map(|value| value as i8))?;
+                set_once(&mut by_week_no, values, index)?;
+            } else if is("BYMONTH") {
+                let values = parser.list(|p| {
+                    p.unsigned(1, 12).map(|month| match Month::try_from(month) {
+                        Ok(month) => month,
+                        Err(_) => bug!("month is checked to be in range"),
+                    })
+                })?;
+                set_once(&mut by_month, values, index)?;
+            } else if is("BYSETPOS") {
+                set_once(&mut by_set_pos, parser.list(|p| p.signed(366))?, index)?;
+            } else if is("WKST") {
+                set_once(&mut week_start, parser.weekday()?, index)?;
+            } else {
+                return Err(error::InvalidRecurrenceRule::Malformed { index });
+            }
+
+            if parser.is_done() {
+                break;
+            }
+            parser.expect(b';')?;
+        }
+
+        let conflict = |reason| Err(error::InvalidRecurrenceRule::Conflict { reason });
+        let frequency = match frequency {
+            Some(frequency) => frequency,
+            None => return conflict("FREQ is required"),
+        };
+        if count.is_some() && until.is_some() {
+            return conflict("COUNT and UNTIL cannot both be present");
+        }
+        if by_week_no.is_some() && frequency != Frequency::Yearly {
+            return conflict("BYWEEKNO is only permitted with FREQ=YEARLY");
+        }
+        if by_year_day.is_some()
+            && matches!(
+                frequency,
+                Frequency::Daily | Frequency::Weekly | Frequency::Monthly
+            )
+        {
+            return conflict("BYYEARDAY is not permitted with FREQ=DAILY, WEEKLY, or MONTHLY");
+        }
+        if by_month_day.is_some() && frequency == Frequency::Weekly {
+            return conflict("BYMONTHDAY is not permitted with FREQ=WEEKLY");
+        }
+        if by_day.as_ref().map_or(false, |by_day: &Vec<ByDay>| {
+            by_day.iter().any(|day| day.nth != 0)
+        }) {
+            if !matches!(frequency, Frequency::Monthly | Frequency::Yearly) {
+                return conflict("BYDAY ordinals are only permitted with FREQ=MONTHLY or YEARLY");
+            }
+            if by_week_no.is_some() {
+                return conflict("BYDAY ordinals are not permitted with BYWEEKNO");
+            }
+        }
+        if by_set_pos.is_some()
+            && by_second.is_none()
+            && by_minute.is_none()
+            && by_hour.is_none()
+            && by_day.is_none()
+            && by_month_day.is_none()
+            && by_year_day.is_none()
+            && by_week_no.is_none()
+            && by_month.is_none()
+        {
+            return conflict("BYSETPOS requires another BYxxx rule part");
+        }
+
+        let sorted = |values: Option<Vec<u8>>| {
+            let mut values = values.unwrap_or_default();
+            values.sort_unstable();
+            values.dedup();
+            values
+        };
+
+        Ok(Self {
+            frequency,
+            interval: interval.unwrap_or(1),
+            count,
+            until,
+            by_second: sorted(by_second),
+            by_minute: sorted(by_minute),
+            by_hour: sorted(by_hour),
+            by_day: by_day.unwrap_or_default(),
+            by_month_day: by_month_day.unwrap_or_default(),
+            by_year_day: by_year_day.unwrap_or_default(),
+            by_week_no: by_week_no.unwrap_or_default(),
+            by_month: by_month.unwrap_or_default(),
+            by_set_pos: by_set_pos.unwrap_or_default(),
+            week_start: week_start.unwrap_or(Weekday::Monday),
+        })
+    }
+
+    /// Get the unit of time by which the rule repeats.
+    pub const fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// Get the number of units between each period of the rule. This is one unless `INTERVAL` is
+    /// present.
+    pub const fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// Get the maximum number of occurrences, including the start, if `COUNT` is present.
+    pub const fn count(&self) -> Option<u32> {
+        self.count
+    }
+
+    /// Expand the rule from the provided start, which corresponds to the `DTSTART` property.
+    ///
+    /// As required by the RFC, the start is always the first occurrence, even if it does not match
+    /// the rule. Any parts of the rule that are not present are taken from the start. Each
+    /// occurrence has the same subsecond and, for an [`OffsetDateTime`], the same UTC offset as
+    /// the start.
+    ///
+    /// An `UNTIL` value in UTC is compared with each occurrence as an instant when iterating over
+    /// [`OffsetDateTime`]s, and as a local time otherwise.
+    ///
+    /// ```rust
+    /// # use time::recurrence::RecurrenceRule;
+    /// # use time_macros::datetime;
+    /// let rule = RecurrenceRule::parse("FREQ=HOURLY;INTERVAL=3;UNTIL=19970902T170000Z")?;
+    /// assert_eq!(
+    ///     rule.occurrences(datetime!(1997-09-02 9:00 -4)).collect::<Vec<_>>(),
+    ///     [datetime!(1997-09-02 9:00 -4), datetime!(1997-09-02 12:00 -4)]
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn occurrences<T: Occurrence>(&self, start: T) -> Occurrences<T> {
+        let local = start.local();
+        let mut rule = self.clone();
+
+        if rule.by_week_no.is_empty()
+            && rule.by_year_day.is_empty()
+            && rule.by_month_day.is_empty()
+            && rule.by_day.is_empty()
+        {
+            match rule.frequency {
+                Frequency::Yearly => {
+                    if rule.by_month.is_empty() {
+                        rule.by_month.push(local.month());
+                    }
+                    rule.by_month_day.push(local.day() as _);
+                }
+                Frequency::Monthly => rule.by_month_day.push(local.day() as _),
+                Frequency::Weekly => rule.by_day.push(ByDay {
+                    nth: 0,
+                    weekday: local.weekday(),
+                }),
+                _ => {}
+            }
+        }
+        if rule.by_hour.is_empty() && rule.frequency > Frequency::Hourly {
+            rule.by_hour.push(local.hour());
+        }
+        if rule.by_minute.is_empty() && rule.frequency > Frequency::Minutely {
+            rule.by_minute.push(local.minute());
+        }
+        if rule.by_second.is_empty() && rule.frequency > Frequency::Secondly {
+            rule.by_second.push(local.second());
+        }
+
+        let date = local.date();
+        let period = match rule.frequency {
+            Frequency::Yearly => Date::from_calendar_date(date.year(), Month::January, 1).ok(),
+            Frequency::Monthly => Date::from_calendar_date(date.year(), date.month(), 1).ok(),
+            Frequency::Weekly => date.checked_sub(Duration::days(
+                ((date.weekday().number_days_from_monday() + 7
+                    - rule.week_start.number_days_from_monday())
+                    % 7) as _,
+            )),
+            _ => Some(date),
+        }
+        .map(|date| {
+            let time = match rule.frequency {
+                Frequency::Hourly => Time::__from_hms_nanos_unchecked(local.hour(), 0, 0, 0),
+                Frequency::Minutely => {
+                    Time::__from_hms_nanos_unchecked(local.hour(), local.minute(), 0, 0)
+                }
+                Frequency::Secondly => Time::__from_hms_nanos_unchecked(
+                    local.hour(),
+                    local.minute(),
+                    local.second(),
+                    0,
+                ),
+                _ => Time::MIDNIGHT,
+            };
+            PrimitiveDateTime::new(date, time)
+        });
+
+        Occurrences {
+            rule,
+            start,
+            started: false,
+            period,
+            pending: Vec::new(),
+            counted: 0,
+            excluded: Vec::new(),
+        }
+    }
+
+    /// Whether the date matches the `BYxxx` rule parts that apply to days.
+    fn day_matches(&self, date: Date) -> bool {
+        let (year, month, day) = date.to_calendar_date();
+        let ordinal = date.ordinal();
+
+        (self.by_month.is_empty() || self.by_month.contains(&month))
+            && (self.by_week_no.is_empty() || self.week_no_matches(date))
+            && (self.by_year_day.is_empty()
+                || self.by_year_day.iter().any(|&value| {
+                    position_matches(value as _, ordinal as _, util::days_in_year(year) as _)
+                }))
+            && (self.by_month_day.is_empty()
+                || self.by_month_day.iter().any(|&value| {
+                    position_matches(
+                        value as _,
+                        day as _,
+                        util::days_in_year_month(year, month) as _,
+                    )
+                }))
+            && (self.by_day.is_empty()
+                || self.by_day.iter().any(|by_day| {
+                    if by_day.weekday != date.weekday() {
+                        return false;
+                    }
+                    if by_day.nth == 0 {
+                        return true;
+                    }
+
+                    // The ordinal is within the month, unless the rule is yearly and not limited
+                    // to certain months.
+                    let (position, len) =
+                        if self.frequency == Frequency::Monthly || !self.by_month.is_empty() {
+                            (day as i32, util::days_in_year_month(year, month) as i32)
+                        } else {
+                            (ordinal as i32, util::days_in_year(year) as i32)
+                        };
+                    if by_day.nth > 0 {
+                        (position - 1) / 7 + 1 == by_day.nth as i32
+                    } else {
+                        (len - position) / 7 + 1 == -by_day.nth as i32
+                    }
+                }))
+    }
+
+    /// Whether the date falls within a week matching `BYWEEKNO`.
+    ///
+    /// Week one of a year is the first week with at least four days in that year, with weeks
+    /// starting on `WKST`. Days before week one are in the last week of the previous year.
+    fn week_no_matches(&self, date: Date) -> bool {
+        let week_one = |year: i32| {
+            let january_1 = Date::from_calendar_date(year, Month::January, 1).ok()?;
+            let days_into_week = (january_1.weekday().number_days_from_monday() + 7
+                - self.week_start.number_days_from_monday())
+                % 7;
+            let first_week = january_1.to_julian_day() - days_into_week as i32;
+            Some(if days_into_week <= 3 {
+                first_week
+            } else {
+                first_week + 7
+            })
+        };
+
+        let day = date.to_julian_day();
+        let year = date.year();
+        let weeks = (|| {
+            let this_year = week_one(year)?;
+            if day < this_year {
+                return Some((week_one(year - 1)?, this_year));
+            }
+            let next_year = week_one(year + 1)?;
+            if day < next_year {
+                Some((this_year, next_year))
+            } else {
+                Some((next_year, week_one(year + 2)?))
+            }
+        })();
+        let (start, end) = match weeks {
+            Some(weeks) => weeks,
+            None => return false,
+        };
+
+        let week = (day - start) / 7 + 1;
+        let weeks_in_year = (end - start) / 7;
+        self.by_week_no
+            .iter()
+            .any(|&value| position_matches(value as _, week, weeks_in_year))
+    }
+
+    /// Obtain the sorted candidates within the period beginning at the provided moment.
+    fn expand(&self, period: PrimitiveDateTime, nanosecond: u32) -> Vec<PrimitiveDateTime> {
+        let date = period.date();
+        let days = match self.frequency {
+            Frequency::Yearly => util::days_in_year(date.year()),
+            Frequency::Monthly => util::days_in_year_month(date.year(), date.month()) as _,
+            Frequency::Weekly => 7,
+            _ => 1,
+        };
+
+        // Values are expanded for frequencies larger than their own unit, and otherwise limit the
+        // value of the period.
+        let select = |values: &[u8], current: u8, unit: Frequency| -> Vec<u8> {
+            if self.frequency > unit {
+                values.to_vec()
+            } else if values.is_empty() || values.contains(&current) {
+                alloc::vec![current]
+            } else {
+                Vec::new()
+            }
+        };
+        let hours = select(&self.by_hour, period.hour(), Frequency::Hourly);
+        let minutes = select(&self.by_minute, period.minute(), Frequency::Minutely);
+        let seconds = select(&self.by_second, period.second(), Frequency::Secondly);
+
+        let mut candidates = Vec::new();
+        let mut date = Some(date);
+        let mut remaining = days;
+        while remaining > 0 {
+            let current = match date {
+                Some(date) => date,
+                None => break,
+            };
+            // Skip entire months that cannot match rather than checking each of their days.
+            if !self.by_month.is_empty() && !self.by_month.contains(&current.month()) {
+                let skip =
+                    util::days_in_year_month(current.year(), current.month()) - current.day() + 1;
+                remaining = remaining.saturating_sub(skip as _);
+                date = current.checked_add(Duration::days(skip as _));
+                continue;
+            }
+            if self.day_matches(current) {
+                for &hour in &hours {
+                    for &minute in &minutes {
+                        for &second in &seconds {
+                            candidates.push(PrimitiveDateTime::new(
+                                current,
+                                Time::__from_hms_nanos_unchecked(hour, minute, second, nanosecond),
+                            ));
+                        }
+                    }
+                }
+            }
+            remaining -= 1;
+            date = current.next_day();
+        }
+
+        if self.by_set_pos.is_empty() {
+            return candidates;
+        }
+        let len = candidates.len() as i32;
+        let mut selected: Vec<_> = self
+            .by_set_pos
+            .iter()
+            .filter_map(|&position| {
+                let index = if position > 0 {
+                    position as i32 - 1
+                } else {
+                    len + position as i32
+                };
+                usize::try_from(index)
+                    .ok()
+                    .and_then(|index| candidates.get(index))
+                    .copied()
+            })
+            .collect();
+        selected.sort_unstable();
+        selected.dedup();
+        selected
+    }
+
+    /// Obtain the beginning of the period after the provided one, if it can be represented.
+    ///
+    /// For frequencies smaller than a day, periods that cannot contain any candidates because of
+    /// their day, hour, or minute are skipped.
+    fn next_period(&self, period: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        let date = period.date();
+        let interval = self.interval as i64;
+
+        let unit_seconds = match self.frequency {
+            Frequency::Yearly => {
+                let year = date.year().checked_add(self.interval.try_into().ok()?)?;
+                let date = Date::from_calendar_date(year, Month::January, 1).ok()?;
+                return Some(PrimitiveDateTime::new(date, Time::MIDNIGHT));
+            }
+            Frequency::Monthly => {
+                let month = date.year() as i64 * 12 + date.month() as i64 - 1 + interval;
+                let year = month.div_euclid(12).try_into().ok()?;
+                let month = Month::try_from(month.rem_euclid(12) as u8 + 1).ok()?;
+                let date = Date::from_calendar_date(year, month, 1).ok()?;
+                return Some(PrimitiveDateTime::new(date, Time::MIDNIGHT));
+            }
+            Frequency::Weekly => return period.checked_add(Duration::weeks(interval)),
+            Frequency::Daily => return period.checked_add(Duration::days(interval)),
+            Frequency::Hourly => 3_600,
+            Frequency::Minutely => 60,
+            Frequency::Secondly => 1,
+        };
+
+        let step = unit_seconds * interval;
+        let target = if !self.day_matches(date) {
+            PrimitiveDateTime::new(date.next_day()?, Time::MIDNIGHT)
+        } else if self.frequency < Frequency::Hourly
+            && !self.by_hour.is_empty()
+            && !self.by_hour.contains(&period.hour())
+        {
+            PrimitiveDateTime::new(
+                date,
+                Time::__from_hms_nanos_unchecked(period.hour(), 0, 0, 0),
+            )
+            .checked_add(Duration::HOUR)?
+        } else if self.frequency < Frequency::Minutely
+            && !self.by_minute.is_empty()
+            && !self.by_minute.contains(&period.minute())
+        {
+            PrimitiveDateTime::new(
+                date,
+                Time::__from_hms_nanos_unchecked(period.hour(), period.minute(), 0, 0),
+            )
+            .checked_add(Duration::MINUTE)?
+        } else {
+            return period.checked_add(Duration::seconds(step));
+        };
+
+        let remaining = (target - period).whole_seconds();
+        let steps = (remaining + step - 1) / step;
+        period.checked_add(Duration::seconds(steps.checked_mul(step)?))
+    }
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = error::InvalidRecurrenceRule;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Whether a one-based position within a sequence of `len` items matches a value, which counts
+/// from the end of the sequence if negative.
+const fn position_matches(value: i32, position: i32, len: i32) -> bool {
+    if value > 0 {
+        value == position
+    } else {
+        len + 1 + value == position
+    }
+}
+
+/// A parser for the value of an `RRULE` property.
+struct Parser<'a> {
+    /// The complete input.
+    input: &'a [u8],
+    /// The index of the next byte to be consumed.
+    index: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Whether the entire input has been consumed.
+    const fn is_done(&self) -> bool {
+        self.index == self.input.len()
+    }
+
+    /// Obtain the next byte without consuming it.
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.index).copied()
+    }
+
+    /// An error at the provided position.
+    const fn error(index: usize) -> error::InvalidRecurrenceRule {
+        error::InvalidRecurrenceRule::Malformed { index }
+    }
+
+    /// Consume the given byte.
+    fn expect(&mut self, byte: u8) -> Result<(), error::InvalidRecurrenceRule> {
+        if self.peek() == Some(byte) {
+            self.index += 1;
+            Ok(())
+        } else {
+            Err(Self::error(self.index))
+        }
+    }
+
+    /// Consume the name of a rule part.
+    fn name(&mut self) -> Result<&'a [u8], error::InvalidRecurrenceRule> {
+        let start = self.index;
+        let len = self.input[start..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_alphanumeric() || **byte == b'-')
+            .count();
+        if len == 0 {
+            return Err(Self::error(start));
+        }
+        self.index += len;
+        Ok(&self.input[start..self.index])
+    }
+
+    /// Consume a non-negative number.
+    fn number(&mut self) -> Result<u32, error::InvalidRecurrenceRule> {
+        let start = self.index;
+        let mut value: u32 = 0;
+        while let Some(digit @ b'0'..=b'9') = self.peek() {
+            value = value
+                .checked_mul(10)
+                .and_then(|value| value.checked_add((digit - b'0') as _))
+                .ok_or_else(|| Self::error(start))?;
+            self.index += 1;
+        }
+        if self.index == start {
+            return Err(Self::error(start));
+        }
+        Ok(value)
+    }
+
+    /// Consume a number in the range `min..=max`.
+    fn unsigned(&mut self, min: u8, max: u8) -> Result<u8, error::InvalidRecurrenceRule> {
+        let start = self.index;
+        match self.number()? {
+            value if (min as u32..=max as u32).contains(&value) => Ok(value as _),
+            _ => Err(Self::error(start)),
+        }
+    }
+
+    /// Consume an optionally signed number whose magnitude is in the range `1..=max`.
+    fn signed(&mut self, max: u16) -> Result<i16, error::InvalidRecurrenceRule> {
+        let start = self.index;
+        let is_negative = match self.peek() {
+            Some(b'-') => true,
+            Some(b'+') => false,
+            _ => {
+                return match self.number()? {
+                    value if (1..=max as u32).contains(&value) => Ok(value as _),
+                    _ => Err(Self::error(start)),
+                };
+            }
+        };
+        self.index += 1;
+        match self.number()? {
+            value if (1..=max as u32).contains(&value) && is_negative => Ok(-(value as i16)),
+            value if (1..=max as u32).contains(&value) => Ok(value as _),
+            _ => Err(Self::error(start)),
+        }
+    }
+
+    /// Consume a comma-separated list of at least one item.
+    fn list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, error::InvalidRecurrenceRule>,
+    ) -> Result<Vec<T>, error::InvalidRecurrenceRule> {
+        let mut items = alloc::vec![item(self)?];
+        while self.peek() == Some(b',') {
+            self.index += 1;
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Consume a frequency.
+    fn frequency(&mut self) -> Result<Frequency, error::InvalidRecurrenceRule> {
+        let start = self.index;
+        let name = self.name()?;
+        [
+            ("SECONDLY", Frequency::Secondly),
+            ("MINUTELY", Frequency::Minutely),
+            ("HOURLY", Frequency::Hourly),
+            ("DAILY", Frequency::Daily),
+            ("WEEKLY", Frequency::Weekly),
+            ("MONTHLY", Frequency::Monthly),
+            ("YEARLY", Frequency::Yearly),
+        ]
+        .iter()
+        .find(|(expected, _)| name.eq_ignore_ascii_case(expected.as_bytes()))
+        .map(|&(_, frequency)| frequency)
+        .ok_or_else(|| Self::error(start))
+    }
+
+    /// Consume a two-letter day of the week.
+    fn weekday(&mut self) -> Result<Weekday, error::InvalidRecurrenceRule> {
+        let start = self.index;
+        let name = self
+            .input
+            .get(start..start + 2)
+            .ok_or_else(|| Self::error(start))?;
+        let weekday = [
+            (b"MO", Weekday::Monday),
+            (b"TU", Weekday::Tuesday),
+            (b"WE", Weekday::Wednesday),
+            (b"TH", Weekday::Thursday),
+            (b"FR", Weekday::Friday),
+            (b"SA", Weekday::Saturday),
+            (b"SU", Weekday::Sunday),
+        ]
+        .iter()
+        .find(|(expected, _)| name.eq_ignore_ascii_case(*expected))
+        .map(|&(_, weekday)| weekday)
+        .ok_or_else(|| Self::error(start))?;
+        self.index += 2;
+        Ok(weekday)
+    }
+
+    /// Consume a `BYDAY` value, which is a day of the week with an optional ordinal.
+    fn by_day(&mut self) -> Result<ByDay, error::InvalidRecurrenceRule> {
+        let nth = match self.peek() {
+            Some(b'+' | b'-' | b'0'..=b'9') => self.signed(53)? as _,
+            _ => 0,
+        };
+        let weekday = self.weekday()?;
+        Ok(ByDay { nth, weekday })
+    }
+
+    /// Consume an `UNTIL` value, which is a date or a date and time, either local or in UTC.
+    fn until(&mut self) -> Result<Until, error::InvalidRecurrenceRule> {
+        let start = self.index;
+        let mut digits = |count: usize| {
+            let digits = self
+                .input
+                .get(self.index..self.index + count)
+                .filter(|digits| digits.iter().all(u8::is_ascii_digit))
+                .ok_or_else(|| Self::error(self.index))?;
+            self.index += count;
+            Ok(digits
+                .iter()
+                .fold(0, |value, digit| value * 10 + (digit - b'0') as u32))
+        };
+
+        let year = digits(4)?;
+        let month = digits(2)?;
+        let day = digits(2)?;
+        let date = Month::try_from(month as u8)
+            .and_then(|month| Date::from_calendar_date(year as _, month, day as _))
+            .map_err(|_| Self::error(start))?;
+
+        if self.peek() != Some(b'T') && self.peek() != Some(b't') {
+            return Ok(Until::Date(date));
+        }
+        self.index += 1;
+
+        let mut digits = |count: usize| {
+            let digits = self
+                .input
+                .get(self.index..self.index + count)
+                .filter(|digits| digits.iter().all(u8::is_ascii_digit))
+                .ok_or_else(|| Self::error(self.index))?;
+            self.index += count;
+            Ok(digits
+                .iter()
+                .fold(0, |value, digit| value * 10 + (digit - b'0')))
+        };
+        let hour = digits(2)?;
+        let minute = digits(2)?;
+        let second = digits(2)?;
+        let time = Time::from_hms(hour, minute, second).map_err(|_| Self::error(start))?;
+        let datetime = PrimitiveDateTime::new(date, time);
+
+        if matches!(self.peek(), Some(b'Z' | b'z')) {
+            self.index += 1;
+            Ok(Until::Utc(datetime.assume_utc()))
+        } else {
+            Ok(Until::Local(datetime))
+        }
+    }
+}
+
+/// A type that can be an occurrence of a [`RecurrenceRule`]: [`PrimitiveDateTime`] or
+/// [`OffsetDateTime`].
+pub trait Occurrence: sealed::Sealed {}
+impl Occurrence for PrimitiveDateTime {}
+impl Occurrence for OffsetDateTime {}
+
+/// Seal the trait to prevent downstream users from implementing it.
+mod sealed {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// Conversion to and from the local date and time.
+    pub trait Sealed: Copy + PartialEq {
+        /// The local date and time.
+        fn local(self) -> PrimitiveDateTime;
+
+        /// The UTC offset, if any.
+        fn offset(self) -> Option<UtcOffset>;
+
+        /// The same kind of value, with the provided local date and time.
+        fn with_local(self, local: PrimitiveDateTime) -> Self;
+    }
+
+    impl Sealed for PrimitiveDateTime {
+        fn local(self) -> PrimitiveDateTime {
+            self
+        }
+
+        fn offset(self) -> Option<UtcOffset> {
+            None
+        }
+
+        fn with_local(self, local: PrimitiveDateTime) -> Self {
+            local
+        }
+    }
+
+    impl Sealed for OffsetDateTime {
+        fn local(self) -> PrimitiveDateTime {
+            PrimitiveDateTime::new(self.date(), self.time())
+        }
+
+        fn offset(self) -> Option<UtcOffset> {
+            Some(self.offset())
+        }
+
+        fn with_local(self, local: PrimitiveDateTime) -> Self {
+            local.assume_offset(self.offset())
+        }
+    }
+}
+
+/// An iterator over the occurrences of a [`RecurrenceRule`], created by
+/// [`RecurrenceRule::occurrences`].
+#[derive(Debug, Clone)]
+pub struct Occurrences<T> {
+    /// The rule being expanded, with any parts taken from the start filled in.
+    rule: RecurrenceRule,
+    /// The first occurrence.
+    start: T,
+    /// Whether the start has been returned or excluded.
+    started: bool,
+    /// The beginning of the next period to be expanded, or `None` if iteration has finished.
+    period: Option<PrimitiveDateTime>,
+    /// The candidates of the current period that have not yet been considered, in reverse order.
+    pending: Vec<PrimitiveDateTime>,
+    /// The number of occurrences counted towards `COUNT`, including those that were excluded.
+    counted: u32,
+    /// The occurrences that are not returned, corresponding to the `EXDATE` property.
+    excluded: Vec<T>,
+}
+
+impl<T: Occurrence> Occurrences<T> {
+    /// Exclude the provided values from the occurrences, as the `EXDATE` property does. An
+    /// excluded occurrence still counts towards the `COUNT` of the rule.
+    ///
+    /// ```rust
+    /// # use time::recurrence::RecurrenceRule;
+    /// # use time_macros::datetime;
+    /// let rule = RecurrenceRule::parse("FREQ=DAILY;COUNT=3")?;
+    /// assert_eq!(
+    ///     rule.occurrences(datetime!(2024-01-01 9:00))
+    ///         .excluding([datetime!(2024-01-02 9:00)])
+    ///         .collect::<Vec<_>>(),
+    ///     [datetime!(2024-01-01 9:00), datetime!(2024-01-03 9:00)]
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[must_use = "this does not modify the original value"]
+    pub fn excluding(mut self, values: impl IntoIterator<Item = T>) -> Self {
+        self.excluded.extend(values);
+        self
+    }
+
+    /// Whether the local date and time is after the `UNTIL` value of the rule.
+    fn is_after_until(&self, local: PrimitiveDateTime) -> bool {
+        match self.rule.until {
+            None => false,
+            Some(Until::Date(date)) => local.date() > date,
+            Some(Until::Local(until)) => local > until,
+            Some(Until::Utc(until)) => match self.start.offset() {
+                Some(offset) => local.assume_offset(offset) > until,
+                None => local > PrimitiveDateTime::new(until.date(), until.time()),
+            },
+        }
+    }
+}
+
+impl<T: Occurrence> Iterator for Occurrences<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            if self.rule.count == Some(0) {
+                self.period = None;
+                return None;
+            }
+            self.counted = 1;
+            if !self.excluded.contains(&self.start) {
+                return Some(self.start);
+            }
+        }
+
+        loop {
+            let local = match self.pending.pop() {
+                Some(local) => local,
+                None => {
+                    let period = self.period?;
+                    self.pending = self.rule.expand(period, self.start.local().nanosecond());
+                    self.pending.reverse();
+                    self.period = self.rule.next_period(period);
+                    continue;
+                }
+            };
+
+            // The start is always the first occurrence, so earlier candidates are ignored.
+            if local <= self.start.local() {
+                continue;
+            }
+            if self.is_after_until(local)
+                || self.rule.count.map_or(false, |count| self.counted >= count)
+            {
+                self.period = None;
+                self.pending.clear();
+                return None;
+            }
+
+            self.counted = self.counted.saturating_add(1);
+            let value = self.start.with_local(local);
+            if !self.excluded.contains(&value) {
+                return Some(value);
+            }
+        }
+    }
+}
+
+impl<T: Occurrence> FusedIterator for Occurrences<T> {}
@@ -0,0 +1,506 @@
+//! Human-readable relative times, such as "3 hours ago" or "in 2 days".
+//!
+//! A relative time is described using a single [`Unit`], chosen as the largest unit that fits
+//! within the duration. The phrasing is provided by a [`Language`], which is implemented for the
+//! built-in [locales](crate::format_description::locale).
+//!
+//! Relative times can also be parsed from text typed by people, such as "tomorrow 17:00" or
+//! "3 days ago", using [`parse`]. This is lenient by design and is never used by the other parsing
+//! methods in this crate.
+
+use core::fmt;
+
+use crate::format_description::locale::{
+    Dutch, English, French, German, Italian, Japanese, Portuguese, Spanish,
+};
+use crate::{Duration, OffsetDateTime};
+
+#[cfg(feature = "parsing")]
+mod parse;
+
+#[cfg(feature = "parsing")]
+pub use self::parse::{parse, parse_with, Vocabulary};
+
+/// A unit of time used to describe a relative time.
+///
+/// Durations have no calendar, so months and years are approximated as 30 and 365 days
+/// respectively.
+#[allow(clippy::missing_docs_in_private_items)] // variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    /// 30 days.
+    Month,
+    /// 365 days.
+    Year,
+}
+
+impl Unit {
+    /// The length of the unit in nanoseconds.
+    const fn nanoseconds(self) -> u128 {
+        let seconds: u128 = match self {
+            Self::Second => 1,
+            Self::Minute => 60,
+            Self::Hour => 3_600,
+            Self::Day => 86_400,
+            Self::Week => 7 * 86_400,
+            Self::Month => 30 * 86_400,
+            Self::Year => 365 * 86_400,
+        };
+        seconds * 1_000_000_000
+    }
+
+    /// The next larger unit, if any.
+    const fn next(self) -> Option<Self> {
+        Some(match self {
+            Self::Second => Self::Minute,
+            Self::Minute => Self::Hour,
+            Self::Hour => Self::Day,
+            Self::Day => Self::Week,
+            Self::Week => Self::Month,
+            Self::Month => Self::Year,
+            Self::Year => return None,
+        })
+    }
+
+    /// The next smaller unit, if any.
+    const fn previous(self) -> Option<Self> {
+        Some(match self {
+            Self::Second => return None,
+            Self::Minute => Self::Second,
+            Self::Hour => Self::Minute,
+            Self::Day => Self::Hour,
+            Self::Week => Self::Day,
+            Self::Month => Self::Week,
+            Self::Year => Self::Month,
+        })
+    }
+}
+
+/// How the number of units is rounded when the duration is not a whole number of units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round towards zero, so that 1 hour and 50 minutes is described as 1 hour.
+    Down,
+    /// Round to the nearest whole number, with halfway values rounded away from zero.
+    Nearest,
+    /// Round away from zero, so that 1 hour and 10 minutes is described as 2 hours.
+    Up,
+}
+
+impl Rounding {
+    /// Divide the magnitude by the length of a unit, rounding as specified.
+    const fn divide(self, magnitude: u128, unit: u128) -> u128 {
+        match self {
+            Self::Down => magnitude / unit,
+            Self::Nearest => (magnitude + unit / 2) / unit,
+            Self::Up => (magnitude + unit - 1) / unit,
+        }
+    }
+}
+
+/// The phrases used to describe a relative time.
+///
+/// Each phrase is a pattern in which `{}` is replaced, for example `"{} ago"` or `"{} hours"`.
+/// This trait is implemented by the built-in locales. It may also be implemented for any other
+/// type to supply custom phrasing.
+pub trait Language {
+    /// The pattern for the given number of units, such as `"{} hour"` or `"{} hours"`. `{}` is
+    /// replaced by the number. The count is never zero.
+    fn unit(&self, unit: Unit, count: u64) -> &str;
+    /// The pattern for a time in the past, such as `"{} ago"`. `{}` is replaced by the result of
+    /// [`unit`](Self::unit).
+    fn past(&self) -> &str;
+    /// The pattern for a time in the future, such as `"in {}"`. `{}` is replaced by the result of
+    /// [`unit`](Self::unit).
+    fn future(&self) -> &str;
+    /// The phrase used for a time that is too close to the present to be described in units, such
+    /// as `"just now"`.
+    fn just_now(&self) -> &str;
+}
+
+/// Implement [`Language`] for a built-in locale. Units are listed as `[singular, plural]`, from
+/// seconds to years.
+macro_rules! declare_language {
+    ($(
+        $name:ident {
+            units: $units:expr,
+            past: $past:literal,
+            future: $future:literal,
+            just_now: $just_now:literal,
+        }
+    )*) => {$(
+        impl Language for $name {
+            fn unit(&self, unit: Unit, count: u64) -> &str {
+                const UNITS: [[&str; 2]; 7] = $units;
+                UNITS[unit as usize][(count != 1) as usize]
+            }
+
+            fn past(&self) -> &str {
+                $past
+            }
+
+            fn future(&self) -> &str {
+                $future
+            }
+
+            fn just_now(&self) -> &str {
+                $just_now
+            }
+        }
+    )*};
+}
+
+declare_language! {
+    English {
+        units: [
+            ["{} second", "{} seconds"],
+            ["{} minute", "{} minutes"],
+            ["{} hour", "{} hours"],
+            ["{} day", "{} days"],
+            ["{} week", "{} weeks"],
+            ["{} month", "{} months"],
+            ["{} year", "{} years"],
+        ],
+        past: "{} ago",
+        future: "in {}",
+        just_now: "just now",
+    }
+
+    German {
+        units: [
+            ["{} Sekunde", "{} Sekunden"],
+            ["{} Minute", "{} Minuten"],
+            ["{} Stunde", "{} Stunden"],
+            ["{} Tag", "{} Tagen"],
+            ["{} Woche", "{} Wochen"],
+            ["{} Monat", "{} Monaten"],
+            ["{} Jahr", "{} Jahren"],
+        ],
+        past: "vor {}",
+        future: "in {}",
+        just_now: "gerade eben",
+    }
+
+    French {
+        units: [
+            ["{} seconde", "{} secondes"],
+            ["{} minute", "{} minutes"],
+            ["{} heure", "{} heures"],
+            ["{} jour", "{} jours"],
+            ["{} semaine", "{} semaines"],
+            ["{} mois", "{} mois"],
+            ["{} an", "{} ans"],
+        ],
+        past: "il y a {}",
+        future: "dans {}",
+        just_now: "à l'instant",
+    }
+
+    Spanish {
+        units: [
+            ["{} segundo", "{} segundos"],
+            ["{} minuto", "{} minutos"],
+            ["{} hora", "{} horas"],
+            ["{} día", "{} días"],
+            ["{} semana", "{} semanas"],
+            ["{} mes", "{} meses"],
+            ["{} año", "{} años"],
+        ],
+        past: "hace {}",
+        future: "dentro de {}",
+        just_now: "ahora mismo",
+    }
+
+    Italian {
+        units: [
+            ["{} secondo", "{} secondi"],
+            ["{} minuto", "{} minuti"],
+            ["{} ora", "{} ore"],
+            ["{} giorno", "{} giorni"],
+            ["{} settimana", "{} settimane"],
+            ["{} mese", "{} mesi"],
+            ["{} anno", "{} anni"],
+        ],
+        past: "{} fa",
+        future: "tra {}",
+        just_now: "proprio ora",
+    }
+
+    Portuguese {
+        units: [
+            ["{} segundo", "{} segundos"],
+            ["{} minuto", "{} minutos"],
+            ["{} hora", "{} horas"],
+            ["{} dia", "{} dias"],
+            ["{} semana", "{} semanas"],
+            ["{} mês", "{} meses"],
+            ["{} ano", "{} anos"],
+        ],
+        past: "há {}",
+        future: "em {}",
+        just_now: "agora mesmo",
+    }
+
+    Dutch {
+        units: [
+            ["{} seconde", "{} seconden"],
+            ["{} minuut", "{} minuten"],
+            ["{} uur", "{} uur"],
+            ["{} dag", "{} dagen"],
+            ["{} week", "{} weken"],
+            ["{} maand", "{} maanden"],
+            ["{} jaar", "{} jaar"],
+        ],
+        past: "{} geleden",
+        future: "over {}",
+        just_now: "zojuist",
+    }
+
+    Japanese {
+        units: [
+            ["{}秒", "{}秒"],
+            ["{}分", "{}分"],
+            ["{}時間", "{}時間"],
+            ["{}日", "{}日"],
+            ["{}週間", "{}週間"],
+            ["{}か月", "{}か月"],
+            ["{}年", "{}年"],
+        ],
+        past: "{}前",
+        future: "{}後",
+        just_now: "たった今",
+    }
+}
+
+/// The configuration used to describe relative times.
+///
+/// ```rust
+/// # use time::relative::{Config, Unit};
+/// # use time::Duration;
+/// # use time_macros::datetime;
+/// assert_eq!(Config::DEFAULT.format(Duration::hours(-3)).to_string(), "3 hours ago");
+/// assert_eq!(Config::DEFAULT.format(Duration::days(2)).to_string(), "in 2 days");
+///
+/// let config = Config::DEFAULT
+///     .set_smallest_unit(Unit::Minute)
+///     .set_just_now_threshold(Duration::minutes(1));
+/// assert_eq!(
+///     config
+///         .format_between(
+///             datetime!(2023-01-01 11:59:30 UTC),
+///             datetime!(2023-01-01 12:00 UTC)
+///         )
+///         .to_string(),
+///     "just now"
+/// );
+/// ```
+#[derive(Clone, Copy)]
+pub struct Config<'a> {
+    /// The smallest unit that may be used.
+    pub(crate) smallest_unit: Unit,
+    /// The largest unit that may be used.
+    pub(crate) largest_unit: Unit,
+    /// How the number of units is rounded.
+    pub(crate) rounding: Rounding,
+    /// Durations shorter than this are described as "just now".
+    pub(crate) just_now_threshold: Duration,
+    /// The language used for the phrasing.
+    pub(crate) language: &'a (dyn Language + Sync),
+}
+
+impl fmt::Debug for Config<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Config")
+            .field("smallest_unit", &self.smallest_unit)
+            .field("largest_unit", &self.largest_unit)
+            .field("rounding", &self.rounding)
+            .field("just_now_threshold", &self.just_now_threshold)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Config<'static> {
+    /// The default configuration.
+    ///
+    /// The following is the default behavior:
+    ///
+    /// - Any unit from seconds to years may be used.
+    /// - The number of units is rounded down.
+    /// - Only durations of less than one second are described as "just now".
+    /// - The phrasing is in English.
+    ///
+    /// If you need different behavior, use the setter methods on this struct.
+    pub const DEFAULT: Self = Self {
+        smallest_unit: Unit::Second,
+        largest_unit: Unit::Year,
+        rounding: Rounding::Down,
+        just_now_threshold: Duration::ZERO,
+        language: &English,
+    };
+}
+
+impl<'a> Config<'a> {
+    /// Set the smallest unit that may be used. Durations shorter than one of this unit are
+    /// described as "just now" unless rounding results in a nonzero count.
+    ///
+    /// If this is larger than the largest unit, the largest unit is increased to match.
+    pub const fn set_smallest_unit(self, smallest_unit: Unit) -> Self {
+        Self {
+            smallest_unit,
+            largest_unit: if (self.largest_unit as u8) < smallest_unit as u8 {
+                smallest_unit
+            } else {
+                self.largest_unit
+            },
+            ..self
+        }
+    }
+
+    /// Set the largest unit that may be used. Longer durations are described using a larger count
+    /// of this unit, such as "400 days ago".
+    ///
+    /// If this is smaller than the smallest unit, the smallest unit is decreased to match.
+    pub const fn set_largest_unit(self, largest_unit: Unit) -> Self {
+        Self {
+            largest_unit,
+            smallest_unit: if (self.smallest_unit as u8) > largest_unit as u8 {
+                largest_unit
+            } else {
+                self.smallest_unit
+            },
+            ..self
+        }
+    }
+
+    /// Set how the number of units is rounded.
+    pub const fn set_rounding(self, rounding: Rounding) -> Self {
+        Self { rounding, ..self }
+    }
+
+    /// Set the threshold below which durations are described as "just now". The sign of the
+    /// duration is ignored.
+    pub const fn set_just_now_threshold(self, just_now_threshold: Duration) -> Self {
+        Self {
+            just_now_threshold,
+            ..self
+        }
+    }
+
+    /// Set the language used for the phrasing.
+    pub const fn set_language<'b>(self, language: &'b (dyn Language + Sync)) -> Config<'b> {
+        Config {
+            smallest_unit: self.smallest_unit,
+            largest_unit: self.largest_unit,
+            rounding: self.rounding,
+            just_now_threshold: self.just_now_threshold,
+            language,
+        }
+    }
+
+    /// Describe the duration relative to the present. Positive durations are in the future and
+    /// negative durations are in the past.
+    pub const fn format(self, duration: Duration) -> Relative<'a> {
+        Relative {
+            config: self,
+            duration,
+        }
+    }
+
+    /// Describe `then` relative to `now`.
+    pub fn format_between(self, then: OffsetDateTime, now: OffsetDateTime) -> Relative<'a> {
+        self.format(then - now)
+    }
+}
+
+/// A relative time that has been described using a [`Config`]. The description is obtained using
+/// the [`Display`](fmt::Display) implementation.
+#[derive(Debug, Clone, Copy)]
+pub struct Relative<'a> {
+    /// The configuration used for the description.
+    config: Config<'a>,
+    /// The duration relative to the present.
+    duration: Duration,
+}
+
+impl Relative<'_> {
+    /// Determine the unit and number of units that describe the duration, or `None` if the
+    /// duration should be described as "just now".
+    fn unit_and_count(&self) -> Option<(Unit, u64)> {
+        let Config {
+            smallest_unit,
+            largest_unit,
+            rounding,
+            just_now_threshold,
+            language: _,
+        } = self.config;
+
+        let magnitude = self.duration.whole_nanoseconds().unsigned_abs();
+        if magnitude < just_now_threshold.whole_nanoseconds().unsigned_abs() {
+            return None;
+        }
+
+        // Use the largest unit that the duration reaches.
+        let mut unit = largest_unit;
+        while unit > smallest_unit && magnitude < unit.nanoseconds() {
+            unit = match unit.previous() {
+                Some(unit) => unit,
+                None => bug!("the smallest unit has no previous unit"),
+            };
+        }
+        let mut count = rounding.divide(magnitude, unit.nanoseconds());
+
+        // Rounding may result in a whole number of the next unit, such as 60 minutes.
+        while let Some(next) = unit.next() {
+            if next > largest_unit || count * unit.nanoseconds() < next.nanoseconds() {
+                break;
+            }
+            unit = next;
+            count = rounding.divide(magnitude, unit.nanoseconds());
+        }
+
+        match count {
+            0 => None,
+            // The duration's magnitude is bounded, so the count always fits in a `u64`.
+            count => Some((unit, count as u64)),
+        }
+    }
+}
+
+impl fmt::Display for Relative<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let language = self.config.language;
+        let Some((unit, count)) = self.unit_and_count() else {
+            return f.write_str(language.just_now());
+        };
+
+        let tense = if self.duration.is_negative() {
+            language.past()
+        } else {
+            language.future()
+        };
+        write_pattern(f, tense, |f| {
+            write_pattern(f, language.unit(unit, count), |f| write!(f, "{count}"))
+        })
+    }
+}
+
+/// Write the pattern, replacing the first `{}` with the output of `write_value`.
+fn write_pattern(
+    f: &mut fmt::Formatter<'_>,
+    pattern: &str,
+    write_value: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    match pattern.split_once("{}") {
+        Some((before, after)) => {
+            f.write_str(before)?;
+            write_value(f)?;
+            f.write_str(after)
+        }
+        None => f.write_str(pattern),
+    }
+}
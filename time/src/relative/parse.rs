@@ -0,0 +1,608 @@
+//! Lenient parsing of relative times typed by people.
+
+use super::Unit;
+use crate::error::InvalidRelativeTime;
+use crate::format_description::locale::English;
+use crate::parsing::combinator::{ascii_char, exactly_n_digits, longest_match, n_to_m_digits};
+use crate::parsing::shim::IntegerParseBytes;
+use crate::parsing::ParsedItem;
+use crate::util::{days_in_year, days_in_year_month};
+use crate::{Date, Duration, Month, OffsetDateTime, Time, Weekday};
+
+/// The words and phrases accepted when parsing a relative time.
+///
+/// Each method returns the alternatives accepted in one position of the grammar described in
+/// [`parse`]. Matching ignores case, and a space within a phrase matches any amount of whitespace.
+/// This trait is implemented by [`English`]. It may also be implemented for any other type to parse
+/// another language.
+pub trait Vocabulary {
+    /// The present moment, such as `"now"`.
+    fn now(&self) -> &[&str];
+    /// The current day, such as `"today"`.
+    fn today(&self) -> &[&str];
+    /// The following day, such as `"tomorrow"`.
+    fn tomorrow(&self) -> &[&str];
+    /// The previous day, such as `"yesterday"`.
+    fn yesterday(&self) -> &[&str];
+    /// Precedes a weekday after the current day, such as `"next"`.
+    fn next(&self) -> &[&str];
+    /// Precedes a weekday before the current day, such as `"last"`.
+    fn last(&self) -> &[&str];
+    /// Precedes an offset into the future, such as `"in"`.
+    fn future(&self) -> &[&str];
+    /// Follows an offset into the past, such as `"ago"`.
+    fn past(&self) -> &[&str];
+    /// Used in place of a count of one, such as `"a"` in "in a week".
+    fn one(&self) -> &[&str];
+    /// Precedes a unit to refer to the start of the current unit, such as `"start of"`.
+    fn start_of(&self) -> &[&str];
+    /// Precedes a unit to refer to the end of the current unit, such as `"end of"`.
+    fn end_of(&self) -> &[&str];
+    /// Optionally separates a day from a time of day, such as `"at"`.
+    fn at(&self) -> &[&str];
+    /// 12:00, such as `"noon"`.
+    fn noon(&self) -> &[&str];
+    /// 00:00, such as `"midnight"`.
+    fn midnight(&self) -> &[&str];
+    /// Follows an hour before noon, such as `"am"`.
+    fn am(&self) -> &[&str];
+    /// Follows an hour after noon, such as `"pm"`.
+    fn pm(&self) -> &[&str];
+    /// The given unit, such as `"day"` or `"days"`.
+    fn unit(&self, unit: Unit) -> &[&str];
+    /// The given weekday, such as `"monday"` or `"mon"`.
+    fn weekday(&self, weekday: Weekday) -> &[&str];
+}
+
+/// Implement [`Vocabulary`] for a built-in locale. Units are listed from seconds to years and
+/// weekdays from Monday to Sunday.
+macro_rules! declare_vocabulary {
+    ($(
+        $name:ident {
+            units: $units:expr,
+            weekdays: $weekdays:expr,
+            $($method:ident: $words:expr,)*
+        }
+    )*) => {$(
+        impl Vocabulary for $name {
+            $(fn $method(&self) -> &[&str] {
+                &$words
+            })*
+
+            fn unit(&self, unit: Unit) -> &[&str] {
+                const UNITS: [&[&str]; 7] = $units;
+                UNITS[unit as usize]
+            }
+
+            fn weekday(&self, weekday: Weekday) -> &[&str] {
+                const WEEKDAYS: [&[&str]; 7] = $weekdays;
+                WEEKDAYS[weekday.number_days_from_monday() as usize]
+            }
+        }
+    )*};
+}
+
+declare_vocabulary! {
+    English {
+        units: [
+            &["second", "seconds", "sec", "secs"],
+            &["minute", "minutes", "min", "mins"],
+            &["hour", "hours", "hr", "hrs"],
+            &["day", "days"],
+            &["week", "weeks"],
+            &["month", "months"],
+            &["year", "years"],
+        ],
+        weekdays: [
+            &["monday", "mon"],
+            &["tuesday", "tue", "tues"],
+            &["wednesday", "wed"],
+            &["thursday", "thu", "thur", "thurs"],
+            &["friday", "fri"],
+            &["saturday", "sat"],
+            &["sunday", "sun"],
+        ],
+        now: ["now", "right now"],
+        today: ["today"],
+        tomorrow: ["tomorrow"],
+        yesterday: ["yesterday"],
+        next: ["next"],
+        last: ["last"],
+        future: ["in"],
+        past: ["ago"],
+        one: ["a", "an", "one"],
+        start_of: ["start of", "start of the", "beginning of", "beginning of the"],
+        end_of: ["end of", "end of the"],
+        at: ["at"],
+        noon: ["noon", "midday"],
+        midnight: ["midnight"],
+        am: ["am", "a.m."],
+        pm: ["pm", "p.m."],
+    }
+}
+
+/// All units, from smallest to largest.
+const UNITS: [Unit; 7] = [
+    Unit::Second,
+    Unit::Minute,
+    Unit::Hour,
+    Unit::Day,
+    Unit::Week,
+    Unit::Month,
+    Unit::Year,
+];
+
+/// The items that may begin an expression.
+const EXPRESSION: &[&str] = &[
+    "a relative day",
+    "a weekday",
+    "a time of day",
+    "an offset",
+    "the start or end of a unit",
+];
+/// A weekday, as required after "next" or "last".
+const WEEKDAY: &[&str] = &["a weekday"];
+/// A count, as required after "in".
+const COUNT: &[&str] = &["a number"];
+/// A unit, as required after a count or "start of".
+const UNIT: &[&str] = &["a unit"];
+/// The marker following an offset into the past.
+const PAST: &[&str] = &["a past marker"];
+/// A time of day, as required after "at".
+const TIME: &[&str] = &["a time of day"];
+/// The items that may follow a day.
+const TIME_OR_END: &[&str] = &["a time of day", "the end of input"];
+/// The items that may follow a time of day.
+const DAY_OR_END: &[&str] = &["a relative day", "a weekday", "the end of input"];
+/// The end of the input.
+const END: &[&str] = &["the end of input"];
+
+/// Parse a relative time in English, such as "tomorrow 17:00" or "3 days ago", resolving it
+/// against `reference`.
+///
+/// The following expressions are accepted, ignoring case and surrounding whitespace:
+///
+/// - `now`
+/// - a day, optionally followed by a time of day that may be preceded by `at`. A day is `today`,
+///   `tomorrow`, `yesterday`, a weekday, `next <weekday>` or `last <weekday>`.
+/// - a time of day, optionally followed by a day. A time of day is `17:00`, `17:00:30`, `5pm`,
+///   `5:30 am`, `noon` or `midnight`.
+/// - an offset, `in <count> <unit>` or `<count> <unit> ago`. The count is a number, `a` or `an`.
+/// - a boundary, `start of <unit>` or `end of <unit>`, such as `end of month`.
+///
+/// A day without a time of day is at midnight. A bare weekday is the first such day on or after
+/// the reference date, while `next` and `last` always skip the reference date. Months and years in
+/// offsets are calendar months and years, with the day clamped to the end of the month if
+/// necessary. Weeks start on Monday, and the end of a unit is its last nanosecond. The result is
+/// in the same offset as `reference`.
+///
+/// ```rust
+/// # use time::relative;
+/// # use time_macros::datetime;
+/// let reference = datetime!(2023-06-14 10:30 UTC); // a Wednesday
+/// assert_eq!(
+///     relative::parse("tomorrow 17:00", reference)?,
+///     datetime!(2023-06-15 17:00 UTC)
+/// );
+/// assert_eq!(
+///     relative::parse("next monday", reference)?,
+///     datetime!(2023-06-19 0:00 UTC)
+/// );
+/// assert_eq!(
+///     relative::parse("in 2 hours", reference)?,
+///     datetime!(2023-06-14 12:30 UTC)
+/// );
+/// assert_eq!(
+///     relative::parse("3 days ago", reference)?,
+///     datetime!(2023-06-11 10:30 UTC)
+/// );
+/// assert_eq!(
+///     relative::parse("end of month", reference)?,
+///     datetime!(2023-06-30 23:59:59.999_999_999 UTC)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+pub fn parse(
+    input: &str,
+    reference: OffsetDateTime,
+) -> Result<OffsetDateTime, InvalidRelativeTime> {
+    parse_with(input, reference, &English)
+}
+
+/// Parse a relative time using the provided vocabulary, resolving it against `reference`. The
+/// grammar is described in [`parse`].
+pub fn parse_with(
+    input: &str,
+    reference: OffsetDateTime,
+    vocabulary: &dyn Vocabulary,
+) -> Result<OffsetDateTime, InvalidRelativeTime> {
+    Parser {
+        input: input.as_bytes(),
+        reference,
+        vocabulary,
+    }
+    .expression()
+}
+
+/// The state needed while parsing a relative time.
+struct Parser<'a> {
+    /// The complete input, used to determine the index of errors.
+    input: &'a [u8],
+    /// The value that the expression is relative to.
+    reference: OffsetDateTime,
+    /// The words and phrases that are accepted.
+    vocabulary: &'a dyn Vocabulary,
+}
+
+impl<'a> Parser<'a> {
+    /// An error indicating that the remaining input did not match any of the expected items.
+    const fn unexpected(
+        &self,
+        remaining: &[u8],
+        expected: &'static [&'static str],
+    ) -> InvalidRelativeTime {
+        InvalidRelativeTime::Unexpected {
+            index: self.input.len() - remaining.len(),
+            expected,
+        }
+    }
+
+    /// An error indicating that the item beginning the remaining input is out of range.
+    const fn out_of_range(&self, remaining: &[u8]) -> InvalidRelativeTime {
+        InvalidRelativeTime::OutOfRange {
+            index: self.input.len() - remaining.len(),
+        }
+    }
+
+    /// Parse the complete input.
+    fn expression(&self) -> Result<OffsetDateTime, InvalidRelativeTime> {
+        let vocabulary = self.vocabulary;
+        let input = whitespace(self.input);
+
+        let (ParsedItem(remaining, value), expected) =
+            if let Some(remaining) = keyword(input, vocabulary.now()) {
+                (ParsedItem(remaining, self.reference), END)
+            } else if let Some(remaining) = keyword(input, vocabulary.future()) {
+                let remaining = whitespace(remaining);
+                let ParsedItem(remaining, (count, unit)) = self
+                    .offset(remaining)?
+                    .ok_or_else(|| self.unexpected(remaining, COUNT))?;
+                let value = self.shift(input, count, unit, false)?;
+                (ParsedItem(remaining, value), END)
+            } else if let Some(remaining) = keyword(input, vocabulary.start_of()) {
+                (self.boundary(whitespace(remaining), false)?, END)
+            } else if let Some(remaining) = keyword(input, vocabulary.end_of()) {
+                (self.boundary(whitespace(remaining), true)?, END)
+            } else if let Some(ParsedItem(remaining, date)) = self.day(input)? {
+                let after_day = whitespace(remaining);
+                let after_at = keyword(after_day, vocabulary.at()).map(whitespace);
+                match (self.time(after_at.unwrap_or(after_day)), after_at) {
+                    (Some(ParsedItem(remaining, time)), _) => {
+                        (ParsedItem(remaining, self.at(date, time)), END)
+                    }
+                    (None, Some(after_at)) => return Err(self.unexpected(after_at, TIME)),
+                    (None, None) => (
+                        ParsedItem(remaining, self.at(date, Time::MIDNIGHT)),
+                        TIME_OR_END,
+                    ),
+                }
+            } else if let Some(ParsedItem(remaining, time)) = self.time(input) {
+                match self.day(whitespace(remaining))? {
+                    Some(ParsedItem(remaining, date)) => {
+                        (ParsedItem(remaining, self.at(date, time)), END)
+                    }
+                    None => (
+                        ParsedItem(remaining, self.at(self.reference.date(), time)),
+                        DAY_OR_END,
+                    ),
+                }
+            } else if let Some(ParsedItem(remaining, (count, unit))) = self.offset(input)? {
+                let remaining = whitespace(remaining);
+                let remaining = keyword(remaining, vocabulary.past())
+                    .ok_or_else(|| self.unexpected(remaining, PAST))?;
+                let value = self.shift(input, count, unit, true)?;
+                (ParsedItem(remaining, value), END)
+            } else {
+                return Err(self.unexpected(input, EXPRESSION));
+            };
+
+        let remaining = whitespace(remaining);
+        if remaining.is_empty() {
+            Ok(value)
+        } else {
+            Err(self.unexpected(remaining, expected))
+        }
+    }
+
+    /// The given date and time in the offset of the reference value.
+    const fn at(&self, date: Date, time: Time) -> OffsetDateTime {
+        self.reference.replace_date(date).replace_time(time)
+    }
+
+    /// Parse a day, returning `None` if the input does not begin with one.
+    fn day(&self, input: &'a [u8]) -> Result<Option<ParsedItem<'a, Date>>, InvalidRelativeTime> {
+        let vocabulary = self.vocabulary;
+        let today = self.reference.date();
+        let weekday = |input| {
+            let input = whitespace(input);
+            self.weekday(input)
+                .ok_or_else(|| self.unexpected(input, WEEKDAY))
+        };
+
+        let (remaining, days) = if let Some(remaining) = keyword(input, vocabulary.today()) {
+            (remaining, 0)
+        } else if let Some(remaining) = keyword(input, vocabulary.tomorrow()) {
+            (remaining, 1)
+        } else if let Some(remaining) = keyword(input, vocabulary.yesterday()) {
+            (remaining, -1)
+        } else if let Some(remaining) = keyword(input, vocabulary.next()) {
+            let ParsedItem(remaining, weekday) = weekday(remaining)?;
+            (remaining, days_until_next(today.weekday(), weekday) as i64)
+        } else if let Some(remaining) = keyword(input, vocabulary.last()) {
+            let ParsedItem(remaining, weekday) = weekday(remaining)?;
+            (
+                remaining,
+                -(days_until_next(weekday, today.weekday()) as i64),
+            )
+        } else if let Some(ParsedItem(remaining, weekday)) = self.weekday(input) {
+            (remaining, days_between(today.weekday(), weekday) as i64)
+        } else {
+            return Ok(None);
+        };
+
+        match today.checked_add(Duration::days(days)) {
+            Some(date) => Ok(Some(ParsedItem(remaining, date))),
+            None => Err(self.out_of_range(input)),
+        }
+    }
+
+    /// Parse a weekday.
+    fn weekday(&self, input: &'a [u8]) -> Option<ParsedItem<'a, Weekday>> {
+        (0..7)
+            .map(|n| Weekday::Monday.nth_next(n))
+            .filter_map(|weekday| {
+                keyword(input, self.vocabulary.weekday(weekday))
+                    .map(|remaining| ParsedItem(remaining, weekday))
+            })
+            .min_by_key(|ParsedItem(remaining, _)| remaining.len())
+    }
+
+    /// Parse a time of day. A number is only considered a time of day if it is followed by minutes
+    /// or a meridiem.
+    fn time(&self, input: &'a [u8]) -> Option<ParsedItem<'a, Time>> {
+        let vocabulary = self.vocabulary;
+        if let Some(remaining) = keyword(input, vocabulary.noon()) {
+            return Some(ParsedItem(
+                remaining,
+                Time::__from_hms_nanos_unchecked(12, 0, 0, 0),
+            ));
+        }
+        if let Some(remaining) = keyword(input, vocabulary.midnight()) {
+            return Some(ParsedItem(remaining, Time::MIDNIGHT));
+        }
+
+        let two_digits = |input| {
+            ascii_char::<b':'>(input).and_then(|item| exactly_n_digits::<2, u8>(item.into_inner()))
+        };
+        let ParsedItem(mut remaining, hour) = n_to_m_digits::<1, 2, u8>(input)?;
+        let (mut minute, mut second) = (None, 0);
+        if let Some(ParsedItem(after_minute, value)) = two_digits(remaining) {
+            remaining = after_minute;
+            minute = Some(value);
+            if let Some(ParsedItem(after_second, value)) = two_digits(remaining) {
+                remaining = after_second;
+                second = value;
+            }
+        }
+
+        let meridiem = whitespace(remaining);
+        let hour = if let Some(after_meridiem) = keyword(meridiem, vocabulary.am()) {
+            remaining = after_meridiem;
+            (1..=12).contains(&hour).then_some(hour % 12)?
+        } else if let Some(after_meridiem) = keyword(meridiem, vocabulary.pm()) {
+            remaining = after_meridiem;
+            (1..=12).contains(&hour).then_some(hour % 12 + 12)?
+        } else if minute.is_some() {
+            hour
+        } else {
+            return None;
+        };
+
+        Time::from_hms(hour, minute.unwrap_or(0), second)
+            .ok()
+            .map(|time| ParsedItem(remaining, time))
+    }
+
+    /// Parse a unit.
+    fn unit(&self, input: &'a [u8]) -> Option<ParsedItem<'a, Unit>> {
+        UNITS
+            .iter()
+            .filter_map(|&unit| {
+                keyword(input, self.vocabulary.unit(unit))
+                    .map(|remaining| ParsedItem(remaining, unit))
+            })
+            .min_by_key(|ParsedItem(remaining, _)| remaining.len())
+    }
+
+    /// Parse a count followed by a unit, returning `None` if the input does not begin with a
+    /// count.
+    fn offset(
+        &self,
+        input: &'a [u8],
+    ) -> Result<Option<ParsedItem<'a, (u32, Unit)>>, InvalidRelativeTime> {
+        let (remaining, count) = if let Some(remaining) = keyword(input, self.vocabulary.one()) {
+            (remaining, 1)
+        } else {
+            let len = input
+                .iter()
+                .take_while(|byte| byte.is_ascii_digit())
+                .count();
+            if len == 0 {
+                return Ok(None);
+            }
+            let (digits, remaining) = input.split_at(len);
+            match digits.parse_bytes() {
+                Some(count) => (remaining, count),
+                None => return Err(self.out_of_range(input)),
+            }
+        };
+
+        let remaining = whitespace(remaining);
+        match self.unit(remaining) {
+            Some(ParsedItem(remaining, unit)) => Ok(Some(ParsedItem(remaining, (count, unit)))),
+            None => Err(self.unexpected(remaining, UNIT)),
+        }
+    }
+
+    /// Shift the reference value by the given number of units. `input` is the start of the
+    /// offset, which is used as the index of any error.
+    fn shift(
+        &self,
+        input: &[u8],
+        count: u32,
+        unit: Unit,
+        is_negative: bool,
+    ) -> Result<OffsetDateTime, InvalidRelativeTime> {
+        let shifted = match unit {
+            Unit::Month | Unit::Year => {
+                let months = match unit {
+                    Unit::Year => i64::from(count) * 12,
+                    _ => i64::from(count),
+                };
+                let (year, month, day) = self.reference.to_calendar_date();
+                let months = i64::from(year) * 12
+                    + i64::from(month as u8 - 1)
+                    + if is_negative { -months } else { months };
+                i32::try_from(months.div_euclid(12))
+                    .ok()
+                    .and_then(|year| {
+                        let month = Month::try_from(months.rem_euclid(12) as u8 + 1).ok()?;
+                        Date::from_calendar_date(
+                            year,
+                            month,
+                            day.min(days_in_year_month(year, month)),
+                        )
+                        .ok()
+                    })
+                    .map(|date| self.reference.replace_date(date))
+            }
+            _ => {
+                let seconds = (unit.nanoseconds() / 1_000_000_000) as i64;
+                let duration = Duration::seconds(i64::from(count) * seconds);
+                if is_negative {
+                    self.reference.checked_sub(duration)
+                } else {
+                    self.reference.checked_add(duration)
+                }
+            }
+        };
+        shifted.ok_or_else(|| self.out_of_range(input))
+    }
+
+    /// Parse a unit, returning the start or end of the unit containing the reference value.
+    fn boundary(
+        &self,
+        input: &'a [u8],
+        is_end: bool,
+    ) -> Result<ParsedItem<'a, OffsetDateTime>, InvalidRelativeTime> {
+        let ParsedItem(remaining, unit) = self
+            .unit(input)
+            .ok_or_else(|| self.unexpected(input, UNIT))?;
+
+        let date = self.reference.date();
+        let (year, ordinal) = date.to_ordinal_date();
+        let (hour, minute, second) = self.reference.to_hms();
+        let fill = if is_end { 59 } else { 0 };
+        let time = |hour, minute, second| {
+            Time::__from_hms_nanos_unchecked(
+                hour,
+                minute,
+                second,
+                if is_end { 999_999_999 } else { 0 },
+            )
+        };
+        let day_time = time(if is_end { 23 } else { 0 }, fill, fill);
+
+        let (date, time) = match unit {
+            Unit::Second => (date, time(hour, minute, second)),
+            Unit::Minute => (date, time(hour, minute, fill)),
+            Unit::Hour => (date, time(hour, fill, fill)),
+            Unit::Day => (date, day_time),
+            Unit::Week => {
+                let days_from_monday = date.weekday().number_days_from_monday() as i64;
+                let date = if is_end {
+                    date.checked_add(Duration::days(6 - days_from_monday))
+                } else {
+                    date.checked_sub(Duration::days(days_from_monday))
+                };
+                match date {
+                    Some(date) => (date, day_time),
+                    None => return Err(self.out_of_range(input)),
+                }
+            }
+            Unit::Month => {
+                let day = date.day() as u16;
+                let ordinal = if is_end {
+                    ordinal - day + days_in_year_month(year, date.month()) as u16
+                } else {
+                    ordinal - day + 1
+                };
+                (Date::__from_ordinal_date_unchecked(year, ordinal), day_time)
+            }
+            Unit::Year => {
+                let ordinal = if is_end { days_in_year(year) } else { 1 };
+                (Date::__from_ordinal_date_unchecked(year, ordinal), day_time)
+            }
+        };
+
+        Ok(ParsedItem(remaining, self.at(date, time)))
+    }
+}
+
+/// The number of days from `from` until the next `to`, which is zero if they are the same.
+const fn days_between(from: Weekday, to: Weekday) -> u8 {
+    (to.number_days_from_monday() + 7 - from.number_days_from_monday()) % 7
+}
+
+/// The number of days from `from` until the next `to`, which is seven if they are the same.
+const fn days_until_next(from: Weekday, to: Weekday) -> u8 {
+    match days_between(from, to) {
+        0 => 7,
+        days => days,
+    }
+}
+
+/// Consume any amount of leading whitespace.
+fn whitespace(input: &[u8]) -> &[u8] {
+    let len = input
+        .iter()
+        .take_while(|byte| byte.is_ascii_whitespace())
+        .count();
+    &input[len..]
+}
+
+/// Consume the longest of the provided phrases, which must be followed by a word boundary. Words
+/// within a phrase may be separated by any amount of whitespace in the input.
+fn keyword<'a>(input: &'a [u8], phrases: &[&str]) -> Option<&'a [u8]> {
+    phrases
+        .iter()
+        .filter(|phrase| !phrase.trim().is_empty())
+        .filter_map(|phrase| {
+            let mut remaining = input;
+            for (i, word) in phrase.split_whitespace().enumerate() {
+                if i != 0 {
+                    let after_whitespace = whitespace(remaining);
+                    if after_whitespace.len() == remaining.len() {
+                        return None;
+                    }
+                    remaining = after_whitespace;
+                }
+                remaining = longest_match([(word, ())], false)(remaining)?.into_inner();
+            }
+            match remaining.first() {
+                Some(byte) if byte.is_ascii_alphanumeric() => None,
+                _ => Some(remaining),
+            }
+        })
+        .min_by_key(|remaining| remaining.len())
+}
@@ -13,6 +13,8 @@ macro_rules! item {
     };
 }
 
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+pub mod http_date;
 #[cfg(any(feature = "formatting", feature = "parsing"))]
 pub mod iso8601;
 #[cfg(any(feature = "formatting", feature = "parsing"))]
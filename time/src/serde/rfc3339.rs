@@ -70,3 +70,125 @@ pub mod option {
         deserializer.deserialize_option(Visitor::<Option<Rfc3339>>(PhantomData))
     }
 }
+
+/// Declare a module that serializes using a fixed number of subsecond digits.
+macro_rules! fixed_precision {
+    ($(#[$doc:meta])* $module:ident, $digits:literal, $unit:literal) => {
+        $(#[$doc])*
+        pub mod $module {
+            #[cfg(feature = "parsing")]
+            use core::marker::PhantomData;
+
+            #[cfg(feature = "formatting")]
+            use serde::ser::Error as _;
+            #[cfg(feature = "parsing")]
+            use serde::Deserializer;
+            #[cfg(feature = "formatting")]
+            use serde::{Serialize, Serializer};
+
+            #[cfg(feature = "parsing")]
+            use super::super::Visitor;
+            #[cfg(feature = "formatting")]
+            use crate::format_description::well_known::rfc3339::{
+                Config, EncodedConfig, SubsecondDigits,
+            };
+            use crate::format_description::well_known::Rfc3339;
+            use crate::OffsetDateTime;
+
+            /// The configuration of RFC3339 used by this module.
+            #[cfg(feature = "formatting")]
+            const CONFIG: EncodedConfig = Config::DEFAULT
+                .set_subsecond_digits(SubsecondDigits::Fixed($digits))
+                .encode();
+
+            #[doc = concat!(
+                "Serialize an [`OffsetDateTime`] using the well-known RFC3339 format with ",
+                $unit,
+                " precision."
+            )]
+            #[cfg(feature = "formatting")]
+            pub fn serialize<S: Serializer>(
+                datetime: &OffsetDateTime,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                datetime
+                    .format(&Rfc3339::<CONFIG>::FORMAT)
+                    .map_err(S::Error::custom)?
+                    .serialize(serializer)
+            }
+
+            /// Deserialize an [`OffsetDateTime`] from its RFC3339 representation. Any number of
+            /// subsecond digits is accepted.
+            #[cfg(feature = "parsing")]
+            pub fn deserialize<'a, D: Deserializer<'a>>(
+                deserializer: D,
+            ) -> Result<OffsetDateTime, D::Error> {
+                deserializer.deserialize_str(Visitor::<Rfc3339>(PhantomData))
+            }
+
+            #[doc = concat!(
+                "Use the well-known [RFC3339 format] with ",
+                $unit,
+                " precision when serializing and deserializing an [`Option<OffsetDateTime>`]."
+            )]
+            ///
+            /// Use this module in combination with serde's [`#[with]`][with] attribute.
+            ///
+            /// [RFC3339 format]: https://tools.ietf.org/html/rfc3339#section-5.6
+            /// [with]: https://serde.rs/field-attrs.html#with
+            pub mod option {
+                #[allow(clippy::wildcard_imports)]
+                use super::*;
+
+                #[doc = concat!(
+                    "Serialize an [`Option<OffsetDateTime>`] using the well-known RFC3339 format ",
+                    "with ",
+                    $unit,
+                    " precision."
+                )]
+                #[cfg(feature = "formatting")]
+                pub fn serialize<S: Serializer>(
+                    option: &Option<OffsetDateTime>,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    option
+                        .map(|odt| odt.format(&Rfc3339::<CONFIG>::FORMAT))
+                        .transpose()
+                        .map_err(S::Error::custom)?
+                        .serialize(serializer)
+                }
+
+                /// Deserialize an [`Option<OffsetDateTime>`] from its RFC3339 representation. Any
+                /// number of subsecond digits is accepted.
+                #[cfg(feature = "parsing")]
+                pub fn deserialize<'a, D: Deserializer<'a>>(
+                    deserializer: D,
+                ) -> Result<Option<OffsetDateTime>, D::Error> {
+                    deserializer.deserialize_option(Visitor::<Option<Rfc3339>>(PhantomData))
+                }
+            }
+        }
+    };
+}
+
+fixed_precision! {
+    /// Use the well-known [RFC3339 format] with exactly three subsecond digits when serializing
+    /// and deserializing an [`OffsetDateTime`]. A UTC offset of zero is serialized as `Z`.
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    ///
+    /// [RFC3339 format]: https://tools.ietf.org/html/rfc3339#section-5.6
+    /// [with]: https://serde.rs/field-attrs.html#with
+    millis, 3, "millisecond"
+}
+
+fixed_precision! {
+    /// Use the well-known [RFC3339 format] with exactly six subsecond digits when serializing and
+    /// deserializing an [`OffsetDateTime`]. A UTC offset of zero is serialized as `Z`.
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    ///
+    /// [RFC3339 format]: https://tools.ietf.org/html/rfc3339#section-5.6
+    /// [with]: https://serde.rs/field-attrs.html#with
+    micros, 6, "microsecond"
+}
@@ -308,6 +308,7 @@ macro_rules! well_known {
 }
 
 well_known!("an", "RFC2822", Rfc2822);
+well_known!("an", "HTTP-date", HttpDate);
 well_known!("an", "RFC3339", Rfc3339);
 well_known!(
     "an",
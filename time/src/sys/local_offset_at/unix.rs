@@ -1,10 +1,16 @@
 //! Get the system's UTC offset on Unix.
 
 use core::mem::MaybeUninit;
+use std::sync::Mutex;
 
 use crate::util::local_offset::{self, Soundness};
+use crate::zone::{TimeZone, Tzif};
 use crate::{OffsetDateTime, UtcOffset};
 
+/// The system's time zone, loaded on first use. The inner value is `None` if it could not be
+/// loaded.
+static LOCAL_ZONE: Mutex<Option<Option<Tzif>>> = Mutex::new(None);
+
 /// Whether the operating system has a thread-safe environment. This allows bypassing the check for
 /// if the process is multi-threaded.
 // This is the same value as `cfg!(target_os = "x")`.
@@ -129,8 +135,28 @@ fn tm_to_offset(unix_timestamp: i64, tm: libc::tm) -> Option<UtcOffset> {
     UtcOffset::from_whole_seconds(diff_secs).ok()
 }
 
+/// Obtain the system's UTC offset by reading the time zone database directly.
+///
+/// This is sound regardless of the number of threads, as the environment is only accessed via the
+/// standard library. `TZ` is only read the first time this is called.
+fn local_offset_from_tzif(datetime: OffsetDateTime) -> Option<UtcOffset> {
+    let mut zone = match LOCAL_ZONE.lock() {
+        Ok(zone) => zone,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    zone.get_or_insert_with(|| Tzif::local().ok())
+        .as_ref()
+        .map(|zone| zone.offset_at_utc(datetime))
+}
+
 /// Obtain the system's UTC offset.
 pub(super) fn local_offset_at(datetime: OffsetDateTime) -> Option<UtcOffset> {
+    if let Some(offset) = local_offset_from_tzif(datetime) {
+        return Some(offset);
+    }
+
+    // The time zone database could not be read, so fall back to the C library.
+    //
     // Continue to obtaining the UTC offset if and only if the call is sound or the user has
     // explicitly opted out of soundness.
     //
@@ -10,7 +10,7 @@ use crate::convert::*;
 #[cfg(feature = "formatting")]
 use crate::formatting::Formattable;
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{remaining_str, Parsable};
 use crate::util::DateAdjustment;
 use crate::{error, Duration};
 
@@ -602,7 +602,7 @@ impl Time {
         output: &mut impl io::Write,
         format: &(impl Formattable + ?Sized),
     ) -> Result<usize, crate::error::Format> {
-        format.format_into(output, None, Some(self), None)
+        format.format_into(output, None, Some(self), None, None, None)
     }
 
     /// Format the `Time` using the provided [format description](crate::format_description).
@@ -618,7 +618,7 @@ impl Time {
         self,
         format: &(impl Formattable + ?Sized),
     ) -> Result<String, crate::error::Format> {
-        format.format(None, Some(self), None)
+        format.format(None, Some(self), None, None, None)
     }
 }
 
@@ -638,8 +638,49 @@ impl Time {
         input: &str,
         description: &(impl Parsable + ?Sized),
     ) -> Result<Self, error::Parse> {
+        Self::parse_located(input, description).map_err(error::Located::into_inner)
+    }
+
+    /// Parse a `Time` from the input using the provided [format
+    /// description](crate::format_description). Unlike [`Time::parse`], the error records the
+    /// location in the input at which parsing failed.
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// # use time_macros::format_description;
+    /// let format = format_description!("[hour]:[minute]:[second]");
+    /// let err = Time::parse_located("12:00-00", &format).unwrap_err();
+    /// assert_eq!(err.offset(), Some(5));
+    /// ```
+    pub fn parse_located(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<Self, error::Located<error::Parse>> {
         description.parse_time(input.as_bytes())
     }
+
+    /// Parse a `Time` from the start of the input using the provided [format
+    /// description](crate::format_description). Rather than any trailing characters being an
+    /// error, they are returned alongside the parsed value. If parsing fails, the error records the
+    /// location in the input at which it did so.
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// # use time_macros::{format_description, time};
+    /// let format = format_description!("[hour]:[minute]:[second]");
+    /// assert_eq!(
+    ///     Time::parse_prefix("12:00:00 PM", &format)?,
+    ///     (time!(12:00), " PM")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a str), error::Located<error::Parse>> {
+        let (parsed, remaining) = description.parse_prefix(input.as_bytes())?;
+        Ok((parsed.try_into()?, remaining_str(input, remaining)?))
+    }
 }
 
 impl fmt::Display for Time {
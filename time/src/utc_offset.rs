@@ -10,7 +10,7 @@ use crate::error;
 #[cfg(feature = "formatting")]
 use crate::formatting::Formattable;
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{remaining_str, Parsable};
 #[cfg(feature = "local-offset")]
 use crate::sys::local_offset_at;
 #[cfg(feature = "local-offset")]
@@ -289,7 +289,7 @@ impl UtcOffset {
         output: &mut impl io::Write,
         format: &(impl Formattable + ?Sized),
     ) -> Result<usize, error::Format> {
-        format.format_into(output, None, None, Some(self))
+        format.format_into(output, None, None, Some(self), None, None)
     }
 
     /// Format the `UtcOffset` using the provided [format description](crate::format_description).
@@ -302,7 +302,7 @@ impl UtcOffset {
     /// # Ok::<_, time::Error>(())
     /// ```
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
-        format.format(None, None, Some(self))
+        format.format(None, None, Some(self), None, None)
     }
 }
 
@@ -322,8 +322,49 @@ impl UtcOffset {
         input: &str,
         description: &(impl Parsable + ?Sized),
     ) -> Result<Self, error::Parse> {
+        Self::parse_located(input, description).map_err(error::Located::into_inner)
+    }
+
+    /// Parse a `UtcOffset` from the input using the provided [format
+    /// description](crate::format_description). Unlike [`UtcOffset::parse`], the error records the
+    /// location in the input at which parsing failed.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// # use time_macros::format_description;
+    /// let format = format_description!("[offset_hour]:[offset_minute]");
+    /// let err = UtcOffset::parse_located("-03-42", &format).unwrap_err();
+    /// assert_eq!(err.offset(), Some(3));
+    /// ```
+    pub fn parse_located(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<Self, error::Located<error::Parse>> {
         description.parse_offset(input.as_bytes())
     }
+
+    /// Parse a `UtcOffset` from the start of the input using the provided [format
+    /// description](crate::format_description). Rather than any trailing characters being an
+    /// error, they are returned alongside the parsed value. If parsing fails, the error records the
+    /// location in the input at which it did so.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// # use time_macros::{format_description, offset};
+    /// let format = format_description!("[offset_hour]:[offset_minute]");
+    /// assert_eq!(
+    ///     UtcOffset::parse_prefix("-03:42 America/Santiago", &format)?,
+    ///     (offset!(-3:42), " America/Santiago")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a str), error::Located<error::Parse>> {
+        let (parsed, remaining) = description.parse_prefix(input.as_bytes())?;
+        Ok((parsed.try_into()?, remaining_str(input, remaining)?))
+    }
 }
 
 impl fmt::Display for UtcOffset {
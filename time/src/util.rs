@@ -67,7 +67,9 @@ pub mod local_offset {
     /// The first two conditions are automatically checked by `time`, such that you do not need to
     /// declare your code unsound. Currently, the only known operating systems that does _not_
     /// provide a thread-safe environment are some Unix-like OS's. All other operating systems
-    /// should succeed when attempting to obtain the local UTC offset.
+    /// should succeed when attempting to obtain the local UTC offset. On Unix-like OS's, the time
+    /// zone database is read directly whenever possible, which does not involve the C library and
+    /// is sound regardless of this setting.
     ///
     /// Note that you must not only verify this safety condition for your code, but for **all** code
     /// that will be included in the final binary. Notably, it applies to both direct and transitive
@@ -0,0 +1,153 @@
+//! The result of mapping a local date-time to an instant.
+
+use crate::{error, OffsetDateTime};
+
+/// The result of mapping a local date-time to an instant in a [`TimeZone`](super::TimeZone).
+///
+/// When `T` is [`OffsetDateTime`], the values in the `Ambiguous` and `Gap` variants share the
+/// local date-time that was looked up, each with one of the candidate offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocalResult<T> {
+    /// The local date-time maps to exactly one instant.
+    Single(T),
+    /// The local date-time occurs twice, typically because clocks were turned back. The values
+    /// are ordered such that the first is the earlier instant.
+    Ambiguous(T, T),
+    /// The local date-time does not exist, typically because clocks were turned forward. The
+    /// values are the result of using the offset in effect before and after the gap,
+    /// respectively.
+    Gap(T, T),
+}
+
+impl<T> LocalResult<T> {
+    /// Obtain the value if and only if the local date-time maps to exactly one instant.
+    ///
+    /// ```rust
+    /// # use time::zone::LocalResult;
+    /// assert_eq!(LocalResult::Single(1).single(), Some(1));
+    /// assert_eq!(LocalResult::Ambiguous(1, 2).single(), None);
+    /// assert_eq!(LocalResult::Gap(1, 2).single(), None);
+    /// ```
+    pub fn single(self) -> Option<T> {
+        match self {
+            Self::Single(value) => Some(value),
+            Self::Ambiguous(..) | Self::Gap(..) => None,
+        }
+    }
+
+    /// Whether the local date-time occurs more than once.
+    ///
+    /// ```rust
+    /// # use time::zone::LocalResult;
+    /// assert!(LocalResult::Ambiguous(1, 2).is_ambiguous());
+    /// assert!(!LocalResult::Single(1).is_ambiguous());
+    /// ```
+    pub const fn is_ambiguous(&self) -> bool {
+        matches!(self, Self::Ambiguous(..))
+    }
+
+    /// Whether the local date-time does not exist.
+    ///
+    /// ```rust
+    /// # use time::zone::LocalResult;
+    /// assert!(LocalResult::Gap(1, 2).is_gap());
+    /// assert!(!LocalResult::Single(1).is_gap());
+    /// ```
+    pub const fn is_gap(&self) -> bool {
+        matches!(self, Self::Gap(..))
+    }
+
+    /// Apply a function to every contained value, preserving the variant.
+    ///
+    /// ```rust
+    /// # use time::zone::LocalResult;
+    /// assert_eq!(
+    ///     LocalResult::Ambiguous(1, 2).map(|v| v * 10),
+    ///     LocalResult::Ambiguous(10, 20)
+    /// );
+    /// ```
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> LocalResult<U> {
+        match self {
+            Self::Single(value) => LocalResult::Single(f(value)),
+            Self::Ambiguous(earlier, later) => LocalResult::Ambiguous(f(earlier), f(later)),
+            Self::Gap(before, after) => LocalResult::Gap(f(before), f(after)),
+        }
+    }
+}
+
+impl LocalResult<OffsetDateTime> {
+    /// Obtain a single [`OffsetDateTime`] using the provided policy.
+    ///
+    /// When resolving a gap, the returned value always has the offset that is actually in effect
+    /// at the resulting instant.
+    ///
+    /// ```rust
+    /// # use time::zone::{Disambiguation, LocalResult};
+    /// # use time_macros::datetime;
+    /// // 02:30 does not exist on this day in New York.
+    /// let gap = LocalResult::Gap(
+    ///     datetime!(2024-03-10 2:30 -5),
+    ///     datetime!(2024-03-10 2:30 -4),
+    /// );
+    /// assert_eq!(gap.resolve(Disambiguation::Earliest)?, datetime!(2024-03-10 1:30 -5));
+    /// assert_eq!(gap.resolve(Disambiguation::ShiftForward)?, datetime!(2024-03-10 3:30 -4));
+    /// assert!(gap.resolve(Disambiguation::Reject).is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method panics if resolving a gap would result in a date-time outside the supported
+    /// range.
+    pub fn resolve(
+        self,
+        disambiguation: Disambiguation,
+    ) -> Result<OffsetDateTime, error::UnresolvedLocalTime> {
+        match (self, disambiguation) {
+            (Self::Single(value), _) => Ok(value),
+            (Self::Ambiguous(..), Disambiguation::Reject) => {
+                Err(error::UnresolvedLocalTime::Ambiguous)
+            }
+            (Self::Gap(..), Disambiguation::Reject) => Err(error::UnresolvedLocalTime::Nonexistent),
+            (
+                Self::Ambiguous(earlier, _),
+                Disambiguation::Earliest | Disambiguation::ShiftForward,
+            ) => Ok(earlier),
+            (Self::Ambiguous(_, later), Disambiguation::Latest) => Ok(later),
+            (Self::Gap(before, after), _) => {
+                // Each candidate is expressed in the offset that is actually in effect at its
+                // instant, which is the offset from the other side of the gap.
+                let (earlier, later) = if after < before {
+                    (
+                        after.to_offset(before.offset()),
+                        before.to_offset(after.offset()),
+                    )
+                } else {
+                    (
+                        before.to_offset(after.offset()),
+                        after.to_offset(before.offset()),
+                    )
+                };
+                match disambiguation {
+                    Disambiguation::Earliest => Ok(earlier),
+                    Disambiguation::Latest | Disambiguation::ShiftForward => Ok(later),
+                    Disambiguation::Reject => bug!("rejection is handled above"),
+                }
+            }
+        }
+    }
+}
+
+/// A policy for obtaining a single instant from a [`LocalResult`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Disambiguation {
+    /// Use the earlier of the two candidate instants.
+    Earliest,
+    /// Use the later of the two candidate instants.
+    Latest,
+    /// Move a nonexistent local date-time forward by the length of the gap. Ambiguous local
+    /// date-times resolve to the earlier instant.
+    ShiftForward,
+    /// Return an error unless the local date-time maps to exactly one instant.
+    Reject,
+}
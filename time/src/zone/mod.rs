@@ -0,0 +1,137 @@
+//! Time zone rules and the mapping of local date-times to instants.
+//!
+//! A [`TimeZone`] is anything that is able to provide the [`UtcOffset`] in effect at a given
+//! instant. Mapping in the other direction, from a [`PrimitiveDateTime`] to an instant, is not
+//! always possible: a local date-time may occur twice when clocks are turned back, or not at all
+//! when they are turned forward. The [`LocalResult`] type expresses all three cases, and a
+//! [`Disambiguation`] policy can be used to obtain a single [`OffsetDateTime`] from it.
+
+mod local_result;
+mod posix_tz;
+mod rules;
+#[cfg(feature = "alloc")]
+mod tzif;
+
+pub use local_result::{Disambiguation, LocalResult};
+pub use posix_tz::PosixTz;
+pub use rules::{LocalTimeType, Transition, TransitionsBetween, ZoneRules, ZoneTransition};
+#[cfg(feature = "alloc")]
+pub use tzif::Tzif;
+
+use crate::{Duration, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+/// A set of rules that determine the [`UtcOffset`] in effect at any given instant.
+///
+/// Only [`offset_at_utc`](TimeZone::offset_at_utc) must be implemented. The default
+/// implementation of [`offset_at_local`](TimeZone::offset_at_local) assumes that no two offset
+/// transitions are less than a day apart, which is the case for all real-world time zones.
+pub trait TimeZone {
+    /// Obtain the UTC offset in effect at the provided instant.
+    ///
+    /// ```rust
+    /// # use time::zone::TimeZone;
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(offset!(+1).offset_at_utc(datetime!(2000-01-01 0:00 UTC)), offset!(+1));
+    /// ```
+    fn offset_at_utc(&self, datetime: OffsetDateTime) -> UtcOffset;
+
+    /// Obtain the UTC offset(s) that the provided local date-time may have in this time zone.
+    ///
+    /// ```rust
+    /// # use time::zone::{LocalResult, TimeZone};
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(
+    ///     offset!(+1).offset_at_local(datetime!(2000-01-01 0:00)),
+    ///     LocalResult::Single(offset!(+1)),
+    /// );
+    /// ```
+    fn offset_at_local(&self, datetime: PrimitiveDateTime) -> LocalResult<UtcOffset> {
+        let assumed_utc = datetime.assume_utc();
+        let before = self.offset_at_utc(
+            assumed_utc
+                .checked_sub(Duration::DAY)
+                .unwrap_or(assumed_utc),
+        );
+        let after = self.offset_at_utc(
+            assumed_utc
+                .checked_add(Duration::DAY)
+                .unwrap_or(assumed_utc),
+        );
+
+        let is_valid = |offset| self.offset_at_utc(datetime.assume_offset(offset)) == offset;
+
+        match (is_valid(before), is_valid(after)) {
+            (true, true) if before == after => LocalResult::Single(before),
+            // A larger offset means an earlier instant for the same local date-time.
+            (true, true) if before > after => LocalResult::Ambiguous(before, after),
+            (true, true) => LocalResult::Ambiguous(after, before),
+            (true, false) => LocalResult::Single(before),
+            (false, true) => LocalResult::Single(after),
+            (false, false) => LocalResult::Gap(before, after),
+        }
+    }
+
+    /// Obtain the identifier of the time zone, such as `Europe/Paris`, if it is known.
+    ///
+    /// This is used by the `[zone_name]` component when formatting. The default implementation
+    /// returns `None`.
+    ///
+    /// ```rust
+    /// # use time::zone::TimeZone;
+    /// # use time_macros::offset;
+    /// assert_eq!(offset!(+1).name(), None);
+    /// ```
+    fn name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Obtain the [`LocalTimeType`] in effect at the provided instant, if the time zone has that
+    /// information.
+    ///
+    /// This is used by the `[zone_abbr]` component when formatting. The default implementation
+    /// returns `None`.
+    ///
+    /// ```rust
+    /// # use time::zone::{LocalTimeType, TimeZone, ZoneRules};
+    /// # use time_macros::{datetime, offset};
+    /// let cet = LocalTimeType::new(offset!(+1), false, "CET")?;
+    /// assert_eq!(
+    ///     ZoneRules::fixed(cet).local_time_type_at_utc(datetime!(2000-01-01 0:00 UTC)),
+    ///     Some(cet),
+    /// );
+    /// assert_eq!(offset!(+1).local_time_type_at_utc(datetime!(2000-01-01 0:00 UTC)), None);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    fn local_time_type_at_utc(&self, datetime: OffsetDateTime) -> Option<LocalTimeType> {
+        let _ = datetime;
+        None
+    }
+}
+
+impl TimeZone for UtcOffset {
+    fn offset_at_utc(&self, _: OffsetDateTime) -> UtcOffset {
+        *self
+    }
+
+    fn offset_at_local(&self, _: PrimitiveDateTime) -> LocalResult<UtcOffset> {
+        LocalResult::Single(*self)
+    }
+}
+
+impl<T: TimeZone + ?Sized> TimeZone for &T {
+    fn offset_at_utc(&self, datetime: OffsetDateTime) -> UtcOffset {
+        T::offset_at_utc(self, datetime)
+    }
+
+    fn offset_at_local(&self, datetime: PrimitiveDateTime) -> LocalResult<UtcOffset> {
+        T::offset_at_local(self, datetime)
+    }
+
+    fn name(&self) -> Option<&str> {
+        T::name(self)
+    }
+
+    fn local_time_type_at_utc(&self, datetime: OffsetDateTime) -> Option<LocalTimeType> {
+        T::local_time_type_at_utc(self, datetime)
+    }
+}
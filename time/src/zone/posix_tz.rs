@@ -0,0 +1,440 @@
+//! POSIX TZ strings, which describe rules that repeat every year.
+
+use super::{LocalTimeType, Transition};
+use crate::convert::*;
+use crate::{error, util, Date, Month, OffsetDateTime, UtcOffset};
+
+/// The day of the year on which a daylight saving time transition occurs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum RuleDay {
+    /// `Jn`: the one-based day of the year, where February 29 is never counted.
+    JulianWithoutLeap(u16),
+    /// `n`: the zero-based day of the year, where February 29 is counted in leap years.
+    JulianWithLeap(u16),
+    /// `Mm.w.d`: day `d` (0 is Sunday) of week `w` (5 is the last) of month `m`.
+    MonthWeekDay {
+        /// The month of the year.
+        month: Month,
+        /// The week of the month, in the range `1..=5`.
+        week: u8,
+        /// The number of days from Sunday, in the range `0..=6`.
+        weekday: u8,
+    },
+}
+
+impl RuleDay {
+    /// Obtain the date the rule refers to in the given year.
+    fn date(self, year: i32) -> Option<Date> {
+        match self {
+            Self::JulianWithoutLeap(day) if util::is_leap_year(year) && day >= 60 => {
+                Date::from_ordinal_date(year, day + 1).ok()
+            }
+            Self::JulianWithoutLeap(day) => Date::from_ordinal_date(year, day).ok(),
+            Self::JulianWithLeap(day) => Date::from_ordinal_date(year, day + 1).ok(),
+            Self::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = Date::from_calendar_date(year, month, 1).ok()?;
+                let first_weekday = first.weekday().number_days_from_sunday();
+                let mut day = 1 + (weekday + 7 - first_weekday) % 7 + (week - 1) * 7;
+                while day > util::days_in_year_month(year, month) {
+                    day -= 7;
+                }
+                Date::from_calendar_date(year, month, day).ok()
+            }
+        }
+    }
+}
+
+/// The daylight saving time portion of a POSIX TZ string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct DstRule {
+    /// The local time type in effect during daylight saving time.
+    dst: LocalTimeType,
+    /// The day daylight saving time starts.
+    start: RuleDay,
+    /// The local time daylight saving time starts, in seconds after midnight in standard time.
+    start_time: i32,
+    /// The day daylight saving time ends.
+    end: RuleDay,
+    /// The local time daylight saving time ends, in seconds after midnight in daylight saving
+    /// time.
+    end_time: i32,
+}
+
+/// A rule that applies to every year, as described by a POSIX TZ string such as
+/// `CET-1CEST,M3.5.0,M10.5.0/3`.
+///
+/// TZif files use such a rule for instants after their last explicit transition.
+///
+/// ```rust
+/// # use time::zone::{PosixTz, TimeZone};
+/// # use time_macros::{datetime, offset};
+/// let paris = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3")?;
+/// assert_eq!(paris.offset_at_utc(datetime!(2050-01-01 0:00 UTC)), offset!(+1));
+/// assert_eq!(paris.offset_at_utc(datetime!(2050-07-01 0:00 UTC)), offset!(+2));
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PosixTz {
+    /// The local time type in effect outside of daylight saving time.
+    std: LocalTimeType,
+    /// When daylight saving time is in effect, if ever.
+    dst: Option<DstRule>,
+}
+
+impl PosixTz {
+    /// Parse a POSIX TZ string, including the extensions described in RFC 8536.
+    ///
+    /// When a daylight saving time name is present without a rule, the rule used in the United
+    /// States since 2007 is assumed.
+    ///
+    /// ```rust
+    /// # use time::zone::PosixTz;
+    /// assert!(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0").is_ok());
+    /// assert!(PosixTz::parse("<+0530>-5:30").is_ok());
+    /// assert!(PosixTz::parse("EST").is_err());
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, error::InvalidTimeZone> {
+        let mut parser = Parser {
+            input: s.as_bytes(),
+            index: 0,
+        };
+
+        let std_name = parser.name()?;
+        let std_offset = parser.offset(24)?;
+        let std = parser.local_time_type(std_offset, false, std_name)?;
+
+        if parser.is_done() {
+            return Ok(Self { std, dst: None });
+        }
+
+        let dst_name = parser.name()?;
+        let dst_offset = if parser.is_done() || parser.peek() == Some(b',') {
+            std_offset - Second.per(Hour) as i32
+        } else {
+            parser.offset(24)?
+        };
+        let dst = parser.local_time_type(dst_offset, true, dst_name)?;
+
+        let (start, start_time, end, end_time) = if parser.is_done() {
+            (
+                RuleDay::MonthWeekDay {
+                    month: Month::March,
+                    week: 2,
+                    weekday: 0,
+                },
+                2 * Second.per(Hour) as i32,
+                RuleDay::MonthWeekDay {
+                    month: Month::November,
+                    week: 1,
+                    weekday: 0,
+                },
+                2 * Second.per(Hour) as i32,
+            )
+        } else {
+            parser.expect(b',')?;
+            let (start, start_time) = parser.rule()?;
+            parser.expect(b',')?;
+            let (end, end_time) = parser.rule()?;
+            (start, start_time, end, end_time)
+        };
+
+        if !parser.is_done() {
+            return Err(parser.error());
+        }
+
+        Ok(Self {
+            std,
+            dst: Some(DstRule {
+                dst,
+                start,
+                start_time,
+                end,
+                end_time,
+            }),
+        })
+    }
+
+    /// Obtain the local time type in effect outside of daylight saving time.
+    ///
+    /// ```rust
+    /// # use time::zone::PosixTz;
+    /// assert_eq!(PosixTz::parse("EST5EDT")?.standard().abbreviation(), "EST");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn standard(self) -> LocalTimeType {
+        self.std
+    }
+
+    /// Obtain the local time type in effect during daylight saving time, if it is ever observed.
+    ///
+    /// ```rust
+    /// # use time::zone::PosixTz;
+    /// assert_eq!(
+    ///     PosixTz::parse("EST5EDT")?.daylight().map(|ty| ty.is_dst()),
+    ///     Some(true),
+    /// );
+    /// assert_eq!(PosixTz::parse("<+0530>-5:30")?.daylight(), None);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn daylight(self) -> Option<LocalTimeType> {
+        match self.dst {
+            Some(rule) => Some(rule.dst),
+            None => None,
+        }
+    }
+
+    /// Obtain the local time type in effect at the provided instant.
+    ///
+    /// ```rust
+    /// # use time::zone::PosixTz;
+    /// # use time_macros::datetime;
+    /// let new_york = PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?;
+    /// assert_eq!(
+    ///     new_york.local_time_type_at(datetime!(2050-07-01 0:00 UTC)).abbreviation(),
+    ///     "EDT",
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn local_time_type_at(self, datetime: OffsetDateTime) -> LocalTimeType {
+        let timestamp = datetime.unix_timestamp();
+        // Near the limits of the supported range, the instant may not be representable in UTC. The
+        // local year is then adjacent to the UTC year, which is covered by the neighboring years.
+        let year = datetime
+            .checked_to_offset(UtcOffset::UTC)
+            .map_or_else(|| datetime.year(), OffsetDateTime::year);
+
+        // Rules may place a transition on the other side of a year boundary, so the neighboring
+        // years are considered as well.
+        let mut local_time_type = self.std;
+        let mut latest = i64::MIN;
+        for year in [year - 1, year, year + 1] {
+            for transition in self.transitions_in_year(year).into_iter().flatten() {
+                if transition.unix_timestamp() <= timestamp && transition.unix_timestamp() >= latest
+                {
+                    latest = transition.unix_timestamp();
+                    local_time_type = transition.local_time_type();
+                }
+            }
+        }
+        local_time_type
+    }
+
+    /// Obtain the two transitions that occur in the given year, sorted by their instant. `None` is
+    /// returned if daylight saving time is not observed or if the year is out of range.
+    pub(crate) fn transitions_in_year(self, year: i32) -> Option<[Transition; 2]> {
+        let rule = self.dst?;
+
+        let start = unix_timestamp(rule.start.date(year)?, rule.start_time, self.std.offset());
+        let end = unix_timestamp(rule.end.date(year)?, rule.end_time, rule.dst.offset());
+
+        let start = Transition::new(start, rule.dst);
+        let end = Transition::new(end, self.std);
+        if start.unix_timestamp() <= end.unix_timestamp() {
+            Some([start, end])
+        } else {
+            Some([end, start])
+        }
+    }
+}
+
+impl super::TimeZone for PosixTz {
+    fn offset_at_utc(&self, datetime: OffsetDateTime) -> UtcOffset {
+        self.local_time_type_at(datetime).offset()
+    }
+
+    fn local_time_type_at_utc(&self, datetime: OffsetDateTime) -> Option<LocalTimeType> {
+        Some(self.local_time_type_at(datetime))
+    }
+}
+
+/// Obtain the Unix timestamp of the given number of seconds after local midnight on the date.
+const fn unix_timestamp(date: Date, seconds: i32, offset: UtcOffset) -> i64 {
+    date.midnight().assume_utc().unix_timestamp() + seconds as i64 - offset.whole_seconds() as i64
+}
+
+/// A parser for POSIX TZ strings.
+struct Parser<'a> {
+    /// The complete input.
+    input: &'a [u8],
+    /// The index of the next byte to be consumed.
+    index: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Whether the entire input has been consumed.
+    const fn is_done(&self) -> bool {
+        self.index == self.input.len()
+    }
+
+    /// Obtain the next byte without consuming it.
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.index).copied()
+    }
+
+    /// An error at the current position.
+    const fn error(&self) -> error::InvalidTimeZone {
+        error::InvalidTimeZone::PosixTz { index: self.index }
+    }
+
+    /// Consume the given byte.
+    fn expect(&mut self, byte: u8) -> Result<(), error::InvalidTimeZone> {
+        if self.peek() == Some(byte) {
+            self.index += 1;
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Consume a time zone name, which is either alphabetic or enclosed in angle brackets.
+    fn name(&mut self) -> Result<&'a str, error::InvalidTimeZone> {
+        let (start, end) = if self.peek() == Some(b'<') {
+            let start = self.index + 1;
+            let len = self.input[start..]
+                .iter()
+                .take_while(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'-'))
+                .count();
+            self.index = start + len;
+            self.expect(b'>')?;
+            (start, start + len)
+        } else {
+            let start = self.index;
+            let len = self.input[start..]
+                .iter()
+                .take_while(|byte| byte.is_ascii_alphabetic())
+                .count();
+            self.index = start + len;
+            (start, start + len)
+        };
+
+        if end - start < 3 {
+            return Err(error::InvalidTimeZone::PosixTz { index: start });
+        }
+        match core::str::from_utf8(&self.input[start..end]) {
+            Ok(name) => Ok(name),
+            Err(_) => bug!("name is checked to be ASCII"),
+        }
+    }
+
+    /// Consume a number with at most `max_digits` digits.
+    fn number(&mut self, max_digits: usize) -> Result<i32, error::InvalidTimeZone> {
+        let digits = self.input[self.index..]
+            .iter()
+            .take(max_digits)
+            .take_while(|byte| byte.is_ascii_digit())
+            .fold((0, 0), |(count, value), byte| {
+                (count + 1, value * 10 + (byte - b'0') as i32)
+            });
+        if digits.0 == 0 {
+            return Err(self.error());
+        }
+        self.index += digits.0;
+        Ok(digits.1)
+    }
+
+    /// Consume a signed duration of the form `[+-]hh[:mm[:ss]]`, returning the number of seconds.
+    fn time(&mut self, max_hours: i32) -> Result<i32, error::InvalidTimeZone> {
+        let start = self.index;
+        let sign = match self.peek() {
+            Some(b'-') => {
+                self.index += 1;
+                -1
+            }
+            Some(b'+') => {
+                self.index += 1;
+                1
+            }
+            _ => 1,
+        };
+
+        let hours = self.number(3)?;
+        let mut minutes = 0;
+        let mut seconds = 0;
+        if self.peek() == Some(b':') {
+            self.index += 1;
+            minutes = self.number(2)?;
+            if self.peek() == Some(b':') {
+                self.index += 1;
+                seconds = self.number(2)?;
+            }
+        }
+
+        if hours > max_hours
+            || minutes >= Minute.per(Hour) as i32
+            || seconds >= Second.per(Minute) as i32
+        {
+            return Err(error::InvalidTimeZone::PosixTz { index: start });
+        }
+
+        Ok(
+            sign * (hours * Second.per(Hour) as i32
+                + minutes * Second.per(Minute) as i32
+                + seconds),
+        )
+    }
+
+    /// Consume a POSIX offset. The sign of the result is such that a positive value indicates an
+    /// offset to the west of UTC, as in POSIX.
+    fn offset(&mut self, max_hours: i32) -> Result<i32, error::InvalidTimeZone> {
+        self.time(max_hours)
+    }
+
+    /// Create a local time type from a POSIX offset.
+    fn local_time_type(
+        &self,
+        posix_offset: i32,
+        is_dst: bool,
+        name: &str,
+    ) -> Result<LocalTimeType, error::InvalidTimeZone> {
+        UtcOffset::from_whole_seconds(-posix_offset)
+            .and_then(|offset| LocalTimeType::new(offset, is_dst, name))
+            .map_err(|_| self.error())
+    }
+
+    /// Consume a rule of the form `date[/time]`.
+    fn rule(&mut self) -> Result<(RuleDay, i32), error::InvalidTimeZone> {
+        let start = self.index;
+        let day = match self.peek() {
+            Some(b'J') => {
+                self.index += 1;
+                match self.number(3)? {
+                    day @ 1..=365 => RuleDay::JulianWithoutLeap(day as _),
+                    _ => return Err(error::InvalidTimeZone::PosixTz { index: start }),
+                }
+            }
+            Some(b'M') => {
+                self.index += 1;
+                let month = self.number(2)?;
+                self.expect(b'.')?;
+                let week = self.number(1)?;
+                self.expect(b'.')?;
+                let weekday = self.number(1)?;
+                match (Month::try_from(month as u8), week, weekday) {
+                    (Ok(month), 1..=5, 0..=6) => RuleDay::MonthWeekDay {
+                        month,
+                        week: week as _,
+                        weekday: weekday as _,
+                    },
+                    _ => return Err(error::InvalidTimeZone::PosixTz { index: start }),
+                }
+            }
+            _ => match self.number(3)? {
+                day @ 0..=365 => RuleDay::JulianWithLeap(day as _),
+                _ => return Err(error::InvalidTimeZone::PosixTz { index: start }),
+            },
+        };
+
+        let time = if self.peek() == Some(b'/') {
+            self.index += 1;
+            self.time(167)?
+        } else {
+            2 * Second.per(Hour) as i32
+        };
+
+        Ok((day, time))
+    }
+}
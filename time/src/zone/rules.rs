@@ -0,0 +1,692 @@
+//! Time zone rules backed by a table of offset transitions.
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+use super::{PosixTz, TimeZone};
+use crate::{error, OffsetDateTime, UtcOffset};
+
+/// The maximum length of a time zone abbreviation, in bytes.
+const MAX_ABBREVIATION_LEN: usize = 8;
+
+/// The offset, daylight saving time flag, and abbreviation in effect for some period of time.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocalTimeType {
+    /// The offset from UTC.
+    offset: UtcOffset,
+    /// Whether daylight saving time is in effect.
+    is_dst: bool,
+    /// The length of the abbreviation in bytes.
+    abbreviation_len: u8,
+    /// The ASCII bytes of the abbreviation, padded with zeros.
+    abbreviation: [u8; MAX_ABBREVIATION_LEN],
+}
+
+impl LocalTimeType {
+    /// Create a `LocalTimeType` from its components. The abbreviation must be ASCII and at most
+    /// eight bytes long.
+    ///
+    /// ```rust
+    /// # use time::zone::LocalTimeType;
+    /// # use time_macros::offset;
+    /// let cest = LocalTimeType::new(offset!(+2), true, "CEST")?;
+    /// assert_eq!(cest.abbreviation(), "CEST");
+    /// assert!(LocalTimeType::new(offset!(+2), true, "TOO LONG ABBREVIATION").is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn new(
+        offset: UtcOffset,
+        is_dst: bool,
+        abbreviation: &str,
+    ) -> Result<Self, error::ComponentRange> {
+        let bytes = abbreviation.as_bytes();
+        let len = bytes.len();
+        if len > MAX_ABBREVIATION_LEN {
+            return Err(error::ComponentRange {
+                name: "abbreviation",
+                minimum: 0,
+                maximum: MAX_ABBREVIATION_LEN as _,
+                value: len as _,
+                conditional_range: false,
+            });
+        }
+
+        let mut buf = [0; MAX_ABBREVIATION_LEN];
+        let mut idx = 0;
+        while idx < len {
+            if !bytes[idx].is_ascii() {
+                return Err(error::ComponentRange {
+                    name: "abbreviation",
+                    minimum: 0,
+                    maximum: 0x7F,
+                    value: bytes[idx] as _,
+                    conditional_range: false,
+                });
+            }
+            buf[idx] = bytes[idx];
+            idx += 1;
+        }
+
+        Ok(Self {
+            offset,
+            is_dst,
+            abbreviation_len: len as _,
+            abbreviation: buf,
+        })
+    }
+
+    /// Obtain the offset from UTC.
+    ///
+    /// ```rust
+    /// # use time::zone::LocalTimeType;
+    /// # use time_macros::offset;
+    /// assert_eq!(LocalTimeType::new(offset!(+2), true, "CEST")?.offset(), offset!(+2));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn offset(self) -> UtcOffset {
+        self.offset
+    }
+
+    /// Whether daylight saving time is in effect.
+    ///
+    /// ```rust
+    /// # use time::zone::LocalTimeType;
+    /// # use time_macros::offset;
+    /// assert!(LocalTimeType::new(offset!(+2), true, "CEST")?.is_dst());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn is_dst(self) -> bool {
+        self.is_dst
+    }
+
+    /// Obtain the abbreviation, such as `CEST` or `PST`.
+    ///
+    /// ```rust
+    /// # use time::zone::LocalTimeType;
+    /// # use time_macros::offset;
+    /// assert_eq!(
+    ///     LocalTimeType::new(offset!(-8), false, "PST")?.abbreviation(),
+    ///     "PST"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn abbreviation(&self) -> &str {
+        match core::str::from_utf8(&self.abbreviation[..self.abbreviation_len as usize]) {
+            Ok(abbreviation) => abbreviation,
+            Err(_) => bug!("abbreviation is checked to be ASCII"),
+        }
+    }
+}
+
+impl fmt::Debug for LocalTimeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalTimeType")
+            .field("offset", &self.offset)
+            .field("is_dst", &self.is_dst)
+            .field("abbreviation", &self.abbreviation())
+            .finish()
+    }
+}
+
+/// A change to a new [`LocalTimeType`] at a given instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Transition {
+    /// The instant of the transition as a Unix timestamp.
+    unix_timestamp: i64,
+    /// The local time type in effect from the transition onwards.
+    local_time_type: LocalTimeType,
+}
+
+impl Transition {
+    /// Create a `Transition` to the provided local time type at the given Unix timestamp.
+    ///
+    /// ```rust
+    /// # use time::zone::{LocalTimeType, Transition};
+    /// # use time_macros::offset;
+    /// let cest = LocalTimeType::new(offset!(+2), true, "CEST")?;
+    /// let transition = Transition::new(1_711_846_800, cest);
+    /// assert_eq!(transition.unix_timestamp(), 1_711_846_800);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn new(unix_timestamp: i64, local_time_type: LocalTimeType) -> Self {
+        Self {
+            unix_timestamp,
+            local_time_type,
+        }
+    }
+
+    /// Obtain the instant of the transition as a Unix timestamp.
+    ///
+    /// ```rust
+    /// # use time::zone::{LocalTimeType, Transition};
+    /// # use time::UtcOffset;
+    /// let utc = LocalTimeType::new(UtcOffset::UTC, false, "UTC")?;
+    /// assert_eq!(Transition::new(0, utc).unix_timestamp(), 0);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn unix_timestamp(self) -> i64 {
+        self.unix_timestamp
+    }
+
+    /// Obtain the local time type in effect from the transition onwards.
+    ///
+    /// ```rust
+    /// # use time::zone::{LocalTimeType, Transition};
+    /// # use time::UtcOffset;
+    /// let utc = LocalTimeType::new(UtcOffset::UTC, false, "UTC")?;
+    /// assert_eq!(Transition::new(0, utc).local_time_type(), utc);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn local_time_type(self) -> LocalTimeType {
+        self.local_time_type
+    }
+}
+
+/// Time zone rules defined by a table of transitions.
+///
+/// Rules can be constructed in a `const` context, which allows a fixed table to be embedded in a
+/// program:
+///
+/// ```rust
+/// # use time::zone::{LocalTimeType, TimeZone, Transition, ZoneRules};
+/// # use time_macros::{datetime, offset};
+/// const CET: LocalTimeType = match LocalTimeType::new(offset!(+1), false, "CET") {
+///     Ok(ty) => ty,
+///     Err(_) => panic!(),
+/// };
+/// const CEST: LocalTimeType = match LocalTimeType::new(offset!(+2), true, "CEST") {
+///     Ok(ty) => ty,
+///     Err(_) => panic!(),
+/// };
+/// const TRANSITIONS: [Transition; 2] = [
+///     Transition::new(1_711_846_800, CEST), // 2024-03-31 01:00 UTC
+///     Transition::new(1_729_990_800, CET),  // 2024-10-27 01:00 UTC
+/// ];
+/// const PARIS_2024: ZoneRules<'static> = ZoneRules::new(CET, &TRANSITIONS);
+///
+/// assert_eq!(
+///     PARIS_2024.offset_at_utc(datetime!(2024-07-01 0:00 UTC)),
+///     offset!(+2),
+/// );
+/// assert!(PARIS_2024.offset_at_local(datetime!(2024-10-27 2:30)).is_ambiguous());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ZoneRules<'a> {
+    /// The local time type in effect before the first transition.
+    pub(super) initial: LocalTimeType,
+    /// All transitions, sorted by their instant.
+    pub(super) transitions: &'a [Transition],
+    /// The rule in effect after the last transition.
+    pub(super) posix_tz: Option<PosixTz>,
+}
+
+impl<'a> ZoneRules<'a> {
+    /// Create rules from the local time type in effect before the first transition and a table of
+    /// transitions. The transitions must be sorted by their instant in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// This may panic if the transitions are not sorted. In a `const` context, this results in a
+    /// compilation error.
+    pub const fn new(initial: LocalTimeType, transitions: &'a [Transition]) -> Self {
+        let mut idx = 1;
+        while idx < transitions.len() {
+            assert!(
+                transitions[idx - 1].unix_timestamp < transitions[idx].unix_timestamp,
+                "transitions must be sorted in ascending order"
+            );
+            idx += 1;
+        }
+
+        Self {
+            initial,
+            transitions,
+            posix_tz: None,
+        }
+    }
+
+    /// Create rules where the provided local time type is always in effect.
+    ///
+    /// ```rust
+    /// # use time::zone::{LocalTimeType, TimeZone, ZoneRules};
+    /// # use time::UtcOffset;
+    /// # use time_macros::datetime;
+    /// let utc = ZoneRules::fixed(LocalTimeType::new(UtcOffset::UTC, false, "UTC")?);
+    /// assert_eq!(utc.offset_at_utc(datetime!(2024-01-01 0:00 UTC)), UtcOffset::UTC);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn fixed(local_time_type: LocalTimeType) -> Self {
+        Self {
+            initial: local_time_type,
+            transitions: &[],
+            posix_tz: None,
+        }
+    }
+
+    /// Use the provided rule for all instants after the last transition, or for all instants if
+    /// there are no transitions. This mirrors the footer of a TZif file.
+    ///
+    /// ```rust
+    /// # use time::zone::{LocalTimeType, PosixTz, TimeZone, ZoneRules};
+    /// # use time_macros::{datetime, offset};
+    /// let lmt = LocalTimeType::new(offset!(-4:56:02), false, "LMT")?;
+    /// let rules = ZoneRules::new(lmt, &[]).with_posix_tz(PosixTz::parse("EST5EDT")?);
+    /// assert_eq!(rules.offset_at_utc(datetime!(2050-07-01 0:00 UTC)), offset!(-4));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn with_posix_tz(self, posix_tz: PosixTz) -> Self {
+        Self {
+            posix_tz: Some(posix_tz),
+            ..self
+        }
+    }
+
+    /// Obtain the local time type in effect before the first transition.
+    ///
+    /// ```rust
+    /// # use time::zone::{LocalTimeType, ZoneRules};
+    /// # use time::UtcOffset;
+    /// let utc = LocalTimeType::new(UtcOffset::UTC, false, "UTC")?;
+    /// assert_eq!(ZoneRules::fixed(utc).initial(), utc);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn initial(self) -> LocalTimeType {
+        self.initial
+    }
+
+    /// Obtain the table of transitions.
+    ///
+    /// ```rust
+    /// # use time::zone::{LocalTimeType, Transition, ZoneRules};
+    /// # use time_macros::offset;
+    /// let cet = LocalTimeType::new(offset!(+1), false, "CET")?;
+    /// let cest = LocalTimeType::new(offset!(+2), true, "CEST")?;
+    /// let transitions = [Transition::new(1_711_846_800, cest)];
+    /// assert_eq!(ZoneRules::new(cet, &transitions).transitions(), transitions);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn transitions(self) -> &'a [Transition] {
+        self.transitions
+    }
+
+    /// Obtain the rule in effect after the last transition, if any.
+    ///
+    /// ```rust
+    /// # use time::zone::{PosixTz, ZoneRules};
+    /// let posix_tz = PosixTz::parse("EST5EDT")?;
+    /// let rules = ZoneRules::fixed(posix_tz.standard());
+    /// assert_eq!(rules.posix_tz(), None);
+    /// assert_eq!(rules.with_posix_tz(posix_tz).posix_tz(), Some(posix_tz));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn posix_tz(self) -> Option<PosixTz> {
+        self.posix_tz
+    }
+
+    /// Obtain the local time type in effect at the provided instant.
+    ///
+    /// ```rust
+    /// # use time::zone::{LocalTimeType, Transition, ZoneRules};
+    /// # use time_macros::{datetime, offset};
+    /// let lmt = LocalTimeType::new(offset!(+0:09:21), false, "LMT")?;
+    /// let pmt = LocalTimeType::new(offset!(+0:09:21), false, "PMT")?;
+    /// let transitions = [Transition::new(-2_486_592_561, pmt)];
+    /// let rules = ZoneRules::new(lmt, &transitions);
+    /// assert_eq!(
+    ///     rules.local_time_type_at(datetime!(1800-01-01 0:00 UTC)).abbreviation(),
+    ///     "LMT",
+    /// );
+    /// assert_eq!(
+    ///     rules.local_time_type_at(datetime!(1900-01-01 0:00 UTC)).abbreviation(),
+    ///     "PMT",
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn local_time_type_at(self, datetime: OffsetDateTime) -> LocalTimeType {
+        let timestamp = datetime.unix_timestamp();
+        let idx = self
+            .transitions
+            .partition_point(|transition| transition.unix_timestamp <= timestamp);
+
+        if let Some(posix_tz) = self.posix_tz {
+            if idx == self.transitions.len() {
+                return posix_tz.local_time_type_at(datetime);
+            }
+        }
+
+        match idx {
+            0 => self.initial,
+            idx => self.transitions[idx - 1].local_time_type,
+        }
+    }
+
+    /// Obtain the first transition strictly after the provided instant, if any.
+    ///
+    /// Only transitions that change the offset, daylight saving time flag, or abbreviation are
+    /// considered.
+    ///
+    /// ```rust
+    /// # use time::zone::{PosixTz, ZoneRules};
+    /// # use time_macros::{datetime, offset};
+    /// let posix_tz = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3")?;
+    /// let rules = ZoneRules::fixed(posix_tz.standard()).with_posix_tz(posix_tz);
+    /// let transition = rules.next_transition(datetime!(2024-01-01 0:00 UTC)).unwrap();
+    /// assert_eq!(transition.datetime(), datetime!(2024-03-31 3:00 +2));
+    /// assert_eq!(transition.old_offset(), offset!(+1));
+    /// assert_eq!(transition.new_offset(), offset!(+2));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn next_transition(self, datetime: OffsetDateTime) -> Option<ZoneTransition> {
+        self.first_transition_from(datetime.unix_timestamp().checked_add(1)?)
+    }
+
+    /// Obtain the last transition strictly before the provided instant, if any.
+    ///
+    /// Only transitions that change the offset, daylight saving time flag, or abbreviation are
+    /// considered.
+    ///
+    /// ```rust
+    /// # use time::zone::{PosixTz, ZoneRules};
+    /// # use time_macros::datetime;
+    /// let posix_tz = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3")?;
+    /// let rules = ZoneRules::fixed(posix_tz.standard()).with_posix_tz(posix_tz);
+    /// let transition = rules.previous_transition(datetime!(2024-01-01 0:00 UTC)).unwrap();
+    /// assert_eq!(transition.datetime(), datetime!(2023-10-29 2:00 +1));
+    /// assert_eq!(transition.abbreviation(), "CET");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn previous_transition(self, datetime: OffsetDateTime) -> Option<ZoneTransition> {
+        let timestamp = datetime.unix_timestamp();
+
+        if let Some(posix_tz) = self.posix_tz {
+            let last = self.last_table_timestamp();
+            // Near the limits of the supported range, the instant may not be representable in
+            // UTC. No transition can occur beyond the range, so the local year is used instead.
+            let year = datetime
+                .checked_to_offset(UtcOffset::UTC)
+                .map_or_else(|| datetime.year(), OffsetDateTime::year);
+            // Any rule with daylight saving time has transitions every year, so looking back a
+            // few years is sufficient.
+            for year in (year - 2..=year).rev() {
+                for transition in posix_tz
+                    .transitions_in_year(year)
+                    .into_iter()
+                    .flatten()
+                    .rev()
+                {
+                    let candidate = transition.unix_timestamp;
+                    if candidate < timestamp && last.map_or(true, |last| candidate > last) {
+                        if let Some(transition) = self.transition_at(candidate) {
+                            return Some(transition);
+                        }
+                    }
+                }
+            }
+        }
+
+        let idx = self
+            .transitions
+            .partition_point(|transition| transition.unix_timestamp < timestamp);
+        self.transitions[..idx]
+            .iter()
+            .rev()
+            .find_map(|transition| self.transition_at(transition.unix_timestamp))
+    }
+
+    /// Obtain an iterator over all transitions from `start` (inclusive) to `end` (exclusive), in
+    /// ascending order.
+    ///
+    /// Only transitions that change the offset, daylight saving time flag, or abbreviation are
+    /// included.
+    ///
+    /// ```rust
+    /// # use time::zone::{PosixTz, ZoneRules};
+    /// # use time_macros::datetime;
+    /// let posix_tz = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3")?;
+    /// let rules = ZoneRules::fixed(posix_tz.standard()).with_posix_tz(posix_tz);
+    /// let transitions = rules
+    ///     .transitions_between(datetime!(2024-01-01 0:00 UTC), datetime!(2026-01-01 0:00 UTC))
+    ///     .map(|transition| transition.datetime())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     transitions,
+    ///     [
+    ///         datetime!(2024-03-31 3:00 +2),
+    ///         datetime!(2024-10-27 2:00 +1),
+    ///         datetime!(2025-03-30 3:00 +2),
+    ///         datetime!(2025-10-26 2:00 +1),
+    ///     ]
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn transitions_between(
+        self,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> TransitionsBetween<'a> {
+        TransitionsBetween {
+            rules: self,
+            start: Some(start.unix_timestamp()),
+            end: end.unix_timestamp(),
+        }
+    }
+
+    /// Obtain the first transition at or after the provided Unix timestamp.
+    fn first_transition_from(self, timestamp: i64) -> Option<ZoneTransition> {
+        let idx = self
+            .transitions
+            .partition_point(|transition| transition.unix_timestamp < timestamp);
+        if let Some(transition) = self.transitions[idx..]
+            .iter()
+            .find_map(|transition| self.transition_at(transition.unix_timestamp))
+        {
+            return Some(transition);
+        }
+
+        let posix_tz = self.posix_tz?;
+        let last = self.last_table_timestamp();
+        let start = match last {
+            Some(last) => timestamp.max(last.checked_add(1)?),
+            None => timestamp,
+        };
+        let year = OffsetDateTime::from_unix_timestamp(start).ok()?.year();
+        // Any rule with daylight saving time has transitions every year, so looking ahead a few
+        // years is sufficient.
+        for year in year - 1..=year + 2 {
+            for transition in posix_tz.transitions_in_year(year).into_iter().flatten() {
+                if transition.unix_timestamp >= start {
+                    if let Some(transition) = self.transition_at(transition.unix_timestamp) {
+                        return Some(transition);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Obtain the transition at the provided Unix timestamp if the local time type changes at
+    /// that instant.
+    fn transition_at(self, timestamp: i64) -> Option<ZoneTransition> {
+        let datetime = OffsetDateTime::from_unix_timestamp(timestamp).ok()?;
+        let old = self.local_time_type_at(OffsetDateTime::from_unix_timestamp(timestamp - 1).ok()?);
+        let new = self.local_time_type_at(datetime);
+        if old == new {
+            return None;
+        }
+        Some(ZoneTransition {
+            datetime: datetime.checked_to_offset(new.offset)?,
+            old,
+            new,
+        })
+    }
+
+    /// Obtain the Unix timestamp of the last transition in the table, if any.
+    fn last_table_timestamp(self) -> Option<i64> {
+        self.transitions
+            .last()
+            .map(|transition| transition.unix_timestamp)
+    }
+}
+
+impl TimeZone for ZoneRules<'_> {
+    fn offset_at_utc(&self, datetime: OffsetDateTime) -> UtcOffset {
+        self.local_time_type_at(datetime).offset
+    }
+
+    fn local_time_type_at_utc(&self, datetime: OffsetDateTime) -> Option<LocalTimeType> {
+        Some(self.local_time_type_at(datetime))
+    }
+}
+
+/// A change from one [`LocalTimeType`] to another, as found by
+/// [`ZoneRules::next_transition`], [`ZoneRules::previous_transition`], and
+/// [`ZoneRules::transitions_between`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ZoneTransition {
+    /// The instant of the transition, in the offset in effect from the transition onwards.
+    datetime: OffsetDateTime,
+    /// The local time type in effect immediately before the transition.
+    old: LocalTimeType,
+    /// The local time type in effect from the transition onwards.
+    new: LocalTimeType,
+}
+
+impl ZoneTransition {
+    /// Obtain the instant of the transition, expressed in the new offset.
+    ///
+    /// ```rust
+    /// # use time::zone::{PosixTz, ZoneRules};
+    /// # use time_macros::datetime;
+    /// let posix_tz = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3")?;
+    /// let rules = ZoneRules::fixed(posix_tz.standard()).with_posix_tz(posix_tz);
+    /// let transition = rules.next_transition(datetime!(2024-01-01 0:00 UTC)).unwrap();
+    /// assert_eq!(transition.datetime(), datetime!(2024-03-31 3:00 +2));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn datetime(self) -> OffsetDateTime {
+        self.datetime
+    }
+
+    /// Obtain the offset in effect immediately before the transition.
+    ///
+    /// ```rust
+    /// # use time::zone::{PosixTz, ZoneRules};
+    /// # use time_macros::{datetime, offset};
+    /// let posix_tz = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3")?;
+    /// let rules = ZoneRules::fixed(posix_tz.standard()).with_posix_tz(posix_tz);
+    /// let transition = rules.next_transition(datetime!(2024-01-01 0:00 UTC)).unwrap();
+    /// assert_eq!(transition.old_offset(), offset!(+1));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn old_offset(self) -> UtcOffset {
+        self.old.offset
+    }
+
+    /// Obtain the offset in effect from the transition onwards.
+    ///
+    /// ```rust
+    /// # use time::zone::{PosixTz, ZoneRules};
+    /// # use time_macros::{datetime, offset};
+    /// let posix_tz = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3")?;
+    /// let rules = ZoneRules::fixed(posix_tz.standard()).with_posix_tz(posix_tz);
+    /// let transition = rules.next_transition(datetime!(2024-01-01 0:00 UTC)).unwrap();
+    /// assert_eq!(transition.new_offset(), offset!(+2));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn new_offset(self) -> UtcOffset {
+        self.new.offset
+    }
+
+    /// Whether daylight saving time is in effect from the transition onwards.
+    ///
+    /// ```rust
+    /// # use time::zone::{PosixTz, ZoneRules};
+    /// # use time_macros::datetime;
+    /// let posix_tz = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3")?;
+    /// let rules = ZoneRules::fixed(posix_tz.standard()).with_posix_tz(posix_tz);
+    /// let transition = rules.next_transition(datetime!(2024-01-01 0:00 UTC)).unwrap();
+    /// assert!(transition.is_dst());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn is_dst(self) -> bool {
+        self.new.is_dst
+    }
+
+    /// Obtain the abbreviation in effect from the transition onwards.
+    ///
+    /// ```rust
+    /// # use time::zone::{PosixTz, ZoneRules};
+    /// # use time_macros::datetime;
+    /// let posix_tz = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3")?;
+    /// let rules = ZoneRules::fixed(posix_tz.standard()).with_posix_tz(posix_tz);
+    /// let transition = rules.next_transition(datetime!(2024-01-01 0:00 UTC)).unwrap();
+    /// assert_eq!(transition.abbreviation(), "CEST");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn abbreviation(&self) -> &str {
+        self.new.abbreviation()
+    }
+
+    /// Obtain the local time type in effect immediately before the transition.
+    ///
+    /// ```rust
+    /// # use time::zone::{PosixTz, ZoneRules};
+    /// # use time_macros::datetime;
+    /// let posix_tz = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3")?;
+    /// let rules = ZoneRules::fixed(posix_tz.standard()).with_posix_tz(posix_tz);
+    /// let transition = rules.next_transition(datetime!(2024-01-01 0:00 UTC)).unwrap();
+    /// assert_eq!(transition.old_local_time_type(), posix_tz.standard());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn old_local_time_type(self) -> LocalTimeType {
+        self.old
+    }
+
+    /// Obtain the local time type in effect from the transition onwards.
+    ///
+    /// ```rust
+    /// # use time::zone::{PosixTz, ZoneRules};
+    /// # use time_macros::datetime;
+    /// let posix_tz = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3")?;
+    /// let rules = ZoneRules::fixed(posix_tz.standard()).with_posix_tz(posix_tz);
+    /// let transition = rules.next_transition(datetime!(2024-01-01 0:00 UTC)).unwrap();
+    /// assert_eq!(transition.new_local_time_type(), posix_tz.daylight().unwrap());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn new_local_time_type(self) -> LocalTimeType {
+        self.new
+    }
+}
+
+/// An iterator over the transitions of a [`ZoneRules`] within a range, created by
+/// [`ZoneRules::transitions_between`].
+#[derive(Debug, Clone)]
+pub struct TransitionsBetween<'a> {
+    /// The rules being iterated over.
+    rules: ZoneRules<'a>,
+    /// The Unix timestamp from which to search for the next transition, or `None` if iteration
+    /// has finished.
+    start: Option<i64>,
+    /// The Unix timestamp before which transitions are included.
+    end: i64,
+}
+
+impl Iterator for TransitionsBetween<'_> {
+    type Item = ZoneTransition;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.start.filter(|&start| start < self.end)?;
+        match self.rules.first_transition_from(start) {
+            Some(transition) if transition.datetime.unix_timestamp() < self.end => {
+                self.start = transition.datetime.unix_timestamp().checked_add(1);
+                Some(transition)
+            }
+            _ => {
+                self.start = None;
+                None
+            }
+        }
+    }
+}
+
+impl FusedIterator for TransitionsBetween<'_> {}
@@ -0,0 +1,398 @@
+//! Time zone information in the TZif format, as described in RFC 8536.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::{io, path::Path};
+
+use super::{LocalTimeType, PosixTz, TimeZone, Transition, ZoneRules};
+use crate::{error, OffsetDateTime, UtcOffset};
+
+/// The directory searched for named time zones when `TZDIR` is not set.
+#[cfg(all(feature = "std", target_family = "unix"))]
+const DEFAULT_ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+
+/// The file describing the system's time zone when `TZ` is not set.
+#[cfg(all(feature = "std", target_family = "unix"))]
+const LOCALTIME_PATH: &str = "/etc/localtime";
+
+/// Time zone rules loaded from a TZif file, such as those found in `/usr/share/zoneinfo`.
+///
+/// ```rust,no_run
+/// # use time::zone::{TimeZone, Tzif};
+/// # use time_macros::{datetime, offset};
+/// let paris = Tzif::parse(&std::fs::read("/usr/share/zoneinfo/Europe/Paris")?)?;
+/// assert_eq!(paris.offset_at_utc(datetime!(2024-07-01 0:00 UTC)), offset!(+2));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tzif {
+    /// The name of the time zone, such as `Europe/Paris`, if known.
+    name: Option<String>,
+    /// The local time type in effect before the first transition.
+    initial: LocalTimeType,
+    /// All transitions, sorted by their instant.
+    transitions: Vec<Transition>,
+    /// The rule in effect after the last transition.
+    posix_tz: Option<PosixTz>,
+}
+
+impl Tzif {
+    /// Parse the contents of a TZif file. Versions 1 through 4 are supported. Leap second
+    /// records are ignored.
+    pub fn parse(bytes: &[u8]) -> Result<Self, error::InvalidTimeZone> {
+        let mut reader = Reader { bytes };
+
+        let header = Header::read(&mut reader)?;
+        let (header, time_size) = if header.version == 0 {
+            (header, 4)
+        } else {
+            // The version 1 data block is only present for backwards compatibility.
+            reader.take(header.data_block_len(4))?;
+            let header = Header::read(&mut reader)?;
+            (header, 8)
+        };
+        // Checking this up front avoids allocating based on counts that cannot be valid.
+        if reader.bytes.len() < header.data_block_len(time_size) {
+            return Err(tzif_error("unexpected end of data"));
+        }
+
+        let mut timestamps = Vec::with_capacity(header.timecnt);
+        for _ in 0..header.timecnt {
+            let timestamp = if time_size == 4 {
+                reader.i32()? as i64
+            } else {
+                reader.i64()?
+            };
+            if timestamps
+                .last()
+                .map_or(false, |&previous| previous >= timestamp)
+            {
+                return Err(tzif_error("transition times are not sorted"));
+            }
+            timestamps.push(timestamp);
+        }
+        let indices = reader.take(header.timecnt)?;
+
+        let mut raw_types = Vec::with_capacity(header.typecnt);
+        for _ in 0..header.typecnt {
+            let utoff = reader.i32()?;
+            let is_dst = match reader.u8()? {
+                0 => false,
+                1 => true,
+                _ => return Err(tzif_error("invalid DST indicator")),
+            };
+            let idx = reader.u8()? as usize;
+            raw_types.push((utoff, is_dst, idx));
+        }
+        let designations = reader.take(header.charcnt)?;
+
+        let mut types = Vec::with_capacity(raw_types.len());
+        for (utoff, is_dst, idx) in raw_types {
+            let designation = designations
+                .get(idx..)
+                .and_then(|rest| rest.split(|&byte| byte == 0).next())
+                .filter(|designation| designation.len() < designations.len() - idx)
+                .and_then(|designation| core::str::from_utf8(designation).ok())
+                .ok_or_else(|| tzif_error("invalid time zone designation"))?;
+            let offset = UtcOffset::from_whole_seconds(utoff)
+                .map_err(|_| tzif_error("UTC offset out of range"))?;
+            let local_time_type = LocalTimeType::new(offset, is_dst, designation)
+                .map_err(|_| tzif_error("invalid time zone designation"))?;
+            types.push(local_time_type);
+        }
+
+        let mut transitions = Vec::with_capacity(timestamps.len());
+        for (timestamp, &idx) in timestamps.into_iter().zip(indices) {
+            let local_time_type = *types
+                .get(idx as usize)
+                .ok_or_else(|| tzif_error("local time type index out of range"))?;
+            transitions.push(Transition::new(timestamp, local_time_type));
+        }
+
+        reader.take(header.leapcnt * (time_size + 4))?;
+        reader.take(header.isstdcnt)?;
+        reader.take(header.isutcnt)?;
+
+        let posix_tz = if header.version == 0 {
+            None
+        } else {
+            let footer = match reader.bytes {
+                [b'\n', footer @ .., b'\n'] => footer,
+                _ => return Err(tzif_error("invalid footer")),
+            };
+            let footer = core::str::from_utf8(footer).map_err(|_| tzif_error("invalid footer"))?;
+            if footer.is_empty() {
+                None
+            } else {
+                Some(PosixTz::parse(footer).map_err(|_| tzif_error("invalid footer"))?)
+            }
+        };
+
+        Ok(Self {
+            name: None,
+            initial: types[0],
+            transitions,
+            posix_tz,
+        })
+    }
+
+    /// Obtain the name of the time zone, such as `Europe/Paris`, if it is known.
+    ///
+    /// The name is only known when the rules were loaded by name, either explicitly or via the
+    /// `TZ` environment variable or the `/etc/localtime` symbolic link.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the rules contained in the file.
+    pub fn rules(&self) -> ZoneRules<'_> {
+        // The transitions were checked to be sorted when parsing.
+        ZoneRules {
+            initial: self.initial,
+            transitions: &self.transitions,
+            posix_tz: self.posix_tz,
+        }
+    }
+
+    /// Obtain the local time type in effect at the provided instant.
+    pub fn local_time_type_at(&self, datetime: OffsetDateTime) -> LocalTimeType {
+        self.rules().local_time_type_at(datetime)
+    }
+
+    /// Rules for UTC, which are used when no other information is available.
+    fn utc() -> Self {
+        let utc = match LocalTimeType::new(UtcOffset::UTC, false, "UTC") {
+            Ok(utc) => utc,
+            Err(_) => bug!("abbreviation is valid"),
+        };
+        Self {
+            name: Some(String::from("UTC")),
+            initial: utc,
+            transitions: Vec::new(),
+            posix_tz: None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Tzif {
+    /// Read and parse the TZif file at the provided path. Invalid data is reported as an error
+    /// of kind [`io::ErrorKind::InvalidData`].
+    pub fn from_path(path: impl AsRef<Path>) -> io::Result<Self> {
+        let bytes = std::fs::read(path)?;
+        Self::parse(&bytes).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// Load rules as described by a value of the `TZ` environment variable.
+    ///
+    /// - An empty value means UTC.
+    /// - A leading `:` is ignored.
+    /// - An absolute path is read as a TZif file.
+    /// - Any other value is looked up as a TZif file in `zoneinfo_dir`. If no such file exists, the
+    ///   value is parsed as a POSIX TZ string, such as `EST5EDT,M3.2.0,M11.1.0`.
+    ///
+    /// ```rust,no_run
+    /// # use time::zone::Tzif;
+    /// let zone = Tzif::from_tz("Europe/Paris", "/usr/share/zoneinfo")?;
+    /// assert_eq!(zone.name(), Some("Europe/Paris"));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn from_tz(tz: &str, zoneinfo_dir: impl AsRef<Path>) -> io::Result<Self> {
+        let tz = tz.strip_prefix(':').unwrap_or(tz);
+
+        if tz.is_empty() {
+            return Ok(Self::utc());
+        }
+        if tz.starts_with('/') {
+            return Self::from_path(tz);
+        }
+
+        // Disallow escaping the zoneinfo directory.
+        let err = if tz.split('/').any(|component| component == "..") {
+            io::Error::new(io::ErrorKind::InvalidInput, "invalid time zone name")
+        } else {
+            match Self::from_path(zoneinfo_dir.as_ref().join(tz)) {
+                Ok(zone) => {
+                    return Ok(Self {
+                        name: Some(String::from(tz)),
+                        ..zone
+                    });
+                }
+                Err(err) => err,
+            }
+        };
+
+        match PosixTz::parse(tz) {
+            Ok(posix_tz) => Ok(Self {
+                name: None,
+                initial: posix_tz.standard(),
+                transitions: Vec::new(),
+                posix_tz: Some(posix_tz),
+            }),
+            Err(_) => Err(err),
+        }
+    }
+
+    /// Load the system's time zone, following the conventions of the C library on Unix.
+    ///
+    /// The `TZ` environment variable is used if it is set, with named time zones being looked up
+    /// in `TZDIR` (defaulting to `/usr/share/zoneinfo`). Otherwise `/etc/localtime` is read. If
+    /// that file does not exist, UTC is assumed.
+    ///
+    /// Unlike the C library, this does not access the environment in a way that is unsound when
+    /// other threads are running.
+    #[cfg(target_family = "unix")]
+    pub fn local() -> io::Result<Self> {
+        let zoneinfo_dir = std::env::var_os("TZDIR");
+        let zoneinfo_dir = zoneinfo_dir
+            .as_deref()
+            .map_or_else(|| Path::new(DEFAULT_ZONEINFO_DIR), Path::new);
+
+        if let Some(tz) = std::env::var_os("TZ") {
+            let tz = tz
+                .to_str()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid TZ"))?;
+            return Self::from_tz(tz, zoneinfo_dir);
+        }
+
+        match Self::from_path(LOCALTIME_PATH) {
+            Ok(zone) => {
+                // The name is only known if `/etc/localtime` links into a zoneinfo directory.
+                let name = std::fs::read_link(LOCALTIME_PATH).ok().and_then(|target| {
+                    let target = target.to_str()?;
+                    let idx = target.rfind("zoneinfo/")?;
+                    Some(String::from(&target[idx + "zoneinfo/".len()..]))
+                });
+                Ok(Self { name, ..zone })
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::utc()),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl TimeZone for Tzif {
+    fn offset_at_utc(&self, datetime: OffsetDateTime) -> UtcOffset {
+        self.rules().offset_at_utc(datetime)
+    }
+
+    fn name(&self) -> Option<&str> {
+        self.name()
+    }
+
+    fn local_time_type_at_utc(&self, datetime: OffsetDateTime) -> Option<LocalTimeType> {
+        Some(self.local_time_type_at(datetime))
+    }
+}
+
+/// Create an error for malformed TZif data.
+const fn tzif_error(reason: &'static str) -> error::InvalidTimeZone {
+    error::InvalidTimeZone::Tzif { reason }
+}
+
+/// The header of a TZif data block.
+struct Header {
+    /// The version number, where version 1 is represented as zero.
+    version: u8,
+    /// The number of UT/local indicators.
+    isutcnt: usize,
+    /// The number of standard/wall indicators.
+    isstdcnt: usize,
+    /// The number of leap second records.
+    leapcnt: usize,
+    /// The number of transition times.
+    timecnt: usize,
+    /// The number of local time type records.
+    typecnt: usize,
+    /// The number of bytes of time zone designations.
+    charcnt: usize,
+}
+
+impl Header {
+    /// Read a header, validating its contents.
+    fn read(reader: &mut Reader<'_>) -> Result<Self, error::InvalidTimeZone> {
+        if reader.take(4)? != b"TZif" {
+            return Err(tzif_error("missing magic bytes"));
+        }
+        let version = match reader.u8()? {
+            0 => 0,
+            version @ b'2'..=b'4' => version - b'0',
+            _ => return Err(tzif_error("unsupported version")),
+        };
+        reader.take(15)?;
+
+        let header = Self {
+            version,
+            isutcnt: reader.u32()? as _,
+            isstdcnt: reader.u32()? as _,
+            leapcnt: reader.u32()? as _,
+            timecnt: reader.u32()? as _,
+            typecnt: reader.u32()? as _,
+            charcnt: reader.u32()? as _,
+        };
+
+        if header.typecnt == 0
+            || header.charcnt == 0
+            || (header.isutcnt != 0 && header.isutcnt != header.typecnt)
+            || (header.isstdcnt != 0 && header.isstdcnt != header.typecnt)
+        {
+            return Err(tzif_error("invalid header"));
+        }
+
+        Ok(header)
+    }
+
+    /// The length in bytes of the data block following the header.
+    const fn data_block_len(&self, time_size: usize) -> usize {
+        self.timecnt
+            .saturating_mul(time_size + 1)
+            .saturating_add(self.typecnt.saturating_mul(6))
+            .saturating_add(self.charcnt)
+            .saturating_add(self.leapcnt.saturating_mul(time_size + 4))
+            .saturating_add(self.isstdcnt)
+            .saturating_add(self.isutcnt)
+    }
+}
+
+/// A cursor over the bytes of a TZif file.
+struct Reader<'a> {
+    /// The bytes that have not yet been consumed.
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Consume the given number of bytes.
+    fn take(&mut self, len: usize) -> Result<&'a [u8], error::InvalidTimeZone> {
+        if self.bytes.len() < len {
+            return Err(tzif_error("unexpected end of data"));
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    /// Consume a single byte.
+    fn u8(&mut self) -> Result<u8, error::InvalidTimeZone> {
+        Ok(self.take(1)?[0])
+    }
+
+    /// Consume a big-endian `u32`.
+    fn u32(&mut self) -> Result<u32, error::InvalidTimeZone> {
+        let mut buf = [0; 4];
+        buf.copy_from_slice(self.take(4)?);
+        Ok(u32::from_be_bytes(buf))
+    }
+
+    /// Consume a big-endian `i32`.
+    fn i32(&mut self) -> Result<i32, error::InvalidTimeZone> {
+        Ok(self.u32()? as i32)
+    }
+
+    /// Consume a big-endian `i64`.
+    fn i64(&mut self) -> Result<i64, error::InvalidTimeZone> {
+        let mut buf = [0; 8];
+        buf.copy_from_slice(self.take(8)?);
+        Ok(i64::from_be_bytes(buf))
+    }
+}